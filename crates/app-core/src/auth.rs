@@ -4,15 +4,26 @@
 //! account creation, and session validation.
 
 use atproto_client::{
-    session::{SessionAccount, SessionManager, SessionManagerError},
-    AgentError,
+    api::com::atproto::server::{
+        activate_account, confirm_email, create_app_password, deactivate_account, delete_account,
+        list_app_passwords, request_account_delete, request_email_update, revoke_app_password,
+        update_email,
+    },
+    session::{
+        session_credential, SessionAccount, SessionCredential, SessionManager, SessionManagerError,
+    },
+    xrpc::EndpointError,
+    AgentError, BskyAgent,
 };
 use chrono::Duration;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use storage::kv::{AccountStore, KvError};
 use thiserror::Error;
 use tokio::sync::RwLock;
 
+use crate::handles::HandleChangeService;
+
 /// Authentication service error types
 #[derive(Debug, Error)]
 pub enum AuthError {
@@ -71,6 +82,91 @@ pub enum AuthError {
     /// Email validation error
     #[error("Invalid email address")]
     InvalidEmail,
+
+    /// App password name is empty
+    #[error("App password name cannot be empty")]
+    InvalidAppPasswordName,
+
+    /// Emailed token is invalid
+    #[error("Invalid email token")]
+    InvalidEmailToken,
+
+    /// Emailed token has expired
+    #[error("Email token has expired")]
+    ExpiredEmailToken,
+
+    /// Changing the email needs a token from `request_email_update`
+    #[error("An emailed token is required to change the email address")]
+    EmailTokenRequired,
+
+    /// Local account storage error
+    #[error("Storage error: {0}")]
+    Storage(#[from] KvError),
+}
+
+impl From<EndpointError<list_app_passwords::Error>> for AuthError {
+    fn from(error: EndpointError<list_app_passwords::Error>) -> Self {
+        match error {
+            EndpointError::Declared {
+                kind: list_app_passwords::Error::AccountTakedown,
+                error,
+            } => Self::AccountSuspended(error.message().to_string()),
+            EndpointError::Other(error) => Self::Network(error.to_string()),
+        }
+    }
+}
+
+impl From<EndpointError<create_app_password::Error>> for AuthError {
+    fn from(error: EndpointError<create_app_password::Error>) -> Self {
+        match error {
+            EndpointError::Declared {
+                kind: create_app_password::Error::AccountTakedown,
+                error,
+            } => Self::AccountSuspended(error.message().to_string()),
+            EndpointError::Other(error) => Self::Network(error.to_string()),
+        }
+    }
+}
+
+impl From<EndpointError<update_email::Error>> for AuthError {
+    fn from(error: EndpointError<update_email::Error>) -> Self {
+        match error {
+            EndpointError::Declared { kind, .. } => match kind {
+                update_email::Error::ExpiredToken => Self::ExpiredEmailToken,
+                update_email::Error::InvalidToken => Self::InvalidEmailToken,
+                update_email::Error::TokenRequired => Self::EmailTokenRequired,
+            },
+            EndpointError::Other(error) => Self::Network(error.to_string()),
+        }
+    }
+}
+
+impl From<EndpointError<confirm_email::Error>> for AuthError {
+    fn from(error: EndpointError<confirm_email::Error>) -> Self {
+        match error {
+            EndpointError::Declared { kind, error } => match kind {
+                confirm_email::Error::AccountNotFound => {
+                    Self::AccountNotFound(error.message().to_string())
+                }
+                confirm_email::Error::ExpiredToken => Self::ExpiredEmailToken,
+                confirm_email::Error::InvalidToken => Self::InvalidEmailToken,
+                confirm_email::Error::InvalidEmail => Self::InvalidEmail,
+            },
+            EndpointError::Other(error) => Self::Network(error.to_string()),
+        }
+    }
+}
+
+impl From<EndpointError<delete_account::Error>> for AuthError {
+    fn from(error: EndpointError<delete_account::Error>) -> Self {
+        match error {
+            EndpointError::Declared { kind, .. } => match kind {
+                delete_account::Error::ExpiredToken => Self::ExpiredEmailToken,
+                delete_account::Error::InvalidToken => Self::InvalidEmailToken,
+            },
+            EndpointError::Other(error) => Self::Network(error.to_string()),
+        }
+    }
 }
 
 /// Result type for authentication operations
@@ -119,6 +215,61 @@ pub struct LoginResult {
     pub two_factor_enabled: bool,
 }
 
+/// App password, as listed in settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppPassword {
+    /// Name given when the password was created
+    pub name: String,
+    /// Creation time (RFC 3339)
+    pub created_at: String,
+    /// Whether the password has access to privileged state such as DMs
+    pub privileged: bool,
+}
+
+/// Newly created app password
+///
+/// The password itself is only returned once, at creation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreatedAppPassword {
+    /// Name given to the password
+    pub name: String,
+    /// The generated password (e.g. `abcd-efgh-ijkl-mnop`)
+    pub password: String,
+    /// Creation time (RFC 3339)
+    pub created_at: String,
+    /// Whether the password has access to privileged state such as DMs
+    pub privileged: bool,
+}
+
+/// Outcome of asking to change the account's email
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EmailUpdateRequest {
+    /// The current email is confirmed, so a token was sent to it
+    TokenSent,
+    /// The current email is unconfirmed; the email can be changed without a token
+    TokenNotRequired,
+}
+
+/// Account email after an update or confirmation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmailStatus {
+    /// Email address
+    pub email: String,
+    /// Whether the address is confirmed
+    pub confirmed: bool,
+}
+
+/// Local state cleared after an account was deactivated or deleted
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountRemoval {
+    /// DID of the account
+    pub did: String,
+    /// Handle of the account
+    pub handle: String,
+    /// Number of account-level settings removed from the account store
+    pub settings_removed: usize,
+}
+
 /// Authentication service
 ///
 /// Provides high-level authentication flows with comprehensive error handling,
@@ -181,6 +332,7 @@ pub struct LoginResult {
 /// ```
 pub struct AuthService {
     session_manager: Arc<RwLock<SessionManager>>,
+    account_store: Option<Arc<AccountStore>>,
 }
 
 impl AuthService {
@@ -193,6 +345,7 @@ impl AuthService {
         let session_manager = SessionManager::new(session_path).await?;
         Ok(Self {
             session_manager: Arc::new(RwLock::new(session_manager)),
+            account_store: None,
         })
     }
 
@@ -209,9 +362,23 @@ impl AuthService {
         let session_manager = SessionManager::with_service(session_path, default_service).await?;
         Ok(Self {
             session_manager: Arc::new(RwLock::new(session_manager)),
+            account_store: None,
         })
     }
 
+    /// Clear account-level settings from `store` when an account is deleted
+    pub fn with_account_store(mut self, store: Arc<AccountStore>) -> Self {
+        self.account_store = Some(store);
+        self
+    }
+
+    /// Handle change workflow for the current account
+    ///
+    /// See [`crate::handles`].
+    pub fn handle_change(&self) -> HandleChangeService {
+        HandleChangeService::new(self.session_manager.clone())
+    }
+
     /// Login with credentials
     ///
     /// # Arguments
@@ -754,6 +921,497 @@ impl AuthService {
         false
     }
 
+    /// List the current account's app passwords
+    ///
+    /// # Errors
+    ///
+    /// - `AuthError::NoSession` - No active session
+    /// - `AuthError::AccountSuspended` - Account is taken down
+    /// - `AuthError::Network` - Network error
+    pub async fn list_app_passwords(&self) -> Result<Vec<AppPassword>> {
+        let manager = self.session_manager.read().await;
+        let agent_arc = manager.current_agent().ok_or(AuthError::NoSession)?;
+        let agent = agent_arc.read().await;
+
+        let output = list_app_passwords::list_app_passwords(agent.write_client()).await?;
+
+        Ok(output
+            .passwords
+            .into_iter()
+            .map(|password| AppPassword {
+                name: password.name,
+                created_at: password.created_at,
+                privileged: password.privileged.unwrap_or(false),
+            })
+            .collect())
+    }
+
+    /// Create an app password for the current account
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name to tell the password apart from others
+    /// * `privileged` - Whether the password may access privileged state such as DMs
+    ///
+    /// # Returns
+    ///
+    /// The generated password, which the server won't show again
+    ///
+    /// # Errors
+    ///
+    /// - `AuthError::InvalidAppPasswordName` - Name is empty
+    /// - `AuthError::NoSession` - No active session
+    /// - `AuthError::AccountSuspended` - Account is taken down
+    /// - `AuthError::Network` - Network error
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use app_core::auth::AuthService;
+    /// # async fn example(auth: &AuthService) -> Result<(), Box<dyn std::error::Error>> {
+    /// let created = auth.create_app_password("Desktop client", false).await?;
+    /// println!("Use this password to sign in: {}", created.password);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_app_password(
+        &self,
+        name: &str,
+        privileged: bool,
+    ) -> Result<CreatedAppPassword> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(AuthError::InvalidAppPasswordName);
+        }
+
+        let manager = self.session_manager.read().await;
+        let agent_arc = manager.current_agent().ok_or(AuthError::NoSession)?;
+        let agent = agent_arc.read().await;
+
+        let input = create_app_password::Input {
+            name: name.to_string(),
+            privileged: Some(privileged),
+        };
+        let output = create_app_password::create_app_password(agent.write_client(), &input).await?;
+
+        Ok(CreatedAppPassword {
+            name: output.name,
+            password: output.password,
+            created_at: output.created_at,
+            privileged: output.privileged.unwrap_or(privileged),
+        })
+    }
+
+    /// Revoke one of the current account's app passwords
+    ///
+    /// Sessions created with the password are signed out. Check
+    /// [`session_credential()`](Self::session_credential) first to warn when
+    /// the current session itself uses an app password.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the password to revoke
+    ///
+    /// # Errors
+    ///
+    /// - `AuthError::NoSession` - No active session
+    /// - `AuthError::Network` - Network error
+    pub async fn revoke_app_password(&self, name: &str) -> Result<()> {
+        let manager = self.session_manager.read().await;
+        let agent_arc = manager.current_agent().ok_or(AuthError::NoSession)?;
+        let agent = agent_arc.read().await;
+
+        let input = revoke_app_password::Input { name: name.to_string() };
+        revoke_app_password::revoke_app_password(agent.write_client(), &input)
+            .await
+            .map_err(|e| AuthError::Network(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Get the credential the current session was created with
+    ///
+    /// The server doesn't say which app password a session uses, only that
+    /// it uses one.
+    ///
+    /// # Returns
+    ///
+    /// `None` if there is no session or it uses OAuth
+    pub async fn session_credential(&self) -> Option<SessionCredential> {
+        let manager = self.session_manager.read().await;
+        let account = manager.current_account()?;
+        if account.oauth.is_some() {
+            return None;
+        }
+        account.access_jwt.as_deref().and_then(session_credential)
+    }
+
+    /// Check if the current session was created with an app password
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use app_core::auth::AuthService;
+    /// # async fn example(auth: &AuthService) -> Result<(), Box<dyn std::error::Error>> {
+    /// if auth.is_using_app_password().await {
+    ///     println!("Revoking the password in use will sign you out");
+    /// }
+    /// auth.revoke_app_password("Desktop client").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_using_app_password(&self) -> bool {
+        self.session_credential()
+            .await
+            .is_some_and(|credential| credential.is_app_password())
+    }
+
+    /// Ask to change the current account's email
+    ///
+    /// If the current address is confirmed, the server emails it a token to
+    /// pass to [`update_email()`](Self::update_email).
+    ///
+    /// # Errors
+    ///
+    /// - `AuthError::NoSession` - No active session
+    /// - `AuthError::Network` - Network error
+    pub async fn request_email_update(&self) -> Result<EmailUpdateRequest> {
+        let manager = self.session_manager.read().await;
+        let agent_arc = manager.current_agent().ok_or(AuthError::NoSession)?;
+        let agent = agent_arc.read().await;
+
+        let output = request_email_update::request_email_update(agent.write_client())
+            .await
+            .map_err(|e| AuthError::Network(e.to_string()))?;
+
+        Ok(if output.token_required {
+            EmailUpdateRequest::TokenSent
+        } else {
+            EmailUpdateRequest::TokenNotRequired
+        })
+    }
+
+    /// Change the current account's email
+    ///
+    /// The new address starts out unconfirmed; confirm it with
+    /// [`request_email_auth_token()`](Self::request_email_auth_token) and
+    /// [`confirm_email()`](Self::confirm_email).
+    ///
+    /// # Arguments
+    ///
+    /// * `email` - New email address
+    /// * `token` - Token from [`request_email_update()`](Self::request_email_update), if one was sent
+    ///
+    /// # Errors
+    ///
+    /// - `AuthError::InvalidEmail` - Email format is invalid
+    /// - `AuthError::NoSession` - No active session
+    /// - `AuthError::EmailTokenRequired` - The current email is confirmed and no token was given
+    /// - `AuthError::InvalidEmailToken` / `AuthError::ExpiredEmailToken` - Bad token
+    /// - `AuthError::Network` - Network error
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use app_core::auth::{AuthService, EmailUpdateRequest};
+    /// # async fn example(auth: &AuthService) -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = match auth.request_email_update().await? {
+    ///     EmailUpdateRequest::TokenSent => Some("ABCDE-12345"),
+    ///     EmailUpdateRequest::TokenNotRequired => None,
+    /// };
+    /// let status = auth.update_email("alice@example.org", token).await?;
+    /// assert!(!status.confirmed);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_email(&self, email: &str, token: Option<&str>) -> Result<EmailStatus> {
+        if !email.contains('@') || email.len() < 3 {
+            return Err(AuthError::InvalidEmail);
+        }
+
+        let (account, agent_arc) = self.current_agent().await?;
+        {
+            let agent = agent_arc.read().await;
+            let input = update_email::Input {
+                email: email.to_string(),
+                email_auth_factor: None,
+                token: token.map(str::to_string),
+            };
+            update_email::update_email(agent.write_client(), &input).await?;
+        }
+
+        let status = EmailStatus { email: email.to_string(), confirmed: false };
+        self.store_email(&account.did, &status).await?;
+        Ok(status)
+    }
+
+    /// Confirm the current account's email with an emailed token
+    ///
+    /// # Arguments
+    ///
+    /// * `email` - The address being confirmed
+    /// * `token` - Token from [`request_email_auth_token()`](Self::request_email_auth_token)
+    ///
+    /// # Errors
+    ///
+    /// - `AuthError::NoSession` - No active session
+    /// - `AuthError::InvalidEmail` - The address doesn't match the account
+    /// - `AuthError::InvalidEmailToken` / `AuthError::ExpiredEmailToken` - Bad token
+    /// - `AuthError::Network` - Network error
+    pub async fn confirm_email(&self, email: &str, token: &str) -> Result<EmailStatus> {
+        let (account, agent_arc) = self.current_agent().await?;
+        {
+            let agent = agent_arc.read().await;
+            let input = confirm_email::Input {
+                email: email.to_string(),
+                token: token.trim().to_string(),
+            };
+            confirm_email::confirm_email(agent.write_client(), &input).await?;
+        }
+
+        let status = EmailStatus { email: email.to_string(), confirmed: true };
+        self.store_email(&account.did, &status).await?;
+        Ok(status)
+    }
+
+    /// Send a password reset code to the current account's email
+    ///
+    /// Use the code with [`change_password()`](Self::change_password).
+    ///
+    /// # Errors
+    ///
+    /// - `AuthError::NoSession` - No active session
+    /// - `AuthError::InvalidEmail` - The account has no email on record
+    /// - `AuthError::Network` - Network error
+    pub async fn request_password_change(&self) -> Result<()> {
+        let (email, service) = {
+            let manager = self.session_manager.read().await;
+            let account = manager.current_account().ok_or(AuthError::NoSession)?;
+            let email = account.email.clone().ok_or(AuthError::InvalidEmail)?;
+            (
+                email,
+                account
+                    .pds_url
+                    .clone()
+                    .unwrap_or_else(|| account.service.clone()),
+            )
+        };
+
+        self.request_password_reset(&email, Some(service)).await
+    }
+
+    /// Change the current account's password with an emailed reset code
+    ///
+    /// The server revokes existing sessions when the password changes, so the
+    /// current account is logged out but kept in the account list.
+    ///
+    /// # Arguments
+    ///
+    /// * `reset_code` - Code from [`request_password_change()`](Self::request_password_change)
+    /// * `new_password` - New password to set
+    ///
+    /// # Errors
+    ///
+    /// - `AuthError::NoSession` - No active session
+    /// - `AuthError::InvalidResetCode` - Reset code format is invalid
+    /// - `AuthError::Network` - Network error (may also indicate expired/invalid code)
+    pub async fn change_password(&self, reset_code: &str, new_password: &str) -> Result<()> {
+        let service = {
+            let manager = self.session_manager.read().await;
+            let account = manager.current_account().ok_or(AuthError::NoSession)?;
+            account
+                .pds_url
+                .clone()
+                .unwrap_or_else(|| account.service.clone())
+        };
+
+        self.reset_password(reset_code, new_password, Some(service))
+            .await?;
+
+        let mut manager = self.session_manager.write().await;
+        manager.logout_current().await?;
+        Ok(())
+    }
+
+    /// Deactivate the current account
+    ///
+    /// The account's repo stops being served until it is reactivated by
+    /// logging in again and calling [`activate_account()`](Self::activate_account).
+    /// The account is removed from the account list, but its local settings are
+    /// kept for when it comes back.
+    ///
+    /// # Arguments
+    ///
+    /// * `delete_after` - Suggested time for the server to delete the account (RFC 3339)
+    ///
+    /// # Errors
+    ///
+    /// - `AuthError::NoSession` - No active session
+    /// - `AuthError::Network` - Network error
+    pub async fn deactivate_account(&self, delete_after: Option<String>) -> Result<AccountRemoval> {
+        let (account, agent_arc) = self.current_agent().await?;
+        {
+            let agent = agent_arc.read().await;
+            let input = deactivate_account::Input { delete_after };
+            deactivate_account::deactivate_account(agent.write_client(), &input)
+                .await
+                .map_err(|e| AuthError::Network(e.to_string()))?;
+        }
+
+        self.remove_local_account(&account, false).await
+    }
+
+    /// Reactivate the current account after it was deactivated
+    ///
+    /// # Returns
+    ///
+    /// The refreshed session
+    ///
+    /// # Errors
+    ///
+    /// - `AuthError::NoSession` - No active session
+    /// - `AuthError::Network` - Network error
+    pub async fn activate_account(&self) -> Result<LoginResult> {
+        let (account, agent_arc) = self.current_agent().await?;
+        {
+            let agent = agent_arc.read().await;
+            activate_account::activate_account(agent.write_client())
+                .await
+                .map_err(|e| AuthError::Network(e.to_string()))?;
+        }
+
+        // New tokens carry the account's active status
+        {
+            let mut manager = self.session_manager.write().await;
+            if manager
+                .current_account()
+                .map(|current| current.did.as_str())
+                == Some(account.did.as_str())
+            {
+                manager.refresh_current_session().await?;
+            }
+        }
+
+        self.current_session().await.ok_or(AuthError::NoSession)
+    }
+
+    /// Email the current account a token to confirm its deletion
+    ///
+    /// # Errors
+    ///
+    /// - `AuthError::NoSession` - No active session
+    /// - `AuthError::Network` - Network error
+    pub async fn request_account_delete(&self) -> Result<()> {
+        let manager = self.session_manager.read().await;
+        let agent_arc = manager.current_agent().ok_or(AuthError::NoSession)?;
+        let agent = agent_arc.read().await;
+
+        request_account_delete::request_account_delete(agent.write_client())
+            .await
+            .map_err(|e| AuthError::Network(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Permanently delete the current account
+    ///
+    /// Removes the account from the account list and clears its settings
+    /// from the account store.
+    ///
+    /// # Arguments
+    ///
+    /// * `password` - The account's main password
+    /// * `token` - Token from [`request_account_delete()`](Self::request_account_delete)
+    ///
+    /// # Errors
+    ///
+    /// - `AuthError::NoSession` - No active session
+    /// - `AuthError::InvalidEmailToken` / `AuthError::ExpiredEmailToken` - Bad token
+    /// - `AuthError::Network` - Network error (including a wrong password)
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use app_core::auth::AuthService;
+    /// # async fn example(auth: &AuthService) -> Result<(), Box<dyn std::error::Error>> {
+    /// auth.request_account_delete().await?;
+    ///
+    /// // User receives the token via email
+    ///
+    /// let removed = auth.delete_account("password123", "ABCDE-12345").await?;
+    /// println!("Deleted {}", removed.handle);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_account(&self, password: &str, token: &str) -> Result<AccountRemoval> {
+        let (account, agent_arc) = self.current_agent().await?;
+        {
+            let agent = agent_arc.read().await;
+            let input = delete_account::Input {
+                did: account.did.clone(),
+                password: password.to_string(),
+                token: token.trim().to_string(),
+            };
+            delete_account::delete_account(agent.write_client(), &input).await?;
+        }
+
+        self.remove_local_account(&account, true).await
+    }
+
+    /// Get the current account and its agent
+    ///
+    /// The session manager lock is released before returning, so requests
+    /// made with the agent don't block other session operations. Results are
+    /// stored against the returned account, even if another account became
+    /// current in the meantime.
+    async fn current_agent(&self) -> Result<(SessionAccount, Arc<RwLock<BskyAgent>>)> {
+        let manager = self.session_manager.read().await;
+        let account = manager
+            .current_account()
+            .ok_or(AuthError::NoSession)?
+            .clone();
+        let agent = manager.current_agent().ok_or(AuthError::NoSession)?;
+        Ok((account, agent))
+    }
+
+    /// Save an email change to an account's stored session
+    async fn store_email(&self, did: &str, status: &EmailStatus) -> Result<()> {
+        let mut manager = self.session_manager.write().await;
+        if let Some(account) = manager.get_account(did) {
+            let mut account = account.clone();
+            account.email = Some(status.email.clone());
+            account.email_confirmed = Some(status.confirmed);
+            manager.add_account(account).await?;
+        }
+        Ok(())
+    }
+
+    /// Remove an account from the account list and optionally its settings
+    async fn remove_local_account(
+        &self,
+        account: &SessionAccount,
+        clear_settings: bool,
+    ) -> Result<AccountRemoval> {
+        let did = account.did.clone();
+        {
+            let mut manager = self.session_manager.write().await;
+            if manager.get_account(&did).is_some() {
+                manager.remove_account(&did).await?;
+            }
+        }
+
+        let settings_removed = match (&self.account_store, clear_settings) {
+            (Some(store), true) => store.remove_account(&did)?,
+            _ => 0,
+        };
+
+        Ok(AccountRemoval {
+            did,
+            handle: account.handle.clone(),
+            settings_removed,
+        })
+    }
+
     /// Request a password reset email
     ///
     /// Sends a password reset code to the specified email address. This does not require
@@ -1538,4 +2196,258 @@ mod tests {
             assert!(result.is_ok(), "Failed for code: {}", code);
         }
     }
+
+    #[tokio::test]
+    async fn test_app_passwords_require_session() {
+        let temp_dir = TempDir::new().unwrap();
+        let auth = AuthService::new(temp_dir.path().join("sessions.json"))
+            .await
+            .unwrap();
+
+        assert!(matches!(auth.list_app_passwords().await, Err(AuthError::NoSession)));
+        assert!(matches!(
+            auth.create_app_password("  ", false).await,
+            Err(AuthError::InvalidAppPasswordName)
+        ));
+        assert!(matches!(
+            auth.create_app_password("Desktop", true).await,
+            Err(AuthError::NoSession)
+        ));
+        assert!(matches!(auth.revoke_app_password("Desktop").await, Err(AuthError::NoSession)));
+        assert_eq!(auth.session_credential().await, None);
+        assert!(!auth.is_using_app_password().await);
+    }
+
+    #[tokio::test]
+    async fn test_detects_app_password_session() {
+        // Unsigned token with scope com.atproto.appPass, expiring in 2100
+        const APP_PASS_JWT: &str = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.\
+            eyJzdWIiOiJkaWQ6cGxjOmFsaWNlIiwiZXhwIjo0MTAyNDQ0ODAwLCJzY29wZSI6ImNvbS5hdHByb3RvLmFwcFBhc3MifQ.\
+            c2lnbmF0dXJl";
+
+        let temp_dir = TempDir::new().unwrap();
+        let auth = AuthService::new(temp_dir.path().join("sessions.json"))
+            .await
+            .unwrap();
+
+        let mut account = SessionAccount::new(
+            "https://bsky.social".to_string(),
+            "did:plc:alice".to_string(),
+            "alice.bsky.social".to_string(),
+        );
+        account.access_jwt = Some(APP_PASS_JWT.to_string());
+        account.refresh_jwt = Some(APP_PASS_JWT.to_string());
+        {
+            let mut manager = auth.session_manager.write().await;
+            manager.add_account(account).await.unwrap();
+        }
+        auth.switch_account("did:plc:alice").await.unwrap();
+
+        assert_eq!(auth.session_credential().await, Some(SessionCredential::AppPassword));
+        assert!(auth.is_using_app_password().await);
+    }
+
+    /// Log in as alice with a mock transport standing in for her PDS
+    async fn signed_in_with_mock(
+        auth: &AuthService,
+    ) -> Arc<atproto_client::transport::MockTransport> {
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::{XrpcClient, XrpcClientConfig};
+
+        // Unsigned token with scope com.atproto.access, expiring in 2100
+        const ACCESS_JWT: &str = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.\
+            eyJzdWIiOiJkaWQ6cGxjOmFsaWNlIiwiZXhwIjo0MTAyNDQ0ODAwLCJzY29wZSI6ImNvbS5hdHByb3RvLmFjY2VzcyJ9.\
+            c2lnbmF0dXJl";
+
+        let mut account = SessionAccount::new(
+            "https://bsky.social".to_string(),
+            "did:plc:alice".to_string(),
+            "alice.bsky.social".to_string(),
+        );
+        account.email = Some("alice@example.com".to_string());
+        account.email_confirmed = Some(true);
+        account.access_jwt = Some(ACCESS_JWT.to_string());
+        account.refresh_jwt = Some(ACCESS_JWT.to_string());
+        auth.session_manager
+            .write()
+            .await
+            .add_account(account)
+            .await
+            .unwrap();
+        auth.switch_account("did:plc:alice").await.unwrap();
+
+        let transport = Arc::new(MockTransport::new());
+        let agent = auth.session_manager.read().await.current_agent().unwrap();
+        *agent.write().await.write_client_mut() =
+            XrpcClient::new(XrpcClientConfig::default().with_transport(transport.clone()));
+        transport
+    }
+
+    #[tokio::test]
+    async fn test_account_lifecycle_requires_session() {
+        let temp_dir = TempDir::new().unwrap();
+        let auth = AuthService::new(temp_dir.path().join("sessions.json"))
+            .await
+            .unwrap();
+
+        assert!(matches!(auth.request_email_update().await, Err(AuthError::NoSession)));
+        assert!(matches!(
+            auth.update_email("not-an-email", None).await,
+            Err(AuthError::InvalidEmail)
+        ));
+        assert!(matches!(
+            auth.update_email("alice@example.org", None).await,
+            Err(AuthError::NoSession)
+        ));
+        assert!(matches!(auth.request_password_change().await, Err(AuthError::NoSession)));
+        assert!(matches!(auth.deactivate_account(None).await, Err(AuthError::NoSession)));
+        assert!(matches!(auth.activate_account().await, Err(AuthError::NoSession)));
+        assert!(matches!(
+            auth.delete_account("password", "ABCDE-12345").await,
+            Err(AuthError::NoSession)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_email_stores_unconfirmed_address() {
+        let temp_dir = TempDir::new().unwrap();
+        let auth = AuthService::new(temp_dir.path().join("sessions.json"))
+            .await
+            .unwrap();
+        let transport = signed_in_with_mock(&auth).await;
+
+        transport.push_json(
+            "com.atproto.server.requestEmailUpdate",
+            200,
+            serde_json::json!({"tokenRequired": true}),
+        );
+        assert_eq!(auth.request_email_update().await.unwrap(), EmailUpdateRequest::TokenSent);
+
+        transport.push_json(
+            "com.atproto.server.updateEmail",
+            400,
+            serde_json::json!({"error": "TokenRequired", "message": "confirmation token required"}),
+        );
+        transport.push_json("com.atproto.server.updateEmail", 200, serde_json::json!({}));
+        assert!(matches!(
+            auth.update_email("alice@example.org", None).await,
+            Err(AuthError::EmailTokenRequired)
+        ));
+
+        let status = auth
+            .update_email("alice@example.org", Some("ABCDE-12345"))
+            .await
+            .unwrap();
+        assert_eq!(
+            status,
+            EmailStatus {
+                email: "alice@example.org".to_string(),
+                confirmed: false
+            }
+        );
+
+        let session = auth.current_session().await.unwrap();
+        assert_eq!(session.email.as_deref(), Some("alice@example.org"));
+        assert!(!session.email_confirmed);
+    }
+
+    #[tokio::test]
+    async fn test_account_requests_do_not_hold_the_session_lock() {
+        use atproto_client::transport::Transport;
+        use atproto_client::xrpc::{
+            HttpRequest, HttpResponse, XrpcClient, XrpcClientConfig, XrpcError,
+        };
+        use tokio::sync::Notify;
+
+        /// Transport that holds each request until released
+        #[derive(Debug, Default)]
+        struct GatedTransport {
+            entered: Notify,
+            release: Notify,
+        }
+
+        #[async_trait::async_trait]
+        impl Transport for GatedTransport {
+            async fn send(
+                &self,
+                _request: HttpRequest,
+            ) -> std::result::Result<HttpResponse, XrpcError> {
+                self.entered.notify_one();
+                self.release.notified().await;
+                Ok(HttpResponse::json(200, &serde_json::json!({})))
+            }
+        }
+
+        let temp_dir = TempDir::new().unwrap();
+        let auth = AuthService::new(temp_dir.path().join("sessions.json"))
+            .await
+            .unwrap();
+        signed_in_with_mock(&auth).await;
+        let gate = Arc::new(GatedTransport::default());
+        let agent = auth.session_manager.read().await.current_agent().unwrap();
+        *agent.write().await.write_client_mut() =
+            XrpcClient::new(XrpcClientConfig::default().with_transport(gate.clone()));
+
+        let update = auth.update_email("alice@example.org", Some("ABCDE-12345"));
+        let while_in_flight = async {
+            gate.entered.notified().await;
+            let manager = tokio::time::timeout(
+                std::time::Duration::from_secs(5),
+                auth.session_manager.write(),
+            )
+            .await;
+            assert!(manager.is_ok(), "session manager was locked during the request");
+            drop(manager);
+            gate.release.notify_one();
+        };
+
+        let (status, ()) = tokio::join!(update, while_in_flight);
+        assert!(!status.unwrap().confirmed);
+        let session = auth.current_session().await.unwrap();
+        assert_eq!(session.email.as_deref(), Some("alice@example.org"));
+    }
+
+    #[tokio::test]
+    async fn test_delete_account_clears_local_state() {
+        use storage::kv::KvStore;
+
+        let temp_dir = TempDir::new().unwrap();
+        let store = Arc::new(AccountStore::new(Arc::new(KvStore::in_memory().unwrap())));
+        store.set("did:plc:alice", "theme", &"dark").unwrap();
+        store.set("did:plc:alice", "language", &"en").unwrap();
+        let auth = AuthService::new(temp_dir.path().join("sessions.json"))
+            .await
+            .unwrap()
+            .with_account_store(store.clone());
+        let transport = signed_in_with_mock(&auth).await;
+
+        // A bad token leaves everything in place
+        transport.push_json(
+            "com.atproto.server.deleteAccount",
+            400,
+            serde_json::json!({"error": "InvalidToken", "message": "Token is invalid"}),
+        );
+        transport.push_json("com.atproto.server.deleteAccount", 200, serde_json::json!({}));
+        assert!(matches!(
+            auth.delete_account("password", "ABCDE-12345").await,
+            Err(AuthError::InvalidEmailToken)
+        ));
+        assert_eq!(auth.list_accounts().await.len(), 1);
+
+        let removed = auth
+            .delete_account("password", "ABCDE-12345")
+            .await
+            .unwrap();
+        assert_eq!(removed.did, "did:plc:alice");
+        assert_eq!(removed.settings_removed, 2);
+        assert!(auth.current_session().await.is_none());
+        assert!(auth.list_accounts().await.is_empty());
+        assert!(!store.contains("did:plc:alice", "theme").unwrap());
+
+        let requests = transport.requests();
+        let body: serde_json::Value =
+            serde_json::from_slice(requests.last().unwrap().body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["did"], "did:plc:alice");
+        assert_eq!(body["token"], "ABCDE-12345");
+    }
 }
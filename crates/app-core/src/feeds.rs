@@ -2454,6 +2454,39 @@ mod tests {
         assert_eq!(sort, HashtagFeedSort::Top);
     }
 
+    #[tokio::test]
+    async fn test_following_feed_fetch_offline() {
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::{XrpcClient, XrpcClientConfig};
+        use std::sync::Arc;
+        use tokio::sync::RwLock;
+
+        let post = create_test_post("at://did:plc:alice/app.bsky.feed.post/1", "did:plc:alice");
+        let transport = Arc::new(MockTransport::new().with_json(
+            "app.bsky.feed.getTimeline",
+            200,
+            serde_json::json!({ "cursor": "next", "feed": [{ "post": post }] }),
+        ));
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let feed = FollowingFeed::new(Arc::new(RwLock::new(XrpcClient::new(config))));
+
+        let params = FeedParams { cursor: Some("start".to_string()), limit: 25 };
+        let response = feed.fetch(params).await.unwrap();
+
+        assert_eq!(response.cursor.as_deref(), Some("next"));
+        assert_eq!(response.feed.len(), 1);
+        assert_eq!(response.feed[0].post.uri, "at://did:plc:alice/app.bsky.feed.post/1");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0]
+            .params
+            .contains(&("limit".to_string(), "25".to_string())));
+        assert!(requests[0]
+            .params
+            .contains(&("cursor".to_string(), "start".to_string())));
+    }
+
     #[tokio::test]
     async fn test_hashtag_feed_empty_hashtag_error() {
         use atproto_client::xrpc::{XrpcClient, XrpcClientConfig};
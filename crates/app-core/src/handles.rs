@@ -0,0 +1,615 @@
+//! Handle changes and custom-domain verification
+//!
+//! Changing to a domain handle takes three steps:
+//! 1. [`HandleChangeService::prepare`] returns a [`HandleChallenge`] with the
+//!    DNS TXT record or `.well-known` file to publish on the domain
+//! 2. [`HandleChangeService::complete`] polls both verification methods until
+//!    one of them points at the account's DID
+//! 3. The PDS is asked to switch handles (`com.atproto.identity.updateHandle`)
+//!    and the stored session account is updated
+//!
+//! Handles under a PDS's own domain (e.g. `alice.bsky.social`) are managed
+//! by the PDS; use [`HandleChangeService::update_handle`] for those.
+
+use atproto_client::api::com::atproto::identity::update_handle;
+use atproto_client::identity::{DnsHandleResolver, HandleResolver, WellKnownHandleResolver};
+use atproto_client::session::{SessionManager, SessionManagerError};
+use atproto_client::types::Handle;
+use atproto_client::BskyAgent;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::RwLock;
+
+/// Default delay between verification attempts
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Default number of verification attempts (about a minute with the default interval)
+pub const DEFAULT_POLL_ATTEMPTS: u32 = 12;
+
+/// Handle change error types
+#[derive(Debug, Error)]
+pub enum HandleChangeError {
+    /// The handle is not a valid domain name
+    #[error("Invalid handle: {0}")]
+    InvalidHandle(String),
+
+    /// No active session
+    #[error("No active session")]
+    NoSession,
+
+    /// The challenge was prepared for a different account than the current one
+    #[error("Handle challenge was prepared for {expected}, but the current account is {current}")]
+    AccountMismatch {
+        /// DID the challenge was prepared for
+        expected: String,
+        /// DID of the current account
+        current: String,
+    },
+
+    /// Neither verification method points at the account's DID
+    #[error("Could not verify {}: {}", .0.handle, .0)]
+    NotVerified(HandleCheck),
+
+    /// XRPC error
+    #[error("XRPC error: {0}")]
+    Xrpc(String),
+
+    /// Session manager error
+    #[error("Session error: {0}")]
+    Session(#[from] SessionManagerError),
+}
+
+/// Result type for handle change operations
+pub type Result<T> = std::result::Result<T, HandleChangeError>;
+
+/// Way of proving control of a domain handle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HandleVerificationMethod {
+    /// `_atproto.<handle>` DNS TXT record
+    Dns,
+    /// `https://<handle>/.well-known/atproto-did` file
+    WellKnown,
+}
+
+/// What the account needs to publish to claim a domain handle
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HandleChallenge {
+    /// The new handle
+    pub handle: String,
+    /// DID of the account changing handle
+    pub did: String,
+}
+
+impl HandleChallenge {
+    /// Name of the DNS TXT record to create
+    pub fn dns_record_name(&self) -> String {
+        format!("_atproto.{}", self.handle)
+    }
+
+    /// Value of the DNS TXT record
+    pub fn dns_record_value(&self) -> String {
+        format!("did={}", self.did)
+    }
+
+    /// URL the `.well-known` file must be served at
+    pub fn well_known_url(&self) -> String {
+        format!("https://{}/.well-known/atproto-did", self.handle)
+    }
+
+    /// Contents of the `.well-known` file
+    pub fn well_known_body(&self) -> &str {
+        &self.did
+    }
+}
+
+/// Outcome of one verification method
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "status", content = "detail")]
+pub enum MethodStatus {
+    /// The record points at the account's DID
+    Verified,
+    /// No record was found
+    Missing,
+    /// The record points at another DID
+    WrongDid(String),
+    /// The lookup failed (DNS, TLS or network error)
+    Failed(String),
+}
+
+impl MethodStatus {
+    /// Whether the method verified the handle
+    pub fn is_verified(&self) -> bool {
+        matches!(self, Self::Verified)
+    }
+}
+
+/// Outcome of checking both verification methods
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HandleCheck {
+    /// Handle that was checked
+    pub handle: String,
+    /// DNS TXT record status
+    pub dns: MethodStatus,
+    /// `.well-known` file status
+    pub well_known: MethodStatus,
+}
+
+impl HandleCheck {
+    /// Whether either method verified the handle
+    pub fn is_verified(&self) -> bool {
+        self.verified_by().is_some()
+    }
+
+    /// The method that verified the handle, preferring DNS
+    pub fn verified_by(&self) -> Option<HandleVerificationMethod> {
+        if self.dns.is_verified() {
+            Some(HandleVerificationMethod::Dns)
+        } else if self.well_known.is_verified() {
+            Some(HandleVerificationMethod::WellKnown)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for HandleCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let describe = |status: &MethodStatus| match status {
+            MethodStatus::Verified => "verified".to_string(),
+            MethodStatus::Missing => "no record found".to_string(),
+            MethodStatus::WrongDid(did) => format!("points to {} instead", did),
+            MethodStatus::Failed(reason) => format!("lookup failed ({})", reason),
+        };
+
+        write!(
+            f,
+            "DNS TXT _atproto.{}: {}; HTTPS https://{}/.well-known/atproto-did: {}",
+            self.handle,
+            describe(&self.dns),
+            self.handle,
+            describe(&self.well_known)
+        )
+    }
+}
+
+/// Handle change workflow for the current account
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use app_core::auth::AuthService;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let auth = AuthService::new("sessions.json").await?;
+///     let handles = auth.handle_change();
+///
+///     let challenge = handles.prepare("alice.example.com").await?;
+///     println!("Add a TXT record {} = {}", challenge.dns_record_name(), challenge.dns_record_value());
+///
+///     // Once the record is published
+///     let check = handles.complete(&challenge).await?;
+///     println!("Verified by {:?}", check.verified_by());
+///
+///     Ok(())
+/// }
+/// ```
+pub struct HandleChangeService {
+    session_manager: Arc<RwLock<SessionManager>>,
+    dns: Arc<dyn HandleResolver>,
+    well_known: Arc<dyn HandleResolver>,
+    poll_interval: Duration,
+    poll_attempts: u32,
+}
+
+impl HandleChangeService {
+    /// Create a handle change service using DNS and HTTPS lookups
+    pub fn new(session_manager: Arc<RwLock<SessionManager>>) -> Self {
+        Self {
+            session_manager,
+            dns: Arc::new(DnsHandleResolver::new()),
+            well_known: Arc::new(WellKnownHandleResolver::new(Duration::from_secs(10))),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            poll_attempts: DEFAULT_POLL_ATTEMPTS,
+        }
+    }
+
+    /// Use a different resolver for DNS TXT verification
+    pub fn with_dns_resolver(mut self, resolver: Arc<dyn HandleResolver>) -> Self {
+        self.dns = resolver;
+        self
+    }
+
+    /// Use a different resolver for `.well-known` verification
+    pub fn with_well_known_resolver(mut self, resolver: Arc<dyn HandleResolver>) -> Self {
+        self.well_known = resolver;
+        self
+    }
+
+    /// Set how often and how many times [`complete`](Self::complete) checks verification
+    pub fn with_polling(mut self, interval: Duration, attempts: u32) -> Self {
+        self.poll_interval = interval;
+        self.poll_attempts = attempts.max(1);
+        self
+    }
+
+    /// Start changing the current account's handle
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The new domain handle
+    ///
+    /// # Returns
+    ///
+    /// The records to publish on the domain
+    ///
+    /// # Errors
+    ///
+    /// - `HandleChangeError::InvalidHandle` - Not a valid domain name
+    /// - `HandleChangeError::NoSession` - No active session
+    pub async fn prepare(&self, handle: &str) -> Result<HandleChallenge> {
+        let handle = parse_handle(handle)?;
+        let did = self.current_did().await?;
+
+        Ok(HandleChallenge { handle: handle.as_str().to_string(), did })
+    }
+
+    /// Check both verification methods once
+    ///
+    /// Lookup failures are reported in the returned [`HandleCheck`] rather
+    /// than as errors.
+    pub async fn check(&self, challenge: &HandleChallenge) -> HandleCheck {
+        let (dns, well_known) = match Handle::new(challenge.handle.as_str()) {
+            Ok(handle) => tokio::join!(
+                lookup(self.dns.as_ref(), &handle, &challenge.did),
+                lookup(self.well_known.as_ref(), &handle, &challenge.did),
+            ),
+            Err(e) => {
+                let failed = MethodStatus::Failed(e.to_string());
+                (failed.clone(), failed)
+            }
+        };
+
+        HandleCheck { handle: challenge.handle.clone(), dns, well_known }
+    }
+
+    /// Check verification until it succeeds or the attempts run out
+    ///
+    /// # Errors
+    ///
+    /// - `HandleChangeError::NotVerified` - Neither method verified the
+    ///   handle; carries the last check for each method
+    pub async fn wait_for_verification(&self, challenge: &HandleChallenge) -> Result<HandleCheck> {
+        let mut attempt = 1;
+        loop {
+            let check = self.check(challenge).await;
+            if check.is_verified() {
+                return Ok(check);
+            }
+            if attempt >= self.poll_attempts {
+                return Err(HandleChangeError::NotVerified(check));
+            }
+            attempt += 1;
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Finish a handle change once the domain is set up
+    ///
+    /// Waits for verification, asks the PDS to switch handles and updates
+    /// the stored session account.
+    ///
+    /// # Returns
+    ///
+    /// The verification check that succeeded
+    ///
+    /// # Errors
+    ///
+    /// - `HandleChangeError::NotVerified` - Neither method verified the handle
+    /// - `HandleChangeError::AccountMismatch` - The current account changed since `prepare`
+    /// - `HandleChangeError::NoSession` - No active session
+    /// - `HandleChangeError::Xrpc` - The PDS rejected the handle
+    pub async fn complete(&self, challenge: &HandleChallenge) -> Result<HandleCheck> {
+        let current = self.current_did().await?;
+        if current != challenge.did {
+            return Err(HandleChangeError::AccountMismatch {
+                expected: challenge.did.clone(),
+                current,
+            });
+        }
+
+        let check = self.wait_for_verification(challenge).await?;
+
+        // The user may have switched accounts while the domain was polled
+        let (current, agent) = self.current_agent().await?;
+        if current != challenge.did {
+            return Err(HandleChangeError::AccountMismatch {
+                expected: challenge.did.clone(),
+                current,
+            });
+        }
+
+        let handle = parse_handle(&challenge.handle)?;
+        self.switch_handle(&current, agent, &handle).await?;
+        Ok(check)
+    }
+
+    /// Switch the current account's handle without checking the domain first
+    ///
+    /// The PDS still verifies the handle; this skips the client-side polling,
+    /// e.g. for handles under the PDS's own domain.
+    ///
+    /// # Errors
+    ///
+    /// - `HandleChangeError::InvalidHandle` - Not a valid domain name
+    /// - `HandleChangeError::NoSession` - No active session
+    /// - `HandleChangeError::Xrpc` - The PDS rejected the handle
+    pub async fn update_handle(&self, handle: &str) -> Result<()> {
+        let handle = parse_handle(handle)?;
+        let (did, agent) = self.current_agent().await?;
+        self.switch_handle(&did, agent, &handle).await
+    }
+
+    /// Ask the PDS to switch handles and store the new handle on the account
+    ///
+    /// The session manager is only locked to store the result, not during
+    /// the request.
+    async fn switch_handle(
+        &self,
+        did: &str,
+        agent: Arc<RwLock<BskyAgent>>,
+        handle: &Handle,
+    ) -> Result<()> {
+        {
+            let agent = agent.read().await;
+            let input = update_handle::Input { handle: handle.as_str().to_string() };
+            update_handle::update_handle(agent.write_client(), &input)
+                .await
+                .map_err(|e| HandleChangeError::Xrpc(e.to_string()))?;
+        }
+
+        let mut manager = self.session_manager.write().await;
+        if let Some(account) = manager.get_account(did) {
+            let mut account = account.clone();
+            account.handle = handle.as_str().to_string();
+            manager.add_account(account).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the current account's DID and its agent
+    async fn current_agent(&self) -> Result<(String, Arc<RwLock<BskyAgent>>)> {
+        let manager = self.session_manager.read().await;
+        let did = manager
+            .current_account()
+            .map(|account| account.did.clone())
+            .ok_or(HandleChangeError::NoSession)?;
+        let agent = manager
+            .current_agent()
+            .ok_or(HandleChangeError::NoSession)?;
+        Ok((did, agent))
+    }
+
+    async fn current_did(&self) -> Result<String> {
+        let manager = self.session_manager.read().await;
+        manager
+            .current_account()
+            .map(|account| account.did.clone())
+            .ok_or(HandleChangeError::NoSession)
+    }
+}
+
+/// Validate and normalize a handle (handles are case-insensitive)
+fn parse_handle(handle: &str) -> Result<Handle> {
+    let handle = handle.trim().trim_start_matches('@').to_lowercase();
+    Handle::new(handle).map_err(|e| HandleChangeError::InvalidHandle(e.to_string()))
+}
+
+/// Run one verification method
+async fn lookup(resolver: &dyn HandleResolver, handle: &Handle, did: &str) -> MethodStatus {
+    match resolver.resolve_handle(handle).await {
+        Ok(Some(found)) if found.as_str() == did => MethodStatus::Verified,
+        Ok(Some(found)) => MethodStatus::WrongDid(found.to_string()),
+        Ok(None) => MethodStatus::Missing,
+        Err(e) => MethodStatus::Failed(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use atproto_client::identity::IdentityError;
+    use atproto_client::session::SessionAccount;
+    use atproto_client::transport::MockTransport;
+    use atproto_client::types::Did;
+    use atproto_client::xrpc::{XrpcClient, XrpcClientConfig};
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use tempfile::TempDir;
+
+    /// Resolver that answers from a script, repeating the last answer
+    struct ScriptedResolver {
+        answers: Vec<std::result::Result<Option<&'static str>, &'static str>>,
+        calls: AtomicU32,
+    }
+
+    impl ScriptedResolver {
+        fn new(answers: Vec<std::result::Result<Option<&'static str>, &'static str>>) -> Arc<Self> {
+            Arc::new(Self { answers, calls: AtomicU32::new(0) })
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl HandleResolver for ScriptedResolver {
+        async fn resolve_handle(
+            &self,
+            _handle: &Handle,
+        ) -> atproto_client::identity::Result<Option<Did>> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) as usize;
+            match self.answers[call.min(self.answers.len() - 1)] {
+                Ok(did) => Ok(did.map(|did| Did::new(did).unwrap())),
+                Err(e) => Err(IdentityError::Dns(e.to_string())),
+            }
+        }
+    }
+
+    // Unsigned token with scope com.atproto.access, expiring in 2100
+    const ACCESS_JWT: &str = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.\
+        eyJzdWIiOiJkaWQ6cGxjOmFsaWNlIiwiZXhwIjo0MTAyNDQ0ODAwLCJzY29wZSI6ImNvbS5hdHByb3RvLmFjY2VzcyJ9.\
+        c2lnbmF0dXJl";
+
+    /// Resolver that verifies alice's handle after switching to bob
+    struct SwitchingResolver(Arc<RwLock<SessionManager>>);
+
+    #[async_trait::async_trait]
+    impl HandleResolver for SwitchingResolver {
+        async fn resolve_handle(
+            &self,
+            _handle: &Handle,
+        ) -> atproto_client::identity::Result<Option<Did>> {
+            self.0
+                .write()
+                .await
+                .switch_account("did:plc:bob")
+                .await
+                .unwrap();
+            Ok(Some(Did::new("did:plc:alice").unwrap()))
+        }
+    }
+
+    /// Session manager signed in as alice, with a mock transport for her PDS
+    async fn signed_in() -> (TempDir, Arc<RwLock<SessionManager>>, Arc<MockTransport>) {
+        let temp_dir = TempDir::new().unwrap();
+        let mut manager = SessionManager::new(temp_dir.path().join("sessions.json"))
+            .await
+            .unwrap();
+        let mut account = SessionAccount::new(
+            "https://bsky.social".to_string(),
+            "did:plc:alice".to_string(),
+            "alice.bsky.social".to_string(),
+        );
+        account.access_jwt = Some(ACCESS_JWT.to_string());
+        account.refresh_jwt = Some(ACCESS_JWT.to_string());
+        manager.add_account(account).await.unwrap();
+        manager.switch_account("did:plc:alice").await.unwrap();
+
+        let transport = Arc::new(MockTransport::new());
+        let agent = manager.current_agent().unwrap();
+        *agent.write().await.write_client_mut() =
+            XrpcClient::new(XrpcClientConfig::default().with_transport(transport.clone()));
+
+        (temp_dir, Arc::new(RwLock::new(manager)), transport)
+    }
+
+    #[test]
+    fn test_challenge_records() {
+        let challenge = HandleChallenge {
+            handle: "alice.example.com".to_string(),
+            did: "did:plc:alice".to_string(),
+        };
+
+        assert_eq!(challenge.dns_record_name(), "_atproto.alice.example.com");
+        assert_eq!(challenge.dns_record_value(), "did=did:plc:alice");
+        assert_eq!(challenge.well_known_url(), "https://alice.example.com/.well-known/atproto-did");
+        assert_eq!(challenge.well_known_body(), "did:plc:alice");
+    }
+
+    #[tokio::test]
+    async fn test_prepare_normalizes_handle() {
+        let (_dir, manager, _) = signed_in().await;
+        let service = HandleChangeService::new(manager);
+
+        let challenge = service.prepare("@Alice.Example.com").await.unwrap();
+        assert_eq!(challenge.handle, "alice.example.com");
+        assert_eq!(challenge.did, "did:plc:alice");
+
+        assert!(matches!(
+            service.prepare("not a domain").await,
+            Err(HandleChangeError::InvalidHandle(_))
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_not_verified_reports_each_method() {
+        let (_dir, manager, transport) = signed_in().await;
+        let service = HandleChangeService::new(manager)
+            .with_dns_resolver(ScriptedResolver::new(vec![Ok(Some("did:plc:mallory"))]))
+            .with_well_known_resolver(ScriptedResolver::new(vec![Err("connection refused")]))
+            .with_polling(Duration::from_secs(5), 3);
+        let challenge = service.prepare("alice.example.com").await.unwrap();
+
+        let Err(HandleChangeError::NotVerified(check)) = service.complete(&challenge).await else {
+            panic!("Expected verification to fail");
+        };
+        assert_eq!(check.dns, MethodStatus::WrongDid("did:plc:mallory".to_string()));
+        assert!(matches!(check.well_known, MethodStatus::Failed(_)));
+        let message = check.to_string();
+        assert!(message.contains("_atproto.alice.example.com: points to did:plc:mallory"));
+        assert!(message.contains("atproto-did: lookup failed"));
+        assert_eq!(transport.request_count(update_handle::NSID), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_complete_updates_session_handle() {
+        let (_dir, manager, transport) = signed_in().await;
+        transport.push_json(update_handle::NSID, 200, serde_json::json!({}));
+
+        // The DNS record shows up on the third check
+        let dns = ScriptedResolver::new(vec![Ok(None), Ok(None), Ok(Some("did:plc:alice"))]);
+        let service = HandleChangeService::new(manager.clone())
+            .with_dns_resolver(dns.clone())
+            .with_well_known_resolver(ScriptedResolver::new(vec![Ok(None)]))
+            .with_polling(Duration::from_secs(5), 5);
+        let challenge = service.prepare("alice.example.com").await.unwrap();
+
+        let check = service.complete(&challenge).await.unwrap();
+        assert_eq!(check.verified_by(), Some(HandleVerificationMethod::Dns));
+        assert_eq!(check.well_known, MethodStatus::Missing);
+        assert_eq!(dns.calls.load(Ordering::SeqCst), 3);
+
+        let requests = transport.requests();
+        let body: serde_json::Value =
+            serde_json::from_slice(requests.last().unwrap().body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["handle"], "alice.example.com");
+
+        let manager = manager.read().await;
+        assert_eq!(manager.current_account().unwrap().handle, "alice.example.com");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_complete_rechecks_account_after_polling() {
+        let (_dir, manager, transport) = signed_in().await;
+        transport.push_json(update_handle::NSID, 200, serde_json::json!({}));
+        let mut bob = SessionAccount::new(
+            "https://bsky.social".to_string(),
+            "did:plc:bob".to_string(),
+            "bob.bsky.social".to_string(),
+        );
+        bob.access_jwt = Some(ACCESS_JWT.to_string());
+        bob.refresh_jwt = Some(ACCESS_JWT.to_string());
+        manager.write().await.add_account(bob).await.unwrap();
+
+        let service = HandleChangeService::new(manager.clone())
+            .with_dns_resolver(Arc::new(SwitchingResolver(manager.clone())))
+            .with_well_known_resolver(ScriptedResolver::new(vec![Ok(None)]))
+            .with_polling(Duration::from_secs(5), 3);
+        let challenge = service.prepare("alice.example.com").await.unwrap();
+
+        let err = service.complete(&challenge).await.unwrap_err();
+        assert!(matches!(
+            err,
+            HandleChangeError::AccountMismatch { ref expected, ref current }
+                if expected == "did:plc:alice" && current == "did:plc:bob"
+        ));
+        assert_eq!(transport.request_count(update_handle::NSID), 0);
+
+        let manager = manager.read().await;
+        assert_eq!(manager.get_account("did:plc:alice").unwrap().handle, "alice.bsky.social");
+    }
+}
@@ -13,6 +13,7 @@ pub mod branding;
 pub mod editor;
 pub mod embeds;
 pub mod feeds;
+pub mod handles;
 pub mod interactions;
 pub mod link_preview;
 pub mod lists;
@@ -6,12 +6,18 @@
 
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 
 use crate::feeds::Label;
-use crate::posts::Facet;
+use crate::posts::{default_schemas, Facet};
 use crate::profiles::ProfileViewBasic;
-use atproto_client::xrpc::XrpcClient;
+use atproto_client::api::com::atproto::repo::create_record;
+use atproto_client::lexicon::{validate_record, RecordValidationError, SchemaRegistry};
+use atproto_client::repo::write::{
+    create_records_paced, ApplyWritesBatch, RecordUpdate, WriteError,
+};
+use atproto_client::xrpc::{XrpcClient, XrpcError};
 use atproto_client::XrpcRequest;
 
 /// Maximum items per page when fetching list members
@@ -46,6 +52,14 @@ pub enum ListError {
     /// No active session
     #[error("No active session")]
     NoSession,
+
+    /// Record does not match its lexicon
+    #[error(transparent)]
+    InvalidRecord(#[from] RecordValidationError),
+
+    /// The record kept changing while being updated
+    #[error("Update conflict: {0}")]
+    Conflict(String),
 }
 
 /// Result type for list operations
@@ -340,16 +354,46 @@ pub struct CreateRecordResponse {
     pub cid: String,
 }
 
+/// Outcome of adding several users to a list
+#[derive(Debug, Clone, Default)]
+pub struct AddMembersResult {
+    /// List item records created, in order
+    pub added: Vec<CreateRecordResponse>,
+
+    /// Users not sent because the rate limit budget ran out or a write failed
+    pub pending: Vec<String>,
+
+    /// How long to wait before adding the pending users, if known
+    pub retry_after: Option<Duration>,
+
+    /// User whose write failed with an error other than a rate limit
+    pub failed: Option<(String, XrpcError)>,
+}
+
 /// List service for managing user lists
 pub struct ListService {
     /// XRPC client
     client: Arc<RwLock<XrpcClient>>,
+    /// Lexicons to validate records against before creating them
+    schemas: Option<Arc<SchemaRegistry>>,
 }
 
 impl ListService {
     /// Create a new list service
+    ///
+    /// Records are validated against the built-in lexicons when the
+    /// `builtin-lexicons` feature is enabled.
     pub fn new(client: Arc<RwLock<XrpcClient>>) -> Self {
-        ListService { client }
+        ListService { client, schemas: default_schemas() }
+    }
+
+    /// Validate records against the lexicons in `registry` before creating them
+    ///
+    /// Replaces the default registry. The registry must contain the
+    /// `app.bsky.graph` record lexicons and the lexicons they refer to.
+    pub fn with_schema_registry(mut self, registry: Arc<SchemaRegistry>) -> Self {
+        self.schemas = Some(registry);
+        self
     }
 
     /// Get a list by URI
@@ -428,28 +472,25 @@ impl ListService {
         repo: &str,
         params: CreateListParams,
     ) -> Result<CreateRecordResponse> {
-        let client = self.client.read().await;
-
-        let record = serde_json::json!({
+        let mut record = serde_json::json!({
             "$type": "app.bsky.graph.list",
             "purpose": params.purpose.as_str(),
             "name": params.name,
-            "description": params.description,
-            "descriptionFacets": params.description_facets,
-            "avatar": params.avatar,
             "createdAt": chrono::Utc::now().to_rfc3339(),
         });
+        if let Some(description) = params.description {
+            record["description"] = description.into();
+        }
+        if let Some(facets) = params.description_facets {
+            record["descriptionFacets"] = serde_json::to_value(facets)?;
+        }
+        if let Some(avatar) = params.avatar {
+            record["avatar"] = avatar;
+        }
 
-        let body = serde_json::json!({
-            "repo": repo,
-            "collection": "app.bsky.graph.list",
-            "record": record,
-        });
-
-        let request = XrpcRequest::procedure("com.atproto.repo.createRecord")
-            .json_body(&body)
-            .map_err(|e| ListError::ApiError(e.to_string()))?;
+        let request = self.create_record_request(repo, "app.bsky.graph.list", record)?;
 
+        let client = self.client.read().await;
         let response = client
             .procedure(request)
             .await
@@ -466,24 +507,9 @@ impl ListService {
         list_uri: &str,
         subject_did: &str,
     ) -> Result<CreateRecordResponse> {
-        let client = self.client.read().await;
-
-        let record = ListItemRecord {
-            subject: subject_did.to_string(),
-            list: list_uri.to_string(),
-            created_at: chrono::Utc::now().to_rfc3339(),
-        };
-
-        let body = serde_json::json!({
-            "repo": repo,
-            "collection": "app.bsky.graph.listitem",
-            "record": record,
-        });
-
-        let request = XrpcRequest::procedure("com.atproto.repo.createRecord")
-            .json_body(&body)
-            .map_err(|e| ListError::ApiError(e.to_string()))?;
+        let request = self.list_item_request(repo, list_uri, subject_did)?;
 
+        let client = self.client.read().await;
         let response = client
             .procedure(request)
             .await
@@ -493,6 +519,47 @@ impl ListService {
         Ok(data)
     }
 
+    /// Add several users to a list, pacing requests to the rate limit
+    ///
+    /// Requests are delayed by the client as the write budget runs low. If the
+    /// budget runs out, the users added so far are returned along with the
+    /// ones still pending and how long to wait before adding them. If a write
+    /// fails, the users added so far are returned with the failed user and
+    /// its error, so a retry doesn't add anyone twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - DID of the list owner
+    /// * `list_uri` - URI of the list
+    /// * `subject_dids` - DIDs of the users to add
+    ///
+    /// # Errors
+    ///
+    /// - `ListError::InvalidRecord` - A list item fails its lexicon (nothing is added)
+    pub async fn add_members(
+        &self,
+        repo: &str,
+        list_uri: &str,
+        subject_dids: &[String],
+    ) -> Result<AddMembersResult> {
+        let creates = subject_dids
+            .iter()
+            .map(|did| Ok((did.clone(), self.list_item_request(repo, list_uri, did)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let outcome = create_records_paced(&self.client, creates).await;
+        Ok(AddMembersResult {
+            added: outcome
+                .created
+                .into_iter()
+                .map(|created| CreateRecordResponse { uri: created.uri, cid: created.cid })
+                .collect(),
+            pending: outcome.pending,
+            retry_after: outcome.retry_after,
+            failed: outcome.failed,
+        })
+    }
+
     /// Remove a user from a list
     pub async fn remove_from_list(&self, repo: &str, list_item_uri: &str) -> Result<()> {
         let client = self.client.read().await;
@@ -522,58 +589,39 @@ impl ListService {
     }
 
     /// Delete a list and all its items
+    ///
+    /// Items are deleted before the list itself, so a failure part-way
+    /// leaves the list in place and the call can be retried.
+    ///
+    /// # Errors
+    ///
+    /// - `ListError::ApiError` - Some deletes were not applied
     pub async fn delete_list(&self, repo: &str, list_uri: &str) -> Result<()> {
         // First, get all list items
         let items = self.get_all_members(list_uri).await?;
 
-        let client = self.client.read().await;
-
-        // Build writes array for batch delete
-        let mut writes: Vec<serde_json::Value> = Vec::new();
-
-        // Add list items to delete
+        let mut batch = ApplyWritesBatch::new(repo);
         for item in items {
             let rkey = item
                 .uri
                 .rsplit('/')
                 .next()
                 .ok_or_else(|| ListError::ApiError("Invalid item URI".to_string()))?;
-
-            writes.push(serde_json::json!({
-                "$type": "com.atproto.repo.applyWrites#delete",
-                "collection": "app.bsky.graph.listitem",
-                "rkey": rkey,
-            }));
+            batch.delete("app.bsky.graph.listitem", rkey);
         }
 
-        // Add the list itself
         let list_rkey = list_uri
             .rsplit('/')
             .next()
             .ok_or_else(|| ListError::ApiError("Invalid list URI".to_string()))?;
+        batch.delete("app.bsky.graph.list", list_rkey);
 
-        writes.push(serde_json::json!({
-            "$type": "com.atproto.repo.applyWrites#delete",
-            "collection": "app.bsky.graph.list",
-            "rkey": list_rkey,
-        }));
-
-        // Apply in chunks of 10
-        for chunk in writes.chunks(10) {
-            let body = serde_json::json!({
-                "repo": repo,
-                "writes": chunk,
-            });
-
-            let request = XrpcRequest::procedure("com.atproto.repo.applyWrites")
-                .json_body(&body)
-                .map_err(|e| ListError::ApiError(e.to_string()))?;
-
-            client
-                .procedure::<serde_json::Value>(request)
-                .await
-                .map_err(|e| ListError::ApiError(e.to_string()))?;
-        }
+        let client = self.client.read().await;
+        batch
+            .execute(&client)
+            .await
+            .into_result()
+            .map_err(|e| ListError::ApiError(e.to_string()))?;
 
         Ok(())
     }
@@ -620,24 +668,15 @@ impl ListService {
 
     /// Block a moderation list
     pub async fn block_list(&self, repo: &str, list_uri: &str) -> Result<CreateRecordResponse> {
-        let client = self.client.read().await;
-
         let record = serde_json::json!({
             "$type": "app.bsky.graph.listblock",
             "subject": list_uri,
             "createdAt": chrono::Utc::now().to_rfc3339(),
         });
 
-        let body = serde_json::json!({
-            "repo": repo,
-            "collection": "app.bsky.graph.listblock",
-            "record": record,
-        });
-
-        let request = XrpcRequest::procedure("com.atproto.repo.createRecord")
-            .json_body(&body)
-            .map_err(|e| ListError::ApiError(e.to_string()))?;
+        let request = self.create_record_request(repo, "app.bsky.graph.listblock", record)?;
 
+        let client = self.client.read().await;
         let response = client
             .procedure(request)
             .await
@@ -675,6 +714,17 @@ impl ListService {
     }
 
     /// Update list metadata
+    ///
+    /// The name, description and facets are replaced; the avatar is replaced
+    /// only if `params.avatar` is set. Other fields of the stored record are
+    /// kept. The write is swapped on the record's CID, so a concurrent edit
+    /// made elsewhere is re-read and updated rather than overwritten.
+    ///
+    /// # Errors
+    ///
+    /// - `ListError::NotFound` - The list record doesn't exist
+    /// - `ListError::Conflict` - The list kept changing during the update
+    /// - `ListError::ApiError` - Network or other XRPC error
     pub async fn update_list(&self, repo: &str, params: UpdateListParams) -> Result<()> {
         let client = self.client.read().await;
 
@@ -684,55 +734,84 @@ impl ListService {
             .next()
             .ok_or_else(|| ListError::ApiError("Invalid list URI".to_string()))?;
 
-        // First get the current record
-        let get_request = XrpcRequest::query("com.atproto.repo.getRecord")
-            .param("repo", repo.to_string())
-            .param("collection", "app.bsky.graph.list".to_string())
-            .param("rkey", rkey.to_string());
-
-        let get_response = client
-            .query::<serde_json::Value>(get_request)
+        let description_facets = params
+            .description_facets
+            .map(serde_json::to_value)
+            .transpose()?;
+
+        RecordUpdate::new(repo, "app.bsky.graph.list", rkey)
+            .apply(&client, |current: Option<serde_json::Map<String, serde_json::Value>>| {
+                let mut fields = current.ok_or_else(|| WriteError::Rejected(params.uri.clone()))?;
+
+                fields.insert("name".to_string(), params.name.clone().into());
+                let replaced = [
+                    ("description", params.description.clone().map(Into::into)),
+                    ("descriptionFacets", description_facets.clone()),
+                ];
+                for (key, value) in replaced {
+                    match value {
+                        Some(value) => fields.insert(key.to_string(), value),
+                        None => fields.remove(key),
+                    };
+                }
+                match &params.avatar {
+                    Some(Some(avatar)) => {
+                        fields.insert("avatar".to_string(), avatar.clone());
+                    }
+                    Some(None) => {
+                        fields.remove("avatar");
+                    }
+                    None => {}
+                }
+
+                Ok(fields)
+            })
             .await
-            .map_err(|e| ListError::ApiError(e.to_string()))?;
+            .map_err(|e| match e {
+                WriteError::Rejected(uri) => ListError::NotFound(uri),
+                WriteError::Conflict { .. } => ListError::Conflict(e.to_string()),
+                e => ListError::ApiError(e.to_string()),
+            })?;
 
-        let mut record: serde_json::Value = get_response
-            .data
-            .get("value")
-            .cloned()
-            .ok_or_else(|| ListError::NotFound(params.uri.clone()))?;
-
-        // Update fields
-        record["name"] = serde_json::Value::String(params.name);
-        record["description"] = params
-            .description
-            .map(serde_json::Value::String)
-            .unwrap_or(serde_json::Value::Null);
-        record["descriptionFacets"] = params
-            .description_facets
-            .map(|f| serde_json::to_value(f).unwrap_or(serde_json::Value::Null))
-            .unwrap_or(serde_json::Value::Null);
+        Ok(())
+    }
 
-        if let Some(avatar_opt) = params.avatar {
-            record["avatar"] = avatar_opt.unwrap_or(serde_json::Value::Null);
+    /// Build the createRecord request for a list item
+    fn list_item_request(
+        &self,
+        repo: &str,
+        list_uri: &str,
+        subject_did: &str,
+    ) -> Result<XrpcRequest> {
+        let record = ListItemRecord {
+            subject: subject_did.to_string(),
+            list: list_uri.to_string(),
+            created_at: chrono::Utc::now().to_rfc3339(),
+        };
+
+        self.create_record_request(repo, "app.bsky.graph.listitem", serde_json::to_value(record)?)
+    }
+
+    /// Build a createRecord request, validating the record if a registry is set
+    fn create_record_request(
+        &self,
+        repo: &str,
+        collection: &str,
+        record: serde_json::Value,
+    ) -> Result<XrpcRequest> {
+        if let Some(registry) = &self.schemas {
+            validate_record(registry, collection, &record)?;
         }
 
         let body = serde_json::json!({
             "repo": repo,
-            "collection": "app.bsky.graph.list",
-            "rkey": rkey,
+            "collection": collection,
             "record": record,
         });
 
-        let request = XrpcRequest::procedure("com.atproto.repo.putRecord")
+        XrpcRequest::procedure(create_record::NSID)
             .json_body(&body)
-            .map_err(|e| ListError::ApiError(e.to_string()))?;
-
-        client
-            .procedure::<serde_json::Value>(request)
-            .await
-            .map_err(|e| ListError::ApiError(e.to_string()))?;
-
-        Ok(())
+            .map_err(|e| ListError::ApiError(e.to_string()))
     }
 }
 
@@ -882,4 +961,176 @@ mod tests {
         assert_eq!(json["subject"], "did:plc:user123");
         assert_eq!(json["list"], "at://did:plc:abc/app.bsky.graph.list/123");
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_add_members_stops_when_budget_runs_out() {
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::{HttpResponse, XrpcClientConfig};
+        use std::collections::HashMap;
+
+        let headers = |remaining: &str| {
+            HashMap::from([
+                ("RateLimit-Limit".to_string(), "100".to_string()),
+                ("RateLimit-Remaining".to_string(), remaining.to_string()),
+                ("RateLimit-Reset".to_string(), "3600".to_string()),
+            ])
+        };
+        let created = serde_json::json!({
+            "uri": "at://did:plc:owner/app.bsky.graph.listitem/1",
+            "cid": "bafy"
+        });
+
+        // The second write spends the budget; the third would wait an hour
+        let transport = Arc::new(MockTransport::new());
+        for remaining in ["40", "0"] {
+            transport.push_response(
+                create_record::NSID,
+                HttpResponse::new(200, headers(remaining), serde_json::to_vec(&created).unwrap()),
+            );
+        }
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let service = ListService::new(Arc::new(RwLock::new(XrpcClient::new(config))));
+
+        let subjects: Vec<String> = (1..=4).map(|i| format!("did:plc:user{}", i)).collect();
+        let result = service
+            .add_members("did:plc:owner", "at://did:plc:owner/app.bsky.graph.list/1", &subjects)
+            .await
+            .unwrap();
+
+        assert_eq!(result.added.len(), 2);
+        assert_eq!(result.pending, subjects[2..].to_vec());
+        assert!(result.retry_after.unwrap() > Duration::from_secs(3500));
+        assert_eq!(transport.request_count(create_record::NSID), 2);
+    }
+
+    #[tokio::test]
+    async fn test_records_are_validated_before_create() {
+        use atproto_client::lexicon::LexiconDoc;
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::XrpcClientConfig;
+
+        let listitem: LexiconDoc = serde_json::from_value(serde_json::json!({
+            "lexicon": 1,
+            "id": "app.bsky.graph.listitem",
+            "defs": {
+                "main": {
+                    "type": "record",
+                    "key": "tid",
+                    "record": {
+                        "type": "object",
+                        "required": ["subject", "list", "createdAt"],
+                        "properties": {
+                            "subject": {"type": "string", "format": "did"},
+                            "list": {"type": "string", "format": "at-uri"},
+                            "createdAt": {"type": "string", "format": "datetime"}
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+        let mut registry = SchemaRegistry::new();
+        registry.register(listitem);
+
+        let transport = Arc::new(MockTransport::new());
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let service = ListService::new(Arc::new(RwLock::new(XrpcClient::new(config))))
+            .with_schema_registry(Arc::new(registry));
+
+        let result = service
+            .add_to_list("did:plc:owner", "at://did:plc:owner/app.bsky.graph.list/1", "alice")
+            .await;
+
+        match result {
+            Err(ListError::InvalidRecord(e)) => assert_eq!(e.errors[0].path, "subject"),
+            other => panic!("Expected invalid record, got {:?}", other.map(|r| r.uri)),
+        }
+        assert_eq!(transport.request_count(create_record::NSID), 0);
+    }
+
+    #[cfg(feature = "builtin-lexicons")]
+    #[tokio::test]
+    async fn test_records_are_validated_with_builtin_lexicons_by_default() {
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::XrpcClientConfig;
+
+        let transport = Arc::new(MockTransport::new());
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let service = ListService::new(Arc::new(RwLock::new(XrpcClient::new(config))));
+
+        let result = service
+            .add_to_list("did:plc:owner", "at://did:plc:owner/app.bsky.graph.list/1", "alice")
+            .await;
+
+        assert!(matches!(result, Err(ListError::InvalidRecord(_))));
+        assert_eq!(transport.request_count(create_record::NSID), 0);
+    }
+
+    #[tokio::test]
+    async fn test_update_list_swaps_and_reports_missing_list() {
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::XrpcClientConfig;
+
+        let params = || UpdateListParams {
+            uri: "at://did:plc:owner/app.bsky.graph.list/1".to_string(),
+            name: "Renamed".to_string(),
+            description: None,
+            description_facets: None,
+            avatar: None,
+        };
+
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(
+                    "com.atproto.repo.getRecord",
+                    200,
+                    serde_json::json!({
+                        "uri": "at://did:plc:owner/app.bsky.graph.list/1",
+                        "cid": "bafylist",
+                        "value": {
+                            "$type": "app.bsky.graph.list",
+                            "name": "Friends",
+                            "description": "Old",
+                            "purpose": "app.bsky.graph.defs#curatelist",
+                            "avatar": {"$type": "blob"},
+                            "createdAt": "2024-01-15T10:00:00Z"
+                        }
+                    }),
+                )
+                .with_json(
+                    "com.atproto.repo.putRecord",
+                    200,
+                    serde_json::json!({"uri": "at://did:plc:owner/app.bsky.graph.list/1", "cid": "bafynew"}),
+                ),
+        );
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let service = ListService::new(Arc::new(RwLock::new(XrpcClient::new(config))));
+
+        service
+            .update_list("did:plc:owner", params())
+            .await
+            .unwrap();
+
+        let put = transport
+            .requests()
+            .into_iter()
+            .find(|request| request.nsid == "com.atproto.repo.putRecord")
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(put.body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["swapRecord"], "bafylist");
+        assert_eq!(body["record"]["name"], "Renamed");
+        assert_eq!(body["record"]["purpose"], "app.bsky.graph.defs#curatelist");
+        assert!(body["record"].get("description").is_none());
+        assert!(body["record"].get("avatar").is_some());
+
+        transport.reset();
+        transport.push_json(
+            "com.atproto.repo.getRecord",
+            400,
+            serde_json::json!({"error": "RecordNotFound", "message": "Not found"}),
+        );
+        let result = service.update_list("did:plc:owner", params()).await;
+        assert!(matches!(result, Err(ListError::NotFound(_))));
+        assert_eq!(transport.request_count("com.atproto.repo.putRecord"), 0);
+    }
 }
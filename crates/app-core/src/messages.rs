@@ -8,6 +8,7 @@
 //! - Message persistence and caching
 //! - Real-time message updates
 
+use atproto_client::proxy::ServiceProxy;
 use atproto_client::xrpc::{XrpcClient, XrpcRequest};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -257,6 +258,10 @@ pub struct ListMessagesResponse {
 /// Provides methods for listing conversations, viewing message threads,
 /// and sending messages.
 ///
+/// Chat methods are served by the chat service rather than the PDS, so every
+/// request is proxied to Bluesky chat with the `atproto-proxy` header unless
+/// another target is set with [`with_proxy`](Self::with_proxy).
+///
 /// # Example
 ///
 /// ```rust,no_run
@@ -280,12 +285,34 @@ pub struct ListMessagesResponse {
 pub struct ConversationService {
     /// XRPC client
     client: Arc<RwLock<XrpcClient>>,
+    /// Chat service to proxy requests to
+    proxy: Option<ServiceProxy>,
 }
 
 impl ConversationService {
     /// Create a new conversation service
     pub fn new(client: XrpcClient) -> Self {
-        Self { client: Arc::new(RwLock::new(client)) }
+        Self {
+            client: Arc::new(RwLock::new(client)),
+            proxy: Some(ServiceProxy::bsky_chat()),
+        }
+    }
+
+    /// Set the chat service to proxy requests to
+    ///
+    /// Pass `None` when the client already routes `chat.bsky.*` itself, e.g.
+    /// a client talking to the chat service directly.
+    pub fn with_proxy(mut self, proxy: Option<ServiceProxy>) -> Self {
+        self.proxy = proxy;
+        self
+    }
+
+    /// Apply the chat service proxy to a request
+    fn proxied(&self, request: XrpcRequest) -> XrpcRequest {
+        match &self.proxy {
+            Some(proxy) => request.proxy(proxy),
+            None => request,
+        }
     }
 
     /// List all conversations for the current user
@@ -310,6 +337,7 @@ impl ConversationService {
             request = request.param("cursor", cursor);
         }
 
+        let request = self.proxied(request);
         let client = self.client.read().await;
         let response = client
             .query(request)
@@ -339,6 +367,7 @@ impl ConversationService {
             request = request.param("cursor", cursor);
         }
 
+        let request = self.proxied(request);
         let client = self.client.read().await;
         let response = client
             .query(request)
@@ -382,6 +411,7 @@ impl ConversationService {
             .json_body(&request_body)
             .map_err(MessageError::Serialization)?;
 
+        let request = self.proxied(request);
         let client = self.client.read().await;
         let response = client
             .procedure(request)
@@ -410,6 +440,7 @@ impl ConversationService {
             .json_body(&request_body)
             .map_err(MessageError::Serialization)?;
 
+        let request = self.proxied(request);
         let client = self.client.read().await;
         let response = client
             .procedure(request)
@@ -437,6 +468,7 @@ impl ConversationService {
             .json_body(&request_body)
             .map_err(MessageError::Serialization)?;
 
+        let request = self.proxied(request);
         let client = self.client.read().await;
         client
             .procedure::<serde_json::Value>(request)
@@ -464,6 +496,7 @@ impl ConversationService {
             .json_body(&request_body)
             .map_err(MessageError::Serialization)?;
 
+        let request = self.proxied(request);
         let client = self.client.read().await;
         client
             .procedure::<serde_json::Value>(request)
@@ -491,6 +524,7 @@ impl ConversationService {
             .json_body(&request_body)
             .map_err(MessageError::Serialization)?;
 
+        let request = self.proxied(request);
         let client = self.client.read().await;
         client
             .procedure::<serde_json::Value>(request)
@@ -901,6 +935,8 @@ pub struct EventListenerConfig {
     pub enable_typing: bool,
     /// Event buffer size
     pub buffer_size: usize,
+    /// Chat service to proxy polling requests to
+    pub proxy: Option<ServiceProxy>,
 }
 
 impl Default for EventListenerConfig {
@@ -910,6 +946,7 @@ impl Default for EventListenerConfig {
             poll_interval: std::time::Duration::from_secs(5),
             enable_typing: false,
             buffer_size: 100,
+            proxy: Some(ServiceProxy::bsky_chat()),
         }
     }
 }
@@ -998,11 +1035,12 @@ impl MessageEventListener {
         let last_cursor = Arc::clone(&self.last_cursor);
         let event_tx = self.event_tx.clone();
         let poll_interval = self.config.poll_interval;
+        let proxy = self.config.proxy.clone();
 
         tokio::spawn(async move {
             while *running.read().await {
                 // Poll for new messages
-                if let Ok(events) = Self::poll_events(&client, &last_cursor).await {
+                if let Ok(events) = Self::poll_events(&client, &last_cursor, proxy.as_ref()).await {
                     for event in events {
                         let _ = event_tx.send(event);
                     }
@@ -1019,6 +1057,7 @@ impl MessageEventListener {
     async fn poll_events(
         client: &Arc<RwLock<XrpcClient>>,
         last_cursor: &Arc<RwLock<Option<String>>>,
+        proxy: Option<&ServiceProxy>,
     ) -> Result<Vec<MessageEvent>> {
         let cursor = last_cursor.read().await.clone();
 
@@ -1028,6 +1067,9 @@ impl MessageEventListener {
         if let Some(cursor_val) = cursor {
             request = request.param("cursor", cursor_val);
         }
+        if let Some(proxy) = proxy {
+            request = request.proxy(proxy);
+        }
 
         #[derive(Deserialize)]
         #[serde(rename_all = "camelCase")]
@@ -1421,6 +1463,7 @@ impl ConversationService {
             request = request.param("cursor", cursor);
         }
 
+        let request = self.proxied(request);
         let client = self.client.read().await;
         let response = client
             .query(request)
@@ -1449,6 +1492,7 @@ impl ConversationService {
             .json_body(&request_body)
             .map_err(MessageError::Serialization)?;
 
+        let request = self.proxied(request);
         let client = self.client.read().await;
         let response = client
             .procedure(request)
@@ -1470,6 +1514,7 @@ impl ConversationService {
             .json_body(&request_body)
             .map_err(MessageError::Serialization)?;
 
+        let request = self.proxied(request);
         let client = self.client.read().await;
         client
             .procedure::<serde_json::Value>(request)
@@ -1757,6 +1802,44 @@ mod tests {
         let _service = ConversationService::new(client);
     }
 
+    #[tokio::test]
+    async fn test_conversation_requests_are_proxied_to_chat_offline() {
+        use atproto_client::proxy::PROXY_HEADER;
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::XrpcClientConfig;
+
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(
+                    "chat.bsky.convo.listConvos",
+                    200,
+                    serde_json::json!({"conversations": []}),
+                )
+                .with_json("chat.bsky.convo.declineMessageRequest", 200, serde_json::json!({})),
+        );
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let service = ConversationService::new(XrpcClient::new(config));
+
+        let response = service
+            .list_conversations(ListConversationsParams::default())
+            .await
+            .unwrap();
+        assert!(response.conversations.is_empty());
+        service.decline_message_request("req1").await.unwrap();
+
+        for request in transport.requests() {
+            assert_eq!(request.header(PROXY_HEADER), Some("did:web:api.bsky.chat#bsky_chat"));
+        }
+
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let service = ConversationService::new(XrpcClient::new(config)).with_proxy(None);
+        service
+            .list_conversations(ListConversationsParams::default())
+            .await
+            .unwrap();
+        assert_eq!(transport.requests()[2].header(PROXY_HEADER), None);
+    }
+
     // Inbox tests
 
     fn create_test_conversation(
@@ -2227,6 +2310,7 @@ mod tests {
             poll_interval: std::time::Duration::from_secs(10),
             enable_typing: true,
             buffer_size: 200,
+            proxy: None,
         };
 
         assert_eq!(config.mode, ConnectionMode::Polling);
@@ -1217,4 +1217,34 @@ mod tests {
         assert_eq!(json["cursor"], "cursor123");
         assert_eq!(json["priority"], true);
     }
+
+    #[tokio::test]
+    async fn test_unread_count_offline() {
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::XrpcClientConfig;
+
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(
+                    "app.bsky.notification.getUnreadCount",
+                    200,
+                    serde_json::json!({"count": 7}),
+                )
+                .with_json("app.bsky.notification.updateSeen", 200, serde_json::json!({})),
+        );
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let service = NotificationService::new(Arc::new(RwLock::new(XrpcClient::new(config))));
+
+        assert_eq!(service.get_unread_count().await.unwrap(), 7);
+        assert_eq!(service.last_unread_count().await, 7);
+
+        service.mark_all_read().await.unwrap();
+        assert_eq!(service.last_unread_count().await, 0);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        let body: serde_json::Value =
+            serde_json::from_slice(requests[1].body.as_ref().unwrap()).unwrap();
+        assert!(body["seenAt"].is_string());
+    }
 }
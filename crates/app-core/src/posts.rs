@@ -4,7 +4,13 @@
 //! rich text parsing with facets for links, mentions, and hashtags, and
 //! reply handling for threaded conversations.
 
-use atproto_client::lexicon::BlobRef;
+use atproto_client::lexicon::dagjson::to_dag_json;
+use atproto_client::lexicon::{
+    validate_record, BlobRef, DagJsonError, RecordValidationError, SchemaRegistry,
+};
+use atproto_client::upload::{
+    upload_all, BlobLimits, BlobUpload, UploadError, DEFAULT_CONCURRENCY,
+};
 use atproto_client::xrpc::{XrpcClient, XrpcRequest};
 use chrono::Utc;
 use regex::Regex;
@@ -349,6 +355,30 @@ pub struct EmbedImage {
     pub aspect_ratio: Option<AspectRatio>,
 }
 
+/// Image to upload and attach to a post
+#[derive(Debug)]
+pub struct ImageUpload {
+    /// The image blob, streamed from memory, a file or a reader
+    pub upload: BlobUpload,
+    /// Alt text for accessibility
+    pub alt: String,
+    /// Aspect ratio
+    pub aspect_ratio: Option<AspectRatio>,
+}
+
+impl ImageUpload {
+    /// Create an image upload with alt text
+    pub fn new(upload: BlobUpload, alt: impl Into<String>) -> Self {
+        Self { upload, alt: alt.into(), aspect_ratio: None }
+    }
+
+    /// Set the aspect ratio
+    pub fn with_aspect_ratio(mut self, width: u32, height: u32) -> Self {
+        self.aspect_ratio = Some(AspectRatio { width, height });
+        self
+    }
+}
+
 /// Images embed (up to 4 images)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImagesEmbed {
@@ -645,6 +675,18 @@ pub enum PostError {
     /// Invalid post URI
     #[error("Invalid post URI: {0}")]
     InvalidUri(String),
+
+    /// Post record does not match the app.bsky.feed.post lexicon
+    #[error(transparent)]
+    InvalidRecord(#[from] RecordValidationError),
+
+    /// Post record can't be written in the atproto data model (e.g., a bad blob CID)
+    #[error("Invalid record data: {0}")]
+    RecordData(#[from] DagJsonError),
+
+    /// Image upload failed
+    #[error("Image upload failed: {0}")]
+    Upload(#[from] UploadError),
 }
 
 /// Result type for post operations
@@ -686,12 +728,48 @@ pub const MAX_IMAGES_PER_POST: usize = 4;
 pub struct PostComposer {
     /// XRPC client
     client: Arc<RwLock<XrpcClient>>,
+    /// Lexicons to validate records against before creating them
+    schemas: Option<Arc<SchemaRegistry>>,
+}
+
+/// Lexicons records are validated against unless a service is given a registry
+///
+/// The built-in lexicons with the `builtin-lexicons` feature, otherwise none.
+pub(crate) fn default_schemas() -> Option<Arc<SchemaRegistry>> {
+    #[cfg(feature = "builtin-lexicons")]
+    {
+        static BUILTIN: OnceLock<Arc<SchemaRegistry>> = OnceLock::new();
+        Some(
+            BUILTIN
+                .get_or_init(|| Arc::new(SchemaRegistry::with_builtin()))
+                .clone(),
+        )
+    }
+    #[cfg(not(feature = "builtin-lexicons"))]
+    {
+        None
+    }
 }
 
 impl PostComposer {
     /// Create a new post composer
+    ///
+    /// Posts are validated against the built-in lexicons when the
+    /// `builtin-lexicons` feature is enabled.
     pub fn new(client: XrpcClient) -> Self {
-        Self { client: Arc::new(RwLock::new(client)) }
+        Self {
+            client: Arc::new(RwLock::new(client)),
+            schemas: default_schemas(),
+        }
+    }
+
+    /// Validate post records against the lexicons in `registry` before creating them
+    ///
+    /// Replaces the default registry. The registry must contain
+    /// `app.bsky.feed.post` and the lexicons it refers to.
+    pub fn with_schema_registry(mut self, registry: Arc<SchemaRegistry>) -> Self {
+        self.schemas = Some(registry);
+        self
     }
 
     /// Create a post with text only
@@ -716,10 +794,14 @@ impl PostComposer {
 
     /// Create a post with text and images
     ///
+    /// Images are checked against their limits (by default `image/*` up to
+    /// [`crate::media::MAX_IMAGE_SIZE`]) before anything is sent, then
+    /// uploaded in parallel.
+    ///
     /// # Arguments
     ///
     /// * `text` - The post text with facets
-    /// * `images` - Image embeds (up to 4)
+    /// * `images` - Images to upload (up to 4)
     ///
     /// # Returns
     ///
@@ -728,20 +810,49 @@ impl PostComposer {
     /// # Errors
     ///
     /// - `PostError::TooManyImages` - More than 4 images
+    /// - `PostError::Upload` - An image failed its limits, was cancelled or
+    ///   couldn't be uploaded
     /// - `PostError::TextTooLong` - Text exceeds 300 graphemes
     /// - `PostError::NoSession` - No active session
     /// - `PostError::Xrpc` - XRPC error
     pub async fn create_post_with_images(
         &self,
         text: &RichText,
-        images: Vec<EmbedImage>,
+        images: Vec<ImageUpload>,
     ) -> PostResult<(String, String)> {
         if images.len() > MAX_IMAGES_PER_POST {
             return Err(PostError::TooManyImages { count: images.len() });
         }
 
-        let embed = if !images.is_empty() {
-            Some(Embed::Images(ImagesEmbed::new(images)))
+        let mut uploads = Vec::with_capacity(images.len());
+        let mut details = Vec::with_capacity(images.len());
+        for image in images {
+            let upload = match image.upload.limits() {
+                Some(_) => image.upload,
+                None => image.upload.with_limits(
+                    BlobLimits::new()
+                        .with_accept(["image/*"])
+                        .with_max_size(crate::media::MAX_IMAGE_SIZE as u64),
+                ),
+            };
+            // Fail before uploading anything if one image is out of bounds
+            upload.validate()?;
+            uploads.push(upload);
+            details.push((image.alt, image.aspect_ratio));
+        }
+
+        let blobs = {
+            let client = self.client.read().await;
+            upload_all(&client, uploads, DEFAULT_CONCURRENCY).await
+        };
+
+        let mut embed_images = Vec::with_capacity(blobs.len());
+        for (blob, (alt, aspect_ratio)) in blobs.into_iter().zip(details) {
+            embed_images.push(EmbedImage { image: blob?, alt, aspect_ratio });
+        }
+
+        let embed = if !embed_images.is_empty() {
+            Some(Embed::Images(ImagesEmbed::new(embed_images)))
         } else {
             None
         };
@@ -765,6 +876,8 @@ impl PostComposer {
     ///
     /// - `PostError::EmptyPost` - No text and no embed
     /// - `PostError::TextTooLong` - Text exceeds 300 graphemes
+    /// - `PostError::InvalidRecord` - Record fails lexicon validation
+    /// - `PostError::RecordData` - An embedded blob has an invalid CID
     /// - `PostError::NoSession` - No active session
     /// - `PostError::Xrpc` - XRPC error
     pub async fn create_post_with_options(
@@ -797,6 +910,13 @@ impl PostComposer {
             langs,
             record_type: "app.bsky.feed.post".to_string(),
         };
+        // Blob refs serialize their CID in the IPLD `{"/": ...}` form; XRPC
+        // bodies use `$link`
+        let record = to_dag_json(&record)?;
+
+        if let Some(registry) = &self.schemas {
+            validate_record(registry, "app.bsky.feed.post", &record)?;
+        }
 
         let body = serde_json::json!({
             "repo": "self",
@@ -1230,6 +1350,75 @@ mod tests {
         assert_eq!(response.cid, "bafytest");
     }
 
+    #[tokio::test]
+    async fn test_post_composer_validates_record() {
+        use atproto_client::lexicon::LexiconDoc;
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::XrpcClientConfig;
+
+        let post: LexiconDoc = serde_json::from_value(serde_json::json!({
+            "lexicon": 1,
+            "id": "app.bsky.feed.post",
+            "defs": {
+                "main": {
+                    "type": "record",
+                    "key": "tid",
+                    "record": {
+                        "type": "object",
+                        "required": ["text", "createdAt"],
+                        "properties": {
+                            "text": {"type": "string", "maxGraphemes": 300},
+                            "createdAt": {"type": "string", "format": "datetime"},
+                            "langs": {
+                                "type": "array",
+                                "maxLength": 3,
+                                "items": {"type": "string", "format": "language"}
+                            }
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+        let mut registry = SchemaRegistry::new();
+        registry.register(post);
+
+        let transport = Arc::new(MockTransport::new());
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let composer =
+            PostComposer::new(XrpcClient::new(config)).with_schema_registry(Arc::new(registry));
+
+        let langs = ["en", "es", "fr", "de"].map(String::from).to_vec();
+        let result = composer
+            .create_post_with_options(&RichText::new("Hello"), None, Some(langs))
+            .await;
+
+        match result {
+            Err(PostError::InvalidRecord(e)) => assert_eq!(e.errors[0].path, "langs"),
+            other => panic!("Expected invalid record, got {:?}", other),
+        }
+        assert_eq!(transport.request_count("com.atproto.repo.createRecord"), 0);
+    }
+
+    #[cfg(feature = "builtin-lexicons")]
+    #[tokio::test]
+    async fn test_post_composer_validates_with_builtin_lexicons_by_default() {
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::XrpcClientConfig;
+
+        let transport = Arc::new(MockTransport::new());
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let composer = PostComposer::new(XrpcClient::new(config));
+
+        let langs = ["en", "es", "fr", "de"].map(String::from).to_vec();
+        let result = composer
+            .create_post_with_options(&RichText::new("Hello"), None, Some(langs))
+            .await;
+
+        assert!(matches!(result, Err(PostError::InvalidRecord(_))));
+        assert_eq!(transport.request_count("com.atproto.repo.createRecord"), 0);
+    }
+
     #[test]
     fn test_reply_composer_context() {
         use atproto_client::xrpc::XrpcClientConfig;
@@ -1664,4 +1853,72 @@ mod tests {
             assert!(matches!(result.unwrap_err(), PostError::InvalidUri(_)));
         });
     }
+
+    #[tokio::test]
+    async fn test_create_post_with_images_uploads_before_posting() {
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::XrpcClientConfig;
+
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(
+                    "com.atproto.repo.uploadBlob",
+                    200,
+                    serde_json::json!({
+                        "blob": {
+                            "$type": "blob",
+                            "ref": {"$link": "bafkreibme22gw2h7y2h7tg2fhqotaqjucnbc24deqo72b6mkl2egezxhvy"},
+                            "mimeType": "image/png",
+                            "size": 4
+                        }
+                    }),
+                )
+                .with_json(
+                    "com.atproto.repo.createRecord",
+                    200,
+                    serde_json::json!({"uri": "at://did:plc:alice/app.bsky.feed.post/1", "cid": "bafypost"}),
+                ),
+        );
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let composer = PostComposer::new(XrpcClient::new(config));
+        let text = RichText::new("Two pictures");
+
+        // An oversized image stops the post before anything is uploaded
+        let images = vec![
+            ImageUpload::new(BlobUpload::from_bytes(vec![0; 4], "image/png"), "small"),
+            ImageUpload::new(
+                BlobUpload::from_bytes(vec![0; crate::media::MAX_IMAGE_SIZE + 1], "image/png"),
+                "huge",
+            ),
+        ];
+        let result = composer.create_post_with_images(&text, images).await;
+        assert!(matches!(result, Err(PostError::Upload(UploadError::TooLarge { .. }))));
+        assert_eq!(transport.request_count("com.atproto.repo.uploadBlob"), 0);
+
+        let images = vec![
+            ImageUpload::new(BlobUpload::from_bytes(vec![0; 4], "image/png"), "first")
+                .with_aspect_ratio(4, 3),
+            ImageUpload::new(BlobUpload::from_bytes(vec![1; 4], "image/png"), "second"),
+        ];
+        let (uri, cid) = composer
+            .create_post_with_images(&text, images)
+            .await
+            .unwrap();
+        assert_eq!(uri, "at://did:plc:alice/app.bsky.feed.post/1");
+        assert_eq!(cid, "bafypost");
+        assert_eq!(transport.request_count("com.atproto.repo.uploadBlob"), 2);
+
+        let requests = transport.requests();
+        let create = requests.last().unwrap();
+        let body: serde_json::Value =
+            serde_json::from_slice(create.body.as_ref().unwrap()).unwrap();
+        let embedded = &body["record"]["embed"]["images"];
+        assert_eq!(embedded[0]["alt"], "first");
+        assert_eq!(embedded[0]["aspectRatio"]["width"], 4);
+        assert_eq!(embedded[1]["image"]["mimeType"], "image/png");
+        assert_eq!(
+            embedded[1]["image"]["ref"]["$link"],
+            "bafkreibme22gw2h7y2h7tg2fhqotaqjucnbc24deqo72b6mkl2egezxhvy"
+        );
+    }
 }
@@ -3,6 +3,7 @@
 //! This module provides functionality for viewing and managing user profiles,
 //! including fetching profile data, stats, and profile-related operations.
 
+use atproto_client::repo::write::{RecordUpdate, WriteError};
 use atproto_client::xrpc::{XrpcClient, XrpcRequest};
 use chrono;
 use serde::{Deserialize, Serialize};
@@ -36,6 +37,10 @@ pub enum ProfileError {
     /// No active session
     #[error("No active session")]
     NoSession,
+
+    /// The record kept changing while being updated
+    #[error("Update conflict: {0}")]
+    Conflict(String),
 }
 
 /// Result type for profile operations
@@ -824,7 +829,9 @@ impl ProfileService {
     /// Update the current user's profile
     ///
     /// Updates the profile record for the authenticated user. Can update display name,
-    /// description (bio), avatar, and banner images.
+    /// description (bio), avatar, and banner images. Fields not set in `update`
+    /// keep their stored values. If the profile is edited elsewhere while this
+    /// update is in flight, the update is reapplied on top of that edit.
     ///
     /// # Arguments
     ///
@@ -844,6 +851,7 @@ impl ProfileService {
     /// - `ProfileError::InvalidActor` - Character limits exceeded
     /// - `ProfileError::NoSession` - No active session
     /// - `ProfileError::Network` - Network error
+    /// - `ProfileError::Conflict` - The profile kept changing during the update
     ///
     /// # Example
     ///
@@ -882,46 +890,33 @@ impl ProfileService {
             }
         }
 
-        // Get the current profile first to get the repo (DID)
-        // In a real implementation, this should come from the session
-        // For now, we'll use a placeholder approach
-        #[derive(Serialize)]
-        struct ProfileRecord {
-            #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
-            display_name: Option<String>,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            description: Option<String>,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            avatar: Option<serde_json::Value>,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            banner: Option<serde_json::Value>,
-            #[serde(rename = "$type")]
-            record_type: String,
-        }
-
-        let record = ProfileRecord {
-            display_name: update.display_name,
-            description: update.description,
-            avatar: update.avatar,
-            banner: update.banner,
-            record_type: "app.bsky.actor.profile".to_string(),
-        };
-
-        // Update profile record via XRPC
-        let request = XrpcRequest::procedure("com.atproto.repo.putRecord")
-            .json_body(&serde_json::json!({
-                "repo": update.repo,
-                "collection": "app.bsky.actor.profile",
-                "rkey": "self",
-                "record": record,
-            }))
-            .map_err(|e| ProfileError::Xrpc(e.to_string()))?;
-
+        // Merge into the stored record so fields this update doesn't cover
+        // (pinned post, labels, ...) survive, and swap on its CID so an edit
+        // made elsewhere in the meantime isn't overwritten
         let client = self.client.read().await;
-        client
-            .procedure::<serde_json::Value>(request)
+        RecordUpdate::new(&update.repo, "app.bsky.actor.profile", "self")
+            .apply(&client, |current: Option<serde_json::Value>| {
+                let mut record = current.unwrap_or_else(|| serde_json::json!({}));
+                record["$type"] = "app.bsky.actor.profile".into();
+                let fields = [
+                    ("displayName", update.display_name.clone().map(Into::into)),
+                    ("description", update.description.clone().map(Into::into)),
+                    ("avatar", update.avatar.clone()),
+                    ("banner", update.banner.clone()),
+                ];
+                for (key, value) in fields {
+                    if let Some(value) = value {
+                        record[key] = value;
+                    }
+                }
+                Ok(record)
+            })
             .await
-            .map_err(|e| ProfileError::Xrpc(e.to_string()))?;
+            .map_err(|e| match e {
+                WriteError::Conflict { .. } => ProfileError::Conflict(e.to_string()),
+                e => ProfileError::Xrpc(e.to_string()),
+            })?;
+        drop(client);
 
         // Fetch and return the updated profile
         self.get_profile(&update.repo).await
@@ -1700,4 +1695,62 @@ mod tests {
         assert!(debug_str.contains("KnownFollowersResponse"));
         assert!(debug_str.contains("did:plc:test"));
     }
+
+    #[tokio::test]
+    async fn test_update_profile_keeps_other_fields_and_retries_on_swap_conflict() {
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::XrpcClientConfig;
+        use serde_json::json;
+
+        let stored = |cid: &str, name: &str| {
+            json!({
+                "uri": "at://did:plc:alice/app.bsky.actor.profile/self",
+                "cid": cid,
+                "value": {
+                    "$type": "app.bsky.actor.profile",
+                    "displayName": name,
+                    "description": "Old bio",
+                    "pinnedPost": {"uri": "at://did:plc:alice/app.bsky.feed.post/1", "cid": "bafypost"}
+                }
+            })
+        };
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json("com.atproto.repo.getRecord", 200, stored("bafy1", "Alice"))
+                .with_json("com.atproto.repo.getRecord", 200, stored("bafy2", "Alice (phone)"))
+                .with_json(
+                    "com.atproto.repo.putRecord",
+                    400,
+                    json!({"error": "InvalidSwap", "message": "Record was at bafy2"}),
+                )
+                .with_json(
+                    "com.atproto.repo.putRecord",
+                    200,
+                    json!({"uri": "at://did:plc:alice/app.bsky.actor.profile/self", "cid": "bafy3"}),
+                )
+                .with_json(
+                    "app.bsky.actor.getProfile",
+                    200,
+                    json!({"did": "did:plc:alice", "handle": "alice.test"}),
+                ),
+        );
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let service = ProfileService::new(XrpcClient::new(config));
+
+        let update = ProfileUpdateParams::new("did:plc:alice").with_description("New bio");
+        service.update_profile(update).await.unwrap();
+
+        let puts: Vec<serde_json::Value> = transport
+            .requests()
+            .iter()
+            .filter(|request| request.nsid == "com.atproto.repo.putRecord")
+            .map(|request| serde_json::from_slice(request.body.as_ref().unwrap()).unwrap())
+            .collect();
+        assert_eq!(puts.len(), 2);
+        let retry = &puts[1];
+        assert_eq!(retry["swapRecord"], "bafy2");
+        assert_eq!(retry["record"]["displayName"], "Alice (phone)");
+        assert_eq!(retry["record"]["description"], "New bio");
+        assert_eq!(retry["record"]["pinnedPost"]["cid"], "bafypost");
+    }
 }
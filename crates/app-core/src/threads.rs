@@ -3,7 +3,9 @@
 //! This module provides functionality for viewing post threads, including
 //! parent context, replies, and thread navigation.
 
-use atproto_client::xrpc::{XrpcClient, XrpcRequest};
+use atproto_client::repo::write::{RecordUpdate, WriteError};
+use atproto_client::types::AtUri;
+use atproto_client::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use thiserror::Error;
@@ -22,9 +24,13 @@ pub enum ThreadError {
     #[error("Post not found: {0}")]
     NotFound(String),
 
-    /// Thread blocked
-    #[error("Thread blocked")]
-    Blocked,
+    /// The viewer blocks the author of the anchor post
+    #[error("Blocked actor: {0}")]
+    BlockedActor(String),
+
+    /// The author of the anchor post blocks the viewer
+    #[error("Blocked by actor: {0}")]
+    BlockedByActor(String),
 
     /// Serialization error
     #[error("Serialization error: {0}")]
@@ -33,11 +39,64 @@ pub enum ThreadError {
     /// Invalid URI
     #[error("Invalid URI: {0}")]
     InvalidUri(String),
+
+    /// The threadgate kept changing while being updated
+    #[error("Update conflict: {0}")]
+    Conflict(String),
 }
 
 /// Result type for thread operations
 pub type Result<T> = std::result::Result<T, ThreadError>;
 
+/// Errors declared by the thread endpoints
+///
+/// `app.bsky.unspecced.getPostThreadV2` is not in the bundled lexicons, so
+/// its errors are registered here rather than generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GetPostThreadError {
+    /// The anchor post doesn't exist or was deleted
+    NotFound,
+    /// The viewer blocks the author of the anchor post
+    BlockedActor,
+    /// The author of the anchor post blocks the viewer
+    BlockedByActor,
+}
+
+impl XrpcErrorKind for GetPostThreadError {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "NotFound" => Some(Self::NotFound),
+            "BlockedActor" => Some(Self::BlockedActor),
+            "BlockedByActor" => Some(Self::BlockedByActor),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::NotFound => "NotFound",
+            Self::BlockedActor => "BlockedActor",
+            Self::BlockedByActor => "BlockedByActor",
+        }
+    }
+}
+
+impl From<EndpointError<GetPostThreadError>> for ThreadError {
+    fn from(error: EndpointError<GetPostThreadError>) -> Self {
+        match error {
+            EndpointError::Declared { kind, error } => {
+                let message = error.message().to_string();
+                match kind {
+                    GetPostThreadError::NotFound => Self::NotFound(message),
+                    GetPostThreadError::BlockedActor => Self::BlockedActor(message),
+                    GetPostThreadError::BlockedByActor => Self::BlockedByActor(message),
+                }
+            }
+            EndpointError::Other(error) => Self::Xrpc(error.to_string()),
+        }
+    }
+}
+
 /// Sort order for thread replies
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -408,8 +467,9 @@ impl ThreadService {
     /// # Errors
     ///
     /// - `ThreadError::NotFound` - Post not found
-    /// - `ThreadError::Blocked` - Thread is blocked
-    /// - `ThreadError::Network` - Network error
+    /// - `ThreadError::BlockedActor` - The viewer blocks the author
+    /// - `ThreadError::BlockedByActor` - The author blocks the viewer
+    /// - `ThreadError::Xrpc` - Network or other XRPC error
     pub async fn get_thread(&self, params: ThreadParams) -> Result<ThreadResponse> {
         if params.anchor.is_empty() {
             return Err(ThreadError::InvalidUri("Anchor URI cannot be empty".to_string()));
@@ -434,7 +494,7 @@ impl ThreadService {
         let response = client
             .query(request)
             .await
-            .map_err(|e| ThreadError::Xrpc(e.to_string()))?;
+            .map_err(|e| ThreadError::from(e.classify::<GetPostThreadError>()))?;
 
         let thread_response: ThreadResponse =
             serde_json::from_value(response.data).map_err(ThreadError::Serialization)?;
@@ -469,7 +529,7 @@ impl ThreadService {
         let response = client
             .query(request)
             .await
-            .map_err(|e| ThreadError::Xrpc(e.to_string()))?;
+            .map_err(|e| ThreadError::from(e.classify::<GetPostThreadError>()))?;
 
         let thread_response: ThreadResponse =
             serde_json::from_value(response.data).map_err(ThreadError::Serialization)?;
@@ -477,6 +537,110 @@ impl ThreadService {
         Ok(thread_response)
     }
 
+    /// Hide or unhide a reply in a thread
+    ///
+    /// Hidden replies are stored in the threadgate of the thread's root post,
+    /// so only the root's author can hide replies. A threadgate is created if
+    /// the post doesn't have one yet; its reply rules are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `root_uri` - URI of the thread's root post
+    /// * `reply_uri` - URI of the reply to hide or unhide
+    /// * `hidden` - Whether the reply should be hidden
+    ///
+    /// # Errors
+    ///
+    /// - `ThreadError::InvalidUri` - `root_uri` is not a post URI
+    /// - `ThreadError::Conflict` - The threadgate kept changing during the update
+    /// - `ThreadError::Xrpc` - Network or other XRPC error
+    pub async fn set_reply_hidden(
+        &self,
+        root_uri: &str,
+        reply_uri: &str,
+        hidden: bool,
+    ) -> Result<()> {
+        self.update_threadgate(root_uri, |gate| {
+            let mut replies: Vec<String> = gate
+                .remove("hiddenReplies")
+                .and_then(|value| serde_json::from_value(value).ok())
+                .unwrap_or_default();
+            replies.retain(|uri| uri != reply_uri);
+            if hidden {
+                replies.push(reply_uri.to_string());
+            }
+            if !replies.is_empty() {
+                gate.insert("hiddenReplies".to_string(), replies.into());
+            }
+        })
+        .await
+    }
+
+    /// Set who can reply to a thread
+    ///
+    /// # Arguments
+    ///
+    /// * `root_uri` - URI of the thread's root post
+    /// * `allow` - Threadgate rules (e.g., `app.bsky.feed.threadgate#followingRule`);
+    ///   `None` allows everyone, an empty list allows no one
+    ///
+    /// # Errors
+    ///
+    /// - `ThreadError::InvalidUri` - `root_uri` is not a post URI
+    /// - `ThreadError::Conflict` - The threadgate kept changing during the update
+    /// - `ThreadError::Xrpc` - Network or other XRPC error
+    pub async fn set_reply_rules(
+        &self,
+        root_uri: &str,
+        allow: Option<Vec<serde_json::Value>>,
+    ) -> Result<()> {
+        self.update_threadgate(root_uri, |gate| match &allow {
+            Some(rules) => {
+                gate.insert("allow".to_string(), rules.clone().into());
+            }
+            None => {
+                gate.remove("allow");
+            }
+        })
+        .await
+    }
+
+    /// Apply `edit` to the threadgate of `root_uri`, creating it if needed
+    ///
+    /// The write is swapped on the threadgate's CID, so a concurrent change
+    /// (e.g., hiding a reply from another device) is re-read, not overwritten.
+    async fn update_threadgate<F>(&self, root_uri: &str, mut edit: F) -> Result<()>
+    where
+        F: FnMut(&mut serde_json::Map<String, serde_json::Value>),
+    {
+        let root = AtUri::new(root_uri).map_err(|e| ThreadError::InvalidUri(e.to_string()))?;
+        let rkey = match (root.collection(), root.rkey()) {
+            (Some("app.bsky.feed.post"), Some(rkey)) => rkey,
+            _ => return Err(ThreadError::InvalidUri(format!("Not a post URI: {}", root_uri))),
+        };
+
+        let client = self.client.read().await;
+        RecordUpdate::new(root.authority(), "app.bsky.feed.threadgate", rkey)
+            .apply(&client, |current: Option<serde_json::Map<String, serde_json::Value>>| {
+                let mut gate = current.unwrap_or_else(|| {
+                    let mut gate = serde_json::Map::new();
+                    gate.insert("$type".to_string(), "app.bsky.feed.threadgate".into());
+                    gate.insert("post".to_string(), root_uri.into());
+                    gate.insert("createdAt".to_string(), chrono::Utc::now().to_rfc3339().into());
+                    gate
+                });
+                edit(&mut gate);
+                Ok(gate)
+            })
+            .await
+            .map_err(|e| match e {
+                WriteError::Conflict { .. } => ThreadError::Conflict(e.to_string()),
+                e => ThreadError::Xrpc(e.to_string()),
+            })?;
+
+        Ok(())
+    }
+
     /// Find the anchor post in a thread
     ///
     /// The anchor post is the post at depth 0
@@ -583,6 +747,29 @@ mod tests {
         assert_eq!(params.branching_factor, 15);
     }
 
+    #[test]
+    fn test_thread_error_from_declared_errors() {
+        use atproto_client::xrpc::XrpcError;
+
+        let error = XrpcError::new(400, "NotFound", "Post not found: at://did:plc:test/post/1");
+        assert!(matches!(
+            ThreadError::from(error.classify::<GetPostThreadError>()),
+            ThreadError::NotFound(message) if message.ends_with("post/1")
+        ));
+
+        let error = XrpcError::new(400, "BlockedByActor", "Blocked");
+        assert!(matches!(
+            ThreadError::from(error.classify::<GetPostThreadError>()),
+            ThreadError::BlockedByActor(_)
+        ));
+
+        let error = XrpcError::new(502, "UpstreamFailure", "Bad gateway");
+        assert!(matches!(
+            ThreadError::from(error.classify::<GetPostThreadError>()),
+            ThreadError::Xrpc(_)
+        ));
+    }
+
     #[test]
     fn test_thread_sort_order_default() {
         assert_eq!(ThreadSortOrder::default(), ThreadSortOrder::Oldest);
@@ -763,4 +950,55 @@ mod tests {
             }),
         }
     }
+
+    #[tokio::test]
+    async fn test_set_reply_hidden_creates_and_updates_threadgate() {
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::XrpcClientConfig;
+        use serde_json::json;
+
+        let root = "at://did:plc:alice/app.bsky.feed.post/3kroot";
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(
+                    "com.atproto.repo.getRecord",
+                    400,
+                    json!({"error": "RecordNotFound", "message": "Not found"}),
+                )
+                .with_json(
+                    "com.atproto.repo.putRecord",
+                    200,
+                    json!({"uri": "at://did:plc:alice/app.bsky.feed.threadgate/3kroot", "cid": "bafygate"}),
+                ),
+        );
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let service = ThreadService::new(XrpcClient::new(config));
+
+        service
+            .set_reply_hidden(root, "at://did:plc:bob/app.bsky.feed.post/1", true)
+            .await
+            .unwrap();
+
+        let put = transport
+            .requests()
+            .into_iter()
+            .find(|request| request.nsid == "com.atproto.repo.putRecord")
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(put.body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["repo"], "did:plc:alice");
+        assert_eq!(body["collection"], "app.bsky.feed.threadgate");
+        assert_eq!(body["rkey"], "3kroot");
+        assert!(body["swapRecord"].is_null());
+        assert_eq!(body["record"]["post"], root);
+        assert_eq!(
+            body["record"]["hiddenReplies"],
+            json!(["at://did:plc:bob/app.bsky.feed.post/1"])
+        );
+        assert!(body["record"].get("allow").is_none());
+
+        let result = service
+            .set_reply_hidden("at://did:plc:alice/app.bsky.graph.list/1", "at://x/y/z", true)
+            .await;
+        assert!(matches!(result, Err(ThreadError::InvalidUri(_))));
+    }
 }
@@ -0,0 +1,64 @@
+//! Build script
+//!
+//! With the `builtin-lexicons` feature, generates the table of bundled lexicon
+//! documents from the `lexicons/` directory. Each file lives at the path given
+//! by its NSID, e.g. `lexicons/app/bsky/feed/post.json`.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    if env::var_os("CARGO_FEATURE_BUILTIN_LEXICONS").is_none() {
+        return;
+    }
+
+    let root = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("lexicons");
+    // Cargo scans directories recursively for modifications
+    println!("cargo:rerun-if-changed={}", root.display());
+
+    let mut files = Vec::new();
+    collect_json_files(&root, &mut files).expect("Failed to read lexicons directory");
+
+    let mut entries: Vec<(String, PathBuf)> = files
+        .into_iter()
+        .map(|path| {
+            let relative = path.strip_prefix(&root).unwrap().with_extension("");
+            let nsid = relative
+                .components()
+                .map(|c| c.as_os_str().to_str().expect("Non UTF-8 lexicon path"))
+                .collect::<Vec<_>>()
+                .join(".");
+            (nsid, path)
+        })
+        .collect();
+    entries.sort();
+
+    let mut out = String::from("static SOURCES: &[(&str, &str)] = &[\n");
+    for (nsid, path) in &entries {
+        out.push_str(&format!(
+            "    ({:?}, include_str!({:?})),\n",
+            nsid,
+            path.display().to_string()
+        ));
+    }
+    out.push_str("];\n");
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("builtin_lexicons.rs");
+    fs::write(dest, out).expect("Failed to write lexicon table");
+}
+
+/// Recursively collect `.json` files
+fn collect_json_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_json_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
@@ -24,8 +24,17 @@
 //! }
 //! ```
 
+use crate::identity::IdentityResolver;
+use crate::labelers::{AcceptLabelersMiddleware, LabelerProvider};
+use crate::oauth::{DpopAuth, OAuthError, OAuthSession, OAuthSessionData};
+use crate::proxy::{ProxyError, ProxyRouter, ServiceProxy, ServiceProxyMiddleware};
+use crate::repo::write::{RecordUpdate, WriteError};
 use crate::session::{AtpSessionData, SessionError};
-use crate::xrpc::{XrpcClient, XrpcClientConfig, XrpcError};
+use crate::upload::{BlobUpload, UploadError};
+use crate::xrpc::{
+    HttpRequest, HttpResponse, Middleware, Next, XrpcClient, XrpcClientConfig, XrpcError,
+};
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, RwLock};
 use thiserror::Error;
@@ -41,6 +50,14 @@ pub enum AgentError {
     #[error("XRPC error: {0}")]
     Xrpc(#[from] XrpcError),
 
+    /// OAuth error
+    #[error("OAuth error: {0}")]
+    OAuth(#[from] OAuthError),
+
+    /// Service proxy error
+    #[error("Proxy error: {0}")]
+    Proxy(#[from] ProxyError),
+
     /// No active session
     #[error("No active session - please login first")]
     NoSession,
@@ -56,6 +73,14 @@ pub enum AgentError {
     /// Invalid input
     #[error("Invalid input: {0}")]
     InvalidInput(String),
+
+    /// Guarded record write error
+    #[error("Write error: {0}")]
+    Write(#[from] WriteError),
+
+    /// Blob upload error
+    #[error("Upload error: {0}")]
+    Upload(#[from] UploadError),
 }
 
 /// Result type for agent operations
@@ -194,6 +219,10 @@ pub struct BskyAgentConfig {
     pub pds_url: Option<String>,
     /// XRPC client configuration
     pub xrpc_config: XrpcClientConfig,
+    /// Service proxy targets and routes (chat is routed by default)
+    pub proxy_router: ProxyRouter,
+    /// Labelers to accept on AppView reads
+    pub labeler_provider: Option<Arc<dyn LabelerProvider>>,
 }
 
 impl BskyAgentConfig {
@@ -204,6 +233,8 @@ impl BskyAgentConfig {
             app_view: None,
             pds_url: None,
             xrpc_config: XrpcClientConfig::default(),
+            proxy_router: ProxyRouter::bluesky(),
+            labeler_provider: None,
         }
     }
 
@@ -224,6 +255,18 @@ impl BskyAgentConfig {
         self.xrpc_config = config;
         self
     }
+
+    /// Set the service proxy targets and routes
+    pub fn with_proxy_router(mut self, router: ProxyRouter) -> Self {
+        self.proxy_router = router;
+        self
+    }
+
+    /// Set the labelers to accept on AppView reads
+    pub fn with_labeler_provider(mut self, provider: Arc<dyn LabelerProvider>) -> Self {
+        self.labeler_provider = Some(provider);
+        self
+    }
 }
 
 /// Main agent for interacting with AT Protocol services
@@ -232,6 +275,19 @@ impl BskyAgentConfig {
 /// requests to AT Protocol services. It manages sessions, handles token
 /// refresh, and provides convenient methods for common operations.
 ///
+/// Requests made through the read and write clients that fail with an expired
+/// access token are retried once after refreshing the session. Concurrent
+/// requests share a single refresh, and the session callback receives
+/// [`SessionEvent::Update`] with the new tokens.
+///
+/// Requests are proxied through the PDS with the `atproto-proxy` header
+/// according to the agent's [`ProxyRouter`]. Each agent (and so each account)
+/// has its own named targets; by default `chat.bsky.*` goes to Bluesky chat.
+///
+/// AppView reads carry an `atproto-accept-labelers` header listing the
+/// labelers from the agent's [`LabelerProvider`], if one is set, so responses
+/// are labeled according to the user's own choice of labelers.
+///
 /// # Example
 ///
 /// ```rust,no_run
@@ -263,8 +319,14 @@ pub struct BskyAgent {
     write_client: XrpcClient,
     /// Current session data
     session: Arc<RwLock<Option<AtpSessionData>>>,
-    /// Session event callback
-    session_callback: Option<SessionCallback>,
+    /// Session refresh state shared with the clients' refresh middleware
+    refresher: Arc<SessionRefresher>,
+    /// Service proxy routes shared with the clients' proxy middleware
+    proxy_router: Arc<RwLock<ProxyRouter>>,
+    /// Resolver used to check proxy targets against DID documents
+    identity_resolver: Arc<IdentityResolver>,
+    /// Labelers accepted on reads, shared with the read client's middleware
+    labeler_provider: Arc<RwLock<Option<Arc<dyn LabelerProvider>>>>,
 }
 
 impl BskyAgent {
@@ -313,7 +375,25 @@ impl BskyAgent {
         let write_url = config.pds_url.as_ref().unwrap_or(&config.service);
         let mut write_xrpc_config = config.xrpc_config;
         write_xrpc_config.service_url = write_url.clone();
-        let write_client = XrpcClient::new(write_xrpc_config);
+        let write_client = XrpcClient::new(write_xrpc_config.clone());
+
+        // Both clients refresh expired sessions through the same refresher
+        let session = Arc::new(RwLock::new(None));
+        let refresher = Arc::new(SessionRefresher {
+            session: session.clone(),
+            callback: RwLock::new(None),
+            refresh_client: XrpcClient::new(write_xrpc_config),
+            lock: tokio::sync::Mutex::new(()),
+        });
+        let proxy_router = Arc::new(RwLock::new(config.proxy_router));
+        let labeler_provider = Arc::new(RwLock::new(config.labeler_provider));
+        let read_client = read_client
+            .with_middleware(SessionRefreshMiddleware(refresher.clone()))
+            .with_middleware(ServiceProxyMiddleware::shared(proxy_router.clone()))
+            .with_middleware(AcceptLabelersMiddleware::shared(labeler_provider.clone()));
+        let write_client = write_client
+            .with_middleware(SessionRefreshMiddleware(refresher.clone()))
+            .with_middleware(ServiceProxyMiddleware::shared(proxy_router.clone()));
 
         Ok(Self {
             service: config.service,
@@ -321,8 +401,11 @@ impl BskyAgent {
             pds_url: config.pds_url,
             read_client,
             write_client,
-            session: Arc::new(RwLock::new(None)),
-            session_callback: None,
+            session,
+            refresher,
+            proxy_router,
+            identity_resolver: Arc::new(IdentityResolver::default()),
+            labeler_provider,
         })
     }
 
@@ -337,7 +420,7 @@ impl BskyAgent {
     where
         F: Fn(SessionEvent, &AtpSessionData) + Send + Sync + 'static,
     {
-        self.session_callback = Some(Arc::new(callback));
+        *self.refresher.callback.write().unwrap() = Some(Arc::new(callback));
     }
 
     /// Login to the service
@@ -407,6 +490,7 @@ impl BskyAgent {
             email_auth_factor: response.email_auth_factor,
             active: response.active.unwrap_or(true),
             status: response.status,
+            oauth: None,
         };
 
         // Update session
@@ -416,16 +500,42 @@ impl BskyAgent {
         }
 
         // Update client auth headers
-        self.update_auth_headers(&session_data.access_jwt);
+        self.update_auth_headers(&session_data);
 
         // Fire session callback
-        if let Some(ref callback) = self.session_callback {
-            callback(SessionEvent::Create, &session_data);
-        }
+        self.refresher.emit(SessionEvent::Create, &session_data);
 
         Ok(session_data)
     }
 
+    /// Start using a session obtained through OAuth
+    ///
+    /// Requests are authenticated with the DPoP-bound access token, and
+    /// [`refresh_session`](Self::refresh_session) refreshes it at the
+    /// authorization server. The agent should be created for the account's
+    /// PDS (`session.pds_url`).
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - Session returned by [`OAuthClient::callback`](crate::oauth::OAuthClient::callback)
+    pub fn login_with_oauth(&mut self, session: &OAuthSession) -> AtpSessionData {
+        let session_data = session.to_session_data();
+
+        // Update session
+        {
+            let mut session = self.session.write().unwrap();
+            *session = Some(session_data.clone());
+        }
+
+        // Update client auth headers
+        self.update_auth_headers(&session_data);
+
+        // Fire session callback
+        self.refresher.emit(SessionEvent::Create, &session_data);
+
+        session_data
+    }
+
     /// Create a new account
     ///
     /// # Arguments
@@ -494,6 +604,7 @@ impl BskyAgent {
             email_auth_factor: None,
             active: true,
             status: None,
+            oauth: None,
         };
 
         // Update session
@@ -503,12 +614,10 @@ impl BskyAgent {
         }
 
         // Update client auth headers
-        self.update_auth_headers(&session_data.access_jwt);
+        self.update_auth_headers(&session_data);
 
         // Fire session callback
-        if let Some(ref callback) = self.session_callback {
-            callback(SessionEvent::Create, &session_data);
-        }
+        self.refresher.emit(SessionEvent::Create, &session_data);
 
         Ok(session_data)
     }
@@ -535,6 +644,7 @@ impl BskyAgent {
     /// #     email_auth_factor: None,
     /// #     active: true,
     /// #     status: None,
+    /// #     oauth: None,
     /// # };
     /// let mut agent = BskyAgent::new("https://bsky.social")?;
     /// agent.resume_session(session_data).await?;
@@ -542,8 +652,12 @@ impl BskyAgent {
     /// # }
     /// ```
     pub async fn resume_session(&mut self, session_data: AtpSessionData) -> Result<()> {
-        // Check if tokens are expired and refresh if needed
-        let needs_refresh = crate::session::is_jwt_expired(&session_data.access_jwt);
+        // Check if tokens are expired and refresh if needed. OAuth access
+        // tokens are opaque, so use the stored expiration instead.
+        let needs_refresh = match &session_data.oauth {
+            Some(oauth) => oauth.is_expired(),
+            None => crate::session::is_jwt_expired(&session_data.access_jwt),
+        };
 
         if needs_refresh {
             self.refresh_session_internal(session_data).await?;
@@ -555,7 +669,7 @@ impl BskyAgent {
             }
 
             // Update client auth headers
-            self.update_auth_headers(&session_data.access_jwt);
+            self.update_auth_headers(&session_data);
         }
 
         Ok(())
@@ -575,45 +689,10 @@ impl BskyAgent {
 
     /// Internal session refresh implementation
     async fn refresh_session_internal(&mut self, session_data: AtpSessionData) -> Result<()> {
-        use crate::xrpc::XrpcRequest;
-
-        // Temporarily set the refresh token as auth
-        self.write_client
-            .set_auth_header(Some(format!("Bearer {}", session_data.refresh_jwt)));
-
-        let xrpc_request = XrpcRequest::procedure("com.atproto.server.refreshSession");
-
-        let response: RefreshSessionResponse = self
-            .write_client
-            .procedure(xrpc_request)
-            .await
-            .map(|r| r.data)?;
-
-        let new_session = AtpSessionData {
-            access_jwt: response.access_jwt,
-            refresh_jwt: response.refresh_jwt,
-            did: response.did,
-            handle: response.handle,
-            email: session_data.email, // Preserve email from old session
-            email_confirmed: session_data.email_confirmed,
-            email_auth_factor: session_data.email_auth_factor,
-            active: response.active.unwrap_or(true),
-            status: response.status,
-        };
-
-        // Update session
-        {
-            let mut session = self.session.write().unwrap();
-            *session = Some(new_session.clone());
-        }
+        let new_session = self.refresher.refresh(session_data).await?;
 
         // Update client auth headers with new access token
-        self.update_auth_headers(&new_session.access_jwt);
-
-        // Fire session callback
-        if let Some(ref callback) = self.session_callback {
-            callback(SessionEvent::Update, &new_session);
-        }
+        self.update_auth_headers(&new_session);
 
         Ok(())
     }
@@ -681,6 +760,125 @@ impl BskyAgent {
         &self.write_client
     }
 
+    /// Get a mutable reference to the read client
+    ///
+    /// Use this to add middleware that should only apply to reads.
+    pub fn read_client_mut(&mut self) -> &mut XrpcClient {
+        &mut self.read_client
+    }
+
+    /// Get a mutable reference to the write client
+    ///
+    /// Use this to add middleware that should only apply to writes.
+    pub fn write_client_mut(&mut self) -> &mut XrpcClient {
+        &mut self.write_client
+    }
+
+    /// Add a middleware to both the read and write clients
+    ///
+    /// # Arguments
+    ///
+    /// * `middleware` - Middleware to append to each client's chain
+    pub fn add_middleware(&mut self, middleware: Arc<dyn Middleware>) {
+        self.read_client.add_middleware(middleware.clone());
+        self.write_client.add_middleware(middleware);
+    }
+
+    /// Set the labelers to accept on AppView reads
+    ///
+    /// Pass `None` to stop sending the header, so the AppView applies its
+    /// default labelers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use atproto_client::{AcceptedLabeler, BskyAgent};
+    /// use std::sync::{Arc, RwLock};
+    ///
+    /// let agent = BskyAgent::new("https://bsky.social").unwrap();
+    /// let labelers = Arc::new(RwLock::new(vec![AcceptedLabeler::new("did:plc:abc123")]));
+    /// agent.set_labeler_provider(Some(labelers.clone()));
+    ///
+    /// // Later changes apply to the next request
+    /// labelers.write().unwrap().clear();
+    /// ```
+    pub fn set_labeler_provider(&self, provider: Option<Arc<dyn LabelerProvider>>) {
+        *self.labeler_provider.write().unwrap() = provider;
+    }
+
+    /// Set the identity resolver used to check proxy targets
+    pub fn set_identity_resolver(&mut self, resolver: Arc<IdentityResolver>) {
+        self.identity_resolver = resolver;
+    }
+
+    /// Get the service proxy routes
+    ///
+    /// Changes apply to subsequent requests on both clients.
+    pub fn proxy_router(&self) -> Arc<RwLock<ProxyRouter>> {
+        self.proxy_router.clone()
+    }
+
+    /// Add a named service proxy target after checking its DID document
+    ///
+    /// The target's DID document must declare the service, otherwise the PDS
+    /// would reject requests proxied to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name used to route namespaces to the target
+    /// * `proxy` - The service to proxy to
+    ///
+    /// # Returns
+    ///
+    /// The service endpoint from the DID document
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use atproto_client::{BskyAgent, ServiceProxy};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let agent = BskyAgent::new("https://bsky.social")?;
+    ///
+    ///     // Route AppView reads to a custom AppView
+    ///     let appview: ServiceProxy = "did:web:appview.example.com#bsky_appview".parse()?;
+    ///     agent.add_proxy_target("appview", appview).await?;
+    ///     agent.set_proxy_route("app.bsky.*", "appview")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn add_proxy_target(
+        &self,
+        name: impl Into<String>,
+        proxy: ServiceProxy,
+    ) -> Result<String> {
+        let endpoint = proxy.resolve(&self.identity_resolver).await?;
+        self.proxy_router.write().unwrap().set_target(name, proxy);
+        Ok(endpoint)
+    }
+
+    /// Remove a named service proxy target and its routes
+    pub fn remove_proxy_target(&self, name: &str) -> Option<ServiceProxy> {
+        self.proxy_router.write().unwrap().remove_target(name)
+    }
+
+    /// Route an NSID namespace (e.g., "chat.bsky.*") to a named proxy target
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace` - NSID prefix, optionally ending in ".*"
+    /// * `name` - Name of a target added with [`add_proxy_target`](Self::add_proxy_target)
+    pub fn set_proxy_route(&self, namespace: &str, name: &str) -> Result<()> {
+        let mut router = self.proxy_router.write().unwrap();
+        if router.target(name).is_none() {
+            return Err(AgentError::InvalidInput(format!("Unknown proxy target: {}", name)));
+        }
+        router.set_route(namespace, name);
+        Ok(())
+    }
+
     /// Upload a blob to the PDS
     ///
     /// # Arguments
@@ -714,32 +912,106 @@ impl BskyAgent {
         data: &[u8],
         mime_type: impl Into<String>,
     ) -> Result<crate::lexicon::BlobRef> {
-        // Verify we have an active session
+        self.upload(BlobUpload::from_bytes(data, mime_type)).await
+    }
+
+    /// Upload a blob from memory, a file or a reader
+    ///
+    /// Streams file and reader bodies and honours the upload's limits,
+    /// progress channel and cancellation token. See [`crate::upload`].
+    ///
+    /// # Errors
+    ///
+    /// - `AgentError::NoSession` - No active session
+    /// - `AgentError::Upload` - The blob failed its limits, was cancelled or
+    ///   couldn't be sent
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use atproto_client::upload::BlobUpload;
+    /// use atproto_client::BskyAgent;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut agent = BskyAgent::new("https://bsky.social")?;
+    ///     agent.login("alice.bsky.social", "password").await?;
+    ///
+    ///     let upload = BlobUpload::from_file("clip.mp4", "video/mp4").await?;
+    ///     let blob_ref = agent.upload(upload).await?;
+    ///     println!("Uploaded blob CID: {}", blob_ref.ref_link.cid);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn upload(&self, upload: BlobUpload) -> Result<crate::lexicon::BlobRef> {
         if !self.has_session() {
             return Err(AgentError::NoSession);
         }
 
-        let mime_type = mime_type.into();
-
-        // Create the upload request
-        let request = crate::xrpc::XrpcRequest::procedure("com.atproto.repo.uploadBlob")
-            .body(data.to_vec())
-            .encoding(mime_type.clone());
-
-        // Upload the blob
-        let response: crate::xrpc::XrpcResponse<serde_json::Value> =
-            self.write_client.procedure(request).await?;
-
-        // Parse the blob reference from response
-        #[derive(Deserialize)]
-        struct UploadBlobResponse {
-            blob: crate::lexicon::BlobRef,
-        }
+        Ok(upload.send(&self.write_client).await?)
+    }
 
-        let upload_response: UploadBlobResponse = serde_json::from_value(response.data)
-            .map_err(|e| AgentError::Service(format!("Failed to parse upload response: {}", e)))?;
+    /// Update a record in the session's repo without clobbering concurrent edits
+    ///
+    /// Reads the record, passes it to `edit`, and writes the result with
+    /// `swapRecord` set to the CID that was read. If another client changed
+    /// the record in the meantime, the edit is applied again to the fresh
+    /// record, up to [`DEFAULT_MAX_ATTEMPTS`](crate::repo::write::DEFAULT_MAX_ATTEMPTS)
+    /// times. Use [`RecordUpdate`] directly for other repos or retry limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `collection` - Collection NSID (e.g., "app.bsky.actor.profile")
+    /// * `rkey` - Record key
+    /// * `edit` - Produces the new record from the current one (`None` if absent)
+    ///
+    /// # Returns
+    ///
+    /// The URI and CID of the written record
+    ///
+    /// # Errors
+    ///
+    /// - `AgentError::NoSession` - No active session
+    /// - `AgentError::Write` - The write failed or kept conflicting
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use atproto_client::BskyAgent;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let mut agent = BskyAgent::new("https://bsky.social")?;
+    ///     agent.login("alice.bsky.social", "password").await?;
+    ///
+    ///     agent
+    ///         .update_record("app.bsky.actor.profile", "self", |current: Option<serde_json::Value>| {
+    ///             let mut profile = current.unwrap_or_default();
+    ///             profile["$type"] = "app.bsky.actor.profile".into();
+    ///             profile["description"] = "Hello!".into();
+    ///             Ok(profile)
+    ///         })
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn update_record<T, F>(
+        &self,
+        collection: &str,
+        rkey: &str,
+        edit: F,
+    ) -> Result<crate::api::com::atproto::repo::put_record::Output>
+    where
+        T: Serialize + serde::de::DeserializeOwned,
+        F: FnMut(Option<T>) -> crate::repo::write::Result<T>,
+    {
+        let did = self.did().ok_or(AgentError::NoSession)?;
 
-        Ok(upload_response.blob)
+        Ok(RecordUpdate::new(did, collection, rkey)
+            .apply(&self.write_client, edit)
+            .await?)
     }
 
     /// Call a custom XRPC procedure
@@ -775,16 +1047,264 @@ impl BskyAgent {
     }
 
     /// Update auth headers on both clients
-    fn update_auth_headers(&mut self, access_token: &str) {
-        let auth = format!("Bearer {}", access_token);
+    ///
+    /// OAuth sessions use DPoP; each client tracks its own server nonce.
+    fn update_auth_headers(&mut self, session: &AtpSessionData) {
+        if let Some(oauth) = &session.oauth {
+            let auth = DpopAuth::new(session.access_jwt.clone(), oauth.dpop_key.clone());
+            self.read_client.set_dpop_auth(Some(DpopAuth::new(
+                session.access_jwt.clone(),
+                oauth.dpop_key.clone(),
+            )));
+            self.write_client.set_dpop_auth(Some(auth));
+            return;
+        }
+
+        let auth = format!("Bearer {}", session.access_jwt);
         self.read_client.set_auth_header(Some(auth.clone()));
         self.write_client.set_auth_header(Some(auth));
     }
 }
 
+/// NSID of the legacy session refresh procedure
+const REFRESH_SESSION_NSID: &str = "com.atproto.server.refreshSession";
+
+/// Session refresh state shared by an agent and its clients
+///
+/// Refreshes are single-flight: concurrent requests that hit an expired token
+/// wait for one refresh and then replay with the new token.
+struct SessionRefresher {
+    /// The agent's current session
+    session: Arc<RwLock<Option<AtpSessionData>>>,
+    /// Session event callback
+    callback: RwLock<Option<SessionCallback>>,
+    /// Plain PDS client used for `refreshSession`
+    refresh_client: XrpcClient,
+    /// Held while a refresh is in flight
+    lock: tokio::sync::Mutex<()>,
+}
+
+impl SessionRefresher {
+    /// Get the current session
+    fn current(&self) -> Option<AtpSessionData> {
+        self.session.read().unwrap().clone()
+    }
+
+    /// Fire the session callback
+    fn emit(&self, event: SessionEvent, session_data: &AtpSessionData) {
+        let callback = self.callback.read().unwrap().clone();
+        if let Some(callback) = callback {
+            callback(event, session_data);
+        }
+    }
+
+    /// Refresh a session unconditionally
+    async fn refresh(&self, session_data: AtpSessionData) -> Result<AtpSessionData> {
+        let _guard = self.lock.lock().await;
+        self.refresh_locked(session_data).await
+    }
+
+    /// Refresh the current session unless another request already did
+    ///
+    /// # Arguments
+    ///
+    /// * `expired_token` - The access token the server rejected
+    async fn refresh_expired(&self, expired_token: &str) -> Result<AtpSessionData> {
+        let _guard = self.lock.lock().await;
+
+        let current = self.current().ok_or(AgentError::NoSession)?;
+        if current.access_jwt != expired_token {
+            return Ok(current);
+        }
+
+        self.refresh_locked(current).await
+    }
+
+    /// Perform the refresh, store the new session and fire the callback
+    async fn refresh_locked(&self, session_data: AtpSessionData) -> Result<AtpSessionData> {
+        let result = match session_data.oauth.clone() {
+            Some(oauth) => self.refresh_oauth(&session_data, oauth).await,
+            None => self.refresh_legacy(&session_data).await,
+        };
+
+        let new_session = match result {
+            Ok(new_session) => new_session,
+            Err(err) => {
+                let event = if is_network_failure(&err) {
+                    SessionEvent::NetworkError
+                } else {
+                    SessionEvent::Expired
+                };
+                self.emit(event, &session_data);
+                return Err(err);
+            }
+        };
+
+        // Update session
+        {
+            let mut session = self.session.write().unwrap();
+            *session = Some(new_session.clone());
+        }
+
+        self.emit(SessionEvent::Update, &new_session);
+
+        Ok(new_session)
+    }
+
+    /// Refresh an app-password session with `refreshSession`
+    async fn refresh_legacy(&self, session_data: &AtpSessionData) -> Result<AtpSessionData> {
+        use crate::xrpc::XrpcRequest;
+
+        let xrpc_request = XrpcRequest::procedure(REFRESH_SESSION_NSID)
+            .header("Authorization", format!("Bearer {}", session_data.refresh_jwt));
+
+        let response: RefreshSessionResponse = self
+            .refresh_client
+            .procedure(xrpc_request)
+            .await
+            .map(|r| r.data)?;
+
+        Ok(AtpSessionData {
+            access_jwt: response.access_jwt,
+            refresh_jwt: response.refresh_jwt,
+            did: response.did,
+            handle: response.handle,
+            email: session_data.email.clone(), // Preserve email from old session
+            email_confirmed: session_data.email_confirmed,
+            email_auth_factor: session_data.email_auth_factor,
+            active: response.active.unwrap_or(true),
+            status: response.status,
+            oauth: None,
+        })
+    }
+
+    /// Refresh an OAuth session at its authorization server
+    async fn refresh_oauth(
+        &self,
+        session_data: &AtpSessionData,
+        mut oauth: OAuthSessionData,
+    ) -> Result<AtpSessionData> {
+        let token = oauth
+            .refresh(&session_data.refresh_jwt, &session_data.did)
+            .await?;
+
+        Ok(AtpSessionData {
+            access_jwt: token.access_token,
+            refresh_jwt: token
+                .refresh_token
+                .unwrap_or_else(|| session_data.refresh_jwt.clone()),
+            oauth: Some(oauth),
+            ..session_data.clone()
+        })
+    }
+}
+
+/// Check if a refresh failed for network reasons rather than an invalid session
+fn is_network_failure(err: &AgentError) -> bool {
+    match err {
+        AgentError::Xrpc(e) => e.status() == 0 || e.is_network_error(),
+        AgentError::OAuth(OAuthError::Network(_)) => true,
+        _ => false,
+    }
+}
+
+/// Check if a response rejected the access token as expired
+///
+/// The PDS reports `ExpiredToken` for app-password sessions; OAuth resource
+/// servers answer 401 with `error="invalid_token"` in `WWW-Authenticate`.
+fn is_expired_token_response(response: &HttpResponse) -> bool {
+    if response.status != 400 && response.status != 401 {
+        return false;
+    }
+    if response.status == 401
+        && response
+            .header("WWW-Authenticate")
+            .is_some_and(|h| h.contains("invalid_token"))
+    {
+        return true;
+    }
+    response.to_error().error() == "ExpiredToken"
+}
+
+/// Middleware keeping a client's credentials in sync with the agent session
+///
+/// Applies the current access token to authenticated requests, and on an
+/// expired-token response refreshes the session once and replays the request.
+struct SessionRefreshMiddleware(Arc<SessionRefresher>);
+
+impl SessionRefreshMiddleware {
+    /// Check whether a request is authenticated with the session
+    ///
+    /// A bearer token belongs to the session only while it is the client's
+    /// default Authorization header; tokens a caller set on the request itself,
+    /// such as service-auth tokens, are left alone.
+    fn uses_session_auth(
+        request: &HttpRequest,
+        client: &XrpcClient,
+        session: &AtpSessionData,
+    ) -> bool {
+        if session.oauth.is_some() {
+            return client.dpop_auth().is_some();
+        }
+
+        let default = client.config().default_headers.get("Authorization");
+        request
+            .header("Authorization")
+            .is_some_and(|h| h.starts_with("Bearer ") && default.is_some_and(|d| d == h))
+    }
+
+    /// Apply the session's access token to a request
+    fn apply_auth(request: &mut HttpRequest, client: &XrpcClient, session: &AtpSessionData) {
+        match client.dpop_auth() {
+            Some(dpop) if session.oauth.is_some() => {
+                dpop.set_access_token(session.access_jwt.clone());
+            }
+            _ => request.set_header("Authorization", format!("Bearer {}", session.access_jwt)),
+        }
+    }
+}
+
+#[async_trait]
+impl Middleware for SessionRefreshMiddleware {
+    async fn handle(
+        &self,
+        mut request: HttpRequest,
+        next: Next<'_>,
+    ) -> std::result::Result<HttpResponse, XrpcError> {
+        if request.nsid == REFRESH_SESSION_NSID {
+            return next.run(request).await;
+        }
+
+        let Some(session) = self.0.current() else {
+            return next.run(request).await;
+        };
+        if !Self::uses_session_auth(&request, next.client(), &session) {
+            return next.run(request).await;
+        }
+        Self::apply_auth(&mut request, next.client(), &session);
+
+        let response = next.run(request.clone()).await?;
+        if !is_expired_token_response(&response) {
+            return Ok(response);
+        }
+
+        match self.0.refresh_expired(&session.access_jwt).await {
+            Ok(new_session) => {
+                Self::apply_auth(&mut request, next.client(), &new_session);
+                next.run(request).await
+            }
+            Err(err) => {
+                tracing::debug!(error = %err, "Session refresh failed");
+                Ok(response)
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::proxy::PROXY_HEADER;
 
     #[test]
     fn test_agent_new() {
@@ -835,6 +1355,7 @@ mod tests {
             email_auth_factor: Some(false),
             active: true,
             status: None,
+            oauth: None,
         };
 
         // Manually set session for testing
@@ -890,4 +1411,232 @@ mod tests {
         assert_eq!(SessionEvent::Create, SessionEvent::Create);
         assert_ne!(SessionEvent::Create, SessionEvent::Update);
     }
+
+    #[tokio::test]
+    async fn test_update_record_requires_session() {
+        let agent = BskyAgent::new("https://bsky.social").unwrap();
+        let result = agent
+            .update_record(
+                "app.bsky.actor.profile",
+                "self",
+                |current: Option<serde_json::Value>| Ok(current.unwrap_or_default()),
+            )
+            .await;
+
+        assert!(matches!(result, Err(AgentError::NoSession)));
+    }
+
+    /// Mount createSession returning "old" tokens and refreshSession returning "new" ones
+    async fn mount_session_endpoints(server: &wiremock::MockServer) {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        Mock::given(method("POST"))
+            .and(path("/xrpc/com.atproto.server.createSession"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "accessJwt": "old-access",
+                "refreshJwt": "old-refresh",
+                "did": "did:plc:abc123",
+                "handle": "alice.bsky.social"
+            })))
+            .mount(server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/xrpc/com.atproto.server.refreshSession"))
+            .and(header("Authorization", "Bearer old-refresh"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "accessJwt": "new-access",
+                "refreshJwt": "new-refresh",
+                "did": "did:plc:abc123",
+                "handle": "alice.bsky.social"
+            })))
+            .expect(1)
+            .mount(server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/xrpc/app.bsky.actor.getProfile"))
+            .and(header("Authorization", "Bearer old-access"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "ExpiredToken",
+                "message": "Token has expired"
+            })))
+            .mount(server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/xrpc/app.bsky.actor.getProfile"))
+            .and(header("Authorization", "Bearer new-access"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "did": "did:plc:abc123" })),
+            )
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_expired_token_is_refreshed_and_replayed() {
+        use crate::xrpc::XrpcRequest;
+        use std::sync::Mutex;
+
+        let server = wiremock::MockServer::start().await;
+        mount_session_endpoints(&server).await;
+
+        let mut agent = BskyAgent::new(server.uri()).unwrap();
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let events_clone = events.clone();
+        agent.set_session_callback(move |event, session| {
+            events_clone
+                .lock()
+                .unwrap()
+                .push((event, session.refresh_jwt.clone()));
+        });
+        agent.login("alice.bsky.social", "password").await.unwrap();
+
+        let response: crate::xrpc::XrpcResponse<serde_json::Value> = agent
+            .read_client()
+            .query(XrpcRequest::query("app.bsky.actor.getProfile"))
+            .await
+            .unwrap();
+        assert_eq!(response.data["did"], "did:plc:abc123");
+
+        let session = agent.session().unwrap();
+        assert_eq!(session.access_jwt, "new-access");
+        assert_eq!(session.refresh_jwt, "new-refresh");
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                (SessionEvent::Create, "old-refresh".to_string()),
+                (SessionEvent::Update, "new-refresh".to_string()),
+            ]
+        );
+
+        // The other client picks up the refreshed token without another refresh
+        let response: crate::xrpc::XrpcResponse<serde_json::Value> = agent
+            .write_client()
+            .query(XrpcRequest::query("app.bsky.actor.getProfile"))
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_expired_requests_refresh_once() {
+        use crate::xrpc::XrpcRequest;
+
+        let server = wiremock::MockServer::start().await;
+        mount_session_endpoints(&server).await;
+
+        let mut agent = BskyAgent::new(server.uri()).unwrap();
+        agent.login("alice.bsky.social", "password").await.unwrap();
+
+        let client = agent.read_client();
+        let request =
+            || client.query::<serde_json::Value>(XrpcRequest::query("app.bsky.actor.getProfile"));
+        let (a, b, c) = tokio::join!(request(), request(), request());
+
+        for result in [a, b, c] {
+            assert_eq!(result.unwrap().status, 200);
+        }
+        // refreshSession is mounted with expect(1), verified when the server drops
+    }
+
+    #[tokio::test]
+    async fn test_request_bearer_token_is_not_replaced() {
+        use crate::transport::MockTransport;
+        use crate::xrpc::XrpcRequest;
+
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(
+                    "com.atproto.server.createSession",
+                    200,
+                    serde_json::json!({
+                        "accessJwt": "old-access",
+                        "refreshJwt": "old-refresh",
+                        "did": "did:plc:abc123",
+                        "handle": "alice.bsky.social"
+                    }),
+                )
+                .with_json(
+                    "app.bsky.video.getUploadLimits",
+                    401,
+                    serde_json::json!({"error": "ExpiredToken", "message": "Token has expired"}),
+                ),
+        );
+        let config = BskyAgentConfig::new("https://pds.example.com").with_xrpc_config(
+            XrpcClientConfig::new("https://pds.example.com").with_transport(transport.clone()),
+        );
+        let mut agent = BskyAgent::with_config(config).unwrap();
+        agent.login("alice.bsky.social", "password").await.unwrap();
+
+        let request = XrpcRequest::query("app.bsky.video.getUploadLimits")
+            .header("Authorization", "Bearer custom");
+        let err = agent
+            .read_client()
+            .query::<serde_json::Value>(request)
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), 401);
+
+        let requests = transport.requests();
+        let sent = requests
+            .iter()
+            .filter(|r| r.nsid == "app.bsky.video.getUploadLimits")
+            .collect::<Vec<_>>();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].header("Authorization"), Some("Bearer custom"));
+        assert_eq!(transport.request_count("com.atproto.server.refreshSession"), 0);
+    }
+
+    #[tokio::test]
+    async fn test_proxy_routes_apply_per_namespace() {
+        use crate::transport::MockTransport;
+        use crate::xrpc::XrpcRequest;
+
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json("chat.bsky.convo.listConvos", 200, serde_json::json!({"convos": []}))
+                .with_json("app.bsky.feed.getTimeline", 200, serde_json::json!({"feed": []})),
+        );
+        let config = BskyAgentConfig::new("https://pds.example.com").with_xrpc_config(
+            XrpcClientConfig::new("https://pds.example.com").with_transport(transport.clone()),
+        );
+        let agent = BskyAgent::with_config(config).unwrap();
+
+        let err = agent.set_proxy_route("app.bsky.*", "appview").unwrap_err();
+        assert!(matches!(err, AgentError::InvalidInput(_)));
+
+        let appview: ServiceProxy = "did:web:appview.example.com#bsky_appview".parse().unwrap();
+        agent
+            .proxy_router()
+            .write()
+            .unwrap()
+            .set_target("appview", appview);
+        agent.set_proxy_route("app.bsky.feed.*", "appview").unwrap();
+
+        let client = agent.read_client();
+        for nsid in ["chat.bsky.convo.listConvos", "app.bsky.feed.getTimeline"] {
+            client
+                .query::<serde_json::Value>(XrpcRequest::query(nsid))
+                .await
+                .unwrap();
+        }
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].header(PROXY_HEADER), Some("did:web:api.bsky.chat#bsky_chat"));
+        assert_eq!(
+            requests[1].header(PROXY_HEADER),
+            Some("did:web:appview.example.com#bsky_appview")
+        );
+
+        assert!(agent.remove_proxy_target("appview").is_some());
+        client
+            .query::<serde_json::Value>(XrpcRequest::query("app.bsky.feed.getTimeline"))
+            .await
+            .unwrap();
+        assert_eq!(transport.requests()[2].header(PROXY_HEADER), None);
+    }
 }
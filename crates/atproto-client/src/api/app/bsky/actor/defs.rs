@@ -0,0 +1,434 @@
+//! `app.bsky.actor.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.actor.defs";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdultContentPref {
+    pub enabled: bool,
+}
+
+/// If set, an active progress guide. Once completed, can be set to undefined. Should have unspecced fields tracking progress.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BskyAppProgressGuide {
+    pub guide: String,
+}
+
+/// A grab bag of state that's specific to the bsky.app program. Third-party apps shouldn't use this.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BskyAppStatePref {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_progress_guide: Option<BskyAppProgressGuide>,
+    /// Storage for NUXs the user has encountered.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nuxs: Option<Vec<Nux>>,
+    /// An array of tokens which identify nudges (modals, popups, tours, highlight dots) that should be shown to the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queued_nudges: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentLabelPref {
+    pub label: String,
+    /// Which labeler does this preference apply to? If undefined, applies globally.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labeler_did: Option<String>,
+    pub visibility: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedViewPref {
+    /// The URI of the feed, or an identifier which describes the feed.
+    pub feed: String,
+    /// Hide quote posts in the feed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_quote_posts: Option<bool>,
+    /// Hide replies in the feed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_replies: Option<bool>,
+    /// Hide replies in the feed if they do not have this number of likes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_replies_by_like_count: Option<i64>,
+    /// Hide replies in the feed if they are not by followed users.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_replies_by_unfollowed: Option<bool>,
+    /// Hide reposts in the feed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_reposts: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HiddenPostsPref {
+    /// A list of URIs of posts the account owner has hidden.
+    pub items: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestsPref {
+    /// A list of tags which describe the account owner's interests gathered during onboarding.
+    pub tags: Vec<String>,
+}
+
+/// The subject's followers whom you also follow
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KnownFollowers {
+    pub count: i64,
+    pub followers: Vec<ProfileViewBasic>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelerPrefItem {
+    pub did: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelersPref {
+    pub labelers: Vec<LabelerPrefItem>,
+}
+
+/// A word that the account owner has muted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MutedWord {
+    /// Groups of users to apply the muted word to. If undefined, applies to all users.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_target: Option<String>,
+    /// The date and time at which the muted word will expire and no longer be applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The intended targets of the muted word.
+    pub targets: Vec<MutedWordTarget>,
+    /// The muted word itself.
+    pub value: String,
+}
+
+pub type MutedWordTarget = String;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MutedWordsPref {
+    /// A list of words the account owner has muted.
+    pub items: Vec<MutedWord>,
+}
+
+/// A new user experiences (NUX) storage object
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Nux {
+    pub completed: bool,
+    /// Arbitrary data for the NUX. The structure is defined by the NUX itself. Limited to 300 characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    /// The date and time at which the NUX will expire and should be considered completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    pub id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalDetailsPref {
+    /// The birth date of account owner.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub birth_date: Option<String>,
+}
+
+/// Default post interaction settings for the account. These values should be applied as default values when creating new posts. These refs should mirror the threadgate and postgate records exactly.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostInteractionSettingsPref {
+    /// Matches postgate record. List of rules defining who can embed this users posts. If value is an empty array or is undefined, no particular rules apply and anyone can embed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postgate_embedding_rules:
+        Option<Vec<crate::api::Union<PostInteractionSettingsPrefPostgateEmbeddingRulesItem>>>,
+    /// Matches threadgate record. List of rules defining who can reply to this users posts. If value is an empty array, no one can reply. If value is undefined, anyone can reply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threadgate_allow_rules:
+        Option<Vec<crate::api::Union<PostInteractionSettingsPrefThreadgateAllowRulesItem>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum PostInteractionSettingsPrefPostgateEmbeddingRulesItem {
+    #[serde(rename = "app.bsky.feed.postgate#disableRule")]
+    DisableRule(Box<crate::api::app::bsky::feed::postgate::DisableRule>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum PostInteractionSettingsPrefThreadgateAllowRulesItem {
+    #[serde(rename = "app.bsky.feed.threadgate#mentionRule")]
+    MentionRule(Box<crate::api::app::bsky::feed::threadgate::MentionRule>),
+    #[serde(rename = "app.bsky.feed.threadgate#followerRule")]
+    FollowerRule(Box<crate::api::app::bsky::feed::threadgate::FollowerRule>),
+    #[serde(rename = "app.bsky.feed.threadgate#followingRule")]
+    FollowingRule(Box<crate::api::app::bsky::feed::threadgate::FollowingRule>),
+    #[serde(rename = "app.bsky.feed.threadgate#listRule")]
+    ListRule(Box<crate::api::app::bsky::feed::threadgate::ListRule>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum PreferencesItem {
+    #[serde(rename = "app.bsky.actor.defs#adultContentPref")]
+    AdultContentPref(Box<AdultContentPref>),
+    #[serde(rename = "app.bsky.actor.defs#contentLabelPref")]
+    ContentLabelPref(Box<ContentLabelPref>),
+    #[serde(rename = "app.bsky.actor.defs#savedFeedsPref")]
+    SavedFeedsPref(Box<SavedFeedsPref>),
+    #[serde(rename = "app.bsky.actor.defs#savedFeedsPrefV2")]
+    SavedFeedsPrefV2(Box<SavedFeedsPrefV2>),
+    #[serde(rename = "app.bsky.actor.defs#personalDetailsPref")]
+    PersonalDetailsPref(Box<PersonalDetailsPref>),
+    #[serde(rename = "app.bsky.actor.defs#feedViewPref")]
+    FeedViewPref(Box<FeedViewPref>),
+    #[serde(rename = "app.bsky.actor.defs#threadViewPref")]
+    ThreadViewPref(Box<ThreadViewPref>),
+    #[serde(rename = "app.bsky.actor.defs#interestsPref")]
+    InterestsPref(Box<InterestsPref>),
+    #[serde(rename = "app.bsky.actor.defs#mutedWordsPref")]
+    MutedWordsPref(Box<MutedWordsPref>),
+    #[serde(rename = "app.bsky.actor.defs#hiddenPostsPref")]
+    HiddenPostsPref(Box<HiddenPostsPref>),
+    #[serde(rename = "app.bsky.actor.defs#bskyAppStatePref")]
+    BskyAppStatePref(Box<BskyAppStatePref>),
+    #[serde(rename = "app.bsky.actor.defs#labelersPref")]
+    LabelersPref(Box<LabelersPref>),
+    #[serde(rename = "app.bsky.actor.defs#postInteractionSettingsPref")]
+    PostInteractionSettingsPref(Box<PostInteractionSettingsPref>),
+}
+
+pub type Preferences = Vec<crate::api::Union<PreferencesItem>>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileAssociated {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chat: Option<ProfileAssociatedChat>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feedgens: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labeler: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lists: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starter_packs: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileAssociatedChat {
+    pub allow_incoming: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub associated: Option<ProfileAssociated>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    pub handle: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indexed_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::api::com::atproto::label::defs::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<StatusView>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<VerificationState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<ViewerState>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileViewBasic {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub associated: Option<ProfileAssociated>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    pub handle: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::api::com::atproto::label::defs::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<StatusView>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<VerificationState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<ViewerState>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileViewDetailed {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub associated: Option<ProfileAssociated>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub banner: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub followers_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub follows_count: Option<i64>,
+    pub handle: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indexed_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joined_via_starter_pack: Option<crate::api::app::bsky::graph::defs::StarterPackViewBasic>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::api::com::atproto::label::defs::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned_post: Option<crate::api::com::atproto::repo::strong_ref::StrongRef>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub posts_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<StatusView>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<VerificationState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<ViewerState>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedFeed {
+    pub id: String,
+    pub pinned: bool,
+    pub r#type: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedFeedsPref {
+    pub pinned: Vec<String>,
+    pub saved: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeline_index: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedFeedsPrefV2 {
+    pub items: Vec<SavedFeed>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusView {
+    /// An optional embed associated with the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed: Option<crate::api::Union<StatusViewEmbedUnion>>,
+    /// The date when this status will expire. The application might choose to no longer return the status after expiration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    /// True if the status is not expired, false if it is expired. Only present if expiration was set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+    pub record: serde_json::Value,
+    /// The status for the account.
+    pub status: String,
+}
+
+/// An optional embed associated with the status.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum StatusViewEmbedUnion {
+    #[serde(rename = "app.bsky.embed.external#view")]
+    View(Box<crate::api::app::bsky::embed::external::View>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadViewPref {
+    /// Show followed users at the top of all replies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prioritize_followed_users: Option<bool>,
+    /// Sorting mode for threads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+}
+
+/// Represents the verification information about the user this object is attached to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationState {
+    /// The user's status as a trusted verifier.
+    pub trusted_verifier_status: String,
+    /// All verifications issued by trusted verifiers on behalf of this user. Verifications by untrusted verifiers are not included.
+    pub verifications: Vec<VerificationView>,
+    /// The user's status as a verified account.
+    pub verified_status: String,
+}
+
+/// An individual verification for an associated subject.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationView {
+    /// Timestamp when the verification was created.
+    pub created_at: String,
+    /// True if the verification passes validation, otherwise false.
+    pub is_valid: bool,
+    /// The user who issued this verification.
+    pub issuer: String,
+    /// The AT-URI of the verification record.
+    pub uri: String,
+}
+
+/// Metadata about the requesting account's relationship with the subject account. Only has meaningful content for authed requests.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewerState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked_by: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocking: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocking_by_list: Option<crate::api::app::bsky::graph::defs::ListViewBasic>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub followed_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub following: Option<String>,
+    /// This property is present only in selected cases, as an optimization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub known_followers: Option<KnownFollowers>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub muted: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub muted_by_list: Option<crate::api::app::bsky::graph::defs::ListViewBasic>,
+}
@@ -0,0 +1,19 @@
+//! `app.bsky.actor.getPreferences`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.actor.getPreferences";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub preferences: crate::api::app::bsky::actor::defs::Preferences,
+}
+
+/// Get private preferences attached to the current account. Expected use is synchronization between multiple devices, and import/export during account migration. Requires auth.
+pub async fn get_preferences(client: &XrpcClient) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::query(NSID);
+    Ok(client.query(request).await?.data)
+}
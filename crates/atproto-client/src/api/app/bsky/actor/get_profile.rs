@@ -0,0 +1,26 @@
+//! `app.bsky.actor.getProfile`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.actor.getProfile";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// Handle or DID of account to fetch profile of.
+    pub actor: String,
+}
+
+pub type Output = crate::api::app::bsky::actor::defs::ProfileViewDetailed;
+
+/// Get detailed profile view of an actor. Does not require auth, but contains relevant metadata with auth.
+pub async fn get_profile(client: &XrpcClient, params: &Parameters) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.actor;
+        request = request.param("actor", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
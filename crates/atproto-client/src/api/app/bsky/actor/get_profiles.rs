@@ -0,0 +1,28 @@
+//! `app.bsky.actor.getProfiles`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.actor.getProfiles";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub actors: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub profiles: Vec<crate::api::app::bsky::actor::defs::ProfileViewDetailed>,
+}
+
+/// Get detailed profile views of multiple actors.
+pub async fn get_profiles(client: &XrpcClient, params: &Parameters) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    for value in &params.actors {
+        request = request.param("actors", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
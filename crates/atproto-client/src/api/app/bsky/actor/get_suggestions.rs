@@ -0,0 +1,42 @@
+//! `app.bsky.actor.getSuggestions`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.actor.getSuggestions";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub actors: Vec<crate::api::app::bsky::actor::defs::ProfileView>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id: Option<i64>,
+}
+
+/// Get a list of suggested actors. Expected use is discovery of accounts to follow during new account onboarding.
+pub async fn get_suggestions(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.cursor {
+        request = request.param("cursor", value.clone());
+    }
+    if let Some(value) = &params.limit {
+        request = request.param("limit", value.to_string());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,12 @@
+//! `app.bsky.actor.*`
+
+pub mod defs;
+pub mod get_preferences;
+pub mod get_profile;
+pub mod get_profiles;
+pub mod get_suggestions;
+pub mod profile;
+pub mod put_preferences;
+pub mod search_actors;
+pub mod search_actors_typeahead;
+pub mod status;
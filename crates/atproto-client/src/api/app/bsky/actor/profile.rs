@@ -0,0 +1,40 @@
+//! `app.bsky.actor.profile`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.actor.profile";
+
+/// A declaration of a Bluesky account profile.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    /// Small image to be displayed next to posts from account. AKA, 'profile picture'
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<crate::lexicon::BlobRef>,
+    /// Larger horizontal image to display behind profile view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub banner: Option<crate::lexicon::BlobRef>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// Free-form profile description text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joined_via_starter_pack: Option<crate::api::com::atproto::repo::strong_ref::StrongRef>,
+    /// Self-label values, specific to the Bluesky application, on the overall account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<crate::api::Union<ProfileLabelsUnion>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned_post: Option<crate::api::com::atproto::repo::strong_ref::StrongRef>,
+}
+
+/// Self-label values, specific to the Bluesky application, on the overall account.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ProfileLabelsUnion {
+    #[serde(rename = "com.atproto.label.defs#selfLabels")]
+    SelfLabels(Box<crate::api::com::atproto::label::defs::SelfLabels>),
+}
@@ -0,0 +1,22 @@
+//! `app.bsky.actor.putPreferences`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.actor.putPreferences";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    pub preferences: crate::api::app::bsky::actor::defs::Preferences,
+}
+
+/// Set the private preferences attached to the account.
+pub async fn put_preferences(client: &XrpcClient, input: &Input) -> Result<(), XrpcError> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
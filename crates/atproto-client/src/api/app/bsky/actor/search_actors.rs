@@ -0,0 +1,48 @@
+//! `app.bsky.actor.searchActors`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.actor.searchActors";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Search query string. Syntax, phrase, boolean, and faceting is unspecified, but Lucene query syntax is recommended.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+    /// DEPRECATED: use 'q' instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub term: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub actors: Vec<crate::api::app::bsky::actor::defs::ProfileView>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+/// Find actors (profiles) matching search criteria. Does not require auth.
+pub async fn search_actors(client: &XrpcClient, params: &Parameters) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.cursor {
+        request = request.param("cursor", value.clone());
+    }
+    if let Some(value) = &params.limit {
+        request = request.param("limit", value.to_string());
+    }
+    if let Some(value) = &params.q {
+        request = request.param("q", value.clone());
+    }
+    if let Some(value) = &params.term {
+        request = request.param("term", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
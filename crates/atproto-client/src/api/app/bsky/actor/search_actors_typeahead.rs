@@ -0,0 +1,44 @@
+//! `app.bsky.actor.searchActorsTypeahead`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.actor.searchActorsTypeahead";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Search query prefix; not a full query string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+    /// DEPRECATED: use 'q' instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub term: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub actors: Vec<crate::api::app::bsky::actor::defs::ProfileViewBasic>,
+}
+
+/// Find actor suggestions for a prefix search term. Expected use is for auto-completion during text field entry. Does not require auth.
+pub async fn search_actors_typeahead(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.limit {
+        request = request.param("limit", value.to_string());
+    }
+    if let Some(value) = &params.q {
+        request = request.param("q", value.clone());
+    }
+    if let Some(value) = &params.term {
+        request = request.param("term", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
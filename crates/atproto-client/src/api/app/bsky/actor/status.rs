@@ -0,0 +1,35 @@
+//! `app.bsky.actor.status`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.actor.status";
+
+/// A declaration of a Bluesky account status.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Status {
+    pub created_at: String,
+    /// The duration of the status in minutes. Applications can choose to impose minimum and maximum limits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_minutes: Option<i64>,
+    /// An optional embed associated with the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed: Option<crate::api::Union<StatusEmbedUnion>>,
+    /// The status for the account.
+    pub status: String,
+}
+
+/// An optional embed associated with the status.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum StatusEmbedUnion {
+    #[serde(
+        rename = "app.bsky.embed.external",
+        alias = "app.bsky.embed.external#main"
+    )]
+    External(Box<crate::api::app::bsky::embed::external::Main>),
+}
+
+/// Advertises an account as currently offering live content.
+pub const LIVE: &str = "app.bsky.actor.status#live";
@@ -0,0 +1,35 @@
+//! `app.bsky.bookmark.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.bookmark.defs";
+
+/// Object used to store bookmark data in stash.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bookmark {
+    /// A strong ref to the record to be bookmarked. Currently, only `app.bsky.feed.post` records are supported.
+    pub subject: crate::api::com::atproto::repo::strong_ref::StrongRef,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookmarkView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    pub item: crate::api::Union<BookmarkViewItemUnion>,
+    /// A strong ref to the bookmarked record.
+    pub subject: crate::api::com::atproto::repo::strong_ref::StrongRef,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum BookmarkViewItemUnion {
+    #[serde(rename = "app.bsky.feed.defs#blockedPost")]
+    BlockedPost(Box<crate::api::app::bsky::feed::defs::BlockedPost>),
+    #[serde(rename = "app.bsky.feed.defs#notFoundPost")]
+    NotFoundPost(Box<crate::api::app::bsky::feed::defs::NotFoundPost>),
+    #[serde(rename = "app.bsky.feed.defs#postView")]
+    PostView(Box<crate::api::app::bsky::feed::defs::PostView>),
+}
@@ -0,0 +1,47 @@
+//! `app.bsky.bookmark.deleteBookmark`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcError, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.bookmark.deleteBookmark";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    pub uri: String,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// The URI to be bookmarked is for an unsupported collection.
+    UnsupportedCollection,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "UnsupportedCollection" => Some(Self::UnsupportedCollection),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::UnsupportedCollection => "UnsupportedCollection",
+        }
+    }
+}
+
+/// Deletes a private bookmark for the specified record. Currently, only `app.bsky.feed.post` records are supported. Requires authentication.
+pub async fn delete_bookmark(
+    client: &XrpcClient,
+    input: &Input,
+) -> Result<(), EndpointError<Error>> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//! `app.bsky.bookmark.getBookmarks`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.bookmark.getBookmarks";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub bookmarks: Vec<crate::api::app::bsky::bookmark::defs::BookmarkView>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+/// Gets views of records bookmarked by the authenticated user. Requires authentication.
+pub async fn get_bookmarks(client: &XrpcClient, params: &Parameters) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.cursor {
+        request = request.param("cursor", value.clone());
+    }
+    if let Some(value) = &params.limit {
+        request = request.param("limit", value.to_string());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,6 @@
+//! `app.bsky.bookmark.*`
+
+pub mod create_bookmark;
+pub mod defs;
+pub mod delete_bookmark;
+pub mod get_bookmarks;
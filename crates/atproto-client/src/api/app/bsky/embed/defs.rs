@@ -0,0 +1,14 @@
+//! `app.bsky.embed.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.embed.defs";
+
+/// width:height represents an aspect ratio. It may be approximate, and may not correspond to absolute dimensions in any given unit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AspectRatio {
+    pub height: i64,
+    pub width: i64,
+}
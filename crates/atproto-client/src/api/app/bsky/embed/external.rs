@@ -0,0 +1,40 @@
+//! `app.bsky.embed.external`
+//!
+//! A representation of some externally linked content (eg, a URL and 'card'), embedded in a Bluesky record (eg, a post).
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.embed.external";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Main {
+    pub external: External,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct External {
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<crate::lexicon::BlobRef>,
+    pub title: String,
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    pub external: ViewExternal,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewExternal {
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<String>,
+    pub title: String,
+    pub uri: String,
+}
@@ -0,0 +1,43 @@
+//! `app.bsky.embed.images`
+//!
+//! A set of images embedded in a Bluesky record (eg, a post).
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.embed.images";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Images {
+    pub images: Vec<Image>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Image {
+    /// Alt text description of the image, for accessibility.
+    pub alt: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aspect_ratio: Option<crate::api::app::bsky::embed::defs::AspectRatio>,
+    pub image: crate::lexicon::BlobRef,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    pub images: Vec<ViewImage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewImage {
+    /// Alt text description of the image, for accessibility.
+    pub alt: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aspect_ratio: Option<crate::api::app::bsky::embed::defs::AspectRatio>,
+    /// Fully-qualified URL where a large version of the image can be fetched. May or may not be the exact original blob. For example, CDN location provided by the App View.
+    pub fullsize: String,
+    /// Fully-qualified URL where a thumbnail of the image can be fetched. For example, CDN location provided by the App View.
+    pub thumb: String,
+}
@@ -0,0 +1,8 @@
+//! `app.bsky.embed.*`
+
+pub mod defs;
+pub mod external;
+pub mod images;
+pub mod record;
+pub mod record_with_media;
+pub mod video;
@@ -0,0 +1,101 @@
+//! `app.bsky.embed.record`
+//!
+//! A representation of a record embedded in a Bluesky record (eg, a post). For example, a quote-post, or sharing a feed generator record.
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.embed.record";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Record {
+    pub record: crate::api::com::atproto::repo::strong_ref::StrongRef,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    pub record: crate::api::Union<ViewRecordUnion>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ViewRecordUnion {
+    #[serde(rename = "app.bsky.embed.record#viewRecord")]
+    ViewRecord(Box<ViewRecord>),
+    #[serde(rename = "app.bsky.embed.record#viewNotFound")]
+    ViewNotFound(Box<ViewNotFound>),
+    #[serde(rename = "app.bsky.embed.record#viewBlocked")]
+    ViewBlocked(Box<ViewBlocked>),
+    #[serde(rename = "app.bsky.embed.record#viewDetached")]
+    ViewDetached(Box<ViewDetached>),
+    #[serde(rename = "app.bsky.feed.defs#generatorView")]
+    GeneratorView(Box<crate::api::app::bsky::feed::defs::GeneratorView>),
+    #[serde(rename = "app.bsky.graph.defs#listView")]
+    ListView(Box<crate::api::app::bsky::graph::defs::ListView>),
+    #[serde(rename = "app.bsky.labeler.defs#labelerView")]
+    LabelerView(Box<crate::api::app::bsky::labeler::defs::LabelerView>),
+    #[serde(rename = "app.bsky.graph.defs#starterPackViewBasic")]
+    StarterPackViewBasic(Box<crate::api::app::bsky::graph::defs::StarterPackViewBasic>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewBlocked {
+    pub author: crate::api::app::bsky::feed::defs::BlockedAuthor,
+    pub blocked: bool,
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewDetached {
+    pub detached: bool,
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewNotFound {
+    pub not_found: bool,
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewRecord {
+    pub author: crate::api::app::bsky::actor::defs::ProfileViewBasic,
+    pub cid: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<crate::api::Union<ViewRecordEmbedsItem>>>,
+    pub indexed_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::api::com::atproto::label::defs::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quote_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repost_count: Option<i64>,
+    pub uri: String,
+    /// The record data itself.
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ViewRecordEmbedsItem {
+    #[serde(rename = "app.bsky.embed.images#view")]
+    ImagesView(Box<crate::api::app::bsky::embed::images::View>),
+    #[serde(rename = "app.bsky.embed.video#view")]
+    VideoView(Box<crate::api::app::bsky::embed::video::View>),
+    #[serde(rename = "app.bsky.embed.external#view")]
+    ExternalView(Box<crate::api::app::bsky::embed::external::View>),
+    #[serde(rename = "app.bsky.embed.record#view")]
+    RecordView(Box<View>),
+    #[serde(rename = "app.bsky.embed.recordWithMedia#view")]
+    RecordWithMediaView(Box<crate::api::app::bsky::embed::record_with_media::View>),
+}
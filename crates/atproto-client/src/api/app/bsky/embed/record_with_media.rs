@@ -0,0 +1,47 @@
+//! `app.bsky.embed.recordWithMedia`
+//!
+//! A representation of a record embedded in a Bluesky record (eg, a post), alongside other compatible embeds. For example, a quote post and image, or a quote post and external URL card.
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.embed.recordWithMedia";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordWithMedia {
+    pub media: crate::api::Union<RecordWithMediaMediaUnion>,
+    pub record: crate::api::app::bsky::embed::record::Record,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum RecordWithMediaMediaUnion {
+    #[serde(rename = "app.bsky.embed.images", alias = "app.bsky.embed.images#main")]
+    Images(Box<crate::api::app::bsky::embed::images::Images>),
+    #[serde(rename = "app.bsky.embed.video", alias = "app.bsky.embed.video#main")]
+    Video(Box<crate::api::app::bsky::embed::video::Video>),
+    #[serde(
+        rename = "app.bsky.embed.external",
+        alias = "app.bsky.embed.external#main"
+    )]
+    External(Box<crate::api::app::bsky::embed::external::Main>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    pub media: crate::api::Union<ViewMediaUnion>,
+    pub record: crate::api::app::bsky::embed::record::View,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ViewMediaUnion {
+    #[serde(rename = "app.bsky.embed.images#view")]
+    ImagesView(Box<crate::api::app::bsky::embed::images::View>),
+    #[serde(rename = "app.bsky.embed.video#view")]
+    VideoView(Box<crate::api::app::bsky::embed::video::View>),
+    #[serde(rename = "app.bsky.embed.external#view")]
+    ExternalView(Box<crate::api::app::bsky::embed::external::View>),
+}
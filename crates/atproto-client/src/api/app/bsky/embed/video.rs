@@ -0,0 +1,42 @@
+//! `app.bsky.embed.video`
+//!
+//! A video embedded in a Bluesky record (eg, a post).
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.embed.video";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Video {
+    /// Alt text description of the video, for accessibility.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aspect_ratio: Option<crate::api::app::bsky::embed::defs::AspectRatio>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub captions: Option<Vec<Caption>>,
+    /// The mp4 video file. May be up to 100mb, formerly limited to 50mb.
+    pub video: crate::lexicon::BlobRef,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Caption {
+    pub file: crate::lexicon::BlobRef,
+    pub lang: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aspect_ratio: Option<crate::api::app::bsky::embed::defs::AspectRatio>,
+    pub cid: String,
+    pub playlist: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
+}
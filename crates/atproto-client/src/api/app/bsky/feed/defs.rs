@@ -0,0 +1,340 @@
+//! `app.bsky.feed.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.defs";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockedAuthor {
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<crate::api::app::bsky::actor::defs::ViewerState>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockedPost {
+    pub author: BlockedAuthor,
+    pub blocked: bool,
+    pub uri: String,
+}
+
+/// User clicked through to the author of the feed item
+pub const CLICKTHROUGH_AUTHOR: &str = "app.bsky.feed.defs#clickthroughAuthor";
+
+/// User clicked through to the embedded content of the feed item
+pub const CLICKTHROUGH_EMBED: &str = "app.bsky.feed.defs#clickthroughEmbed";
+
+/// User clicked through to the feed item
+pub const CLICKTHROUGH_ITEM: &str = "app.bsky.feed.defs#clickthroughItem";
+
+/// User clicked through to the reposter of the feed item
+pub const CLICKTHROUGH_REPOSTER: &str = "app.bsky.feed.defs#clickthroughReposter";
+
+/// Declares the feed generator returns any types of posts.
+pub const CONTENT_MODE_UNSPECIFIED: &str = "app.bsky.feed.defs#contentModeUnspecified";
+
+/// Declares the feed generator returns posts containing app.bsky.embed.video embeds.
+pub const CONTENT_MODE_VIDEO: &str = "app.bsky.feed.defs#contentModeVideo";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedViewPost {
+    /// Context provided by feed generator that may be passed back alongside interactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_context: Option<String>,
+    pub post: PostView,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<crate::api::Union<FeedViewPostReasonUnion>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply: Option<ReplyRef>,
+    /// Unique identifier per request that may be passed back alongside interactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub req_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum FeedViewPostReasonUnion {
+    #[serde(rename = "app.bsky.feed.defs#reasonRepost")]
+    ReasonRepost(Box<ReasonRepost>),
+    #[serde(rename = "app.bsky.feed.defs#reasonPin")]
+    ReasonPin(Box<ReasonPin>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratorView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accepts_interactions: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    pub cid: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_mode: Option<String>,
+    pub creator: crate::api::app::bsky::actor::defs::ProfileView,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_facets: Option<Vec<crate::api::app::bsky::richtext::facet::Facet>>,
+    pub did: String,
+    pub display_name: String,
+    pub indexed_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::api::com::atproto::label::defs::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like_count: Option<i64>,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<GeneratorViewerState>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratorViewerState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Interaction {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<String>,
+    /// Context on a feed item that was originally supplied by the feed generator on getFeedSkeleton.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_context: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item: Option<String>,
+    /// Unique identifier per request that may be passed back alongside interactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub req_id: Option<String>,
+}
+
+/// User liked the feed item
+pub const INTERACTION_LIKE: &str = "app.bsky.feed.defs#interactionLike";
+
+/// User quoted the feed item
+pub const INTERACTION_QUOTE: &str = "app.bsky.feed.defs#interactionQuote";
+
+/// User replied to the feed item
+pub const INTERACTION_REPLY: &str = "app.bsky.feed.defs#interactionReply";
+
+/// User reposted the feed item
+pub const INTERACTION_REPOST: &str = "app.bsky.feed.defs#interactionRepost";
+
+/// Feed item was seen by user
+pub const INTERACTION_SEEN: &str = "app.bsky.feed.defs#interactionSeen";
+
+/// User shared the feed item
+pub const INTERACTION_SHARE: &str = "app.bsky.feed.defs#interactionShare";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotFoundPost {
+    pub not_found: bool,
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostView {
+    pub author: crate::api::app::bsky::actor::defs::ProfileViewBasic,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bookmark_count: Option<i64>,
+    pub cid: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed: Option<crate::api::Union<PostViewEmbedUnion>>,
+    pub indexed_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::api::com::atproto::label::defs::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quote_count: Option<i64>,
+    pub record: serde_json::Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repost_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threadgate: Option<ThreadgateView>,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<ViewerState>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum PostViewEmbedUnion {
+    #[serde(rename = "app.bsky.embed.images#view")]
+    ImagesView(Box<crate::api::app::bsky::embed::images::View>),
+    #[serde(rename = "app.bsky.embed.video#view")]
+    VideoView(Box<crate::api::app::bsky::embed::video::View>),
+    #[serde(rename = "app.bsky.embed.external#view")]
+    ExternalView(Box<crate::api::app::bsky::embed::external::View>),
+    #[serde(rename = "app.bsky.embed.record#view")]
+    RecordView(Box<crate::api::app::bsky::embed::record::View>),
+    #[serde(rename = "app.bsky.embed.recordWithMedia#view")]
+    RecordWithMediaView(Box<crate::api::app::bsky::embed::record_with_media::View>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReasonPin {}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReasonRepost {
+    pub by: crate::api::app::bsky::actor::defs::ProfileViewBasic,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    pub indexed_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplyRef {
+    /// When parent is a reply to another post, this is the author of that post.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grandparent_author: Option<crate::api::app::bsky::actor::defs::ProfileViewBasic>,
+    pub parent: crate::api::Union<ReplyRefParentUnion>,
+    pub root: crate::api::Union<ReplyRefRootUnion>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ReplyRefParentUnion {
+    #[serde(rename = "app.bsky.feed.defs#postView")]
+    PostView(Box<PostView>),
+    #[serde(rename = "app.bsky.feed.defs#notFoundPost")]
+    NotFoundPost(Box<NotFoundPost>),
+    #[serde(rename = "app.bsky.feed.defs#blockedPost")]
+    BlockedPost(Box<BlockedPost>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ReplyRefRootUnion {
+    #[serde(rename = "app.bsky.feed.defs#postView")]
+    PostView(Box<PostView>),
+    #[serde(rename = "app.bsky.feed.defs#notFoundPost")]
+    NotFoundPost(Box<NotFoundPost>),
+    #[serde(rename = "app.bsky.feed.defs#blockedPost")]
+    BlockedPost(Box<BlockedPost>),
+}
+
+/// Request that less content like the given feed item be shown in the feed
+pub const REQUEST_LESS: &str = "app.bsky.feed.defs#requestLess";
+
+/// Request that more content like the given feed item be shown in the feed
+pub const REQUEST_MORE: &str = "app.bsky.feed.defs#requestMore";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkeletonFeedPost {
+    /// Context that will be passed through to client and may be passed to feed generator back alongside interactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed_context: Option<String>,
+    pub post: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<crate::api::Union<SkeletonFeedPostReasonUnion>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum SkeletonFeedPostReasonUnion {
+    #[serde(rename = "app.bsky.feed.defs#skeletonReasonRepost")]
+    SkeletonReasonRepost(Box<SkeletonReasonRepost>),
+    #[serde(rename = "app.bsky.feed.defs#skeletonReasonPin")]
+    SkeletonReasonPin(Box<SkeletonReasonPin>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkeletonReasonPin {}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkeletonReasonRepost {
+    pub repost: String,
+}
+
+/// Metadata about this post within the context of the thread it is in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadContext {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root_author_like: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadViewPost {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<crate::api::Union<ThreadViewPostParentUnion>>,
+    pub post: PostView,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replies: Option<Vec<crate::api::Union<ThreadViewPostRepliesItem>>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread_context: Option<ThreadContext>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ThreadViewPostParentUnion {
+    #[serde(rename = "app.bsky.feed.defs#threadViewPost")]
+    ThreadViewPost(Box<ThreadViewPost>),
+    #[serde(rename = "app.bsky.feed.defs#notFoundPost")]
+    NotFoundPost(Box<NotFoundPost>),
+    #[serde(rename = "app.bsky.feed.defs#blockedPost")]
+    BlockedPost(Box<BlockedPost>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ThreadViewPostRepliesItem {
+    #[serde(rename = "app.bsky.feed.defs#threadViewPost")]
+    ThreadViewPost(Box<ThreadViewPost>),
+    #[serde(rename = "app.bsky.feed.defs#notFoundPost")]
+    NotFoundPost(Box<NotFoundPost>),
+    #[serde(rename = "app.bsky.feed.defs#blockedPost")]
+    BlockedPost(Box<BlockedPost>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreadgateView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lists: Option<Vec<crate::api::app::bsky::graph::defs::ListViewBasic>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+/// Metadata about the requesting account's relationship with the subject content. Only has meaningful content for authed requests.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewerState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bookmarked: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedding_disabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_disabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repost: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread_muted: Option<bool>,
+}
@@ -0,0 +1,37 @@
+//! `app.bsky.feed.generator`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.generator";
+
+/// Record declaring of the existence of a feed generator, and containing metadata about it. The record can exist in any repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Generator {
+    /// Declaration that a feed accepts feedback interactions from a client through app.bsky.feed.sendInteractions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accepts_interactions: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<crate::lexicon::BlobRef>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_mode: Option<String>,
+    pub created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_facets: Option<Vec<crate::api::app::bsky::richtext::facet::Facet>>,
+    pub did: String,
+    pub display_name: String,
+    /// Self-label values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<crate::api::Union<GeneratorLabelsUnion>>,
+}
+
+/// Self-label values
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum GeneratorLabelsUnion {
+    #[serde(rename = "com.atproto.label.defs#selfLabels")]
+    SelfLabels(Box<crate::api::com::atproto::label::defs::SelfLabels>),
+}
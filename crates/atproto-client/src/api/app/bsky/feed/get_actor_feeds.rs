@@ -0,0 +1,44 @@
+//! `app.bsky.feed.getActorFeeds`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.getActorFeeds";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub actor: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub feeds: Vec<crate::api::app::bsky::feed::defs::GeneratorView>,
+}
+
+/// Get a list of feeds (feed generator records) created by the actor (in the actor's repo).
+pub async fn get_actor_feeds(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.actor;
+        request = request.param("actor", value.clone());
+    }
+    if let Some(value) = &params.cursor {
+        request = request.param("cursor", value.clone());
+    }
+    if let Some(value) = &params.limit {
+        request = request.param("limit", value.to_string());
+    }
+    Ok(client.query(request).await?.data)
+}
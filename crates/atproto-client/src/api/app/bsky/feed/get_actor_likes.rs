@@ -0,0 +1,68 @@
+//! `app.bsky.feed.getActorLikes`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.getActorLikes";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub actor: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub feed: Vec<crate::api::app::bsky::feed::defs::FeedViewPost>,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    BlockedActor,
+    BlockedByActor,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "BlockedActor" => Some(Self::BlockedActor),
+            "BlockedByActor" => Some(Self::BlockedByActor),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::BlockedActor => "BlockedActor",
+            Self::BlockedByActor => "BlockedByActor",
+        }
+    }
+}
+
+/// Get a list of posts liked by an actor. Requires auth, actor must be the requesting account.
+pub async fn get_actor_likes(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, EndpointError<Error>> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.actor;
+        request = request.param("actor", value.clone());
+    }
+    if let Some(value) = &params.cursor {
+        request = request.param("cursor", value.clone());
+    }
+    if let Some(value) = &params.limit {
+        request = request.param("limit", value.to_string());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,79 @@
+//! `app.bsky.feed.getAuthorFeed`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.getAuthorFeed";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub actor: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Combinations of post/repost types to include in response.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_pins: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub feed: Vec<crate::api::app::bsky::feed::defs::FeedViewPost>,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    BlockedActor,
+    BlockedByActor,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "BlockedActor" => Some(Self::BlockedActor),
+            "BlockedByActor" => Some(Self::BlockedByActor),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::BlockedActor => "BlockedActor",
+            Self::BlockedByActor => "BlockedByActor",
+        }
+    }
+}
+
+/// Get a view of an actor's 'author feed' (post and reposts by the author). Does not require auth.
+pub async fn get_author_feed(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, EndpointError<Error>> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.actor;
+        request = request.param("actor", value.clone());
+    }
+    if let Some(value) = &params.cursor {
+        request = request.param("cursor", value.clone());
+    }
+    if let Some(value) = &params.filter {
+        request = request.param("filter", value.clone());
+    }
+    if let Some(value) = &params.include_pins {
+        request = request.param("includePins", value.to_string());
+    }
+    if let Some(value) = &params.limit {
+        request = request.param("limit", value.to_string());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,65 @@
+//! `app.bsky.feed.getFeed`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.getFeed";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub feed: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub feed: Vec<crate::api::app::bsky::feed::defs::FeedViewPost>,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    UnknownFeed,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "UnknownFeed" => Some(Self::UnknownFeed),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::UnknownFeed => "UnknownFeed",
+        }
+    }
+}
+
+/// Get a hydrated feed from an actor's selected feed generator. Implemented by App View.
+pub async fn get_feed(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, EndpointError<Error>> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.cursor {
+        request = request.param("cursor", value.clone());
+    }
+    {
+        let value = &params.feed;
+        request = request.param("feed", value.clone());
+    }
+    if let Some(value) = &params.limit {
+        request = request.param("limit", value.to_string());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,37 @@
+//! `app.bsky.feed.getFeedGenerator`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.getFeedGenerator";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// AT-URI of the feed generator record.
+    pub feed: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    /// Indicates whether the feed generator service has been online recently, or else seems to be inactive.
+    pub is_online: bool,
+    /// Indicates whether the feed generator service is compatible with the record declaration.
+    pub is_valid: bool,
+    pub view: crate::api::app::bsky::feed::defs::GeneratorView,
+}
+
+/// Get information about a feed generator. Implemented by AppView.
+pub async fn get_feed_generator(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.feed;
+        request = request.param("feed", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
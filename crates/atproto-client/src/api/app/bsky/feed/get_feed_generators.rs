@@ -0,0 +1,31 @@
+//! `app.bsky.feed.getFeedGenerators`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.getFeedGenerators";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub feeds: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub feeds: Vec<crate::api::app::bsky::feed::defs::GeneratorView>,
+}
+
+/// Get information about a list of feed generators.
+pub async fn get_feed_generators(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    for value in &params.feeds {
+        request = request.param("feeds", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,59 @@
+//! `app.bsky.feed.getLikes`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.getLikes";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// CID of the subject record (aka, specific version of record), to filter likes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// AT-URI of the subject (eg, a post record).
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub likes: Vec<Like>,
+    pub uri: String,
+}
+
+/// Get like records which reference a subject (by AT-URI and CID).
+pub async fn get_likes(client: &XrpcClient, params: &Parameters) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.cid {
+        request = request.param("cid", value.clone());
+    }
+    if let Some(value) = &params.cursor {
+        request = request.param("cursor", value.clone());
+    }
+    if let Some(value) = &params.limit {
+        request = request.param("limit", value.to_string());
+    }
+    {
+        let value = &params.uri;
+        request = request.param("uri", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Like {
+    pub actor: crate::api::app::bsky::actor::defs::ProfileView,
+    pub created_at: String,
+    pub indexed_at: String,
+}
@@ -0,0 +1,66 @@
+//! `app.bsky.feed.getListFeed`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.getListFeed";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Reference (AT-URI) to the list record.
+    pub list: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub feed: Vec<crate::api::app::bsky::feed::defs::FeedViewPost>,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    UnknownList,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "UnknownList" => Some(Self::UnknownList),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::UnknownList => "UnknownList",
+        }
+    }
+}
+
+/// Get a feed of recent posts from a list (posts and reposts from any actors on the list). Does not require auth.
+pub async fn get_list_feed(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, EndpointError<Error>> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.cursor {
+        request = request.param("cursor", value.clone());
+    }
+    if let Some(value) = &params.limit {
+        request = request.param("limit", value.to_string());
+    }
+    {
+        let value = &params.list;
+        request = request.param("list", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
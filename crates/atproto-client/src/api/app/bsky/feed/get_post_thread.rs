@@ -0,0 +1,79 @@
+//! `app.bsky.feed.getPostThread`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.getPostThread";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// How many levels of reply depth should be included in response.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub depth: Option<i64>,
+    /// How many levels of parent (and grandparent, etc) post to include.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_height: Option<i64>,
+    /// Reference (AT-URI) to post record.
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub thread: crate::api::Union<OutputThreadUnion>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threadgate: Option<crate::api::app::bsky::feed::defs::ThreadgateView>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum OutputThreadUnion {
+    #[serde(rename = "app.bsky.feed.defs#threadViewPost")]
+    ThreadViewPost(Box<crate::api::app::bsky::feed::defs::ThreadViewPost>),
+    #[serde(rename = "app.bsky.feed.defs#notFoundPost")]
+    NotFoundPost(Box<crate::api::app::bsky::feed::defs::NotFoundPost>),
+    #[serde(rename = "app.bsky.feed.defs#blockedPost")]
+    BlockedPost(Box<crate::api::app::bsky::feed::defs::BlockedPost>),
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    NotFound,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "NotFound" => Some(Self::NotFound),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::NotFound => "NotFound",
+        }
+    }
+}
+
+/// Get posts in a thread. Does not require auth, but additional metadata and filtering will be applied for authed requests.
+pub async fn get_post_thread(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, EndpointError<Error>> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.depth {
+        request = request.param("depth", value.to_string());
+    }
+    if let Some(value) = &params.parent_height {
+        request = request.param("parentHeight", value.to_string());
+    }
+    {
+        let value = &params.uri;
+        request = request.param("uri", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
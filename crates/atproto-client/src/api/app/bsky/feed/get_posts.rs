@@ -0,0 +1,29 @@
+//! `app.bsky.feed.getPosts`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.getPosts";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// List of post AT-URIs to return hydrated views for.
+    pub uris: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub posts: Vec<crate::api::app::bsky::feed::defs::PostView>,
+}
+
+/// Gets post views for a specified list of posts (by AT-URI). This is sometimes referred to as 'hydrating' a 'feed skeleton'.
+pub async fn get_posts(client: &XrpcClient, params: &Parameters) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    for value in &params.uris {
+        request = request.param("uris", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,16 @@
+//! `app.bsky.feed.like`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.like";
+
+/// Record declaring a 'like' of a piece of subject content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Like {
+    pub created_at: String,
+    pub subject: crate::api::com::atproto::repo::strong_ref::StrongRef,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub via: Option<crate::api::com::atproto::repo::strong_ref::StrongRef>,
+}
@@ -0,0 +1,27 @@
+//! `app.bsky.feed.*`
+
+pub mod defs;
+pub mod describe_feed_generator;
+pub mod generator;
+pub mod get_actor_feeds;
+pub mod get_actor_likes;
+pub mod get_author_feed;
+pub mod get_feed;
+pub mod get_feed_generator;
+pub mod get_feed_generators;
+pub mod get_feed_skeleton;
+pub mod get_likes;
+pub mod get_list_feed;
+pub mod get_post_thread;
+pub mod get_posts;
+pub mod get_quotes;
+pub mod get_reposted_by;
+pub mod get_suggested_feeds;
+pub mod get_timeline;
+pub mod like;
+pub mod post;
+pub mod postgate;
+pub mod repost;
+pub mod search_posts;
+pub mod send_interactions;
+pub mod threadgate;
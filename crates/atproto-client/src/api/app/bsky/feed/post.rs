@@ -0,0 +1,89 @@
+//! `app.bsky.feed.post`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.post";
+
+/// Record containing a Bluesky post.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Post {
+    /// Client-declared timestamp when this post was originally created.
+    pub created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed: Option<crate::api::Union<PostEmbedUnion>>,
+    /// DEPRECATED: replaced by app.bsky.richtext.facet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<Entity>>,
+    /// Annotations of text (mentions, URLs, hashtags, etc)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<crate::api::app::bsky::richtext::facet::Facet>>,
+    /// Self-label values for this post. Effectively content warnings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<crate::api::Union<PostLabelsUnion>>,
+    /// Indicates human language of post primary text content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub langs: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply: Option<ReplyRef>,
+    /// Additional hashtags, in addition to any included in post text and facets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// The primary post content. May be an empty string, if there are embeds.
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum PostEmbedUnion {
+    #[serde(rename = "app.bsky.embed.images", alias = "app.bsky.embed.images#main")]
+    Images(Box<crate::api::app::bsky::embed::images::Images>),
+    #[serde(rename = "app.bsky.embed.video", alias = "app.bsky.embed.video#main")]
+    Video(Box<crate::api::app::bsky::embed::video::Video>),
+    #[serde(
+        rename = "app.bsky.embed.external",
+        alias = "app.bsky.embed.external#main"
+    )]
+    External(Box<crate::api::app::bsky::embed::external::Main>),
+    #[serde(rename = "app.bsky.embed.record", alias = "app.bsky.embed.record#main")]
+    Record(Box<crate::api::app::bsky::embed::record::Record>),
+    #[serde(
+        rename = "app.bsky.embed.recordWithMedia",
+        alias = "app.bsky.embed.recordWithMedia#main"
+    )]
+    RecordWithMedia(Box<crate::api::app::bsky::embed::record_with_media::RecordWithMedia>),
+}
+
+/// Self-label values for this post. Effectively content warnings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum PostLabelsUnion {
+    #[serde(rename = "com.atproto.label.defs#selfLabels")]
+    SelfLabels(Box<crate::api::com::atproto::label::defs::SelfLabels>),
+}
+
+/// Deprecated: use facets instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Entity {
+    pub index: TextSlice,
+    /// Expected values are 'mention' and 'link'.
+    pub r#type: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplyRef {
+    pub parent: crate::api::com::atproto::repo::strong_ref::StrongRef,
+    pub root: crate::api::com::atproto::repo::strong_ref::StrongRef,
+}
+
+/// Deprecated. Use app.bsky.richtext instead -- A text segment. Start is inclusive, end is exclusive. Indices are for utf16-encoded strings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextSlice {
+    pub end: i64,
+    pub start: i64,
+}
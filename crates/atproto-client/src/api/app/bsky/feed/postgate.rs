@@ -0,0 +1,33 @@
+//! `app.bsky.feed.postgate`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.postgate";
+
+/// Record defining interaction rules for a post. The record key (rkey) of the postgate record must match the record key of the post, and that record must be in the same repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Postgate {
+    pub created_at: String,
+    /// List of AT-URIs embedding this post that the author has detached from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detached_embedding_uris: Option<Vec<String>>,
+    /// List of rules defining who can embed this post. If value is an empty array or is undefined, no particular rules apply and anyone can embed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedding_rules: Option<Vec<crate::api::Union<PostgateEmbeddingRulesItem>>>,
+    /// Reference (AT-URI) to the post record.
+    pub post: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum PostgateEmbeddingRulesItem {
+    #[serde(rename = "app.bsky.feed.postgate#disableRule")]
+    DisableRule(Box<DisableRule>),
+}
+
+/// Disables embedding of this post.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisableRule {}
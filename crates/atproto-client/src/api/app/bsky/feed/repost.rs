@@ -0,0 +1,16 @@
+//! `app.bsky.feed.repost`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.repost";
+
+/// Record representing a 'repost' of an existing Bluesky post.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Repost {
+    pub created_at: String,
+    pub subject: crate::api::com::atproto::repo::strong_ref::StrongRef,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub via: Option<crate::api::com::atproto::repo::strong_ref::StrongRef>,
+}
@@ -0,0 +1,25 @@
+//! `app.bsky.feed.sendInteractions`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.sendInteractions";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    pub interactions: Vec<crate::api::app::bsky::feed::defs::Interaction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {}
+
+/// Send information about interactions with feed items back to the feed generators that served them.
+pub async fn send_interactions(client: &XrpcClient, input: &Input) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    Ok(client.procedure(request).await?.data)
+}
@@ -0,0 +1,56 @@
+//! `app.bsky.feed.threadgate`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.feed.threadgate";
+
+/// Record defining interaction gating rules for a thread (aka, reply controls). The record key (rkey) of the threadgate record must match the record key of the thread's root post, and that record must be in the same repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Threadgate {
+    /// List of rules defining who can reply to this post. If value is an empty array, no one can reply. If value is undefined, anyone can reply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow: Option<Vec<crate::api::Union<ThreadgateAllowItem>>>,
+    pub created_at: String,
+    /// List of hidden reply URIs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hidden_replies: Option<Vec<String>>,
+    /// Reference (AT-URI) to the post record.
+    pub post: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ThreadgateAllowItem {
+    #[serde(rename = "app.bsky.feed.threadgate#mentionRule")]
+    MentionRule(Box<MentionRule>),
+    #[serde(rename = "app.bsky.feed.threadgate#followerRule")]
+    FollowerRule(Box<FollowerRule>),
+    #[serde(rename = "app.bsky.feed.threadgate#followingRule")]
+    FollowingRule(Box<FollowingRule>),
+    #[serde(rename = "app.bsky.feed.threadgate#listRule")]
+    ListRule(Box<ListRule>),
+}
+
+/// Allow replies from actors who follow you.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowerRule {}
+
+/// Allow replies from actors you follow.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowingRule {}
+
+/// Allow replies from actors on a list.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListRule {
+    pub list: String,
+}
+
+/// Allow replies from actors mentioned in your post.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MentionRule {}
@@ -0,0 +1,15 @@
+//! `app.bsky.graph.block`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.graph.block";
+
+/// Record declaring a 'block' relationship against another account. NOTE: blocks are public in Bluesky; see blog posts for details.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Block {
+    pub created_at: String,
+    /// DID of the account to be blocked.
+    pub subject: String,
+}
@@ -0,0 +1,136 @@
+//! `app.bsky.graph.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.graph.defs";
+
+/// A list of actors used for curation purposes such as list feeds or interaction gating.
+pub const CURATELIST: &str = "app.bsky.graph.defs#curatelist";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListItemView {
+    pub subject: crate::api::app::bsky::actor::defs::ProfileView,
+    pub uri: String,
+}
+
+pub type ListPurpose = String;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    pub cid: String,
+    pub creator: crate::api::app::bsky::actor::defs::ProfileView,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_facets: Option<Vec<crate::api::app::bsky::richtext::facet::Facet>>,
+    pub indexed_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::api::com::atproto::label::defs::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list_item_count: Option<i64>,
+    pub name: String,
+    pub purpose: ListPurpose,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<ListViewerState>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListViewBasic {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    pub cid: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indexed_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::api::com::atproto::label::defs::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list_item_count: Option<i64>,
+    pub name: String,
+    pub purpose: ListPurpose,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<ListViewerState>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListViewerState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub muted: Option<bool>,
+}
+
+/// A list of actors to apply an aggregate moderation action (mute/block) on.
+pub const MODLIST: &str = "app.bsky.graph.defs#modlist";
+
+/// indicates that a handle or DID could not be resolved
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotFoundActor {
+    pub actor: String,
+    pub not_found: bool,
+}
+
+/// A list of actors used for only for reference purposes such as within a starter pack.
+pub const REFERENCELIST: &str = "app.bsky.graph.defs#referencelist";
+
+/// lists the bi-directional graph relationships between one actor (not indicated in the object), and the target actors (the DID included in the object)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Relationship {
+    pub did: String,
+    /// if the actor is followed by this DID, contains the AT-URI of the follow record
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub followed_by: Option<String>,
+    /// if the actor follows this DID, this is the AT-URI of the follow record
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub following: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StarterPackView {
+    pub cid: String,
+    pub creator: crate::api::app::bsky::actor::defs::ProfileViewBasic,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feeds: Option<Vec<crate::api::app::bsky::feed::defs::GeneratorView>>,
+    pub indexed_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joined_all_time_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joined_week_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::api::com::atproto::label::defs::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list: Option<ListViewBasic>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list_items_sample: Option<Vec<ListItemView>>,
+    pub record: serde_json::Value,
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StarterPackViewBasic {
+    pub cid: String,
+    pub creator: crate::api::app::bsky::actor::defs::ProfileViewBasic,
+    pub indexed_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joined_all_time_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub joined_week_count: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::api::com::atproto::label::defs::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list_item_count: Option<i64>,
+    pub record: serde_json::Value,
+    pub uri: String,
+}
@@ -0,0 +1,14 @@
+//! `app.bsky.graph.follow`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.graph.follow";
+
+/// Record declaring a social 'follow' relationship of another account. Duplicate follows will be ignored by the AppView.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Follow {
+    pub created_at: String,
+    pub subject: String,
+}
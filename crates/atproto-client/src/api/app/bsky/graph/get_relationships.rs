@@ -0,0 +1,72 @@
+//! `app.bsky.graph.getRelationships`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.graph.getRelationships";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// Primary account requesting relationships for.
+    pub actor: String,
+    /// List of 'other' accounts to be related back to the primary.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub others: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+    pub relationships: Vec<crate::api::Union<OutputRelationshipsItem>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum OutputRelationshipsItem {
+    #[serde(rename = "app.bsky.graph.defs#relationship")]
+    Relationship(Box<crate::api::app::bsky::graph::defs::Relationship>),
+    #[serde(rename = "app.bsky.graph.defs#notFoundActor")]
+    NotFoundActor(Box<crate::api::app::bsky::graph::defs::NotFoundActor>),
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// the primary actor at-identifier could not be resolved
+    ActorNotFound,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "ActorNotFound" => Some(Self::ActorNotFound),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::ActorNotFound => "ActorNotFound",
+        }
+    }
+}
+
+/// Enumerates public relationships between one account, and a list of other accounts. Does not require auth.
+pub async fn get_relationships(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, EndpointError<Error>> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.actor;
+        request = request.param("actor", value.clone());
+    }
+    for value in params.others.iter().flatten() {
+        request = request.param("others", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,33 @@
+//! `app.bsky.graph.getStarterPack`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.graph.getStarterPack";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// Reference (AT-URI) of the starter pack record.
+    pub starter_pack: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub starter_pack: crate::api::app::bsky::graph::defs::StarterPackView,
+}
+
+/// Gets a view of a starter pack.
+pub async fn get_starter_pack(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.starter_pack;
+        request = request.param("starterPack", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,31 @@
+//! `app.bsky.graph.getStarterPacks`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.graph.getStarterPacks";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub uris: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub starter_packs: Vec<crate::api::app::bsky::graph::defs::StarterPackViewBasic>,
+}
+
+/// Get views for a list of starter packs.
+pub async fn get_starter_packs(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    for value in &params.uris {
+        request = request.param("uris", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
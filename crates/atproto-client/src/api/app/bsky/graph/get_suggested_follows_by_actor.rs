@@ -0,0 +1,38 @@
+//! `app.bsky.graph.getSuggestedFollowsByActor`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.graph.getSuggestedFollowsByActor";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub actor: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    /// If true, response has fallen-back to generic results, and is not scoped using relativeToDid
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_fallback: Option<bool>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rec_id: Option<i64>,
+    pub suggestions: Vec<crate::api::app::bsky::actor::defs::ProfileView>,
+}
+
+/// Enumerates follows similar to a given account (actor). Expected use is to recommend additional accounts immediately after following one account.
+pub async fn get_suggested_follows_by_actor(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.actor;
+        request = request.param("actor", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,32 @@
+//! `app.bsky.graph.list`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.graph.list";
+
+/// Record representing a list of accounts (actors). Scope includes both moderation-oriented lists and curration-oriented lists.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct List {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<crate::lexicon::BlobRef>,
+    pub created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_facets: Option<Vec<crate::api::app::bsky::richtext::facet::Facet>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<crate::api::Union<ListLabelsUnion>>,
+    /// Display name for list; can not be empty.
+    pub name: String,
+    /// Defines the purpose of the list (aka, moderation-oriented or curration-oriented)
+    pub purpose: crate::api::app::bsky::graph::defs::ListPurpose,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ListLabelsUnion {
+    #[serde(rename = "com.atproto.label.defs#selfLabels")]
+    SelfLabels(Box<crate::api::com::atproto::label::defs::SelfLabels>),
+}
@@ -0,0 +1,15 @@
+//! `app.bsky.graph.listblock`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.graph.listblock";
+
+/// Record representing a block relationship against an entire an entire list of accounts (actors).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Listblock {
+    pub created_at: String,
+    /// Reference (AT-URI) to the mod list record.
+    pub subject: String,
+}
@@ -0,0 +1,17 @@
+//! `app.bsky.graph.listitem`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.graph.listitem";
+
+/// Record representing an account's inclusion on a specific list. The AppView will ignore duplicate listitem records.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Listitem {
+    pub created_at: String,
+    /// Reference (AT-URI) to the list record (app.bsky.graph.list).
+    pub list: String,
+    /// The account which is included on the list.
+    pub subject: String,
+}
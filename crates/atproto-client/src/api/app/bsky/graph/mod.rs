@@ -0,0 +1,31 @@
+//! `app.bsky.graph.*`
+
+pub mod block;
+pub mod defs;
+pub mod follow;
+pub mod get_actor_starter_packs;
+pub mod get_blocks;
+pub mod get_followers;
+pub mod get_follows;
+pub mod get_known_followers;
+pub mod get_list;
+pub mod get_list_blocks;
+pub mod get_list_mutes;
+pub mod get_lists;
+pub mod get_mutes;
+pub mod get_relationships;
+pub mod get_starter_pack;
+pub mod get_starter_packs;
+pub mod get_suggested_follows_by_actor;
+pub mod list;
+pub mod listblock;
+pub mod listitem;
+pub mod mute_actor;
+pub mod mute_actor_list;
+pub mod mute_thread;
+pub mod search_starter_packs;
+pub mod starterpack;
+pub mod unmute_actor;
+pub mod unmute_actor_list;
+pub mod unmute_thread;
+pub mod verification;
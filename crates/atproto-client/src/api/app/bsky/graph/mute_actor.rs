@@ -0,0 +1,22 @@
+//! `app.bsky.graph.muteActor`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.graph.muteActor";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    pub actor: String,
+}
+
+/// Creates a mute relationship for the specified account. Mutes are private in Bluesky. Requires auth.
+pub async fn mute_actor(client: &XrpcClient, input: &Input) -> Result<(), XrpcError> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
@@ -0,0 +1,22 @@
+//! `app.bsky.graph.muteActorList`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.graph.muteActorList";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    pub list: String,
+}
+
+/// Creates a mute relationship for the specified list of accounts. Mutes are private in Bluesky. Requires auth.
+pub async fn mute_actor_list(client: &XrpcClient, input: &Input) -> Result<(), XrpcError> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
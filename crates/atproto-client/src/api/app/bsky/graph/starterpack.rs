@@ -0,0 +1,29 @@
+//! `app.bsky.graph.starterpack`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.graph.starterpack";
+
+/// Record defining a starter pack of actors and feeds for new users.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Starterpack {
+    pub created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_facets: Option<Vec<crate::api::app::bsky::richtext::facet::Facet>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feeds: Option<Vec<FeedItem>>,
+    /// Reference (AT-URI) to the list record.
+    pub list: String,
+    /// Display name for starter pack; can not be empty.
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedItem {
+    pub uri: String,
+}
@@ -0,0 +1,64 @@
+//! `app.bsky.labeler.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.labeler.defs";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelerPolicies {
+    /// Label values created by this labeler and scoped exclusively to it. Labels defined here will override global label definitions for this labeler.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_value_definitions:
+        Option<Vec<crate::api::com::atproto::label::defs::LabelValueDefinition>>,
+    /// The label values which this labeler publishes. May include global or custom labels.
+    pub label_values: Vec<crate::api::com::atproto::label::defs::LabelValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelerView {
+    pub cid: String,
+    pub creator: crate::api::app::bsky::actor::defs::ProfileView,
+    pub indexed_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::api::com::atproto::label::defs::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like_count: Option<i64>,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<LabelerViewerState>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelerViewDetailed {
+    pub cid: String,
+    pub creator: crate::api::app::bsky::actor::defs::ProfileView,
+    pub indexed_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::api::com::atproto::label::defs::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like_count: Option<i64>,
+    pub policies: LabelerPolicies,
+    /// The set of report reason 'codes' which are in-scope for this service to review and action. These usually align to policy categories. If not defined (distinct from empty array), all reason types are allowed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason_types: Option<Vec<crate::api::com::atproto::moderation::defs::ReasonType>>,
+    /// Set of record types (collection NSIDs) which can be reported to this service. If not defined (distinct from empty array), default is any record type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_collections: Option<Vec<String>>,
+    /// The set of subject types (account, record, etc) this service accepts reports on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_types: Option<Vec<crate::api::com::atproto::moderation::defs::SubjectType>>,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<LabelerViewerState>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelerViewerState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like: Option<String>,
+}
@@ -0,0 +1,42 @@
+//! `app.bsky.labeler.getServices`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.labeler.getServices";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detailed: Option<bool>,
+    pub dids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub views: Vec<crate::api::Union<OutputViewsItem>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum OutputViewsItem {
+    #[serde(rename = "app.bsky.labeler.defs#labelerView")]
+    LabelerView(Box<crate::api::app::bsky::labeler::defs::LabelerView>),
+    #[serde(rename = "app.bsky.labeler.defs#labelerViewDetailed")]
+    LabelerViewDetailed(Box<crate::api::app::bsky::labeler::defs::LabelerViewDetailed>),
+}
+
+/// Get information about a list of labeler services.
+pub async fn get_services(client: &XrpcClient, params: &Parameters) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.detailed {
+        request = request.param("detailed", value.to_string());
+    }
+    for value in &params.dids {
+        request = request.param("dids", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
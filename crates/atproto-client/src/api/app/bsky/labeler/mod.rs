@@ -0,0 +1,5 @@
+//! `app.bsky.labeler.*`
+
+pub mod defs;
+pub mod get_services;
+pub mod service;
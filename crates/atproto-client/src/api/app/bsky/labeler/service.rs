@@ -0,0 +1,32 @@
+//! `app.bsky.labeler.service`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.labeler.service";
+
+/// A declaration of the existence of labeler service.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Service {
+    pub created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<crate::api::Union<ServiceLabelsUnion>>,
+    pub policies: crate::api::app::bsky::labeler::defs::LabelerPolicies,
+    /// The set of report reason 'codes' which are in-scope for this service to review and action. These usually align to policy categories. If not defined (distinct from empty array), all reason types are allowed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason_types: Option<Vec<crate::api::com::atproto::moderation::defs::ReasonType>>,
+    /// Set of record types (collection NSIDs) which can be reported to this service. If not defined (distinct from empty array), default is any record type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_collections: Option<Vec<String>>,
+    /// The set of subject types (account, record, etc) this service accepts reports on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_types: Option<Vec<crate::api::com::atproto::moderation::defs::SubjectType>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ServiceLabelsUnion {
+    #[serde(rename = "com.atproto.label.defs#selfLabels")]
+    SelfLabels(Box<crate::api::com::atproto::label::defs::SelfLabels>),
+}
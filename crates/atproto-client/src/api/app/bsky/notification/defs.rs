@@ -0,0 +1,57 @@
+//! `app.bsky.notification.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.notification.defs";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivitySubscription {
+    pub post: bool,
+    pub reply: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatPreference {
+    pub include: String,
+    pub push: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilterablePreference {
+    pub include: String,
+    pub list: bool,
+    pub push: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Preference {
+    pub list: bool,
+    pub push: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Preferences {
+    pub chat: ChatPreference,
+    pub follow: FilterablePreference,
+    pub like: FilterablePreference,
+    pub like_via_repost: FilterablePreference,
+    pub mention: FilterablePreference,
+    pub quote: FilterablePreference,
+    pub reply: FilterablePreference,
+    pub repost: FilterablePreference,
+    pub repost_via_repost: FilterablePreference,
+    pub starterpack_joined: Preference,
+    pub subscribed_post: Preference,
+    pub unverified: Preference,
+    pub verified: Preference,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordDeleted {}
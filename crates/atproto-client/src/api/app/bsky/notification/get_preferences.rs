@@ -0,0 +1,19 @@
+//! `app.bsky.notification.getPreferences`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.notification.getPreferences";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub preferences: crate::api::app::bsky::notification::defs::Preferences,
+}
+
+/// Get notification-related preferences for an account. Requires auth.
+pub async fn get_preferences(client: &XrpcClient) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::query(NSID);
+    Ok(client.query(request).await?.data)
+}
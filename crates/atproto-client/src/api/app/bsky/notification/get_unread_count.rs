@@ -0,0 +1,37 @@
+//! `app.bsky.notification.getUnreadCount`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.notification.getUnreadCount";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seen_at: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub count: i64,
+}
+
+/// Count the number of unread notifications for the requesting account. Requires auth.
+pub async fn get_unread_count(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.priority {
+        request = request.param("priority", value.to_string());
+    }
+    if let Some(value) = &params.seen_at {
+        request = request.param("seenAt", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,11 @@
+//! `app.bsky.notification.*`
+
+pub mod defs;
+pub mod get_preferences;
+pub mod get_unread_count;
+pub mod list_notifications;
+pub mod put_preferences;
+pub mod put_preferences_v2;
+pub mod register_push;
+pub mod unregister_push;
+pub mod update_seen;
@@ -0,0 +1,52 @@
+//! `app.bsky.notification.putPreferencesV2`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.notification.putPreferencesV2";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chat: Option<crate::api::app::bsky::notification::defs::ChatPreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub follow: Option<crate::api::app::bsky::notification::defs::FilterablePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like: Option<crate::api::app::bsky::notification::defs::FilterablePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub like_via_repost: Option<crate::api::app::bsky::notification::defs::FilterablePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mention: Option<crate::api::app::bsky::notification::defs::FilterablePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quote: Option<crate::api::app::bsky::notification::defs::FilterablePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply: Option<crate::api::app::bsky::notification::defs::FilterablePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repost: Option<crate::api::app::bsky::notification::defs::FilterablePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repost_via_repost: Option<crate::api::app::bsky::notification::defs::FilterablePreference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starterpack_joined: Option<crate::api::app::bsky::notification::defs::Preference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribed_post: Option<crate::api::app::bsky::notification::defs::Preference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unverified: Option<crate::api::app::bsky::notification::defs::Preference>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verified: Option<crate::api::app::bsky::notification::defs::Preference>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub preferences: crate::api::app::bsky::notification::defs::Preferences,
+}
+
+/// Set notification-related preferences for an account. Requires auth.
+pub async fn put_preferences_v2(client: &XrpcClient, input: &Input) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    Ok(client.procedure(request).await?.data)
+}
@@ -0,0 +1,28 @@
+//! `app.bsky.notification.registerPush`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.notification.registerPush";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    /// Set to true when the actor is age restricted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub age_restricted: Option<bool>,
+    pub app_id: String,
+    pub platform: String,
+    pub service_did: String,
+    pub token: String,
+}
+
+/// Register to receive push notifications, via a specified service, for the requesting account. Requires auth.
+pub async fn register_push(client: &XrpcClient, input: &Input) -> Result<(), XrpcError> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
@@ -0,0 +1,54 @@
+//! `app.bsky.richtext.facet`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.richtext.facet";
+
+/// Annotation of a sub-string within rich text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Facet {
+    pub features: Vec<crate::api::Union<FacetFeaturesItem>>,
+    pub index: ByteSlice,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum FacetFeaturesItem {
+    #[serde(rename = "app.bsky.richtext.facet#mention")]
+    Mention(Box<Mention>),
+    #[serde(rename = "app.bsky.richtext.facet#link")]
+    Link(Box<Link>),
+    #[serde(rename = "app.bsky.richtext.facet#tag")]
+    Tag(Box<Tag>),
+}
+
+/// Specifies the sub-string range a facet feature applies to. Start index is inclusive, end index is exclusive. Indices are zero-indexed, counting bytes of the UTF-8 encoded text. NOTE: some languages, like Javascript, use UTF-16 or Unicode codepoints for string slice indexing; in these languages, convert to byte arrays before working with facets.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ByteSlice {
+    pub byte_end: i64,
+    pub byte_start: i64,
+}
+
+/// Facet feature for a URL. The text URL may have been simplified or truncated, but the facet reference should be a complete URL.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Link {
+    pub uri: String,
+}
+
+/// Facet feature for mention of another account. The text is usually a handle, including a '@' prefix, but the facet reference is a DID.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Mention {
+    pub did: String,
+}
+
+/// Facet feature for a hashtag. The text usually includes a '#' prefix, but the facet reference should not (except in the case of 'double hash tags').
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tag {
+    pub tag: String,
+}
@@ -0,0 +1,3 @@
+//! `app.bsky.richtext.*`
+
+pub mod facet;
@@ -0,0 +1,29 @@
+//! `app.bsky.unspecced.getConfig`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.unspecced.getConfig";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub check_email_confirmed: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub live_now: Option<Vec<LiveNowConfig>>,
+}
+
+/// Get miscellaneous runtime configuration.
+pub async fn get_config(client: &XrpcClient) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::query(NSID);
+    Ok(client.query(request).await?.data)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveNowConfig {
+    pub did: String,
+    pub domains: Vec<String>,
+}
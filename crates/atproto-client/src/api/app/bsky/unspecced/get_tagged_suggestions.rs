@@ -0,0 +1,27 @@
+//! `app.bsky.unspecced.getTaggedSuggestions`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.unspecced.getTaggedSuggestions";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub suggestions: Vec<Suggestion>,
+}
+
+/// Get a list of suggestions (feeds and users) tagged with categories
+pub async fn get_tagged_suggestions(client: &XrpcClient) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::query(NSID);
+    Ok(client.query(request).await?.data)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Suggestion {
+    pub subject: String,
+    pub subject_type: String,
+    pub tag: String,
+}
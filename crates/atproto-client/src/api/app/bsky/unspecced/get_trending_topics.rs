@@ -0,0 +1,39 @@
+//! `app.bsky.unspecced.getTrendingTopics`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.unspecced.getTrendingTopics";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// DID of the account making the request (not included for public/unauthenticated queries). Used to boost followed accounts in ranking.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub suggested: Vec<crate::api::app::bsky::unspecced::defs::TrendingTopic>,
+    pub topics: Vec<crate::api::app::bsky::unspecced::defs::TrendingTopic>,
+}
+
+/// Get a list of trending topics
+pub async fn get_trending_topics(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.limit {
+        request = request.param("limit", value.to_string());
+    }
+    if let Some(value) = &params.viewer {
+        request = request.param("viewer", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,10 @@
+//! `app.bsky.unspecced.*`
+
+pub mod defs;
+pub mod get_config;
+pub mod get_popular_feed_generators;
+pub mod get_tagged_suggestions;
+pub mod get_trending_topics;
+pub mod get_trends;
+pub mod search_actors_skeleton;
+pub mod search_posts_skeleton;
@@ -0,0 +1,82 @@
+//! `app.bsky.unspecced.searchActorsSkeleton`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.unspecced.searchActorsSkeleton";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// Optional pagination mechanism; may not necessarily allow scrolling through entire result set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Search query string; syntax, phrase, boolean, and faceting is unspecified, but Lucene query syntax is recommended. For typeahead search, only simple term match is supported, not full syntax.
+    pub q: String,
+    /// If true, acts as fast/simple 'typeahead' query.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub typeahead: Option<bool>,
+    /// DID of the account making the request (not included for public/unauthenticated queries). Used to boost followed accounts in ranking.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub actors: Vec<crate::api::app::bsky::unspecced::defs::SkeletonSearchActor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Count of search hits. Optional, may be rounded/truncated, and may not be possible to paginate through all hits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hits_total: Option<i64>,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    BadQueryString,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "BadQueryString" => Some(Self::BadQueryString),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::BadQueryString => "BadQueryString",
+        }
+    }
+}
+
+/// Backend Actors (profile) search, returns only skeleton.
+pub async fn search_actors_skeleton(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, EndpointError<Error>> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.cursor {
+        request = request.param("cursor", value.clone());
+    }
+    if let Some(value) = &params.limit {
+        request = request.param("limit", value.to_string());
+    }
+    {
+        let value = &params.q;
+        request = request.param("q", value.clone());
+    }
+    if let Some(value) = &params.typeahead {
+        request = request.param("typeahead", value.to_string());
+    }
+    if let Some(value) = &params.viewer {
+        request = request.param("viewer", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,130 @@
+//! `app.bsky.unspecced.searchPostsSkeleton`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.unspecced.searchPostsSkeleton";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// Filter to posts by the given account. Handles are resolved to DID before query-time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// Optional pagination mechanism; may not necessarily allow scrolling through entire result set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Filter to posts with URLs (facet links or embeds) linking to the given domain (hostname). Server may apply hostname normalization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// Filter to posts in the given language. Expected to be based on post language field, though server may override language detection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// Filter to posts which mention the given account. Handles are resolved to DID before query-time. Only matches rich-text facet mentions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mentions: Option<String>,
+    /// Search query string; syntax, phrase, boolean, and faceting is unspecified, but Lucene query syntax is recommended.
+    pub q: String,
+    /// Filter results for posts after the indicated datetime (inclusive). Expected to use 'sortAt' timestamp, which may not match 'createdAt'. Can be a datetime, or just an ISO date (YYYY-MM-DD).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+    /// Specifies the ranking order of results.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    /// Filter to posts with the given tag (hashtag), based on rich-text facet or tag field. Do not include the hash (#) prefix. Multiple tags can be specified, with 'AND' matching.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<Vec<String>>,
+    /// Filter results for posts before the indicated datetime (not inclusive). Expected to use 'sortAt' timestamp, which may not match 'createdAt'. Can be a datetime, or just an ISO date (YYY-MM-DD).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+    /// Filter to posts with links (facet links or embeds) pointing to this URL. Server may apply URL normalization or fuzzy matching.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// DID of the account making the request (not included for public/unauthenticated queries). Used for 'from:me' queries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Count of search hits. Optional, may be rounded/truncated, and may not be possible to paginate through all hits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hits_total: Option<i64>,
+    pub posts: Vec<crate::api::app::bsky::unspecced::defs::SkeletonSearchPost>,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    BadQueryString,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "BadQueryString" => Some(Self::BadQueryString),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::BadQueryString => "BadQueryString",
+        }
+    }
+}
+
+/// Backend Posts search, returns only skeleton
+pub async fn search_posts_skeleton(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, EndpointError<Error>> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.author {
+        request = request.param("author", value.clone());
+    }
+    if let Some(value) = &params.cursor {
+        request = request.param("cursor", value.clone());
+    }
+    if let Some(value) = &params.domain {
+        request = request.param("domain", value.clone());
+    }
+    if let Some(value) = &params.lang {
+        request = request.param("lang", value.clone());
+    }
+    if let Some(value) = &params.limit {
+        request = request.param("limit", value.to_string());
+    }
+    if let Some(value) = &params.mentions {
+        request = request.param("mentions", value.clone());
+    }
+    {
+        let value = &params.q;
+        request = request.param("q", value.clone());
+    }
+    if let Some(value) = &params.since {
+        request = request.param("since", value.clone());
+    }
+    if let Some(value) = &params.sort {
+        request = request.param("sort", value.clone());
+    }
+    for value in params.tag.iter().flatten() {
+        request = request.param("tag", value.clone());
+    }
+    if let Some(value) = &params.until {
+        request = request.param("until", value.clone());
+    }
+    if let Some(value) = &params.url {
+        request = request.param("url", value.clone());
+    }
+    if let Some(value) = &params.viewer {
+        request = request.param("viewer", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,29 @@
+//! `app.bsky.video.getJobStatus`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.video.getJobStatus";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub job_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub job_status: crate::api::app::bsky::video::defs::JobStatus,
+}
+
+/// Get status details for a video processing job.
+pub async fn get_job_status(client: &XrpcClient, params: &Parameters) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.job_id;
+        request = request.param("jobId", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,6 @@
+//! `app.bsky.video.*`
+
+pub mod defs;
+pub mod get_job_status;
+pub mod get_upload_limits;
+pub mod upload_video;
@@ -0,0 +1,23 @@
+//! `app.bsky.video.uploadVideo`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "app.bsky.video.uploadVideo";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub job_status: crate::api::app::bsky::video::defs::JobStatus,
+}
+
+/// Upload a video to be processed then stored on the PDS.
+pub async fn upload_video(
+    client: &XrpcClient,
+    body: Vec<u8>,
+    encoding: &str,
+) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::procedure(NSID).body(body).encoding(encoding);
+    Ok(client.procedure(request).await?.data)
+}
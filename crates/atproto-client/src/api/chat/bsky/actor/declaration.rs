@@ -0,0 +1,13 @@
+//! `chat.bsky.actor.declaration`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "chat.bsky.actor.declaration";
+
+/// A declaration of a Bluesky chat account.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Declaration {
+    pub allow_incoming: String,
+}
@@ -0,0 +1,28 @@
+//! `chat.bsky.actor.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "chat.bsky.actor.defs";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileViewBasic {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub associated: Option<crate::api::app::bsky::actor::defs::ProfileAssociated>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    /// Set to true when the actor cannot actively participate in conversations
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chat_disabled: Option<bool>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    pub handle: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::api::com::atproto::label::defs::Label>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<crate::api::app::bsky::actor::defs::VerificationState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<crate::api::app::bsky::actor::defs::ViewerState>,
+}
@@ -0,0 +1,16 @@
+//! `chat.bsky.actor.deleteAccount`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "chat.bsky.actor.deleteAccount";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {}
+
+pub async fn delete_account(client: &XrpcClient) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::procedure(NSID);
+    Ok(client.procedure(request).await?.data)
+}
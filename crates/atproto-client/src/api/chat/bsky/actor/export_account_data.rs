@@ -0,0 +1,4 @@
+//! `chat.bsky.actor.exportAccountData`
+
+/// NSID of this lexicon
+pub const NSID: &str = "chat.bsky.actor.exportAccountData";
@@ -0,0 +1,6 @@
+//! `chat.bsky.actor.*`
+
+pub mod declaration;
+pub mod defs;
+pub mod delete_account;
+pub mod export_account_data;
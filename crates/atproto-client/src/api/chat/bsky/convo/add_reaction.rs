@@ -0,0 +1,62 @@
+//! `chat.bsky.convo.addReaction`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcError, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "chat.bsky.convo.addReaction";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    pub convo_id: String,
+    pub message_id: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub message: crate::api::chat::bsky::convo::defs::MessageView,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// Indicates that the message has been deleted and reactions can no longer be added/removed.
+    ReactionMessageDeleted,
+    /// Indicates that the message has the maximum number of reactions allowed for a single user, and the requested reaction wasn't yet present. If it was already present, the request will not fail since it is idempotent.
+    ReactionLimitReached,
+    /// Indicates the value for the reaction is not acceptable. In general, this means it is not an emoji.
+    ReactionInvalidValue,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "ReactionMessageDeleted" => Some(Self::ReactionMessageDeleted),
+            "ReactionLimitReached" => Some(Self::ReactionLimitReached),
+            "ReactionInvalidValue" => Some(Self::ReactionInvalidValue),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::ReactionMessageDeleted => "ReactionMessageDeleted",
+            Self::ReactionLimitReached => "ReactionLimitReached",
+            Self::ReactionInvalidValue => "ReactionInvalidValue",
+        }
+    }
+}
+
+/// Adds an emoji reaction to a message. Requires authentication. It is idempotent, so multiple calls from the same user with the same emoji result in a single reaction.
+pub async fn add_reaction(
+    client: &XrpcClient,
+    input: &Input,
+) -> Result<Output, EndpointError<Error>> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    Ok(client.procedure(request).await?.data)
+}
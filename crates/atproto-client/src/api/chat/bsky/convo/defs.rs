@@ -0,0 +1,247 @@
+//! `chat.bsky.convo.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "chat.bsky.convo.defs";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvoView {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_message: Option<crate::api::Union<ConvoViewLastMessageUnion>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_reaction: Option<crate::api::Union<ConvoViewLastReactionUnion>>,
+    pub members: Vec<crate::api::chat::bsky::actor::defs::ProfileViewBasic>,
+    pub muted: bool,
+    pub rev: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    pub unread_count: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ConvoViewLastMessageUnion {
+    #[serde(rename = "chat.bsky.convo.defs#messageView")]
+    MessageView(Box<MessageView>),
+    #[serde(rename = "chat.bsky.convo.defs#deletedMessageView")]
+    DeletedMessageView(Box<DeletedMessageView>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ConvoViewLastReactionUnion {
+    #[serde(rename = "chat.bsky.convo.defs#messageAndReactionView")]
+    MessageAndReactionView(Box<MessageAndReactionView>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletedMessageView {
+    pub id: String,
+    pub rev: String,
+    pub sender: MessageViewSender,
+    pub sent_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogAcceptConvo {
+    pub convo_id: String,
+    pub rev: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogAddReaction {
+    pub convo_id: String,
+    pub message: crate::api::Union<LogAddReactionMessageUnion>,
+    pub reaction: ReactionView,
+    pub rev: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum LogAddReactionMessageUnion {
+    #[serde(rename = "chat.bsky.convo.defs#messageView")]
+    MessageView(Box<MessageView>),
+    #[serde(rename = "chat.bsky.convo.defs#deletedMessageView")]
+    DeletedMessageView(Box<DeletedMessageView>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogBeginConvo {
+    pub convo_id: String,
+    pub rev: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogCreateMessage {
+    pub convo_id: String,
+    pub message: crate::api::Union<LogCreateMessageMessageUnion>,
+    pub rev: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum LogCreateMessageMessageUnion {
+    #[serde(rename = "chat.bsky.convo.defs#messageView")]
+    MessageView(Box<MessageView>),
+    #[serde(rename = "chat.bsky.convo.defs#deletedMessageView")]
+    DeletedMessageView(Box<DeletedMessageView>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogDeleteMessage {
+    pub convo_id: String,
+    pub message: crate::api::Union<LogDeleteMessageMessageUnion>,
+    pub rev: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum LogDeleteMessageMessageUnion {
+    #[serde(rename = "chat.bsky.convo.defs#messageView")]
+    MessageView(Box<MessageView>),
+    #[serde(rename = "chat.bsky.convo.defs#deletedMessageView")]
+    DeletedMessageView(Box<DeletedMessageView>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogLeaveConvo {
+    pub convo_id: String,
+    pub rev: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogMuteConvo {
+    pub convo_id: String,
+    pub rev: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogReadMessage {
+    pub convo_id: String,
+    pub message: crate::api::Union<LogReadMessageMessageUnion>,
+    pub rev: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum LogReadMessageMessageUnion {
+    #[serde(rename = "chat.bsky.convo.defs#messageView")]
+    MessageView(Box<MessageView>),
+    #[serde(rename = "chat.bsky.convo.defs#deletedMessageView")]
+    DeletedMessageView(Box<DeletedMessageView>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogRemoveReaction {
+    pub convo_id: String,
+    pub message: crate::api::Union<LogRemoveReactionMessageUnion>,
+    pub reaction: ReactionView,
+    pub rev: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum LogRemoveReactionMessageUnion {
+    #[serde(rename = "chat.bsky.convo.defs#messageView")]
+    MessageView(Box<MessageView>),
+    #[serde(rename = "chat.bsky.convo.defs#deletedMessageView")]
+    DeletedMessageView(Box<DeletedMessageView>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogUnmuteConvo {
+    pub convo_id: String,
+    pub rev: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageAndReactionView {
+    pub message: MessageView,
+    pub reaction: ReactionView,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageInput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed: Option<crate::api::Union<MessageInputEmbedUnion>>,
+    /// Annotations of text (mentions, URLs, hashtags, etc)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<crate::api::app::bsky::richtext::facet::Facet>>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum MessageInputEmbedUnion {
+    #[serde(rename = "app.bsky.embed.record", alias = "app.bsky.embed.record#main")]
+    Record(Box<crate::api::app::bsky::embed::record::Record>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageRef {
+    pub convo_id: String,
+    pub did: String,
+    pub message_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed: Option<crate::api::Union<MessageViewEmbedUnion>>,
+    /// Annotations of text (mentions, URLs, hashtags, etc)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<crate::api::app::bsky::richtext::facet::Facet>>,
+    pub id: String,
+    /// Reactions to this message, in ascending order of creation time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Vec<ReactionView>>,
+    pub rev: String,
+    pub sender: MessageViewSender,
+    pub sent_at: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum MessageViewEmbedUnion {
+    #[serde(rename = "app.bsky.embed.record#view")]
+    View(Box<crate::api::app::bsky::embed::record::View>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageViewSender {
+    pub did: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionView {
+    pub created_at: String,
+    pub sender: ReactionViewSender,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionViewSender {
+    pub did: String,
+}
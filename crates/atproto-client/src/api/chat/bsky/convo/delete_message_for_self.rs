@@ -0,0 +1,26 @@
+//! `chat.bsky.convo.deleteMessageForSelf`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "chat.bsky.convo.deleteMessageForSelf";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    pub convo_id: String,
+    pub message_id: String,
+}
+
+pub type Output = crate::api::chat::bsky::convo::defs::DeletedMessageView;
+
+pub async fn delete_message_for_self(
+    client: &XrpcClient,
+    input: &Input,
+) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    Ok(client.procedure(request).await?.data)
+}
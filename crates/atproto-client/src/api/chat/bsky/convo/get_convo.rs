@@ -0,0 +1,28 @@
+//! `chat.bsky.convo.getConvo`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "chat.bsky.convo.getConvo";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub convo_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub convo: crate::api::chat::bsky::convo::defs::ConvoView,
+}
+
+pub async fn get_convo(client: &XrpcClient, params: &Parameters) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.convo_id;
+        request = request.param("convoId", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,33 @@
+//! `chat.bsky.convo.getConvoAvailability`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "chat.bsky.convo.getConvoAvailability";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub members: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub can_chat: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub convo: Option<crate::api::chat::bsky::convo::defs::ConvoView>,
+}
+
+/// Get whether the requester and the other members can chat. If an existing convo is found for these members, it is returned.
+pub async fn get_convo_availability(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    for value in &params.members {
+        request = request.param("members", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,30 @@
+//! `chat.bsky.convo.getConvoForMembers`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "chat.bsky.convo.getConvoForMembers";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub members: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub convo: crate::api::chat::bsky::convo::defs::ConvoView,
+}
+
+pub async fn get_convo_for_members(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    for value in &params.members {
+        request = request.param("members", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
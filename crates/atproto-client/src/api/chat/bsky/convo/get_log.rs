@@ -0,0 +1,55 @@
+//! `chat.bsky.convo.getLog`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "chat.bsky.convo.getLog";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub logs: Vec<crate::api::Union<OutputLogsItem>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum OutputLogsItem {
+    #[serde(rename = "chat.bsky.convo.defs#logBeginConvo")]
+    LogBeginConvo(Box<crate::api::chat::bsky::convo::defs::LogBeginConvo>),
+    #[serde(rename = "chat.bsky.convo.defs#logAcceptConvo")]
+    LogAcceptConvo(Box<crate::api::chat::bsky::convo::defs::LogAcceptConvo>),
+    #[serde(rename = "chat.bsky.convo.defs#logLeaveConvo")]
+    LogLeaveConvo(Box<crate::api::chat::bsky::convo::defs::LogLeaveConvo>),
+    #[serde(rename = "chat.bsky.convo.defs#logMuteConvo")]
+    LogMuteConvo(Box<crate::api::chat::bsky::convo::defs::LogMuteConvo>),
+    #[serde(rename = "chat.bsky.convo.defs#logUnmuteConvo")]
+    LogUnmuteConvo(Box<crate::api::chat::bsky::convo::defs::LogUnmuteConvo>),
+    #[serde(rename = "chat.bsky.convo.defs#logCreateMessage")]
+    LogCreateMessage(Box<crate::api::chat::bsky::convo::defs::LogCreateMessage>),
+    #[serde(rename = "chat.bsky.convo.defs#logDeleteMessage")]
+    LogDeleteMessage(Box<crate::api::chat::bsky::convo::defs::LogDeleteMessage>),
+    #[serde(rename = "chat.bsky.convo.defs#logReadMessage")]
+    LogReadMessage(Box<crate::api::chat::bsky::convo::defs::LogReadMessage>),
+    #[serde(rename = "chat.bsky.convo.defs#logAddReaction")]
+    LogAddReaction(Box<crate::api::chat::bsky::convo::defs::LogAddReaction>),
+    #[serde(rename = "chat.bsky.convo.defs#logRemoveReaction")]
+    LogRemoveReaction(Box<crate::api::chat::bsky::convo::defs::LogRemoveReaction>),
+}
+
+pub async fn get_log(client: &XrpcClient, params: &Parameters) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.cursor {
+        request = request.param("cursor", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,20 @@
+//! `chat.bsky.convo.*`
+
+pub mod accept_convo;
+pub mod add_reaction;
+pub mod defs;
+pub mod delete_message_for_self;
+pub mod get_convo;
+pub mod get_convo_availability;
+pub mod get_convo_for_members;
+pub mod get_log;
+pub mod get_messages;
+pub mod leave_convo;
+pub mod list_convos;
+pub mod mute_convo;
+pub mod remove_reaction;
+pub mod send_message;
+pub mod send_message_batch;
+pub mod unmute_convo;
+pub mod update_all_read;
+pub mod update_read;
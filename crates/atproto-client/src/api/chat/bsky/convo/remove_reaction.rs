@@ -0,0 +1,58 @@
+//! `chat.bsky.convo.removeReaction`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcError, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "chat.bsky.convo.removeReaction";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    pub convo_id: String,
+    pub message_id: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub message: crate::api::chat::bsky::convo::defs::MessageView,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// Indicates that the message has been deleted and reactions can no longer be added/removed.
+    ReactionMessageDeleted,
+    /// Indicates the value for the reaction is not acceptable. In general, this means it is not an emoji.
+    ReactionInvalidValue,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "ReactionMessageDeleted" => Some(Self::ReactionMessageDeleted),
+            "ReactionInvalidValue" => Some(Self::ReactionInvalidValue),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::ReactionMessageDeleted => "ReactionMessageDeleted",
+            Self::ReactionInvalidValue => "ReactionInvalidValue",
+        }
+    }
+}
+
+/// Removes an emoji reaction from a message. Requires authentication. It is idempotent, so multiple calls from the same user with the same emoji result in that reaction not being present, even if it already wasn't.
+pub async fn remove_reaction(
+    client: &XrpcClient,
+    input: &Input,
+) -> Result<Output, EndpointError<Error>> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    Ok(client.procedure(request).await?.data)
+}
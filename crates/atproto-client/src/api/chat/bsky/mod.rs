@@ -0,0 +1,5 @@
+//! `chat.bsky.*`
+
+pub mod actor;
+pub mod convo;
+pub mod moderation;
@@ -0,0 +1,56 @@
+//! `chat.bsky.moderation.getMessageContext`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "chat.bsky.moderation.getMessageContext";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<i64>,
+    /// Conversation that the message is from. NOTE: this field will eventually be required.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub convo_id: Option<String>,
+    pub message_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub messages: Vec<crate::api::Union<OutputMessagesItem>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum OutputMessagesItem {
+    #[serde(rename = "chat.bsky.convo.defs#messageView")]
+    MessageView(Box<crate::api::chat::bsky::convo::defs::MessageView>),
+    #[serde(rename = "chat.bsky.convo.defs#deletedMessageView")]
+    DeletedMessageView(Box<crate::api::chat::bsky::convo::defs::DeletedMessageView>),
+}
+
+pub async fn get_message_context(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.after {
+        request = request.param("after", value.to_string());
+    }
+    if let Some(value) = &params.before {
+        request = request.param("before", value.to_string());
+    }
+    if let Some(value) = &params.convo_id {
+        request = request.param("convoId", value.clone());
+    }
+    {
+        let value = &params.message_id;
+        request = request.param("messageId", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,5 @@
+//! `chat.bsky.moderation.*`
+
+pub mod get_actor_metadata;
+pub mod get_message_context;
+pub mod update_actor_access;
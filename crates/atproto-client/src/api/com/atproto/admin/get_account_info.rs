@@ -0,0 +1,28 @@
+//! `com.atproto.admin.getAccountInfo`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.admin.getAccountInfo";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub did: String,
+}
+
+pub type Output = crate::api::com::atproto::admin::defs::AccountView;
+
+/// Get details about an account.
+pub async fn get_account_info(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.did;
+        request = request.param("did", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,31 @@
+//! `com.atproto.admin.getAccountInfos`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.admin.getAccountInfos";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub dids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub infos: Vec<crate::api::com::atproto::admin::defs::AccountView>,
+}
+
+/// Get details about some accounts.
+pub async fn get_account_infos(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    for value in &params.dids {
+        request = request.param("dids", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
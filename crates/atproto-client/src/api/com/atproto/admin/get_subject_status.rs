@@ -0,0 +1,60 @@
+//! `com.atproto.admin.getSubjectStatus`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.admin.getSubjectStatus";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blob: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub did: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deactivated: Option<crate::api::com::atproto::admin::defs::StatusAttr>,
+    pub subject: crate::api::Union<OutputSubjectUnion>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub takedown: Option<crate::api::com::atproto::admin::defs::StatusAttr>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum OutputSubjectUnion {
+    #[serde(rename = "com.atproto.admin.defs#repoRef")]
+    RepoRef(Box<crate::api::com::atproto::admin::defs::RepoRef>),
+    #[serde(
+        rename = "com.atproto.repo.strongRef",
+        alias = "com.atproto.repo.strongRef#main"
+    )]
+    StrongRef(Box<crate::api::com::atproto::repo::strong_ref::StrongRef>),
+    #[serde(rename = "com.atproto.admin.defs#repoBlobRef")]
+    RepoBlobRef(Box<crate::api::com::atproto::admin::defs::RepoBlobRef>),
+}
+
+/// Get the service-specific admin status of a subject (account, record, or blob).
+pub async fn get_subject_status(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.blob {
+        request = request.param("blob", value.clone());
+    }
+    if let Some(value) = &params.did {
+        request = request.param("did", value.clone());
+    }
+    if let Some(value) = &params.uri {
+        request = request.param("uri", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
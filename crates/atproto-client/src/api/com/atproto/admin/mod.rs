@@ -0,0 +1,18 @@
+//! `com.atproto.admin.*`
+
+pub mod defs;
+pub mod delete_account;
+pub mod disable_account_invites;
+pub mod disable_invite_codes;
+pub mod enable_account_invites;
+pub mod get_account_info;
+pub mod get_account_infos;
+pub mod get_invite_codes;
+pub mod get_subject_status;
+pub mod search_accounts;
+pub mod send_email;
+pub mod update_account_email;
+pub mod update_account_handle;
+pub mod update_account_password;
+pub mod update_account_signing_key;
+pub mod update_subject_status;
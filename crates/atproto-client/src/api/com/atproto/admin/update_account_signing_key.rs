@@ -0,0 +1,27 @@
+//! `com.atproto.admin.updateAccountSigningKey`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.admin.updateAccountSigningKey";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    pub did: String,
+    /// Did-key formatted public key
+    pub signing_key: String,
+}
+
+/// Administrative action to update an account's signing key in their Did document.
+pub async fn update_account_signing_key(
+    client: &XrpcClient,
+    input: &Input,
+) -> Result<(), XrpcError> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
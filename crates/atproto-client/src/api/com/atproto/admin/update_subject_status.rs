@@ -0,0 +1,64 @@
+//! `com.atproto.admin.updateSubjectStatus`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.admin.updateSubjectStatus";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deactivated: Option<crate::api::com::atproto::admin::defs::StatusAttr>,
+    pub subject: crate::api::Union<InputSubjectUnion>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub takedown: Option<crate::api::com::atproto::admin::defs::StatusAttr>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum InputSubjectUnion {
+    #[serde(rename = "com.atproto.admin.defs#repoRef")]
+    RepoRef(Box<crate::api::com::atproto::admin::defs::RepoRef>),
+    #[serde(
+        rename = "com.atproto.repo.strongRef",
+        alias = "com.atproto.repo.strongRef#main"
+    )]
+    StrongRef(Box<crate::api::com::atproto::repo::strong_ref::StrongRef>),
+    #[serde(rename = "com.atproto.admin.defs#repoBlobRef")]
+    RepoBlobRef(Box<crate::api::com::atproto::admin::defs::RepoBlobRef>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub subject: crate::api::Union<OutputSubjectUnion>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub takedown: Option<crate::api::com::atproto::admin::defs::StatusAttr>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum OutputSubjectUnion {
+    #[serde(rename = "com.atproto.admin.defs#repoRef")]
+    RepoRef(Box<crate::api::com::atproto::admin::defs::RepoRef>),
+    #[serde(
+        rename = "com.atproto.repo.strongRef",
+        alias = "com.atproto.repo.strongRef#main"
+    )]
+    StrongRef(Box<crate::api::com::atproto::repo::strong_ref::StrongRef>),
+    #[serde(rename = "com.atproto.admin.defs#repoBlobRef")]
+    RepoBlobRef(Box<crate::api::com::atproto::admin::defs::RepoBlobRef>),
+}
+
+/// Update the service-specific admin status of a subject (account, record, or blob).
+pub async fn update_subject_status(
+    client: &XrpcClient,
+    input: &Input,
+) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    Ok(client.procedure(request).await?.data)
+}
@@ -0,0 +1,16 @@
+//! `com.atproto.identity.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.identity.defs";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityInfo {
+    pub did: String,
+    /// The complete DID document for the identity.
+    pub did_doc: serde_json::Value,
+    /// The validated handle of the account; or 'handle.invalid' if the handle did not bi-directionally match the DID document.
+    pub handle: String,
+}
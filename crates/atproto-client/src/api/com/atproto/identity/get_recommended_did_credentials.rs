@@ -0,0 +1,27 @@
+//! `com.atproto.identity.getRecommendedDidCredentials`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.identity.getRecommendedDidCredentials";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub also_known_as: Option<Vec<String>>,
+    /// Recommended rotation keys for PLC dids. Should be undefined (or ignored) for did:webs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotation_keys: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub services: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification_methods: Option<serde_json::Value>,
+}
+
+/// Describe the credentials that should be included in the DID doc of an account that is migrating to this service.
+pub async fn get_recommended_did_credentials(client: &XrpcClient) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::query(NSID);
+    Ok(client.query(request).await?.data)
+}
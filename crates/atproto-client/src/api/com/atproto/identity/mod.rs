@@ -0,0 +1,12 @@
+//! `com.atproto.identity.*`
+
+pub mod defs;
+pub mod get_recommended_did_credentials;
+pub mod refresh_identity;
+pub mod request_plc_operation_signature;
+pub mod resolve_did;
+pub mod resolve_handle;
+pub mod resolve_identity;
+pub mod sign_plc_operation;
+pub mod submit_plc_operation;
+pub mod update_handle;
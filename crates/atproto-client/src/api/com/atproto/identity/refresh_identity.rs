@@ -0,0 +1,56 @@
+//! `com.atproto.identity.refreshIdentity`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcError, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.identity.refreshIdentity";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    pub identifier: String,
+}
+
+pub type Output = crate::api::com::atproto::identity::defs::IdentityInfo;
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// The resolution process confirmed that the handle does not resolve to any DID.
+    HandleNotFound,
+    /// The DID resolution process confirmed that there is no current DID.
+    DidNotFound,
+    /// The DID previously existed, but has been deactivated.
+    DidDeactivated,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "HandleNotFound" => Some(Self::HandleNotFound),
+            "DidNotFound" => Some(Self::DidNotFound),
+            "DidDeactivated" => Some(Self::DidDeactivated),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::HandleNotFound => "HandleNotFound",
+            Self::DidNotFound => "DidNotFound",
+            Self::DidDeactivated => "DidDeactivated",
+        }
+    }
+}
+
+/// Request that the server re-resolve an identity (DID and handle). The server may ignore this request, or require authentication, depending on the role, implementation, and policy of the server.
+pub async fn refresh_identity(
+    client: &XrpcClient,
+    input: &Input,
+) -> Result<Output, EndpointError<Error>> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    Ok(client.procedure(request).await?.data)
+}
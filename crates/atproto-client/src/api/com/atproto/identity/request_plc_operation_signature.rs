@@ -0,0 +1,13 @@
+//! `com.atproto.identity.requestPlcOperationSignature`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.identity.requestPlcOperationSignature";
+
+/// Request an email with a code to in order to request a signed PLC operation. Requires Auth.
+pub async fn request_plc_operation_signature(client: &XrpcClient) -> Result<(), XrpcError> {
+    let request = XrpcRequest::procedure(NSID);
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
@@ -0,0 +1,60 @@
+//! `com.atproto.identity.resolveDid`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.identity.resolveDid";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// DID to resolve.
+    pub did: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    /// The complete DID document for the identity.
+    pub did_doc: serde_json::Value,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// The DID resolution process confirmed that there is no current DID.
+    DidNotFound,
+    /// The DID previously existed, but has been deactivated.
+    DidDeactivated,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "DidNotFound" => Some(Self::DidNotFound),
+            "DidDeactivated" => Some(Self::DidDeactivated),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::DidNotFound => "DidNotFound",
+            Self::DidDeactivated => "DidDeactivated",
+        }
+    }
+}
+
+/// Resolves DID to DID document. Does not bi-directionally verify handle.
+pub async fn resolve_did(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, EndpointError<Error>> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.did;
+        request = request.param("did", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,59 @@
+//! `com.atproto.identity.resolveIdentity`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.identity.resolveIdentity";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// Handle or DID to resolve.
+    pub identifier: String,
+}
+
+pub type Output = crate::api::com::atproto::identity::defs::IdentityInfo;
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// The resolution process confirmed that the handle does not resolve to any DID.
+    HandleNotFound,
+    /// The DID resolution process confirmed that there is no current DID.
+    DidNotFound,
+    /// The DID previously existed, but has been deactivated.
+    DidDeactivated,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "HandleNotFound" => Some(Self::HandleNotFound),
+            "DidNotFound" => Some(Self::DidNotFound),
+            "DidDeactivated" => Some(Self::DidDeactivated),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::HandleNotFound => "HandleNotFound",
+            Self::DidNotFound => "DidNotFound",
+            Self::DidDeactivated => "DidDeactivated",
+        }
+    }
+}
+
+/// Resolves an identity (DID or Handle) to a full identity (DID document and verified handle).
+pub async fn resolve_identity(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, EndpointError<Error>> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.identifier;
+        request = request.param("identifier", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
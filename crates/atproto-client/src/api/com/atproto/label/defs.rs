@@ -0,0 +1,83 @@
+//! `com.atproto.label.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.label.defs";
+
+/// Metadata tag on an atproto resource (eg, repo or record).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Label {
+    /// Optionally, CID specifying the specific version of 'uri' resource this label applies to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    /// Timestamp when this label was created.
+    pub cts: String,
+    /// Timestamp at which this label expires (no longer applies).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exp: Option<String>,
+    /// If true, this is a negation label, overwriting a previous label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neg: Option<bool>,
+    /// Signature of dag-cbor encoded label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sig: Option<serde_json::Value>,
+    /// DID of the actor who created this label.
+    pub src: String,
+    /// AT URI of the record, repository (account), or other resource that this label applies to.
+    pub uri: String,
+    /// The short string name of the value or type of this label.
+    pub val: String,
+    /// The AT Protocol version of the label object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ver: Option<i64>,
+}
+
+pub type LabelValue = String;
+
+/// Declares a label value and its expected interpretations and behaviors.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelValueDefinition {
+    /// Does the user need to have adult content enabled in order to configure this label?
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adult_only: Option<bool>,
+    /// What should this label hide in the UI, if applied? 'content' hides all of the target; 'media' hides the images/video/audio; 'none' hides nothing.
+    pub blurs: String,
+    /// The default setting for this label.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_setting: Option<String>,
+    /// The value of the label being defined. Must only include lowercase ascii and the '-' character ([a-z-]+).
+    pub identifier: String,
+    pub locales: Vec<LabelValueDefinitionStrings>,
+    /// How should a client visually convey this label? 'inform' means neutral and informational; 'alert' means negative and warning; 'none' means show nothing.
+    pub severity: String,
+}
+
+/// Strings which describe the label in the UI, localized into a specific language.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelValueDefinitionStrings {
+    /// A longer description of what the label means and why it might be applied.
+    pub description: String,
+    /// The code of the language these strings are written in.
+    pub lang: String,
+    /// A short human-readable name for the label.
+    pub name: String,
+}
+
+/// Metadata tag on an atproto record, published by the author within the record. Note that schemas should use #selfLabels, not #selfLabel.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfLabel {
+    /// The short string name of the value or type of this label.
+    pub val: String,
+}
+
+/// Metadata tags on an atproto record, published by the author within the record.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfLabels {
+    pub values: Vec<SelfLabel>,
+}
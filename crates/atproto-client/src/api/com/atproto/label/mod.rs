@@ -0,0 +1,5 @@
+//! `com.atproto.label.*`
+
+pub mod defs;
+pub mod query_labels;
+pub mod subscribe_labels;
@@ -0,0 +1,38 @@
+//! `com.atproto.label.subscribeLabels`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.label.subscribeLabels";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// The last known event seq number to backfill from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum Message {
+    #[serde(rename = "com.atproto.label.subscribeLabels#labels")]
+    Labels(Box<Labels>),
+    #[serde(rename = "com.atproto.label.subscribeLabels#info")]
+    Info(Box<Info>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Info {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Labels {
+    pub labels: Vec<crate::api::com::atproto::label::defs::Label>,
+    pub seq: i64,
+}
@@ -0,0 +1,3 @@
+//! `com.atproto.lexicon.*`
+
+pub mod schema;
@@ -0,0 +1,75 @@
+//! `com.atproto.moderation.createReport`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.moderation.createReport";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mod_tool: Option<ModTool>,
+    /// Additional context about the content and violation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Indicates the broad category of violation the report is for.
+    pub reason_type: crate::api::com::atproto::moderation::defs::ReasonType,
+    pub subject: crate::api::Union<InputSubjectUnion>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum InputSubjectUnion {
+    #[serde(rename = "com.atproto.admin.defs#repoRef")]
+    RepoRef(Box<crate::api::com::atproto::admin::defs::RepoRef>),
+    #[serde(
+        rename = "com.atproto.repo.strongRef",
+        alias = "com.atproto.repo.strongRef#main"
+    )]
+    StrongRef(Box<crate::api::com::atproto::repo::strong_ref::StrongRef>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub created_at: String,
+    pub id: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub reason_type: crate::api::com::atproto::moderation::defs::ReasonType,
+    pub reported_by: String,
+    pub subject: crate::api::Union<OutputSubjectUnion>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum OutputSubjectUnion {
+    #[serde(rename = "com.atproto.admin.defs#repoRef")]
+    RepoRef(Box<crate::api::com::atproto::admin::defs::RepoRef>),
+    #[serde(
+        rename = "com.atproto.repo.strongRef",
+        alias = "com.atproto.repo.strongRef#main"
+    )]
+    StrongRef(Box<crate::api::com::atproto::repo::strong_ref::StrongRef>),
+}
+
+/// Submit a moderation report regarding an atproto account or record. Implemented by moderation services (with PDS proxying), and requires auth.
+pub async fn create_report(client: &XrpcClient, input: &Input) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    Ok(client.procedure(request).await?.data)
+}
+
+/// Moderation tool information for tracing the source of the action
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModTool {
+    /// Additional arbitrary metadata about the source
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<serde_json::Value>,
+    /// Name/identifier of the source (e.g., 'bsky-app/android', 'bsky-web/chrome')
+    pub name: String,
+}
@@ -0,0 +1,30 @@
+//! `com.atproto.moderation.defs`
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.moderation.defs";
+
+/// Appeal: appeal a previously taken moderation action
+pub const REASON_APPEAL: &str = "com.atproto.moderation.defs#reasonAppeal";
+
+/// Misleading identity, affiliation, or content
+pub const REASON_MISLEADING: &str = "com.atproto.moderation.defs#reasonMisleading";
+
+/// Other: reports not falling under another report category
+pub const REASON_OTHER: &str = "com.atproto.moderation.defs#reasonOther";
+
+/// Rude, harassing, explicit, or otherwise unwelcoming behavior
+pub const REASON_RUDE: &str = "com.atproto.moderation.defs#reasonRude";
+
+/// Unwanted or mislabeled sexual content
+pub const REASON_SEXUAL: &str = "com.atproto.moderation.defs#reasonSexual";
+
+/// Spam: frequent unwanted promotion, replies, mentions
+pub const REASON_SPAM: &str = "com.atproto.moderation.defs#reasonSpam";
+
+pub type ReasonType = String;
+
+/// Direct violation of server rules, laws, terms of service
+pub const REASON_VIOLATION: &str = "com.atproto.moderation.defs#reasonViolation";
+
+/// Tag describing a type of subject that might be reported.
+pub type SubjectType = String;
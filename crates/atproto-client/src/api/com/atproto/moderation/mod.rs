@@ -0,0 +1,4 @@
+//! `com.atproto.moderation.*`
+
+pub mod create_report;
+pub mod defs;
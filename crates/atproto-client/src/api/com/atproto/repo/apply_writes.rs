@@ -0,0 +1,135 @@
+//! `com.atproto.repo.applyWrites`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcError, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.repo.applyWrites";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    /// The handle or DID of the repo (aka, current account).
+    pub repo: String,
+    /// If provided, the entire operation will fail if the current repo commit CID does not match this value. Used to prevent conflicting repo mutations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub swap_commit: Option<String>,
+    /// Can be set to 'false' to skip Lexicon schema validation of record data across all operations, 'true' to require it, or leave unset to validate only for known Lexicons.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validate: Option<bool>,
+    pub writes: Vec<InputWritesItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum InputWritesItem {
+    #[serde(rename = "com.atproto.repo.applyWrites#create")]
+    Create(Box<Create>),
+    #[serde(rename = "com.atproto.repo.applyWrites#update")]
+    Update(Box<Update>),
+    #[serde(rename = "com.atproto.repo.applyWrites#delete")]
+    Delete(Box<Delete>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<crate::api::com::atproto::repo::defs::CommitMeta>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<OutputResultsItem>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum OutputResultsItem {
+    #[serde(rename = "com.atproto.repo.applyWrites#createResult")]
+    CreateResult(Box<CreateResult>),
+    #[serde(rename = "com.atproto.repo.applyWrites#updateResult")]
+    UpdateResult(Box<UpdateResult>),
+    #[serde(rename = "com.atproto.repo.applyWrites#deleteResult")]
+    DeleteResult(Box<DeleteResult>),
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// Indicates that the 'swapCommit' parameter did not match current commit.
+    InvalidSwap,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "InvalidSwap" => Some(Self::InvalidSwap),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::InvalidSwap => "InvalidSwap",
+        }
+    }
+}
+
+/// Apply a batch transaction of repository creates, updates, and deletes. Requires auth, implemented by PDS.
+pub async fn apply_writes(
+    client: &XrpcClient,
+    input: &Input,
+) -> Result<Output, EndpointError<Error>> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    Ok(client.procedure(request).await?.data)
+}
+
+/// Operation which creates a new record.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Create {
+    pub collection: String,
+    /// NOTE: maxLength is redundant with record-key format. Keeping it temporarily to ensure backwards compatibility.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rkey: Option<String>,
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateResult {
+    pub cid: String,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_status: Option<String>,
+}
+
+/// Operation which deletes an existing record.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Delete {
+    pub collection: String,
+    pub rkey: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteResult {}
+
+/// Operation which updates an existing record.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Update {
+    pub collection: String,
+    pub rkey: String,
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateResult {
+    pub cid: String,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_status: Option<String>,
+}
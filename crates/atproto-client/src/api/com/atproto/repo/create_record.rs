@@ -0,0 +1,71 @@
+//! `com.atproto.repo.createRecord`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcError, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.repo.createRecord";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    /// The NSID of the record collection.
+    pub collection: String,
+    /// The record itself. Must contain a $type field.
+    pub record: serde_json::Value,
+    /// The handle or DID of the repo (aka, current account).
+    pub repo: String,
+    /// The Record Key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rkey: Option<String>,
+    /// Compare and swap with the previous commit by CID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub swap_commit: Option<String>,
+    /// Can be set to 'false' to skip Lexicon schema validation of record data, 'true' to require it, or leave unset to validate only for known Lexicons.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validate: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub cid: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<crate::api::com::atproto::repo::defs::CommitMeta>,
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_status: Option<String>,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// Indicates that 'swapCommit' didn't match current repo commit.
+    InvalidSwap,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "InvalidSwap" => Some(Self::InvalidSwap),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::InvalidSwap => "InvalidSwap",
+        }
+    }
+}
+
+/// Create a single new repository record. Requires auth, implemented by PDS.
+pub async fn create_record(
+    client: &XrpcClient,
+    input: &Input,
+) -> Result<Output, EndpointError<Error>> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    Ok(client.procedure(request).await?.data)
+}
@@ -0,0 +1,13 @@
+//! `com.atproto.repo.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.repo.defs";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitMeta {
+    pub cid: String,
+    pub rev: String,
+}
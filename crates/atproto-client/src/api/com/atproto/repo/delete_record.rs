@@ -0,0 +1,63 @@
+//! `com.atproto.repo.deleteRecord`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcError, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.repo.deleteRecord";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    /// The NSID of the record collection.
+    pub collection: String,
+    /// The handle or DID of the repo (aka, current account).
+    pub repo: String,
+    /// The Record Key.
+    pub rkey: String,
+    /// Compare and swap with the previous commit by CID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub swap_commit: Option<String>,
+    /// Compare and swap with the previous record by CID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub swap_record: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<crate::api::com::atproto::repo::defs::CommitMeta>,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    InvalidSwap,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "InvalidSwap" => Some(Self::InvalidSwap),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::InvalidSwap => "InvalidSwap",
+        }
+    }
+}
+
+/// Delete a repository record, or ensure it doesn't exist. Requires auth, implemented by PDS.
+pub async fn delete_record(
+    client: &XrpcClient,
+    input: &Input,
+) -> Result<Output, EndpointError<Error>> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    Ok(client.procedure(request).await?.data)
+}
@@ -0,0 +1,37 @@
+//! `com.atproto.repo.describeRepo`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.repo.describeRepo";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// The handle or DID of the repo.
+    pub repo: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    /// List of all the collections (NSIDs) for which this repo contains at least one record.
+    pub collections: Vec<String>,
+    pub did: String,
+    /// The complete DID document for this account.
+    pub did_doc: serde_json::Value,
+    pub handle: String,
+    /// Indicates if handle is currently valid (resolves bi-directionally)
+    pub handle_is_correct: bool,
+}
+
+/// Get information about an account and repository, including the list of collections. Does not require auth.
+pub async fn describe_repo(client: &XrpcClient, params: &Parameters) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.repo;
+        request = request.param("repo", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,75 @@
+//! `com.atproto.repo.getRecord`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.repo.getRecord";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// The CID of the version of the record. If not specified, then return the most recent version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    /// The NSID of the record collection.
+    pub collection: String,
+    /// The handle or DID of the repo.
+    pub repo: String,
+    /// The Record Key.
+    pub rkey: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    pub uri: String,
+    pub value: serde_json::Value,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    RecordNotFound,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "RecordNotFound" => Some(Self::RecordNotFound),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::RecordNotFound => "RecordNotFound",
+        }
+    }
+}
+
+/// Get a single record from a repository. Does not require auth.
+pub async fn get_record(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, EndpointError<Error>> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.cid {
+        request = request.param("cid", value.clone());
+    }
+    {
+        let value = &params.collection;
+        request = request.param("collection", value.clone());
+    }
+    {
+        let value = &params.repo;
+        request = request.param("repo", value.clone());
+    }
+    {
+        let value = &params.rkey;
+        request = request.param("rkey", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,17 @@
+//! `com.atproto.repo.importRepo`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.repo.importRepo";
+
+/// Import a repo in the form of a CAR file. Requires Content-Length HTTP header to be set.
+pub async fn import_repo(
+    client: &XrpcClient,
+    body: Vec<u8>,
+    encoding: &str,
+) -> Result<(), XrpcError> {
+    let request = XrpcRequest::procedure(NSID).body(body).encoding(encoding);
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
@@ -0,0 +1,15 @@
+//! `com.atproto.repo.strongRef`
+//!
+//! A URI with a content-hash fingerprint.
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.repo.strongRef";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StrongRef {
+    pub cid: String,
+    pub uri: String,
+}
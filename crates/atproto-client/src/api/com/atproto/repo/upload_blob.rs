@@ -0,0 +1,23 @@
+//! `com.atproto.repo.uploadBlob`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.repo.uploadBlob";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub blob: crate::lexicon::BlobRef,
+}
+
+/// Upload a new blob, to be referenced from a repository record. The blob will be deleted if it is not referenced within a time window (eg, minutes). Blob restrictions (mimetype, size, etc) are enforced when the reference is created. Requires auth, implemented by PDS.
+pub async fn upload_blob(
+    client: &XrpcClient,
+    body: Vec<u8>,
+    encoding: &str,
+) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::procedure(NSID).body(body).encoding(encoding);
+    Ok(client.procedure(request).await?.data)
+}
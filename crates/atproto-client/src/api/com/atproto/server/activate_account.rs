@@ -0,0 +1,13 @@
+//! `com.atproto.server.activateAccount`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.server.activateAccount";
+
+/// Activates a currently deactivated account. Used to finalize account migration after the account's repo is imported and identity is setup.
+pub async fn activate_account(client: &XrpcClient) -> Result<(), XrpcError> {
+    let request = XrpcRequest::procedure(NSID);
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
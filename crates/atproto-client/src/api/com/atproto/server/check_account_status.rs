@@ -0,0 +1,27 @@
+//! `com.atproto.server.checkAccountStatus`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.server.checkAccountStatus";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub activated: bool,
+    pub expected_blobs: i64,
+    pub imported_blobs: i64,
+    pub indexed_records: i64,
+    pub private_state_values: i64,
+    pub repo_blocks: i64,
+    pub repo_commit: String,
+    pub repo_rev: String,
+    pub valid_did: bool,
+}
+
+/// Returns the status of an account, especially as pertaining to import or recovery. Can be called many times over the course of an account migration. Requires auth and can only be called pertaining to oneself.
+pub async fn check_account_status(client: &XrpcClient) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::query(NSID);
+    Ok(client.query(request).await?.data)
+}
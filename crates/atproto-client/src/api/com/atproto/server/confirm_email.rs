@@ -0,0 +1,53 @@
+//! `com.atproto.server.confirmEmail`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcError, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.server.confirmEmail";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    pub email: String,
+    pub token: String,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    AccountNotFound,
+    ExpiredToken,
+    InvalidToken,
+    InvalidEmail,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "AccountNotFound" => Some(Self::AccountNotFound),
+            "ExpiredToken" => Some(Self::ExpiredToken),
+            "InvalidToken" => Some(Self::InvalidToken),
+            "InvalidEmail" => Some(Self::InvalidEmail),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::AccountNotFound => "AccountNotFound",
+            Self::ExpiredToken => "ExpiredToken",
+            Self::InvalidToken => "InvalidToken",
+            Self::InvalidEmail => "InvalidEmail",
+        }
+    }
+}
+
+/// Confirm an email using a token from com.atproto.server.requestEmailConfirmation.
+pub async fn confirm_email(client: &XrpcClient, input: &Input) -> Result<(), EndpointError<Error>> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
@@ -0,0 +1,98 @@
+//! `com.atproto.server.createAccount`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcError, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.server.createAccount";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    /// Pre-existing atproto DID, being imported to a new account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub did: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Requested handle for the account.
+    pub handle: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invite_code: Option<String>,
+    /// Initial account password. May need to meet instance-specific password strength requirements.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /// A signed DID PLC operation to be submitted as part of importing an existing account to this instance. NOTE: this optional field may be updated when full account migration is implemented.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plc_op: Option<serde_json::Value>,
+    /// DID PLC rotation key (aka, recovery key) to be included in PLC creation operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recovery_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification_phone: Option<String>,
+}
+
+/// Account login session returned on successful account creation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub access_jwt: String,
+    /// The DID of the new account.
+    pub did: String,
+    /// Complete DID document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub did_doc: Option<serde_json::Value>,
+    pub handle: String,
+    pub refresh_jwt: String,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    InvalidHandle,
+    InvalidPassword,
+    InvalidInviteCode,
+    HandleNotAvailable,
+    UnsupportedDomain,
+    UnresolvableDid,
+    IncompatibleDidDoc,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "InvalidHandle" => Some(Self::InvalidHandle),
+            "InvalidPassword" => Some(Self::InvalidPassword),
+            "InvalidInviteCode" => Some(Self::InvalidInviteCode),
+            "HandleNotAvailable" => Some(Self::HandleNotAvailable),
+            "UnsupportedDomain" => Some(Self::UnsupportedDomain),
+            "UnresolvableDid" => Some(Self::UnresolvableDid),
+            "IncompatibleDidDoc" => Some(Self::IncompatibleDidDoc),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::InvalidHandle => "InvalidHandle",
+            Self::InvalidPassword => "InvalidPassword",
+            Self::InvalidInviteCode => "InvalidInviteCode",
+            Self::HandleNotAvailable => "HandleNotAvailable",
+            Self::UnsupportedDomain => "UnsupportedDomain",
+            Self::UnresolvableDid => "UnresolvableDid",
+            Self::IncompatibleDidDoc => "IncompatibleDidDoc",
+        }
+    }
+}
+
+/// Create an account. Implemented by PDS.
+pub async fn create_account(
+    client: &XrpcClient,
+    input: &Input,
+) -> Result<Output, EndpointError<Error>> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    Ok(client.procedure(request).await?.data)
+}
@@ -0,0 +1,77 @@
+//! `com.atproto.server.createSession`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcError, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.server.createSession";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    /// When true, instead of throwing error for takendown accounts, a valid response with a narrow scoped token will be returned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_takendown: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_factor_token: Option<String>,
+    /// Handle or other identifier supported by the server for the authenticating user.
+    pub identifier: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub access_jwt: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub did_doc: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_auth_factor: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_confirmed: Option<bool>,
+    pub handle: String,
+    pub refresh_jwt: String,
+    /// If active=false, this optional field indicates a possible reason for why the account is not active. If active=false and no status is supplied, then the host makes no claim for why the repository is no longer being hosted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    AccountTakedown,
+    AuthFactorTokenRequired,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "AccountTakedown" => Some(Self::AccountTakedown),
+            "AuthFactorTokenRequired" => Some(Self::AuthFactorTokenRequired),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::AccountTakedown => "AccountTakedown",
+            Self::AuthFactorTokenRequired => "AuthFactorTokenRequired",
+        }
+    }
+}
+
+/// Create an authentication session.
+pub async fn create_session(
+    client: &XrpcClient,
+    input: &Input,
+) -> Result<Output, EndpointError<Error>> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    Ok(client.procedure(request).await?.data)
+}
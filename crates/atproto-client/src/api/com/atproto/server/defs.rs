@@ -0,0 +1,25 @@
+//! `com.atproto.server.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.server.defs";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InviteCode {
+    pub available: i64,
+    pub code: String,
+    pub created_at: String,
+    pub created_by: String,
+    pub disabled: bool,
+    pub for_account: String,
+    pub uses: Vec<InviteCodeUse>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InviteCodeUse {
+    pub used_at: String,
+    pub used_by: String,
+}
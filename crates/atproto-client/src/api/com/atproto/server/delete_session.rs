@@ -0,0 +1,13 @@
+//! `com.atproto.server.deleteSession`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.server.deleteSession";
+
+/// Delete the current session. Requires auth.
+pub async fn delete_session(client: &XrpcClient) -> Result<(), XrpcError> {
+    let request = XrpcRequest::procedure(NSID);
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
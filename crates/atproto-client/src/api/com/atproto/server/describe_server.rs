@@ -0,0 +1,49 @@
+//! `com.atproto.server.describeServer`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.server.describeServer";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    /// List of domain suffixes that can be used in account handles.
+    pub available_user_domains: Vec<String>,
+    /// Contact information
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contact: Option<Contact>,
+    pub did: String,
+    /// If true, an invite code must be supplied to create an account on this instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invite_code_required: Option<bool>,
+    /// URLs of service policy documents.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links: Option<Links>,
+    /// If true, a phone verification token must be supplied to create an account on this instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone_verification_required: Option<bool>,
+}
+
+/// Describes the server's account creation requirements and capabilities. Implemented by PDS.
+pub async fn describe_server(client: &XrpcClient) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::query(NSID);
+    Ok(client.query(request).await?.data)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contact {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Links {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub privacy_policy: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terms_of_service: Option<String>,
+}
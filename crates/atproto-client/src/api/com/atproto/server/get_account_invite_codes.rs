@@ -0,0 +1,59 @@
+//! `com.atproto.server.getAccountInviteCodes`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.server.getAccountInviteCodes";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// Controls whether any new 'earned' but not 'created' invites should be created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_available: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_used: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub codes: Vec<crate::api::com::atproto::server::defs::InviteCode>,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    DuplicateCreate,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "DuplicateCreate" => Some(Self::DuplicateCreate),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::DuplicateCreate => "DuplicateCreate",
+        }
+    }
+}
+
+/// Get all invite codes for the current account. Requires auth.
+pub async fn get_account_invite_codes(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, EndpointError<Error>> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.create_available {
+        request = request.param("createAvailable", value.to_string());
+    }
+    if let Some(value) = &params.include_used {
+        request = request.param("includeUsed", value.to_string());
+    }
+    Ok(client.query(request).await?.data)
+}
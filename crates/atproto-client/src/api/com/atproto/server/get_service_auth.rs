@@ -0,0 +1,67 @@
+//! `com.atproto.server.getServiceAuth`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.server.getServiceAuth";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// The DID of the service that the token will be used to authenticate with
+    pub aud: String,
+    /// The time in Unix Epoch seconds that the JWT expires. Defaults to 60 seconds in the future. The service may enforce certain time bounds on tokens depending on the requested scope.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+    /// Lexicon (XRPC) method to bind the requested token to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lxm: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub token: String,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// Indicates that the requested expiration date is not a valid. May be in the past or may be reliant on the requested scopes.
+    BadExpiration,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "BadExpiration" => Some(Self::BadExpiration),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::BadExpiration => "BadExpiration",
+        }
+    }
+}
+
+/// Get a signed token on behalf of the requesting DID for the requested service.
+pub async fn get_service_auth(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, EndpointError<Error>> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.aud;
+        request = request.param("aud", value.clone());
+    }
+    if let Some(value) = &params.exp {
+        request = request.param("exp", value.to_string());
+    }
+    if let Some(value) = &params.lxm {
+        request = request.param("lxm", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,50 @@
+//! `com.atproto.server.refreshSession`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.server.refreshSession";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub access_jwt: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub did_doc: Option<serde_json::Value>,
+    pub handle: String,
+    pub refresh_jwt: String,
+    /// Hosting status of the account. If not specified, then assume 'active'.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    AccountTakedown,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "AccountTakedown" => Some(Self::AccountTakedown),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::AccountTakedown => "AccountTakedown",
+        }
+    }
+}
+
+/// Refresh an authentication session. Requires auth using the 'refreshJwt' (not the 'accessJwt').
+pub async fn refresh_session(client: &XrpcClient) -> Result<Output, EndpointError<Error>> {
+    let request = XrpcRequest::procedure(NSID);
+    Ok(client.procedure(request).await?.data)
+}
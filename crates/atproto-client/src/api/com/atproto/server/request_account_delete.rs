@@ -0,0 +1,13 @@
+//! `com.atproto.server.requestAccountDelete`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.server.requestAccountDelete";
+
+/// Initiate a user account deletion via email.
+pub async fn request_account_delete(client: &XrpcClient) -> Result<(), XrpcError> {
+    let request = XrpcRequest::procedure(NSID);
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
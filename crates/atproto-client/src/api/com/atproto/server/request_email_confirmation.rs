@@ -0,0 +1,13 @@
+//! `com.atproto.server.requestEmailConfirmation`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.server.requestEmailConfirmation";
+
+/// Request an email with a code to confirm ownership of email.
+pub async fn request_email_confirmation(client: &XrpcClient) -> Result<(), XrpcError> {
+    let request = XrpcRequest::procedure(NSID);
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
@@ -0,0 +1,19 @@
+//! `com.atproto.server.requestEmailUpdate`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.server.requestEmailUpdate";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub token_required: bool,
+}
+
+/// Request a token in order to update email.
+pub async fn request_email_update(client: &XrpcClient) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::procedure(NSID);
+    Ok(client.procedure(request).await?.data)
+}
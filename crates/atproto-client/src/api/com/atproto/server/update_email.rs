@@ -0,0 +1,54 @@
+//! `com.atproto.server.updateEmail`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcError, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.server.updateEmail";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    pub email: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_auth_factor: Option<bool>,
+    /// Requires a token from com.atproto.sever.requestEmailUpdate if the account's email has been confirmed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    ExpiredToken,
+    InvalidToken,
+    TokenRequired,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "ExpiredToken" => Some(Self::ExpiredToken),
+            "InvalidToken" => Some(Self::InvalidToken),
+            "TokenRequired" => Some(Self::TokenRequired),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::ExpiredToken => "ExpiredToken",
+            Self::InvalidToken => "InvalidToken",
+            Self::TokenRequired => "TokenRequired",
+        }
+    }
+}
+
+/// Update an account's email.
+pub async fn update_email(client: &XrpcClient, input: &Input) -> Result<(), EndpointError<Error>> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
@@ -0,0 +1,6 @@
+//! `com.atproto.sync.defs`
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.sync.defs";
+
+pub type HostStatus = String;
@@ -0,0 +1,49 @@
+//! `com.atproto.sync.getBlob`
+
+use crate::xrpc::XrpcErrorKind;
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.sync.getBlob";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// The CID of the blob to fetch
+    pub cid: String,
+    /// The DID of the account.
+    pub did: String,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    BlobNotFound,
+    RepoNotFound,
+    RepoTakendown,
+    RepoSuspended,
+    RepoDeactivated,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "BlobNotFound" => Some(Self::BlobNotFound),
+            "RepoNotFound" => Some(Self::RepoNotFound),
+            "RepoTakendown" => Some(Self::RepoTakendown),
+            "RepoSuspended" => Some(Self::RepoSuspended),
+            "RepoDeactivated" => Some(Self::RepoDeactivated),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::BlobNotFound => "BlobNotFound",
+            Self::RepoNotFound => "RepoNotFound",
+            Self::RepoTakendown => "RepoTakendown",
+            Self::RepoSuspended => "RepoSuspended",
+            Self::RepoDeactivated => "RepoDeactivated",
+        }
+    }
+}
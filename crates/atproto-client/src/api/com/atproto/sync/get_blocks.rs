@@ -0,0 +1,48 @@
+//! `com.atproto.sync.getBlocks`
+
+use crate::xrpc::XrpcErrorKind;
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.sync.getBlocks";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub cids: Vec<String>,
+    /// The DID of the repo.
+    pub did: String,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    BlockNotFound,
+    RepoNotFound,
+    RepoTakendown,
+    RepoSuspended,
+    RepoDeactivated,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "BlockNotFound" => Some(Self::BlockNotFound),
+            "RepoNotFound" => Some(Self::RepoNotFound),
+            "RepoTakendown" => Some(Self::RepoTakendown),
+            "RepoSuspended" => Some(Self::RepoSuspended),
+            "RepoDeactivated" => Some(Self::RepoDeactivated),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::BlockNotFound => "BlockNotFound",
+            Self::RepoNotFound => "RepoNotFound",
+            Self::RepoTakendown => "RepoTakendown",
+            Self::RepoSuspended => "RepoSuspended",
+            Self::RepoDeactivated => "RepoDeactivated",
+        }
+    }
+}
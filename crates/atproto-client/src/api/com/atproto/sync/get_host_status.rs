@@ -0,0 +1,62 @@
+//! `com.atproto.sync.getHostStatus`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.sync.getHostStatus";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// Hostname of the host (eg, PDS or relay) being queried.
+    pub hostname: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    /// Number of accounts on the server which are associated with the upstream host. Note that the upstream may actually have more accounts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_count: Option<i64>,
+    pub hostname: String,
+    /// Recent repo stream event sequence number. May be delayed from actual stream processing (eg, persisted cursor not in-memory cursor).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seq: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<crate::api::com::atproto::sync::defs::HostStatus>,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    HostNotFound,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "HostNotFound" => Some(Self::HostNotFound),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::HostNotFound => "HostNotFound",
+        }
+    }
+}
+
+/// Returns information about a specified upstream host, as consumed by the server. Implemented by relays.
+pub async fn get_host_status(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, EndpointError<Error>> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.hostname;
+        request = request.param("hostname", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
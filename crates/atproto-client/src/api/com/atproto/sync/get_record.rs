@@ -0,0 +1,50 @@
+//! `com.atproto.sync.getRecord`
+
+use crate::xrpc::XrpcErrorKind;
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.sync.getRecord";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub collection: String,
+    /// The DID of the repo.
+    pub did: String,
+    /// Record Key
+    pub rkey: String,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    RecordNotFound,
+    RepoNotFound,
+    RepoTakendown,
+    RepoSuspended,
+    RepoDeactivated,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "RecordNotFound" => Some(Self::RecordNotFound),
+            "RepoNotFound" => Some(Self::RepoNotFound),
+            "RepoTakendown" => Some(Self::RepoTakendown),
+            "RepoSuspended" => Some(Self::RepoSuspended),
+            "RepoDeactivated" => Some(Self::RepoDeactivated),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::RecordNotFound => "RecordNotFound",
+            Self::RepoNotFound => "RepoNotFound",
+            Self::RepoTakendown => "RepoTakendown",
+            Self::RepoSuspended => "RepoSuspended",
+            Self::RepoDeactivated => "RepoDeactivated",
+        }
+    }
+}
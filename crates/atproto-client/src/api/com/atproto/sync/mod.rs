@@ -0,0 +1,17 @@
+//! `com.atproto.sync.*`
+
+pub mod defs;
+pub mod get_blob;
+pub mod get_blocks;
+pub mod get_host_status;
+pub mod get_latest_commit;
+pub mod get_record;
+pub mod get_repo;
+pub mod get_repo_status;
+pub mod list_blobs;
+pub mod list_hosts;
+pub mod list_repos;
+pub mod list_repos_by_collection;
+pub mod notify_of_update;
+pub mod request_crawl;
+pub mod subscribe_repos;
@@ -0,0 +1,121 @@
+//! `com.atproto.sync.subscribeRepos`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.sync.subscribeRepos";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// The last known event seq number to backfill from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum Message {
+    #[serde(rename = "com.atproto.sync.subscribeRepos#commit")]
+    Commit(Box<Commit>),
+    #[serde(rename = "com.atproto.sync.subscribeRepos#sync")]
+    Sync(Box<Sync>),
+    #[serde(rename = "com.atproto.sync.subscribeRepos#identity")]
+    Identity(Box<Identity>),
+    #[serde(rename = "com.atproto.sync.subscribeRepos#account")]
+    Account(Box<Account>),
+    #[serde(rename = "com.atproto.sync.subscribeRepos#info")]
+    Info(Box<Info>),
+}
+
+/// Represents a change to an account's status on a host (eg, PDS or Relay). The semantics of this event are that the status is at the host which emitted the event, not necessarily that at the currently active PDS. Eg, a Relay takedown would emit a takedown with active=false, even if the PDS is still active.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Account {
+    /// Indicates that the account has a repository which can be fetched from the host that emitted this event.
+    pub active: bool,
+    pub did: String,
+    pub seq: i64,
+    /// If active=false, this optional field indicates a reason for why the account is not active.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    pub time: String,
+}
+
+/// Represents an update of repository state. Note that empty commits are allowed, which include no repo data changes, but an update to rev and signature.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Commit {
+    pub blobs: Vec<crate::lexicon::CidLink>,
+    /// CAR file containing relevant blocks, as a diff since the previous repo state. The commit must be included as a block, and the commit block CID must be the first entry in the CAR header 'roots' list.
+    pub blocks: serde_json::Value,
+    /// Repo commit object CID.
+    pub commit: crate::lexicon::CidLink,
+    pub ops: Vec<RepoOp>,
+    /// The root CID of the MST tree for the previous commit from this repo (indicated by the 'since' revision field in this message). Corresponds to the 'data' field in the repo commit object. NOTE: this field is effectively required for the 'inductive' version of firehose.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev_data: Option<crate::lexicon::CidLink>,
+    /// DEPRECATED -- unused
+    pub rebase: bool,
+    /// The repo this event comes from. Note that all other message types name this field 'did'.
+    pub repo: String,
+    /// The rev of the emitted commit. Note that this information is also in the commit object included in blocks, unless this is a tooBig event.
+    pub rev: String,
+    /// The stream sequence number of this message.
+    pub seq: i64,
+    /// The rev of the last emitted commit from this repo (if any).
+    pub since: Option<String>,
+    /// Timestamp of when this message was originally broadcast.
+    pub time: String,
+    /// DEPRECATED -- replaced by #sync event and data limits. Indicates that this commit contained too many ops, or data size was too large. Consumers will need to make a separate request to get missing data.
+    pub too_big: bool,
+}
+
+/// Represents a change to an account's identity. Could be an updated handle, signing key, or pds hosting endpoint. Serves as a prod to all downstream services to refresh their identity cache.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Identity {
+    pub did: String,
+    /// The current handle for the account, or 'handle.invalid' if validation fails. This field is optional, might have been validated or passed-through from an upstream source. Semantics and behaviors for PDS vs Relay may evolve in the future; see atproto specs for more details.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    pub seq: i64,
+    pub time: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Info {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    pub name: String,
+}
+
+/// A repo operation, ie a mutation of a single record.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepoOp {
+    pub action: String,
+    /// For creates and updates, the new record CID. For deletions, null.
+    pub cid: Option<crate::lexicon::CidLink>,
+    pub path: String,
+    /// For updates and deletes, the previous record CID (required for inductive firehose). For creations, field should not be defined.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev: Option<crate::lexicon::CidLink>,
+}
+
+/// Updates the repo to a new state, without necessarily including that state on the firehose. Used to recover from broken commit streams, data loss incidents, or in situations where upstream host does not know recent state of the repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Sync {
+    /// CAR file containing the commit, as a block. The CAR header must include the commit block CID as the first 'root'.
+    pub blocks: serde_json::Value,
+    /// The account this repo event corresponds to. Must match that in the commit object.
+    pub did: String,
+    /// The rev of the commit. This value must match that in the commit object.
+    pub rev: String,
+    /// The stream sequence number of this message.
+    pub seq: i64,
+    /// Timestamp of when this message was originally broadcast.
+    pub time: String,
+}
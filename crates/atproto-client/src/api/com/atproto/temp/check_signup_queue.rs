@@ -0,0 +1,23 @@
+//! `com.atproto.temp.checkSignupQueue`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.temp.checkSignupQueue";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub activated: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimated_time_ms: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub place_in_queue: Option<i64>,
+}
+
+/// Check accounts location in signup queue.
+pub async fn check_signup_queue(client: &XrpcClient) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::query(NSID);
+    Ok(client.query(request).await?.data)
+}
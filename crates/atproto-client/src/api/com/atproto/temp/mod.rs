@@ -0,0 +1,6 @@
+//! `com.atproto.temp.*`
+
+pub mod add_reserved_handle;
+pub mod check_signup_queue;
+pub mod fetch_labels;
+pub mod request_phone_verification;
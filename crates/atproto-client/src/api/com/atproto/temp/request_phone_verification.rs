@@ -0,0 +1,25 @@
+//! `com.atproto.temp.requestPhoneVerification`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "com.atproto.temp.requestPhoneVerification";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    pub phone_number: String,
+}
+
+/// Request a verification code to be sent to the supplied phone number
+pub async fn request_phone_verification(
+    client: &XrpcClient,
+    input: &Input,
+) -> Result<(), XrpcError> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
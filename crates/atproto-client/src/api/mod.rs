@@ -0,0 +1,25 @@
+//! Types and XRPC methods generated from Lexicon schemas
+//!
+//! Generated by `lexgen`. Do not edit by hand.
+
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+/// Value of an open union
+///
+/// Open unions may hold types added after this code was generated. Those
+/// are kept as raw JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Union<T> {
+    /// One of the types listed in the schema
+    Known(T),
+    /// A type not listed in the schema
+    Unknown(serde_json::Value),
+}
+
+pub mod app;
+pub mod chat;
+pub mod com;
+pub mod tools;
@@ -0,0 +1,3 @@
+//! `tools.*`
+
+pub mod ozone;
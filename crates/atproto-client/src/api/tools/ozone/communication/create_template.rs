@@ -0,0 +1,58 @@
+//! `tools.ozone.communication.createTemplate`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcError, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.communication.createTemplate";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    /// Content of the template, markdown supported, can contain variable placeholders.
+    pub content_markdown: String,
+    /// DID of the user who is creating the template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    /// Message language.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+    /// Name of the template.
+    pub name: String,
+    /// Subject of the message, used in emails.
+    pub subject: String,
+}
+
+pub type Output = crate::api::tools::ozone::communication::defs::TemplateView;
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    DuplicateTemplateName,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "DuplicateTemplateName" => Some(Self::DuplicateTemplateName),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::DuplicateTemplateName => "DuplicateTemplateName",
+        }
+    }
+}
+
+/// Administrative action to create a new, re-usable communication (email for now) template.
+pub async fn create_template(
+    client: &XrpcClient,
+    input: &Input,
+) -> Result<Output, EndpointError<Error>> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    Ok(client.procedure(request).await?.data)
+}
@@ -0,0 +1,27 @@
+//! `tools.ozone.communication.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.communication.defs";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateView {
+    /// Subject of the message, used in emails.
+    pub content_markdown: String,
+    pub created_at: String,
+    pub disabled: bool,
+    pub id: String,
+    /// Message language.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+    /// DID of the user who last updated the template.
+    pub last_updated_by: String,
+    /// Name of the template.
+    pub name: String,
+    /// Content of the template, can contain markdown and variable placeholders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    pub updated_at: String,
+}
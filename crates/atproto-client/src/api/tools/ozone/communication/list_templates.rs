@@ -0,0 +1,19 @@
+//! `tools.ozone.communication.listTemplates`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.communication.listTemplates";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub communication_templates: Vec<crate::api::tools::ozone::communication::defs::TemplateView>,
+}
+
+/// Get list of all communication templates.
+pub async fn list_templates(client: &XrpcClient) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::query(NSID);
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,7 @@
+//! `tools.ozone.communication.*`
+
+pub mod create_template;
+pub mod defs;
+pub mod delete_template;
+pub mod list_templates;
+pub mod update_template;
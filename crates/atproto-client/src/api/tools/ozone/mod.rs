@@ -0,0 +1,9 @@
+//! `tools.ozone.*`
+
+pub mod communication;
+pub mod moderation;
+pub mod server;
+pub mod set;
+pub mod setting;
+pub mod signature;
+pub mod team;
@@ -0,0 +1,685 @@
+//! `tools.ozone.moderation.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.moderation.defs";
+
+/// Logs account status related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountEvent {
+    /// Indicates that the account has a repository which can be fetched from the host that emitted this event.
+    pub active: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountHosting {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deactivated_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactivated_at: Option<String>,
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// Statistics about a particular account subject
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountStats {
+    /// Total number of appeals against a moderation action on the account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub appeal_count: Option<i64>,
+    /// Number of times the account was escalated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub escalate_count: Option<i64>,
+    /// Total number of reports on the account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub report_count: Option<i64>,
+    /// Number of times the account was suspended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suspend_count: Option<i64>,
+    /// Number of times the account was taken down
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub takedown_count: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlobView {
+    pub cid: String,
+    pub created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<crate::api::Union<BlobViewDetailsUnion>>,
+    pub mime_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub moderation: Option<Moderation>,
+    pub size: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum BlobViewDetailsUnion {
+    #[serde(rename = "tools.ozone.moderation.defs#imageDetails")]
+    ImageDetails(Box<ImageDetails>),
+    #[serde(rename = "tools.ozone.moderation.defs#videoDetails")]
+    VideoDetails(Box<VideoDetails>),
+}
+
+/// Logs identity related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityEvent {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pds_host: Option<String>,
+    pub timestamp: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tombstone: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageDetails {
+    pub height: i64,
+    pub width: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventAcknowledge {
+    /// If true, all other reports on content authored by this account will be resolved (acknowledged).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acknowledge_account_subjects: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+/// Add a comment to a subject. An empty comment will clear any previously set sticky comment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventComment {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Make the comment persistent on the subject
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sticky: Option<bool>,
+}
+
+/// Divert a record's blobs to a 3rd party service for further scanning/tagging
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventDivert {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+/// Keep a log of outgoing email to a user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventEmail {
+    /// Additional comment about the outgoing comm.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// The content of the email sent to the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The subject line of the email sent to the user.
+    pub subject_line: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventEscalate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+/// Apply/Negate labels on a subject
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventLabel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    pub create_label_vals: Vec<String>,
+    /// Indicates how long the label will remain on the subject. Only applies on labels that are being added.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_in_hours: Option<i64>,
+    pub negate_label_vals: Vec<String>,
+}
+
+/// Mute incoming reports on a subject
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventMute {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Indicates how long the subject should remain muted.
+    pub duration_in_hours: i64,
+}
+
+/// Mute incoming reports from an account
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventMuteReporter {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Indicates how long the account should remain muted. Falsy value here means a permanent mute.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_in_hours: Option<i64>,
+}
+
+/// Set priority score of the subject. Higher score means higher priority.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventPriorityScore {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    pub score: i64,
+}
+
+/// Report a subject
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventReport {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Set to true if the reporter was muted from reporting at the time of the event. These reports won't impact the reviewState of the subject.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_reporter_muted: Option<bool>,
+    pub report_type: crate::api::com::atproto::moderation::defs::ReasonType,
+}
+
+/// Resolve appeal on a subject
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventResolveAppeal {
+    /// Describe resolution.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+/// Revert take down action on a subject
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventReverseTakedown {
+    /// Describe reasoning behind the reversal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+/// Add/Remove a tag on a subject
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventTag {
+    /// Tags to be added to the subject. If already exists, won't be duplicated.
+    pub add: Vec<String>,
+    /// Additional comment about added/removed tags.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Tags to be removed to the subject. Ignores a tag If it doesn't exist, won't be duplicated.
+    pub remove: Vec<String>,
+}
+
+/// Take down a subject permanently or temporarily
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventTakedown {
+    /// If true, all other reports on content authored by this account will be resolved (acknowledged).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acknowledge_account_subjects: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Indicates how long the takedown should be in effect before automatically expiring.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_in_hours: Option<i64>,
+    /// Names/Keywords of the policies that drove the decision.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policies: Option<Vec<String>>,
+}
+
+/// Unmute action on a subject
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventUnmute {
+    /// Describe reasoning behind the reversal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+/// Unmute incoming reports from an account
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventUnmuteReporter {
+    /// Describe reasoning behind the reversal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventView {
+    pub created_at: String,
+    pub created_by: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator_handle: Option<String>,
+    pub event: crate::api::Union<ModEventViewEventUnion>,
+    pub id: i64,
+    pub subject: crate::api::Union<ModEventViewSubjectUnion>,
+    pub subject_blob_cids: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_handle: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ModEventViewEventUnion {
+    #[serde(rename = "tools.ozone.moderation.defs#modEventTakedown")]
+    ModEventTakedown(Box<ModEventTakedown>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventReverseTakedown")]
+    ModEventReverseTakedown(Box<ModEventReverseTakedown>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventComment")]
+    ModEventComment(Box<ModEventComment>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventReport")]
+    ModEventReport(Box<ModEventReport>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventLabel")]
+    ModEventLabel(Box<ModEventLabel>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventAcknowledge")]
+    ModEventAcknowledge(Box<ModEventAcknowledge>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventEscalate")]
+    ModEventEscalate(Box<ModEventEscalate>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventMute")]
+    ModEventMute(Box<ModEventMute>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventUnmute")]
+    ModEventUnmute(Box<ModEventUnmute>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventMuteReporter")]
+    ModEventMuteReporter(Box<ModEventMuteReporter>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventUnmuteReporter")]
+    ModEventUnmuteReporter(Box<ModEventUnmuteReporter>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventEmail")]
+    ModEventEmail(Box<ModEventEmail>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventResolveAppeal")]
+    ModEventResolveAppeal(Box<ModEventResolveAppeal>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventDivert")]
+    ModEventDivert(Box<ModEventDivert>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventTag")]
+    ModEventTag(Box<ModEventTag>),
+    #[serde(rename = "tools.ozone.moderation.defs#accountEvent")]
+    AccountEvent(Box<AccountEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#identityEvent")]
+    IdentityEvent(Box<IdentityEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#recordEvent")]
+    RecordEvent(Box<RecordEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventPriorityScore")]
+    ModEventPriorityScore(Box<ModEventPriorityScore>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ModEventViewSubjectUnion {
+    #[serde(rename = "com.atproto.admin.defs#repoRef")]
+    RepoRef(Box<crate::api::com::atproto::admin::defs::RepoRef>),
+    #[serde(
+        rename = "com.atproto.repo.strongRef",
+        alias = "com.atproto.repo.strongRef#main"
+    )]
+    StrongRef(Box<crate::api::com::atproto::repo::strong_ref::StrongRef>),
+    #[serde(rename = "chat.bsky.convo.defs#messageRef")]
+    MessageRef(Box<crate::api::chat::bsky::convo::defs::MessageRef>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModEventViewDetail {
+    pub created_at: String,
+    pub created_by: String,
+    pub event: crate::api::Union<ModEventViewDetailEventUnion>,
+    pub id: i64,
+    pub subject: crate::api::Union<ModEventViewDetailSubjectUnion>,
+    pub subject_blobs: Vec<BlobView>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ModEventViewDetailEventUnion {
+    #[serde(rename = "tools.ozone.moderation.defs#modEventTakedown")]
+    ModEventTakedown(Box<ModEventTakedown>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventReverseTakedown")]
+    ModEventReverseTakedown(Box<ModEventReverseTakedown>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventComment")]
+    ModEventComment(Box<ModEventComment>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventReport")]
+    ModEventReport(Box<ModEventReport>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventLabel")]
+    ModEventLabel(Box<ModEventLabel>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventAcknowledge")]
+    ModEventAcknowledge(Box<ModEventAcknowledge>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventEscalate")]
+    ModEventEscalate(Box<ModEventEscalate>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventMute")]
+    ModEventMute(Box<ModEventMute>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventUnmute")]
+    ModEventUnmute(Box<ModEventUnmute>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventMuteReporter")]
+    ModEventMuteReporter(Box<ModEventMuteReporter>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventUnmuteReporter")]
+    ModEventUnmuteReporter(Box<ModEventUnmuteReporter>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventEmail")]
+    ModEventEmail(Box<ModEventEmail>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventResolveAppeal")]
+    ModEventResolveAppeal(Box<ModEventResolveAppeal>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventDivert")]
+    ModEventDivert(Box<ModEventDivert>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventTag")]
+    ModEventTag(Box<ModEventTag>),
+    #[serde(rename = "tools.ozone.moderation.defs#accountEvent")]
+    AccountEvent(Box<AccountEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#identityEvent")]
+    IdentityEvent(Box<IdentityEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#recordEvent")]
+    RecordEvent(Box<RecordEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventPriorityScore")]
+    ModEventPriorityScore(Box<ModEventPriorityScore>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum ModEventViewDetailSubjectUnion {
+    #[serde(rename = "tools.ozone.moderation.defs#repoView")]
+    RepoView(Box<RepoView>),
+    #[serde(rename = "tools.ozone.moderation.defs#repoViewNotFound")]
+    RepoViewNotFound(Box<RepoViewNotFound>),
+    #[serde(rename = "tools.ozone.moderation.defs#recordView")]
+    RecordView(Box<RecordView>),
+    #[serde(rename = "tools.ozone.moderation.defs#recordViewNotFound")]
+    RecordViewNotFound(Box<RecordViewNotFound>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Moderation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_status: Option<SubjectStatusView>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModerationDetail {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_status: Option<SubjectStatusView>,
+}
+
+/// Logs lifecycle event on a record subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordEvent {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    pub op: String,
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordHosting {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordView {
+    pub blob_cids: Vec<String>,
+    pub cid: String,
+    pub indexed_at: String,
+    pub moderation: Moderation,
+    pub repo: RepoView,
+    pub uri: String,
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordViewDetail {
+    pub blobs: Vec<BlobView>,
+    pub cid: String,
+    pub indexed_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::api::com::atproto::label::defs::Label>>,
+    pub moderation: ModerationDetail,
+    pub repo: RepoView,
+    pub uri: String,
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordViewNotFound {
+    pub uri: String,
+}
+
+/// Statistics about a set of record subject items
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordsStats {
+    /// Number of items that were appealed at least once
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub appealed_count: Option<i64>,
+    /// Number of items that were escalated at least once
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub escalated_count: Option<i64>,
+    /// Number of item currently in "reviewOpen" or "reviewEscalated" state
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_count: Option<i64>,
+    /// Number of item currently in "reviewNone" or "reviewClosed" state
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub processed_count: Option<i64>,
+    /// Number of items that were reported at least once
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reported_count: Option<i64>,
+    /// Total number of item in the set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_count: Option<i64>,
+    /// Number of item currently taken down
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub takendown_count: Option<i64>,
+    /// Cumulative sum of the number of reports on the items in the set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_reports: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepoView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deactivated_at: Option<String>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    pub handle: String,
+    pub indexed_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invite_note: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invited_by: Option<crate::api::com::atproto::server::defs::InviteCode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invites_disabled: Option<bool>,
+    pub moderation: Moderation,
+    pub related_records: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threat_signatures: Option<Vec<crate::api::com::atproto::admin::defs::ThreatSignature>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepoViewDetail {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deactivated_at: Option<String>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_confirmed_at: Option<String>,
+    pub handle: String,
+    pub indexed_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invite_note: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invited_by: Option<crate::api::com::atproto::server::defs::InviteCode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invites: Option<Vec<crate::api::com::atproto::server::defs::InviteCode>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invites_disabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<crate::api::com::atproto::label::defs::Label>>,
+    pub moderation: ModerationDetail,
+    pub related_records: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threat_signatures: Option<Vec<crate::api::com::atproto::admin::defs::ThreatSignature>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepoViewNotFound {
+    pub did: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReporterStats {
+    /// The total number of reports made by the user on accounts.
+    pub account_report_count: i64,
+    pub did: String,
+    /// The total number of accounts labeled as a result of the user's reports.
+    pub labeled_account_count: i64,
+    /// The total number of records labeled as a result of the user's reports.
+    pub labeled_record_count: i64,
+    /// The total number of reports made by the user on records.
+    pub record_report_count: i64,
+    /// The total number of accounts reported by the user.
+    pub reported_account_count: i64,
+    /// The total number of records reported by the user.
+    pub reported_record_count: i64,
+    /// The total number of accounts taken down as a result of the user's reports.
+    pub takendown_account_count: i64,
+    /// The total number of records taken down as a result of the user's reports.
+    pub takendown_record_count: i64,
+}
+
+/// Moderator review status of a subject: Closed. Indicates that the subject was already reviewed and resolved by a moderator
+pub const REVIEW_CLOSED: &str = "tools.ozone.moderation.defs#reviewClosed";
+
+/// Moderator review status of a subject: Escalated. Indicates that the subject was escalated for review by a moderator
+pub const REVIEW_ESCALATED: &str = "tools.ozone.moderation.defs#reviewEscalated";
+
+/// Moderator review status of a subject: Unnecessary. Indicates that the subject does not need a review at the moment but there is probably some moderation related metadata available for it
+pub const REVIEW_NONE: &str = "tools.ozone.moderation.defs#reviewNone";
+
+/// Moderator review status of a subject: Open. Indicates that the subject needs to be reviewed by a moderator
+pub const REVIEW_OPEN: &str = "tools.ozone.moderation.defs#reviewOpen";
+
+pub type SubjectReviewState = String;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubjectStatusView {
+    /// Statistics related to the account subject
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_stats: Option<AccountStats>,
+    /// True indicates that the a previously taken moderator action was appealed against, by the author of the content. False indicates last appeal was resolved by moderators.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub appealed: Option<bool>,
+    /// Sticky comment on the subject.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Timestamp referencing the first moderation status impacting event was emitted on the subject
+    pub created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hosting: Option<crate::api::Union<SubjectStatusViewHostingUnion>>,
+    pub id: i64,
+    /// Timestamp referencing when the author of the subject appealed a moderation action
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_appealed_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_reported_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_reviewed_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_reviewed_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mute_reporting_until: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mute_until: Option<String>,
+    /// Numeric value representing the level of priority. Higher score means higher priority.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_score: Option<i64>,
+    /// Statistics related to the record subjects authored by the subject's account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub records_stats: Option<RecordsStats>,
+    pub review_state: SubjectReviewState,
+    pub subject: crate::api::Union<SubjectStatusViewSubjectUnion>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_blob_cids: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_repo_handle: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suspend_until: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub takendown: Option<bool>,
+    /// Timestamp referencing when the last update was made to the moderation status of the subject
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum SubjectStatusViewHostingUnion {
+    #[serde(rename = "tools.ozone.moderation.defs#accountHosting")]
+    AccountHosting(Box<AccountHosting>),
+    #[serde(rename = "tools.ozone.moderation.defs#recordHosting")]
+    RecordHosting(Box<RecordHosting>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum SubjectStatusViewSubjectUnion {
+    #[serde(rename = "com.atproto.admin.defs#repoRef")]
+    RepoRef(Box<crate::api::com::atproto::admin::defs::RepoRef>),
+    #[serde(
+        rename = "com.atproto.repo.strongRef",
+        alias = "com.atproto.repo.strongRef#main"
+    )]
+    StrongRef(Box<crate::api::com::atproto::repo::strong_ref::StrongRef>),
+    #[serde(rename = "chat.bsky.convo.defs#messageRef")]
+    MessageRef(Box<crate::api::chat::bsky::convo::defs::MessageRef>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoDetails {
+    pub height: i64,
+    pub length: i64,
+    pub width: i64,
+}
@@ -0,0 +1,115 @@
+//! `tools.ozone.moderation.emitEvent`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcError, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.moderation.emitEvent";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    pub created_by: String,
+    pub event: crate::api::Union<InputEventUnion>,
+    /// An optional external ID for the event, used to deduplicate events from external systems. Fails when an event of same type with the same external ID exists for the same subject.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    pub subject: crate::api::Union<InputSubjectUnion>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_blob_cids: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum InputEventUnion {
+    #[serde(rename = "tools.ozone.moderation.defs#modEventTakedown")]
+    ModEventTakedown(Box<crate::api::tools::ozone::moderation::defs::ModEventTakedown>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventAcknowledge")]
+    ModEventAcknowledge(Box<crate::api::tools::ozone::moderation::defs::ModEventAcknowledge>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventEscalate")]
+    ModEventEscalate(Box<crate::api::tools::ozone::moderation::defs::ModEventEscalate>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventComment")]
+    ModEventComment(Box<crate::api::tools::ozone::moderation::defs::ModEventComment>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventLabel")]
+    ModEventLabel(Box<crate::api::tools::ozone::moderation::defs::ModEventLabel>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventReport")]
+    ModEventReport(Box<crate::api::tools::ozone::moderation::defs::ModEventReport>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventMute")]
+    ModEventMute(Box<crate::api::tools::ozone::moderation::defs::ModEventMute>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventUnmute")]
+    ModEventUnmute(Box<crate::api::tools::ozone::moderation::defs::ModEventUnmute>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventMuteReporter")]
+    ModEventMuteReporter(Box<crate::api::tools::ozone::moderation::defs::ModEventMuteReporter>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventUnmuteReporter")]
+    ModEventUnmuteReporter(Box<crate::api::tools::ozone::moderation::defs::ModEventUnmuteReporter>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventReverseTakedown")]
+    ModEventReverseTakedown(
+        Box<crate::api::tools::ozone::moderation::defs::ModEventReverseTakedown>,
+    ),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventResolveAppeal")]
+    ModEventResolveAppeal(Box<crate::api::tools::ozone::moderation::defs::ModEventResolveAppeal>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventEmail")]
+    ModEventEmail(Box<crate::api::tools::ozone::moderation::defs::ModEventEmail>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventDivert")]
+    ModEventDivert(Box<crate::api::tools::ozone::moderation::defs::ModEventDivert>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventTag")]
+    ModEventTag(Box<crate::api::tools::ozone::moderation::defs::ModEventTag>),
+    #[serde(rename = "tools.ozone.moderation.defs#accountEvent")]
+    AccountEvent(Box<crate::api::tools::ozone::moderation::defs::AccountEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#identityEvent")]
+    IdentityEvent(Box<crate::api::tools::ozone::moderation::defs::IdentityEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#recordEvent")]
+    RecordEvent(Box<crate::api::tools::ozone::moderation::defs::RecordEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventPriorityScore")]
+    ModEventPriorityScore(Box<crate::api::tools::ozone::moderation::defs::ModEventPriorityScore>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum InputSubjectUnion {
+    #[serde(rename = "com.atproto.admin.defs#repoRef")]
+    RepoRef(Box<crate::api::com::atproto::admin::defs::RepoRef>),
+    #[serde(
+        rename = "com.atproto.repo.strongRef",
+        alias = "com.atproto.repo.strongRef#main"
+    )]
+    StrongRef(Box<crate::api::com::atproto::repo::strong_ref::StrongRef>),
+}
+
+pub type Output = crate::api::tools::ozone::moderation::defs::ModEventView;
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    SubjectHasAction,
+    /// An event with the same external ID already exists for the subject.
+    DuplicateExternalId,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "SubjectHasAction" => Some(Self::SubjectHasAction),
+            "DuplicateExternalId" => Some(Self::DuplicateExternalId),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::SubjectHasAction => "SubjectHasAction",
+            Self::DuplicateExternalId => "DuplicateExternalId",
+        }
+    }
+}
+
+/// Take a moderation action on an actor.
+pub async fn emit_event(
+    client: &XrpcClient,
+    input: &Input,
+) -> Result<Output, EndpointError<Error>> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    Ok(client.procedure(request).await?.data)
+}
@@ -0,0 +1,25 @@
+//! `tools.ozone.moderation.getEvent`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.moderation.getEvent";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub id: i64,
+}
+
+pub type Output = crate::api::tools::ozone::moderation::defs::ModEventViewDetail;
+
+/// Get details about a moderation event.
+pub async fn get_event(client: &XrpcClient, params: &Parameters) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.id;
+        request = request.param("id", value.to_string());
+    }
+    Ok(client.query(request).await?.data)
+}
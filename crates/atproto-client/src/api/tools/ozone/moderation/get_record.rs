@@ -0,0 +1,54 @@
+//! `tools.ozone.moderation.getRecord`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.moderation.getRecord";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+    pub uri: String,
+}
+
+pub type Output = crate::api::tools::ozone::moderation::defs::RecordViewDetail;
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    RecordNotFound,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "RecordNotFound" => Some(Self::RecordNotFound),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::RecordNotFound => "RecordNotFound",
+        }
+    }
+}
+
+/// Get details about a record.
+pub async fn get_record(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, EndpointError<Error>> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.cid {
+        request = request.param("cid", value.clone());
+    }
+    {
+        let value = &params.uri;
+        request = request.param("uri", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
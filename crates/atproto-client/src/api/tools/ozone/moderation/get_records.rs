@@ -0,0 +1,37 @@
+//! `tools.ozone.moderation.getRecords`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.moderation.getRecords";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub uris: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub records: Vec<crate::api::Union<OutputRecordsItem>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum OutputRecordsItem {
+    #[serde(rename = "tools.ozone.moderation.defs#recordViewDetail")]
+    RecordViewDetail(Box<crate::api::tools::ozone::moderation::defs::RecordViewDetail>),
+    #[serde(rename = "tools.ozone.moderation.defs#recordViewNotFound")]
+    RecordViewNotFound(Box<crate::api::tools::ozone::moderation::defs::RecordViewNotFound>),
+}
+
+/// Get details about some records.
+pub async fn get_records(client: &XrpcClient, params: &Parameters) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    for value in &params.uris {
+        request = request.param("uris", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,49 @@
+//! `tools.ozone.moderation.getRepo`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.moderation.getRepo";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub did: String,
+}
+
+pub type Output = crate::api::tools::ozone::moderation::defs::RepoViewDetail;
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    RepoNotFound,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "RepoNotFound" => Some(Self::RepoNotFound),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::RepoNotFound => "RepoNotFound",
+        }
+    }
+}
+
+/// Get details about a repository.
+pub async fn get_repo(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, EndpointError<Error>> {
+    let mut request = XrpcRequest::query(NSID);
+    {
+        let value = &params.did;
+        request = request.param("did", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
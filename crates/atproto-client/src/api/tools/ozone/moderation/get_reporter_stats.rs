@@ -0,0 +1,31 @@
+//! `tools.ozone.moderation.getReporterStats`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.moderation.getReporterStats";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub dids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub stats: Vec<crate::api::tools::ozone::moderation::defs::ReporterStats>,
+}
+
+/// Get reporter stats for a list of users.
+pub async fn get_reporter_stats(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    for value in &params.dids {
+        request = request.param("dids", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
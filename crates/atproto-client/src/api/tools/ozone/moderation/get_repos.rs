@@ -0,0 +1,37 @@
+//! `tools.ozone.moderation.getRepos`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.moderation.getRepos";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub dids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub repos: Vec<crate::api::Union<OutputReposItem>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum OutputReposItem {
+    #[serde(rename = "tools.ozone.moderation.defs#repoViewDetail")]
+    RepoViewDetail(Box<crate::api::tools::ozone::moderation::defs::RepoViewDetail>),
+    #[serde(rename = "tools.ozone.moderation.defs#repoViewNotFound")]
+    RepoViewNotFound(Box<crate::api::tools::ozone::moderation::defs::RepoViewNotFound>),
+}
+
+/// Get details about some repositories.
+pub async fn get_repos(client: &XrpcClient, params: &Parameters) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    for value in &params.dids {
+        request = request.param("dids", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,13 @@
+//! `tools.ozone.moderation.*`
+
+pub mod defs;
+pub mod emit_event;
+pub mod get_event;
+pub mod get_record;
+pub mod get_records;
+pub mod get_repo;
+pub mod get_reporter_stats;
+pub mod get_repos;
+pub mod query_events;
+pub mod query_statuses;
+pub mod search_repos;
@@ -0,0 +1,134 @@
+//! `tools.ozone.moderation.queryEvents`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.moderation.queryEvents";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// If specified, only events where all of these labels were added are returned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_labels: Option<Vec<String>>,
+    /// If specified, only events where all of these tags were added are returned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub added_tags: Option<Vec<String>>,
+    /// If specified, only events where the subject belongs to the given collections will be returned. When subjectType is set to 'account', this will be ignored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collections: Option<Vec<String>>,
+    /// If specified, only events with comments containing the keyword are returned. Apply || separator to use multiple keywords and match using OR condition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Retrieve events created after a given timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_after: Option<String>,
+    /// Retrieve events created before a given timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_before: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// If true, only events with comments are returned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_comment: Option<bool>,
+    /// If true, events on all record types (posts, lists, profile etc.) or records from given 'collections' param, owned by the did are returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_all_user_records: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policies: Option<Vec<String>>,
+    /// If specified, only events where all of these labels were removed are returned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub removed_labels: Option<Vec<String>>,
+    /// If specified, only events where all of these tags were removed are returned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub removed_tags: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub report_types: Option<Vec<String>>,
+    /// Sort direction for the events. Defaults to descending order of created at timestamp.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_direction: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// If specified, only events where the subject is of the given type (account or record) will be returned. When this is set to 'account' the 'collections' parameter will be ignored. When includeAllUserRecords or subject is set, this will be ignored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_type: Option<String>,
+    /// The types of events (fully qualified string in the format of tools.ozone.moderation.defs#modEvent<name>) to filter by. If not specified, all events are returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub types: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub events: Vec<crate::api::tools::ozone::moderation::defs::ModEventView>,
+}
+
+/// List moderation events related to a subject.
+pub async fn query_events(client: &XrpcClient, params: &Parameters) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    for value in params.added_labels.iter().flatten() {
+        request = request.param("addedLabels", value.clone());
+    }
+    for value in params.added_tags.iter().flatten() {
+        request = request.param("addedTags", value.clone());
+    }
+    for value in params.collections.iter().flatten() {
+        request = request.param("collections", value.clone());
+    }
+    if let Some(value) = &params.comment {
+        request = request.param("comment", value.clone());
+    }
+    if let Some(value) = &params.created_after {
+        request = request.param("createdAfter", value.clone());
+    }
+    if let Some(value) = &params.created_before {
+        request = request.param("createdBefore", value.clone());
+    }
+    if let Some(value) = &params.created_by {
+        request = request.param("createdBy", value.clone());
+    }
+    if let Some(value) = &params.cursor {
+        request = request.param("cursor", value.clone());
+    }
+    if let Some(value) = &params.has_comment {
+        request = request.param("hasComment", value.to_string());
+    }
+    if let Some(value) = &params.include_all_user_records {
+        request = request.param("includeAllUserRecords", value.to_string());
+    }
+    if let Some(value) = &params.limit {
+        request = request.param("limit", value.to_string());
+    }
+    for value in params.policies.iter().flatten() {
+        request = request.param("policies", value.clone());
+    }
+    for value in params.removed_labels.iter().flatten() {
+        request = request.param("removedLabels", value.clone());
+    }
+    for value in params.removed_tags.iter().flatten() {
+        request = request.param("removedTags", value.clone());
+    }
+    for value in params.report_types.iter().flatten() {
+        request = request.param("reportTypes", value.clone());
+    }
+    if let Some(value) = &params.sort_direction {
+        request = request.param("sortDirection", value.clone());
+    }
+    if let Some(value) = &params.subject {
+        request = request.param("subject", value.clone());
+    }
+    if let Some(value) = &params.subject_type {
+        request = request.param("subjectType", value.clone());
+    }
+    for value in params.types.iter().flatten() {
+        request = request.param("types", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,175 @@
+//! `tools.ozone.moderation.queryStatuses`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.moderation.queryStatuses";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    /// Get subjects in unresolved appealed status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub appealed: Option<bool>,
+    /// If specified, subjects belonging to the given collections will be returned. When subjectType is set to 'account', this will be ignored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collections: Option<Vec<String>>,
+    /// Search subjects by keyword from comments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclude_tags: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ignore_subjects: Option<Vec<String>>,
+    /// Number of queues being used by moderators. Subjects will be split among all queues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_all_user_records: Option<bool>,
+    /// By default, we don't include muted subjects in the results. Set this to true to include them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_muted: Option<bool>,
+    /// Get all subject statuses that were reviewed by a specific moderator
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_reviewed_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// If specified, only subjects that belong to an account that has at least this many suspensions will be returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_account_suspend_count: Option<i64>,
+    /// If specified, only subjects that have priority score value above the given value will be returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_priority_score: Option<i64>,
+    /// If specified, only subjects that belong to an account that has at least this many reported records will be returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_reported_records_count: Option<i64>,
+    /// If specified, only subjects that belong to an account that has at least this many taken down records will be returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_takendown_records_count: Option<i64>,
+    /// When set to true, only muted subjects and reporters will be returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub only_muted: Option<bool>,
+    /// Search subjects reported after a given timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reported_after: Option<String>,
+    /// Search subjects reported before a given timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reported_before: Option<String>,
+    /// Specify when fetching subjects in a certain state
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_state: Option<String>,
+    /// Search subjects reviewed after a given timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reviewed_after: Option<String>,
+    /// Search subjects reviewed before a given timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reviewed_before: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_direction: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_field: Option<String>,
+    /// The subject to get the status for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// If specified, subjects of the given type (account or record) will be returned. When this is set to 'account' the 'collections' parameter will be ignored. When includeAllUserRecords or subject is set, this will be ignored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Get subjects that were taken down
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub takendown: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub subject_statuses: Vec<crate::api::tools::ozone::moderation::defs::SubjectStatusView>,
+}
+
+/// View moderation statuses of subjects (record or repo).
+pub async fn query_statuses(client: &XrpcClient, params: &Parameters) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    if let Some(value) = &params.appealed {
+        request = request.param("appealed", value.to_string());
+    }
+    for value in params.collections.iter().flatten() {
+        request = request.param("collections", value.clone());
+    }
+    if let Some(value) = &params.comment {
+        request = request.param("comment", value.clone());
+    }
+    if let Some(value) = &params.cursor {
+        request = request.param("cursor", value.clone());
+    }
+    for value in params.exclude_tags.iter().flatten() {
+        request = request.param("excludeTags", value.clone());
+    }
+    for value in params.ignore_subjects.iter().flatten() {
+        request = request.param("ignoreSubjects", value.clone());
+    }
+    if let Some(value) = &params.include_all_user_records {
+        request = request.param("includeAllUserRecords", value.to_string());
+    }
+    if let Some(value) = &params.include_muted {
+        request = request.param("includeMuted", value.to_string());
+    }
+    if let Some(value) = &params.last_reviewed_by {
+        request = request.param("lastReviewedBy", value.clone());
+    }
+    if let Some(value) = &params.limit {
+        request = request.param("limit", value.to_string());
+    }
+    if let Some(value) = &params.min_account_suspend_count {
+        request = request.param("minAccountSuspendCount", value.to_string());
+    }
+    if let Some(value) = &params.min_priority_score {
+        request = request.param("minPriorityScore", value.to_string());
+    }
+    if let Some(value) = &params.min_reported_records_count {
+        request = request.param("minReportedRecordsCount", value.to_string());
+    }
+    if let Some(value) = &params.min_takendown_records_count {
+        request = request.param("minTakendownRecordsCount", value.to_string());
+    }
+    if let Some(value) = &params.only_muted {
+        request = request.param("onlyMuted", value.to_string());
+    }
+    if let Some(value) = &params.reported_after {
+        request = request.param("reportedAfter", value.clone());
+    }
+    if let Some(value) = &params.reported_before {
+        request = request.param("reportedBefore", value.clone());
+    }
+    if let Some(value) = &params.review_state {
+        request = request.param("reviewState", value.clone());
+    }
+    if let Some(value) = &params.reviewed_after {
+        request = request.param("reviewedAfter", value.clone());
+    }
+    if let Some(value) = &params.reviewed_before {
+        request = request.param("reviewedBefore", value.clone());
+    }
+    if let Some(value) = &params.sort_direction {
+        request = request.param("sortDirection", value.clone());
+    }
+    if let Some(value) = &params.sort_field {
+        request = request.param("sortField", value.clone());
+    }
+    if let Some(value) = &params.subject {
+        request = request.param("subject", value.clone());
+    }
+    if let Some(value) = &params.subject_type {
+        request = request.param("subjectType", value.clone());
+    }
+    for value in params.tags.iter().flatten() {
+        request = request.param("tags", value.clone());
+    }
+    if let Some(value) = &params.takendown {
+        request = request.param("takendown", value.to_string());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,45 @@
+//! `tools.ozone.server.getConfig`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.server.getConfig";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub appview: Option<ServiceConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blob_divert: Option<ServiceConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chat: Option<ServiceConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pds: Option<ServiceConfig>,
+    /// The did of the verifier used for verification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verifier_did: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<ViewerConfig>,
+}
+
+/// Get details about ozone's server configuration.
+pub async fn get_config(client: &XrpcClient) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::query(NSID);
+    Ok(client.query(request).await?.data)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewerConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+}
@@ -0,0 +1,3 @@
+//! `tools.ozone.server.*`
+
+pub mod get_config;
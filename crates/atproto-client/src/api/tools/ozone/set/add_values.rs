@@ -0,0 +1,25 @@
+//! `tools.ozone.set.addValues`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.set.addValues";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    /// Name of the set to add values to
+    pub name: String,
+    /// Array of string values to add to the set
+    pub values: Vec<String>,
+}
+
+/// Add values to a specific set. Attempting to add values to a set that does not exist will result in an error.
+pub async fn add_values(client: &XrpcClient, input: &Input) -> Result<(), XrpcError> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
@@ -0,0 +1,9 @@
+//! `tools.ozone.set.*`
+
+pub mod add_values;
+pub mod defs;
+pub mod delete_set;
+pub mod delete_values;
+pub mod get_values;
+pub mod query_sets;
+pub mod upsert_set;
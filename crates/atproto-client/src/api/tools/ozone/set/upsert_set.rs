@@ -0,0 +1,18 @@
+//! `tools.ozone.set.upsertSet`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.set.upsertSet";
+
+pub type Input = crate::api::tools::ozone::set::defs::Set;
+
+pub type Output = crate::api::tools::ozone::set::defs::SetView;
+
+/// Create or update set metadata
+pub async fn upsert_set(client: &XrpcClient, input: &Input) -> Result<Output, XrpcError> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    Ok(client.procedure(request).await?.data)
+}
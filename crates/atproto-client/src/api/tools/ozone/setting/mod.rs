@@ -0,0 +1,6 @@
+//! `tools.ozone.setting.*`
+
+pub mod defs;
+pub mod list_options;
+pub mod remove_options;
+pub mod upsert_option;
@@ -0,0 +1,13 @@
+//! `tools.ozone.signature.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.signature.defs";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SigDetail {
+    pub property: String,
+    pub value: String,
+}
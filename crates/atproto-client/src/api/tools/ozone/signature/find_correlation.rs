@@ -0,0 +1,31 @@
+//! `tools.ozone.signature.findCorrelation`
+
+use crate::xrpc::{XrpcClient, XrpcError, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.signature.findCorrelation";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameters {
+    pub dids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Output {
+    pub details: Vec<crate::api::tools::ozone::signature::defs::SigDetail>,
+}
+
+/// Find all correlated threat signatures between 2 or more accounts.
+pub async fn find_correlation(
+    client: &XrpcClient,
+    params: &Parameters,
+) -> Result<Output, XrpcError> {
+    let mut request = XrpcRequest::query(NSID);
+    for value in &params.dids {
+        request = request.param("dids", value.clone());
+    }
+    Ok(client.query(request).await?.data)
+}
@@ -0,0 +1,6 @@
+//! `tools.ozone.signature.*`
+
+pub mod defs;
+pub mod find_correlation;
+pub mod find_related_accounts;
+pub mod search_accounts;
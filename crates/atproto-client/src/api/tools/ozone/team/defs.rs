@@ -0,0 +1,35 @@
+//! `tools.ozone.team.defs`
+
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.team.defs";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Member {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    pub did: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_updated_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<crate::api::app::bsky::actor::defs::ProfileViewDetailed>,
+    pub role: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// Admin role. Highest level of access, can perform all actions.
+pub const ROLE_ADMIN: &str = "tools.ozone.team.defs#roleAdmin";
+
+/// Moderator role. Can perform most actions.
+pub const ROLE_MODERATOR: &str = "tools.ozone.team.defs#roleModerator";
+
+/// Triage role. Mostly intended for monitoring and escalating issues.
+pub const ROLE_TRIAGE: &str = "tools.ozone.team.defs#roleTriage";
+
+/// Verifier role. Only allowed to issue verifications.
+pub const ROLE_VERIFIER: &str = "tools.ozone.team.defs#roleVerifier";
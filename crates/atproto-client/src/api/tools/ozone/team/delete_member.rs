@@ -0,0 +1,48 @@
+//! `tools.ozone.team.deleteMember`
+
+use crate::xrpc::{EndpointError, XrpcClient, XrpcError, XrpcErrorKind, XrpcRequest};
+use serde::{Deserialize, Serialize};
+
+/// NSID of this lexicon
+pub const NSID: &str = "tools.ozone.team.deleteMember";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Input {
+    pub did: String,
+}
+
+/// Errors declared by this endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// The member being deleted does not exist
+    MemberNotFound,
+    /// You can not delete yourself from the team
+    CannotDeleteSelf,
+}
+
+impl XrpcErrorKind for Error {
+    fn from_error_name(name: &str) -> Option<Self> {
+        match name {
+            "MemberNotFound" => Some(Self::MemberNotFound),
+            "CannotDeleteSelf" => Some(Self::CannotDeleteSelf),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::MemberNotFound => "MemberNotFound",
+            Self::CannotDeleteSelf => "CannotDeleteSelf",
+        }
+    }
+}
+
+/// Delete a member from ozone team. Requires admin role.
+pub async fn delete_member(client: &XrpcClient, input: &Input) -> Result<(), EndpointError<Error>> {
+    let request = XrpcRequest::procedure(NSID)
+        .json_body(input)
+        .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+    client.procedure::<serde::de::IgnoredAny>(request).await?;
+    Ok(())
+}
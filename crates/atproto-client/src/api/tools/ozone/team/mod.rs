@@ -0,0 +1,7 @@
+//! `tools.ozone.team.*`
+
+pub mod add_member;
+pub mod defs;
+pub mod delete_member;
+pub mod list_members;
+pub mod update_member;
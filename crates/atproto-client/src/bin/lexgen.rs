@@ -0,0 +1,137 @@
+//! Generate Rust types and XRPC methods from Lexicon schemas
+//!
+//! ```text
+//! lexgen --out <dir> [--lexicons <dir>] [--root <module>] [--crate-path <path>] [prefix...]
+//! ```
+//!
+//! Without `--lexicons`, the lexicons bundled with the crate are used. Prefixes
+//! (e.g., `app.bsky.`) limit generation to matching NSIDs and the documents
+//! they reference. Generated files are formatted with `rustfmt` when it is
+//! installed.
+//!
+//! The crate's own `api` module is regenerated with:
+//!
+//! ```text
+//! cargo run -p atproto-client --bin lexgen -- --out crates/atproto-client/src/api
+//! ```
+
+use anyhow::{bail, Context};
+use atproto_client::lexicon::codegen::CodeGenerator;
+use atproto_client::lexicon::{LexiconDoc, SchemaRegistry};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const USAGE: &str = "Usage: lexgen --out <dir> [--lexicons <dir>] [--root <module>] \
+                     [--crate-path <path>] [prefix...]";
+
+struct Args {
+    out: PathBuf,
+    lexicons: Option<PathBuf>,
+    root: Option<String>,
+    crate_path: Option<String>,
+    prefixes: Vec<String>,
+}
+
+fn parse_args() -> anyhow::Result<Args> {
+    let mut out = None;
+    let mut lexicons = None;
+    let mut root = None;
+    let mut crate_path = None;
+    let mut prefixes = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .with_context(|| format!("{} needs a value", name))
+        };
+        match arg.as_str() {
+            "--out" => out = Some(PathBuf::from(value("--out")?)),
+            "--lexicons" => lexicons = Some(PathBuf::from(value("--lexicons")?)),
+            "--root" => root = Some(value("--root")?),
+            "--crate-path" => crate_path = Some(value("--crate-path")?),
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            flag if flag.starts_with('-') => bail!("Unknown option {}\n{}", flag, USAGE),
+            prefix => prefixes.push(prefix.to_string()),
+        }
+    }
+
+    let out = out.with_context(|| format!("--out is required\n{}", USAGE))?;
+    Ok(Args { out, lexicons, root, crate_path, prefixes })
+}
+
+/// Load every `.json` file under a directory
+fn load_dir(dir: &Path, registry: &mut SchemaRegistry) -> anyhow::Result<()> {
+    for entry in fs::read_dir(dir).with_context(|| format!("Reading {}", dir.display()))? {
+        let path = entry?.path();
+        if path.is_dir() {
+            load_dir(&path, registry)?;
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            let doc = LexiconDoc::from_file(&path)
+                .with_context(|| format!("Parsing {}", path.display()))?;
+            registry.register(doc);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "builtin-lexicons")]
+fn builtin_registry() -> anyhow::Result<SchemaRegistry> {
+    Ok(SchemaRegistry::with_builtin())
+}
+
+#[cfg(not(feature = "builtin-lexicons"))]
+fn builtin_registry() -> anyhow::Result<SchemaRegistry> {
+    bail!("--lexicons is required without the builtin-lexicons feature")
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = parse_args()?;
+
+    let registry = match &args.lexicons {
+        Some(dir) => {
+            let mut registry = SchemaRegistry::new();
+            load_dir(dir, &mut registry)?;
+            registry
+        }
+        None => builtin_registry()?,
+    };
+
+    let mut generator = CodeGenerator::new(&registry);
+    if let Some(root) = args.root {
+        generator = generator.with_root_module(root);
+    }
+    if let Some(crate_path) = args.crate_path {
+        generator = generator.with_crate_path(crate_path);
+    }
+
+    let prefixes: Vec<&str> = args.prefixes.iter().map(String::as_str).collect();
+    let files = generator.generate(&prefixes)?;
+
+    let mut written = Vec::with_capacity(files.len());
+    for file in &files {
+        let path = args.out.join(&file.path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, &file.contents).with_context(|| format!("Writing {}", path.display()))?;
+        written.push(path);
+    }
+
+    // Formatting is best effort; unformatted output still compiles
+    let formatted = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .args(&written)
+        .status()
+        .is_ok_and(|status| status.success());
+    if !formatted {
+        eprintln!("rustfmt failed or is not installed; output is unformatted");
+    }
+
+    println!("Generated {} files in {}", written.len(), args.out.display());
+    Ok(())
+}
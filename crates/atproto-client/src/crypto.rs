@@ -0,0 +1,309 @@
+//! Public keys and signature verification for AT Protocol
+//!
+//! AT Protocol signs repository commits with either a P-256 (`p256`) or a
+//! secp256k1 (`k256`) key. Public keys are published in DID documents and
+//! `did:key` identifiers as multikeys: a multicodec-prefixed compressed key,
+//! multibase-encoded with base58btc (`z...`).
+//!
+//! Signatures are 64-byte `r || s` ECDSA signatures over the SHA-256 hash of
+//! the message, and must be in low-S form.
+//!
+//! Reference: <https://atproto.com/specs/cryptography>
+//!
+//! # Example
+//!
+//! ```rust
+//! use atproto_client::crypto::{KeyAlgorithm, PublicKey};
+//!
+//! let key = PublicKey::from_did_key(
+//!     "did:key:zQ3shqwJEJyMBsBXCWyCBpUBMqxcon9oHB7mCvx4sSpMdLJwc",
+//! ).unwrap();
+//! assert_eq!(key.algorithm(), KeyAlgorithm::K256);
+//! ```
+
+use crate::identity::{DidDocument, VerificationMethod};
+use thiserror::Error;
+
+/// Multicodec prefix for compressed P-256 public keys (0x1200, varint encoded)
+const P256_MULTICODEC: [u8; 2] = [0x80, 0x24];
+
+/// Multicodec prefix for compressed secp256k1 public keys (0xe7, varint encoded)
+const K256_MULTICODEC: [u8; 2] = [0xe7, 0x01];
+
+/// Errors that can occur while decoding keys or verifying signatures
+#[derive(Debug, Error)]
+pub enum CryptoError {
+    /// The key is not valid multibase
+    #[error("Invalid multibase: {0}")]
+    InvalidMultibase(String),
+
+    /// The key type or multicodec is not supported
+    #[error("Unsupported key type: {0}")]
+    UnsupportedKeyType(String),
+
+    /// The key bytes are not a valid curve point
+    #[error("Invalid public key: {0}")]
+    InvalidKey(String),
+
+    /// The signature is malformed
+    #[error("Invalid signature: {0}")]
+    InvalidSignature(String),
+
+    /// The signature is valid but not in low-S form
+    #[error("Signature is not in low-S form")]
+    HighS,
+
+    /// The signature does not match the message and key
+    #[error("Signature verification failed")]
+    VerificationFailed,
+}
+
+/// Result type for crypto operations
+pub type Result<T> = std::result::Result<T, CryptoError>;
+
+/// Elliptic curve of a key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAlgorithm {
+    /// NIST P-256 (`ES256`)
+    P256,
+    /// secp256k1 (`ES256K`)
+    K256,
+}
+
+/// A public key used to verify AT Protocol signatures
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PublicKey {
+    /// P-256 key
+    P256(p256::ecdsa::VerifyingKey),
+    /// secp256k1 key
+    K256(k256::ecdsa::VerifyingKey),
+}
+
+impl PublicKey {
+    /// Decode a multikey (e.g., "zDnae...")
+    ///
+    /// # Arguments
+    ///
+    /// * `multikey` - base58btc multibase string of a multicodec-prefixed key
+    pub fn from_multikey(multikey: &str) -> Result<Self> {
+        let bytes = decode_multibase(multikey)?;
+        if let Some(key) = bytes.strip_prefix(&P256_MULTICODEC) {
+            Self::from_sec1_bytes(KeyAlgorithm::P256, key)
+        } else if let Some(key) = bytes.strip_prefix(&K256_MULTICODEC) {
+            Self::from_sec1_bytes(KeyAlgorithm::K256, key)
+        } else {
+            Err(CryptoError::UnsupportedKeyType(format!(
+                "Unknown multicodec prefix in {}",
+                multikey
+            )))
+        }
+    }
+
+    /// Decode a `did:key` identifier
+    pub fn from_did_key(did: &str) -> Result<Self> {
+        let multikey = did
+            .strip_prefix("did:key:")
+            .ok_or_else(|| CryptoError::UnsupportedKeyType(format!("Not a did:key: {}", did)))?;
+        Self::from_multikey(multikey)
+    }
+
+    /// Decode the key of a DID document verification method
+    ///
+    /// Supports `Multikey` as well as the legacy
+    /// `EcdsaSecp256r1VerificationKey2019` and
+    /// `EcdsaSecp256k1VerificationKey2019` types, whose multibase value holds
+    /// the key without a multicodec prefix.
+    pub fn from_verification_method(method: &VerificationMethod) -> Result<Self> {
+        let value = method.public_key_multibase.as_deref().ok_or_else(|| {
+            CryptoError::InvalidKey(format!("{} has no publicKeyMultibase", method.id))
+        })?;
+
+        match method.key_type.as_str() {
+            "Multikey" => Self::from_multikey(value),
+            "EcdsaSecp256r1VerificationKey2019" => {
+                Self::from_sec1_bytes(KeyAlgorithm::P256, &decode_multibase(value)?)
+            }
+            "EcdsaSecp256k1VerificationKey2019" => {
+                Self::from_sec1_bytes(KeyAlgorithm::K256, &decode_multibase(value)?)
+            }
+            other => Err(CryptoError::UnsupportedKeyType(other.to_string())),
+        }
+    }
+
+    /// Get the `#atproto` signing key of a DID document
+    pub fn from_did_document(document: &DidDocument) -> Result<Self> {
+        let method = document.signing_method().ok_or_else(|| {
+            CryptoError::InvalidKey(format!("{} has no #atproto signing key", document.id))
+        })?;
+        Self::from_verification_method(method)
+    }
+
+    /// Decode a SEC1-encoded (compressed or uncompressed) key
+    pub fn from_sec1_bytes(algorithm: KeyAlgorithm, bytes: &[u8]) -> Result<Self> {
+        match algorithm {
+            KeyAlgorithm::P256 => p256::ecdsa::VerifyingKey::from_sec1_bytes(bytes)
+                .map(Self::P256)
+                .map_err(|e| CryptoError::InvalidKey(e.to_string())),
+            KeyAlgorithm::K256 => k256::ecdsa::VerifyingKey::from_sec1_bytes(bytes)
+                .map(Self::K256)
+                .map_err(|e| CryptoError::InvalidKey(e.to_string())),
+        }
+    }
+
+    /// Get the curve of the key
+    pub fn algorithm(&self) -> KeyAlgorithm {
+        match self {
+            Self::P256(_) => KeyAlgorithm::P256,
+            Self::K256(_) => KeyAlgorithm::K256,
+        }
+    }
+
+    /// Encode as a multikey
+    pub fn to_multikey(&self) -> String {
+        let (prefix, key) = match self {
+            Self::P256(key) => (P256_MULTICODEC, key.to_encoded_point(true).as_bytes().to_vec()),
+            Self::K256(key) => (K256_MULTICODEC, key.to_encoded_point(true).as_bytes().to_vec()),
+        };
+        let mut bytes = prefix.to_vec();
+        bytes.extend_from_slice(&key);
+        multibase::encode(multibase::Base::Base58Btc, bytes)
+    }
+
+    /// Encode as a `did:key` identifier
+    pub fn to_did_key(&self) -> String {
+        format!("did:key:{}", self.to_multikey())
+    }
+
+    /// Verify a signature over a message
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The signed bytes (hashed with SHA-256 during verification)
+    /// * `signature` - 64-byte `r || s` signature, which must be low-S
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> Result<()> {
+        use p256::ecdsa::signature::Verifier;
+
+        match self {
+            Self::P256(key) => {
+                let signature = p256::ecdsa::Signature::from_slice(signature)
+                    .map_err(|e| CryptoError::InvalidSignature(e.to_string()))?;
+                if signature.normalize_s().is_some() {
+                    return Err(CryptoError::HighS);
+                }
+                key.verify(message, &signature)
+            }
+            Self::K256(key) => {
+                let signature = k256::ecdsa::Signature::from_slice(signature)
+                    .map_err(|e| CryptoError::InvalidSignature(e.to_string()))?;
+                if signature.normalize_s().is_some() {
+                    return Err(CryptoError::HighS);
+                }
+                key.verify(message, &signature)
+            }
+        }
+        .map_err(|_| CryptoError::VerificationFailed)
+    }
+}
+
+fn decode_multibase(value: &str) -> Result<Vec<u8>> {
+    if !value.starts_with('z') {
+        return Err(CryptoError::InvalidMultibase(format!(
+            "Expected base58btc ('z' prefix): {}",
+            value
+        )));
+    }
+    multibase::decode(value)
+        .map(|(_, bytes)| bytes)
+        .map_err(|e| CryptoError::InvalidMultibase(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::signature::Signer;
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn test_multikey_roundtrip() {
+        let p256_key =
+            PublicKey::P256(*p256::ecdsa::SigningKey::random(&mut OsRng).verifying_key());
+        let multikey = p256_key.to_multikey();
+        assert!(multikey.starts_with("zDna"));
+        assert_eq!(PublicKey::from_multikey(&multikey).unwrap(), p256_key);
+
+        let k256_key =
+            PublicKey::K256(*k256::ecdsa::SigningKey::random(&mut OsRng).verifying_key());
+        let did = k256_key.to_did_key();
+        assert!(did.starts_with("did:key:zQ3s"));
+        assert_eq!(PublicKey::from_did_key(&did).unwrap(), k256_key);
+    }
+
+    #[test]
+    fn test_invalid_keys() {
+        assert!(matches!(
+            PublicKey::from_multikey("mAAAA"),
+            Err(CryptoError::InvalidMultibase(_))
+        ));
+        assert!(matches!(
+            PublicKey::from_multikey(&multibase::encode(multibase::Base::Base58Btc, [0xed, 0x01])),
+            Err(CryptoError::UnsupportedKeyType(_))
+        ));
+        assert!(matches!(
+            PublicKey::from_multikey(&multibase::encode(
+                multibase::Base::Base58Btc,
+                [0xe7, 0x01, 0x02, 0x03]
+            )),
+            Err(CryptoError::InvalidKey(_))
+        ));
+    }
+
+    #[test]
+    fn test_legacy_verification_method() {
+        let signing_key = k256::ecdsa::SigningKey::random(&mut OsRng);
+        let uncompressed = signing_key.verifying_key().to_encoded_point(false);
+        let method = VerificationMethod {
+            id: "did:plc:alice#atproto".to_string(),
+            key_type: "EcdsaSecp256k1VerificationKey2019".to_string(),
+            controller: "did:plc:alice".to_string(),
+            public_key_multibase: Some(multibase::encode(
+                multibase::Base::Base58Btc,
+                uncompressed.as_bytes(),
+            )),
+        };
+
+        let key = PublicKey::from_verification_method(&method).unwrap();
+        assert_eq!(key, PublicKey::K256(*signing_key.verifying_key()));
+    }
+
+    #[test]
+    fn test_verify_signatures() {
+        let message = b"signed bytes";
+
+        let p256_signing = p256::ecdsa::SigningKey::random(&mut OsRng);
+        let p256_sig: p256::ecdsa::Signature = p256_signing.sign(message);
+        let p256_sig = p256_sig.normalize_s().unwrap_or(p256_sig);
+        let p256_key = PublicKey::P256(*p256_signing.verifying_key());
+        p256_key.verify(message, &p256_sig.to_bytes()).unwrap();
+        assert!(matches!(
+            p256_key.verify(b"other bytes", &p256_sig.to_bytes()),
+            Err(CryptoError::VerificationFailed)
+        ));
+
+        // The high-S twin of a valid signature is rejected
+        let (r, s) = p256_sig.split_scalars();
+        let high = p256::ecdsa::Signature::from_scalars(r, -*s).unwrap();
+        assert!(matches!(p256_key.verify(message, &high.to_bytes()), Err(CryptoError::HighS)));
+
+        let k256_signing = k256::ecdsa::SigningKey::random(&mut OsRng);
+        let k256_sig: k256::ecdsa::Signature = k256_signing.sign(message);
+        let k256_key = PublicKey::K256(*k256_signing.verifying_key());
+        k256_key.verify(message, &k256_sig.to_bytes()).unwrap();
+
+        // Keys don't verify signatures from the other curve
+        assert!(p256_key.verify(message, &k256_sig.to_bytes()).is_err());
+        assert!(matches!(
+            k256_key.verify(message, &[0u8; 10]),
+            Err(CryptoError::InvalidSignature(_))
+        ));
+    }
+}
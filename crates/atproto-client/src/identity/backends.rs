@@ -0,0 +1,446 @@
+//! Identity resolution backends
+//!
+//! Network implementations of [`HandleResolver`] and [`DidResolver`]:
+//! - [`DnsHandleResolver`] reads `_atproto.<handle>` TXT records
+//! - [`WellKnownHandleResolver`] fetches `https://<handle>/.well-known/atproto-did`
+//! - [`PlcDidResolver`] looks up `did:plc` DIDs in a PLC directory
+//! - [`WebDidResolver`] fetches `did:web` documents from `/.well-known/did.json`
+//! - [`DefaultDidResolver`] dispatches to the PLC or web resolver by DID method
+
+use super::{DidDocument, DidResolver, HandleResolver, IdentityError, Result};
+use crate::types::{Did, Handle};
+use async_trait::async_trait;
+use hickory_resolver::error::ResolveErrorKind;
+use hickory_resolver::TokioAsyncResolver;
+use reqwest::{Client as ReqwestClient, StatusCode};
+use std::time::Duration;
+
+/// Default PLC directory URL
+pub const DEFAULT_PLC_DIRECTORY: &str = "https://plc.directory";
+
+/// Build the HTTP client shared by the HTTPS-based backends
+pub(super) fn build_http_client(timeout: Duration) -> ReqwestClient {
+    ReqwestClient::builder()
+        .timeout(timeout)
+        .user_agent(format!("Aurora-Compass/{}", env!("CARGO_PKG_VERSION")))
+        .build()
+        .expect("Failed to build HTTP client")
+}
+
+/// Extract the DID from `_atproto` TXT record values
+///
+/// Exactly one `did=` record must be present; multiple conflicting records
+/// are treated as an error per the handle specification.
+pub(crate) fn parse_dns_txt_records(records: &[String]) -> Result<Option<Did>> {
+    let dids: Vec<&str> = records
+        .iter()
+        .filter_map(|r| r.trim().strip_prefix("did="))
+        .collect();
+
+    match dids.as_slice() {
+        [] => Ok(None),
+        [did] => Did::new(*did)
+            .map(Some)
+            .map_err(|e| IdentityError::InvalidIdentifier(e.to_string())),
+        _ => Err(IdentityError::Dns("Multiple did= TXT records found".to_string())),
+    }
+}
+
+/// Extract the DID from a `/.well-known/atproto-did` response body
+pub(crate) fn parse_well_known_body(body: &str) -> Result<Option<Did>> {
+    let line = body.lines().next().unwrap_or("").trim();
+    if !line.starts_with("did:") {
+        return Ok(None);
+    }
+
+    Did::new(line)
+        .map(Some)
+        .map_err(|e| IdentityError::InvalidIdentifier(e.to_string()))
+}
+
+/// Build the document URL for a `did:web` DID
+///
+/// Only hostname-level `did:web` DIDs are supported by AT Protocol. A
+/// percent-encoded port (`%3A`) is decoded, and `localhost` is served over
+/// plain HTTP to allow local development.
+pub(crate) fn did_web_url(did: &Did) -> Result<String> {
+    let identifier = did.identifier();
+    if identifier.contains(':') {
+        return Err(IdentityError::InvalidIdentifier(format!(
+            "Path-based did:web is not supported: {}",
+            did
+        )));
+    }
+
+    let host = identifier.replace("%3A", ":").replace("%3a", ":");
+    let scheme = if host == "localhost" || host.starts_with("localhost:") {
+        "http"
+    } else {
+        "https"
+    };
+
+    Ok(format!("{}://{}/.well-known/did.json", scheme, host))
+}
+
+/// Fetch and decode a DID document, mapping missing documents to `DidNotFound`
+async fn fetch_did_document(client: &ReqwestClient, url: &str, did: &Did) -> Result<DidDocument> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| IdentityError::Network(format!("Request failed: {}", e)))?;
+
+    match response.status() {
+        status if status.is_success() => {}
+        StatusCode::NOT_FOUND | StatusCode::GONE => {
+            return Err(IdentityError::DidNotFound(did.to_string()));
+        }
+        status => {
+            return Err(IdentityError::Network(format!(
+                "HTTP {} fetching DID document for {}",
+                status.as_u16(),
+                did
+            )));
+        }
+    }
+
+    let doc: DidDocument = response
+        .json()
+        .await
+        .map_err(|e| IdentityError::InvalidDocument(e.to_string()))?;
+
+    if doc.id != did.as_str() {
+        return Err(IdentityError::InvalidDocument(format!(
+            "Document id {} does not match {}",
+            doc.id, did
+        )));
+    }
+
+    Ok(doc)
+}
+
+/// Resolves handles via `_atproto.<handle>` DNS TXT records
+pub struct DnsHandleResolver {
+    resolver: TokioAsyncResolver,
+}
+
+impl DnsHandleResolver {
+    /// Create a resolver using the system DNS configuration
+    ///
+    /// Falls back to the default public resolvers if the system
+    /// configuration cannot be read.
+    pub fn new() -> Self {
+        let resolver = TokioAsyncResolver::tokio_from_system_conf()
+            .unwrap_or_else(|_| TokioAsyncResolver::tokio(Default::default(), Default::default()));
+
+        Self { resolver }
+    }
+
+    /// Create a resolver from an existing hickory resolver
+    pub fn with_resolver(resolver: TokioAsyncResolver) -> Self {
+        Self { resolver }
+    }
+}
+
+impl Default for DnsHandleResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl HandleResolver for DnsHandleResolver {
+    async fn resolve_handle(&self, handle: &Handle) -> Result<Option<Did>> {
+        let name = format!("_atproto.{}.", handle.as_str());
+
+        let lookup = match self.resolver.txt_lookup(name).await {
+            Ok(lookup) => lookup,
+            Err(e) => {
+                return match e.kind() {
+                    ResolveErrorKind::NoRecordsFound { .. } => Ok(None),
+                    _ => Err(IdentityError::Dns(e.to_string())),
+                };
+            }
+        };
+
+        let records: Vec<String> = lookup
+            .iter()
+            .map(|txt| {
+                txt.txt_data()
+                    .iter()
+                    .map(|part| String::from_utf8_lossy(part))
+                    .collect::<String>()
+            })
+            .collect();
+
+        parse_dns_txt_records(&records)
+    }
+}
+
+/// Resolves handles via `https://<handle>/.well-known/atproto-did`
+#[derive(Debug, Clone)]
+pub struct WellKnownHandleResolver {
+    client: ReqwestClient,
+}
+
+impl WellKnownHandleResolver {
+    /// Create a new resolver with the given request timeout
+    pub fn new(timeout: Duration) -> Self {
+        Self { client: build_http_client(timeout) }
+    }
+}
+
+#[async_trait]
+impl HandleResolver for WellKnownHandleResolver {
+    async fn resolve_handle(&self, handle: &Handle) -> Result<Option<Did>> {
+        let url = format!("https://{}/.well-known/atproto-did", handle.as_str());
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| IdentityError::Network(format!("Request failed: {}", e)))?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(IdentityError::Network(format!(
+                "HTTP {} from {}",
+                response.status().as_u16(),
+                url
+            )));
+        }
+
+        let body = response
+            .text()
+            .await
+            .map_err(|e| IdentityError::Network(format!("Failed to read response: {}", e)))?;
+
+        parse_well_known_body(&body)
+    }
+}
+
+/// Resolves `did:plc` DIDs against a PLC directory
+#[derive(Debug, Clone)]
+pub struct PlcDidResolver {
+    client: ReqwestClient,
+    directory_url: String,
+}
+
+impl PlcDidResolver {
+    /// Create a new resolver for the given PLC directory
+    ///
+    /// # Arguments
+    ///
+    /// * `directory_url` - PLC directory base URL (e.g., "https://plc.directory")
+    /// * `timeout` - Request timeout
+    pub fn new(directory_url: impl Into<String>, timeout: Duration) -> Self {
+        Self {
+            client: build_http_client(timeout),
+            directory_url: directory_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Get the PLC directory URL
+    pub fn directory_url(&self) -> &str {
+        &self.directory_url
+    }
+}
+
+#[async_trait]
+impl DidResolver for PlcDidResolver {
+    async fn resolve_did(&self, did: &Did) -> Result<DidDocument> {
+        if did.method() != "plc" {
+            return Err(IdentityError::UnsupportedDidMethod(did.method().to_string()));
+        }
+
+        let url = format!("{}/{}", self.directory_url, did.as_str());
+        fetch_did_document(&self.client, &url, did).await
+    }
+}
+
+/// Resolves `did:web` DIDs from `/.well-known/did.json`
+#[derive(Debug, Clone)]
+pub struct WebDidResolver {
+    client: ReqwestClient,
+}
+
+impl WebDidResolver {
+    /// Create a new resolver with the given request timeout
+    pub fn new(timeout: Duration) -> Self {
+        Self { client: build_http_client(timeout) }
+    }
+}
+
+#[async_trait]
+impl DidResolver for WebDidResolver {
+    async fn resolve_did(&self, did: &Did) -> Result<DidDocument> {
+        if did.method() != "web" {
+            return Err(IdentityError::UnsupportedDidMethod(did.method().to_string()));
+        }
+
+        let url = did_web_url(did)?;
+        fetch_did_document(&self.client, &url, did).await
+    }
+}
+
+/// DID resolver that dispatches on the DID method
+#[derive(Debug, Clone)]
+pub struct DefaultDidResolver {
+    plc: PlcDidResolver,
+    web: WebDidResolver,
+}
+
+impl DefaultDidResolver {
+    /// Create a new resolver
+    ///
+    /// # Arguments
+    ///
+    /// * `plc_directory_url` - PLC directory used for `did:plc` lookups
+    /// * `timeout` - Request timeout
+    pub fn new(plc_directory_url: impl Into<String>, timeout: Duration) -> Self {
+        Self {
+            plc: PlcDidResolver::new(plc_directory_url, timeout),
+            web: WebDidResolver::new(timeout),
+        }
+    }
+}
+
+#[async_trait]
+impl DidResolver for DefaultDidResolver {
+    async fn resolve_did(&self, did: &Did) -> Result<DidDocument> {
+        match did.method() {
+            "plc" => self.plc.resolve_did(did).await,
+            "web" => self.web.resolve_did(did).await,
+            method => Err(IdentityError::UnsupportedDidMethod(method.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_parse_dns_txt_records() {
+        let records = vec!["did=did:plc:abc123".to_string()];
+        assert_eq!(parse_dns_txt_records(&records).unwrap().unwrap().as_str(), "did:plc:abc123");
+
+        let records = vec!["v=spf1 -all".to_string()];
+        assert!(parse_dns_txt_records(&records).unwrap().is_none());
+
+        let records = vec!["did=did:plc:abc123".to_string(), "did=did:plc:def456".to_string()];
+        assert!(matches!(parse_dns_txt_records(&records), Err(IdentityError::Dns(_))));
+    }
+
+    #[test]
+    fn test_parse_well_known_body() {
+        assert_eq!(
+            parse_well_known_body("did:plc:abc123\n")
+                .unwrap()
+                .unwrap()
+                .as_str(),
+            "did:plc:abc123"
+        );
+        assert!(parse_well_known_body("<html>not found</html>")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_did_web_url() {
+        let did = Did::new("did:web:example.com").unwrap();
+        assert_eq!(did_web_url(&did).unwrap(), "https://example.com/.well-known/did.json");
+
+        let did = Did::new("did:web:localhost%3A8080").unwrap();
+        assert_eq!(did_web_url(&did).unwrap(), "http://localhost:8080/.well-known/did.json");
+
+        let did = Did::new("did:web:example.com:users:alice").unwrap();
+        assert!(did_web_url(&did).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_plc_resolver_fetches_document() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/did:plc:abc123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "did:plc:abc123",
+                "alsoKnownAs": ["at://alice.example.com"],
+                "service": [{
+                    "id": "#atproto_pds",
+                    "type": "AtprotoPersonalDataServer",
+                    "serviceEndpoint": "https://pds.example.com"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = PlcDidResolver::new(mock_server.uri(), Duration::from_secs(5));
+        let did = Did::new("did:plc:abc123").unwrap();
+        let doc = resolver.resolve_did(&did).await.unwrap();
+
+        assert_eq!(doc.pds_endpoint(), Some("https://pds.example.com"));
+    }
+
+    #[tokio::test]
+    async fn test_plc_resolver_not_found() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let resolver = PlcDidResolver::new(mock_server.uri(), Duration::from_secs(5));
+        let did = Did::new("did:plc:missing").unwrap();
+        let result = resolver.resolve_did(&did).await;
+
+        assert!(matches!(result, Err(IdentityError::DidNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_plc_resolver_rejects_mismatched_document() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "id": "did:plc:someoneelse" })),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let resolver = PlcDidResolver::new(mock_server.uri(), Duration::from_secs(5));
+        let did = Did::new("did:plc:abc123").unwrap();
+        let result = resolver.resolve_did(&did).await;
+
+        assert!(matches!(result, Err(IdentityError::InvalidDocument(_))));
+    }
+
+    #[tokio::test]
+    async fn test_web_resolver_localhost() {
+        let mock_server = MockServer::start().await;
+        let port = mock_server.address().port();
+        let did_str = format!("did:web:localhost%3A{}", port);
+
+        Mock::given(method("GET"))
+            .and(path("/.well-known/did.json"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": did_str })),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let resolver = WebDidResolver::new(Duration::from_secs(5));
+        let did = Did::new(did_str.clone()).unwrap();
+        let doc = resolver.resolve_did(&did).await.unwrap();
+
+        assert_eq!(doc.id, did_str);
+    }
+}
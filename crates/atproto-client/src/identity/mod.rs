@@ -0,0 +1,298 @@
+//! AT Protocol Identity Resolution
+//!
+//! This module implements handle and DID resolution for AT Protocol, including:
+//! - Handle to DID resolution via DNS TXT `_atproto` records
+//! - Handle to DID resolution via HTTPS `/.well-known/atproto-did`
+//! - DID document lookups for `did:plc` (PLC directory) and `did:web`
+//! - Bidirectional handle verification
+//! - TTL caching of resolution results
+//!
+//! Reference: <https://atproto.com/specs/handle> and <https://atproto.com/specs/did>
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use atproto_client::identity::IdentityResolver;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let resolver = IdentityResolver::default();
+//!
+//!     // Resolve a handle to its DID, verified DID document and PDS
+//!     let identity = resolver.resolve("alice.example.com").await?;
+//!     println!("DID: {}", identity.did);
+//!     println!("PDS: {:?}", identity.pds_endpoint);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod backends;
+mod resolver;
+
+pub use backends::{
+    DefaultDidResolver, DnsHandleResolver, PlcDidResolver, WebDidResolver, WellKnownHandleResolver,
+    DEFAULT_PLC_DIRECTORY,
+};
+pub use resolver::{IdentityResolver, IdentityResolverConfig};
+
+use crate::types::{Did, Handle};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Errors that can occur during identity resolution
+#[derive(Debug, Error)]
+pub enum IdentityError {
+    /// The identifier is neither a valid handle nor a valid DID
+    #[error("Invalid identifier: {0}")]
+    InvalidIdentifier(String),
+
+    /// No DID could be found for the handle
+    #[error("Handle not found: {0}")]
+    HandleNotFound(String),
+
+    /// The DID document could not be found
+    #[error("DID not found: {0}")]
+    DidNotFound(String),
+
+    /// The DID method is not supported by the resolver
+    #[error("Unsupported DID method: {0}")]
+    UnsupportedDidMethod(String),
+
+    /// The DID document is malformed or does not match the requested DID
+    #[error("Invalid DID document: {0}")]
+    InvalidDocument(String),
+
+    /// The handle and DID document do not point at each other
+    #[error("Handle {handle} is not claimed by {did}")]
+    HandleMismatch {
+        /// The handle that was resolved
+        handle: String,
+        /// The DID the handle resolved to
+        did: String,
+    },
+
+    /// DNS lookup failure
+    #[error("DNS error: {0}")]
+    Dns(String),
+
+    /// HTTP request failure
+    #[error("Network error: {0}")]
+    Network(String),
+}
+
+/// Result type for identity operations
+pub type Result<T> = std::result::Result<T, IdentityError>;
+
+/// A DID document
+///
+/// Only the fields used by AT Protocol are modelled: the handle (`alsoKnownAs`),
+/// the signing key (`verificationMethod`) and the PDS endpoint (`service`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+    /// JSON-LD context
+    #[serde(rename = "@context", skip_serializing_if = "Option::is_none")]
+    pub context: Option<serde_json::Value>,
+
+    /// The DID this document describes
+    pub id: String,
+
+    /// Alternative identifiers (`at://` handle URIs)
+    #[serde(default)]
+    pub also_known_as: Vec<String>,
+
+    /// Verification methods (public keys)
+    #[serde(default)]
+    pub verification_method: Vec<VerificationMethod>,
+
+    /// Service endpoints
+    #[serde(default)]
+    pub service: Vec<DidService>,
+}
+
+/// A public key entry in a DID document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+    /// Key identifier (e.g., "did:plc:abc123#atproto")
+    pub id: String,
+
+    /// Key type (e.g., "Multikey")
+    #[serde(rename = "type")]
+    pub key_type: String,
+
+    /// Controlling DID
+    pub controller: String,
+
+    /// Multibase-encoded public key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key_multibase: Option<String>,
+}
+
+/// A service entry in a DID document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidService {
+    /// Service identifier (e.g., "#atproto_pds")
+    pub id: String,
+
+    /// Service type (e.g., "AtprotoPersonalDataServer")
+    #[serde(rename = "type")]
+    pub service_type: String,
+
+    /// Service endpoint URL
+    pub service_endpoint: String,
+}
+
+impl DidDocument {
+    /// Get the handle claimed by this document
+    ///
+    /// Returns the first `at://` entry in `alsoKnownAs`, without the scheme.
+    pub fn handle(&self) -> Option<&str> {
+        self.also_known_as
+            .iter()
+            .find_map(|aka| aka.strip_prefix("at://"))
+    }
+
+    /// Check whether this document claims the given handle
+    pub fn claims_handle(&self, handle: &str) -> bool {
+        self.also_known_as
+            .iter()
+            .filter_map(|aka| aka.strip_prefix("at://"))
+            .any(|h| h.eq_ignore_ascii_case(handle))
+    }
+
+    /// Get the PDS endpoint URL
+    ///
+    /// Looks for the `#atproto_pds` service of type `AtprotoPersonalDataServer`.
+    pub fn pds_endpoint(&self) -> Option<&str> {
+        self.service_endpoint("atproto_pds", "AtprotoPersonalDataServer")
+    }
+
+    /// Get a service endpoint by fragment id and type
+    ///
+    /// # Arguments
+    ///
+    /// * `fragment` - The service id without the leading '#' (e.g., "atproto_pds")
+    /// * `service_type` - The expected service type
+    pub fn service_endpoint(&self, fragment: &str, service_type: &str) -> Option<&str> {
+        self.service_by_id(fragment)
+            .filter(|s| s.service_type == service_type)
+            .map(|s| s.service_endpoint.trim_end_matches('/'))
+    }
+
+    /// Get a service entry by fragment id, whatever its type
+    ///
+    /// # Arguments
+    ///
+    /// * `fragment` - The service id without the leading '#' (e.g., "bsky_chat")
+    pub fn service_by_id(&self, fragment: &str) -> Option<&DidService> {
+        let short_id = format!("#{}", fragment);
+        let full_id = format!("{}#{}", self.id, fragment);
+
+        self.service
+            .iter()
+            .find(|s| s.id == short_id || s.id == full_id)
+    }
+
+    /// Get the multibase-encoded atproto signing key
+    pub fn signing_key(&self) -> Option<&str> {
+        self.signing_method()
+            .and_then(|m| m.public_key_multibase.as_deref())
+    }
+
+    /// Get the `#atproto` verification method
+    pub fn signing_method(&self) -> Option<&VerificationMethod> {
+        let short_id = "#atproto";
+        let full_id = format!("{}#atproto", self.id);
+
+        self.verification_method
+            .iter()
+            .find(|m| m.id == short_id || m.id == full_id)
+    }
+}
+
+/// A fully resolved identity
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedIdentity {
+    /// The account DID
+    pub did: Did,
+
+    /// The handle, only set if it was verified in both directions
+    pub handle: Option<Handle>,
+
+    /// The PDS endpoint from the DID document
+    pub pds_endpoint: Option<String>,
+
+    /// The DID document
+    pub did_doc: DidDocument,
+}
+
+/// Backend that resolves a handle to the DID it claims
+///
+/// Implementations only perform the lookup; verification against the DID
+/// document is done by [`IdentityResolver`].
+#[async_trait]
+pub trait HandleResolver: Send + Sync {
+    /// Resolve a handle to a DID
+    ///
+    /// Returns `Ok(None)` if the backend found no record for the handle.
+    async fn resolve_handle(&self, handle: &Handle) -> Result<Option<Did>>;
+}
+
+/// Backend that fetches the DID document for a DID
+#[async_trait]
+pub trait DidResolver: Send + Sync {
+    /// Fetch the DID document for a DID
+    async fn resolve_did(&self, did: &Did) -> Result<DidDocument>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_doc() -> DidDocument {
+        serde_json::from_value(serde_json::json!({
+            "@context": ["https://www.w3.org/ns/did/v1"],
+            "id": "did:plc:abc123",
+            "alsoKnownAs": ["at://alice.example.com"],
+            "verificationMethod": [{
+                "id": "did:plc:abc123#atproto",
+                "type": "Multikey",
+                "controller": "did:plc:abc123",
+                "publicKeyMultibase": "zQ3shXjHeiBuRCKmM36cuYnm7YEMzhGnCmCyW92sRJ9pribSF"
+            }],
+            "service": [{
+                "id": "#atproto_pds",
+                "type": "AtprotoPersonalDataServer",
+                "serviceEndpoint": "https://pds.example.com/"
+            }]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_did_document_accessors() {
+        let doc = sample_doc();
+        assert_eq!(doc.handle(), Some("alice.example.com"));
+        assert_eq!(doc.pds_endpoint(), Some("https://pds.example.com"));
+        assert_eq!(doc.signing_key(), Some("zQ3shXjHeiBuRCKmM36cuYnm7YEMzhGnCmCyW92sRJ9pribSF"));
+    }
+
+    #[test]
+    fn test_did_document_claims_handle() {
+        let doc = sample_doc();
+        assert!(doc.claims_handle("alice.example.com"));
+        assert!(doc.claims_handle("Alice.Example.com"));
+        assert!(!doc.claims_handle("bob.example.com"));
+    }
+
+    #[test]
+    fn test_did_document_missing_pds() {
+        let mut doc = sample_doc();
+        doc.service[0].service_type = "SomethingElse".to_string();
+        assert!(doc.pds_endpoint().is_none());
+    }
+}
@@ -0,0 +1,432 @@
+//! Identity resolver with bidirectional verification and caching
+//!
+//! The [`IdentityResolver`] ties the handle and DID backends together,
+//! verifies that handles and DID documents point at each other, and caches
+//! results with a TTL.
+
+use super::backends::{
+    DefaultDidResolver, DnsHandleResolver, WellKnownHandleResolver, DEFAULT_PLC_DIRECTORY,
+};
+use super::{DidDocument, DidResolver, HandleResolver, IdentityError, ResolvedIdentity, Result};
+use crate::types::{Did, Handle};
+use std::sync::Arc;
+use std::time::Duration;
+use storage::cache::{CacheConfig, MemoryCache};
+
+/// Configuration for the identity resolver
+#[derive(Debug, Clone)]
+pub struct IdentityResolverConfig {
+    /// PLC directory URL for `did:plc` lookups
+    pub plc_directory_url: String,
+    /// How long resolved handles and DID documents are cached
+    pub cache_ttl: Duration,
+    /// Maximum number of cached entries per cache
+    pub max_cache_entries: usize,
+    /// Request timeout for HTTP lookups
+    pub timeout: Duration,
+}
+
+impl Default for IdentityResolverConfig {
+    fn default() -> Self {
+        Self {
+            plc_directory_url: DEFAULT_PLC_DIRECTORY.to_string(),
+            cache_ttl: Duration::from_secs(3600),
+            max_cache_entries: 1000,
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+impl IdentityResolverConfig {
+    /// Create a new default configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the PLC directory URL
+    pub fn with_plc_directory(mut self, url: impl Into<String>) -> Self {
+        self.plc_directory_url = url.into();
+        self
+    }
+
+    /// Set the cache TTL
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Set the maximum number of cached entries
+    pub fn with_max_cache_entries(mut self, max: usize) -> Self {
+        self.max_cache_entries = max;
+        self
+    }
+
+    /// Set the request timeout
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Resolves handles and DIDs to verified identities
+///
+/// Handles are resolved by trying each handle backend in order (DNS first,
+/// then HTTPS well-known by default). DID documents are fetched from the PLC
+/// directory or the `did:web` host. Backends can be replaced for testing or
+/// for custom infrastructure.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use atproto_client::identity::{IdentityResolver, IdentityResolverConfig};
+/// use std::time::Duration;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = IdentityResolverConfig::new().with_cache_ttl(Duration::from_secs(600));
+/// let resolver = IdentityResolver::new(config);
+///
+/// let pds = resolver.resolve_pds_endpoint("alice.example.com").await?;
+/// println!("PDS: {}", pds);
+/// # Ok(())
+/// # }
+/// ```
+pub struct IdentityResolver {
+    /// Handle backends, tried in order
+    handle_resolvers: Vec<Arc<dyn HandleResolver>>,
+    /// DID document backend
+    did_resolver: Arc<dyn DidResolver>,
+    /// Cache of handle -> DID
+    handle_cache: MemoryCache<Did>,
+    /// Cache of DID -> DID document
+    did_cache: MemoryCache<DidDocument>,
+    /// Configuration
+    config: IdentityResolverConfig,
+}
+
+impl IdentityResolver {
+    /// Create a new resolver with the default network backends
+    pub fn new(config: IdentityResolverConfig) -> Self {
+        let handle_resolvers: Vec<Arc<dyn HandleResolver>> = vec![
+            Arc::new(DnsHandleResolver::new()),
+            Arc::new(WellKnownHandleResolver::new(config.timeout)),
+        ];
+        let did_resolver =
+            Arc::new(DefaultDidResolver::new(config.plc_directory_url.clone(), config.timeout));
+
+        let cache_config = CacheConfig::new()
+            .max_entries(config.max_cache_entries)
+            .default_ttl(Some(config.cache_ttl));
+
+        Self {
+            handle_resolvers,
+            did_resolver,
+            handle_cache: MemoryCache::new(cache_config.clone()),
+            did_cache: MemoryCache::new(cache_config),
+            config,
+        }
+    }
+
+    /// Replace the handle backends
+    ///
+    /// Backends are tried in order until one returns a DID.
+    pub fn with_handle_resolvers(mut self, resolvers: Vec<Arc<dyn HandleResolver>>) -> Self {
+        self.handle_resolvers = resolvers;
+        self
+    }
+
+    /// Replace the DID document backend
+    pub fn with_did_resolver(mut self, resolver: Arc<dyn DidResolver>) -> Self {
+        self.did_resolver = resolver;
+        self
+    }
+
+    /// Get the resolver configuration
+    pub fn config(&self) -> &IdentityResolverConfig {
+        &self.config
+    }
+
+    /// Resolve a handle to a DID (unverified)
+    ///
+    /// This only performs the handle -> DID lookup. Use [`resolve`](Self::resolve)
+    /// to also verify that the DID document claims the handle.
+    ///
+    /// # Errors
+    ///
+    /// - `IdentityError::HandleNotFound` - No backend found a DID and at least
+    ///   one answered without error
+    /// - Otherwise the last backend's error
+    pub async fn resolve_handle(&self, handle: &Handle) -> Result<Did> {
+        let key = handle.as_str().to_lowercase();
+        if let Ok(Some(did)) = self.handle_cache.get(&key) {
+            return Ok(did);
+        }
+
+        // A backend that answered "no record" outweighs one that failed
+        let mut last_error = None;
+        let mut answered = false;
+        for resolver in &self.handle_resolvers {
+            match resolver.resolve_handle(handle).await {
+                Ok(Some(did)) => {
+                    let _ = self.handle_cache.put(key, did.clone(), None);
+                    return Ok(did);
+                }
+                Ok(None) => answered = true,
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        match last_error {
+            Some(e) if !answered => Err(e),
+            _ => Err(IdentityError::HandleNotFound(handle.to_string())),
+        }
+    }
+
+    /// Fetch the DID document for a DID
+    pub async fn resolve_did(&self, did: &Did) -> Result<DidDocument> {
+        if let Ok(Some(doc)) = self.did_cache.get(did.as_str()) {
+            return Ok(doc);
+        }
+
+        let doc = self.did_resolver.resolve_did(did).await?;
+        if doc.id != did.as_str() {
+            return Err(IdentityError::InvalidDocument(format!(
+                "Document id {} does not match {}",
+                doc.id, did
+            )));
+        }
+
+        let _ = self.did_cache.put(did.as_str(), doc.clone(), None);
+        Ok(doc)
+    }
+
+    /// Resolve a handle or DID to a verified identity
+    ///
+    /// For a handle, the handle must resolve to a DID whose document claims the
+    /// handle back; otherwise `HandleMismatch` is returned. For a DID, the
+    /// handle from the document is only reported if it resolves back to the DID.
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - A handle (optionally prefixed with '@') or a DID
+    pub async fn resolve(&self, identifier: &str) -> Result<ResolvedIdentity> {
+        let identifier = identifier.trim().trim_start_matches('@');
+
+        if identifier.starts_with("did:") {
+            let did = Did::new(identifier)
+                .map_err(|e| IdentityError::InvalidIdentifier(e.to_string()))?;
+            let did_doc = self.resolve_did(&did).await?;
+            let handle = self.verified_handle(&did, &did_doc).await;
+
+            return Ok(Self::build_identity(did, handle, did_doc));
+        }
+
+        let handle = Handle::new(identifier.to_lowercase())
+            .map_err(|e| IdentityError::InvalidIdentifier(e.to_string()))?;
+        let did = self.resolve_handle(&handle).await?;
+        let did_doc = self.resolve_did(&did).await?;
+
+        if !did_doc.claims_handle(handle.as_str()) {
+            return Err(IdentityError::HandleMismatch {
+                handle: handle.to_string(),
+                did: did.to_string(),
+            });
+        }
+
+        Ok(Self::build_identity(did, Some(handle), did_doc))
+    }
+
+    /// Resolve a handle or DID to its PDS endpoint
+    pub async fn resolve_pds_endpoint(&self, identifier: &str) -> Result<String> {
+        let identity = self.resolve(identifier).await?;
+        identity.pds_endpoint.ok_or_else(|| {
+            IdentityError::InvalidDocument(format!("No PDS endpoint for {}", identity.did))
+        })
+    }
+
+    /// Remove any cached entries for a handle or DID
+    pub fn invalidate(&self, identifier: &str) {
+        let identifier = identifier.trim().trim_start_matches('@');
+        if identifier.starts_with("did:") {
+            let _ = self.did_cache.remove(identifier);
+        } else {
+            let _ = self.handle_cache.remove(&identifier.to_lowercase());
+        }
+    }
+
+    /// Clear all cached entries
+    pub fn clear_cache(&self) {
+        self.handle_cache.clear();
+        self.did_cache.clear();
+    }
+
+    /// Get the handle from a DID document if it resolves back to the DID
+    async fn verified_handle(&self, did: &Did, did_doc: &DidDocument) -> Option<Handle> {
+        let handle = Handle::new(did_doc.handle()?.to_lowercase()).ok()?;
+        match self.resolve_handle(&handle).await {
+            Ok(resolved) if &resolved == did => Some(handle),
+            _ => None,
+        }
+    }
+
+    fn build_identity(did: Did, handle: Option<Handle>, did_doc: DidDocument) -> ResolvedIdentity {
+        ResolvedIdentity {
+            did,
+            handle,
+            pds_endpoint: did_doc.pds_endpoint().map(str::to_string),
+            did_doc,
+        }
+    }
+}
+
+impl Default for IdentityResolver {
+    fn default() -> Self {
+        Self::new(IdentityResolverConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::identity::{
+        did_document as doc, StaticDidResolver, StaticHandleResolver,
+    };
+
+    fn resolver(
+        handles: Arc<StaticHandleResolver>,
+        dids: Arc<StaticDidResolver>,
+    ) -> IdentityResolver {
+        IdentityResolver::new(IdentityResolverConfig::default())
+            .with_handle_resolvers(vec![handles])
+            .with_did_resolver(dids)
+    }
+
+    #[tokio::test]
+    async fn test_resolve_handle_verified() {
+        let handles =
+            Arc::new(StaticHandleResolver::new(&[("alice.example.com", "did:plc:alice")]));
+        let dids = Arc::new(StaticDidResolver::new(vec![doc(
+            "did:plc:alice",
+            "alice.example.com",
+            "https://pds.example.com",
+        )]));
+        let resolver = resolver(handles, dids);
+
+        let identity = resolver.resolve("@Alice.Example.com").await.unwrap();
+        assert_eq!(identity.did.as_str(), "did:plc:alice");
+        assert_eq!(identity.handle.unwrap().as_str(), "alice.example.com");
+        assert_eq!(identity.pds_endpoint.as_deref(), Some("https://pds.example.com"));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_handle_mismatch() {
+        let handles =
+            Arc::new(StaticHandleResolver::new(&[("mallory.example.com", "did:plc:alice")]));
+        let dids = Arc::new(StaticDidResolver::new(vec![doc(
+            "did:plc:alice",
+            "alice.example.com",
+            "https://pds.example.com",
+        )]));
+        let resolver = resolver(handles, dids);
+
+        let result = resolver.resolve("mallory.example.com").await;
+        assert!(matches!(result, Err(IdentityError::HandleMismatch { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_did_drops_unverified_handle() {
+        let handles =
+            Arc::new(StaticHandleResolver::new(&[("alice.example.com", "did:plc:other")]));
+        let dids = Arc::new(StaticDidResolver::new(vec![doc(
+            "did:plc:alice",
+            "alice.example.com",
+            "https://pds.example.com",
+        )]));
+        let resolver = resolver(handles, dids);
+
+        let identity = resolver.resolve("did:plc:alice").await.unwrap();
+        assert!(identity.handle.is_none());
+        assert_eq!(identity.pds_endpoint.as_deref(), Some("https://pds.example.com"));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_handle_not_found() {
+        let handles = Arc::new(StaticHandleResolver::new(&[]));
+        let dids = Arc::new(StaticDidResolver::new(vec![]));
+        let resolver = resolver(handles, dids);
+
+        let result = resolver.resolve("nobody.example.com").await;
+        assert!(matches!(result, Err(IdentityError::HandleNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_handle_not_found_outweighs_backend_error() {
+        use crate::test_utils::identity::FailingHandleResolver;
+
+        let dids = Arc::new(StaticDidResolver::new(vec![]));
+        let handle = Handle::new("nobody.example.com").unwrap();
+
+        let resolver = IdentityResolver::new(IdentityResolverConfig::default())
+            .with_handle_resolvers(vec![
+                Arc::new(StaticHandleResolver::new(&[])),
+                Arc::new(FailingHandleResolver),
+            ])
+            .with_did_resolver(dids.clone());
+        let result = resolver.resolve_handle(&handle).await;
+        assert!(matches!(result, Err(IdentityError::HandleNotFound(_))));
+
+        let resolver = IdentityResolver::new(IdentityResolverConfig::default())
+            .with_handle_resolvers(vec![Arc::new(FailingHandleResolver)])
+            .with_did_resolver(dids);
+        let result = resolver.resolve_handle(&handle).await;
+        assert!(matches!(result, Err(IdentityError::Network(_))));
+    }
+
+    #[tokio::test]
+    async fn test_resolution_is_cached() {
+        let handles =
+            Arc::new(StaticHandleResolver::new(&[("alice.example.com", "did:plc:alice")]));
+        let dids = Arc::new(StaticDidResolver::new(vec![doc(
+            "did:plc:alice",
+            "alice.example.com",
+            "https://pds.example.com",
+        )]));
+        let resolver = resolver(handles.clone(), dids.clone());
+
+        resolver.resolve("alice.example.com").await.unwrap();
+        resolver.resolve("alice.example.com").await.unwrap();
+        assert_eq!(handles.calls(), 1);
+        assert_eq!(dids.calls(), 1);
+
+        resolver.invalidate("alice.example.com");
+        resolver.resolve("alice.example.com").await.unwrap();
+        assert_eq!(handles.calls(), 2);
+        assert_eq!(dids.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cache_expires() {
+        let handles =
+            Arc::new(StaticHandleResolver::new(&[("alice.example.com", "did:plc:alice")]));
+        let dids = Arc::new(StaticDidResolver::new(vec![doc(
+            "did:plc:alice",
+            "alice.example.com",
+            "https://pds.example.com",
+        )]));
+        let config = IdentityResolverConfig::new().with_cache_ttl(Duration::from_millis(20));
+        let resolver = IdentityResolver::new(config)
+            .with_handle_resolvers(vec![handles.clone()])
+            .with_did_resolver(dids);
+
+        resolver
+            .resolve_handle(&Handle::new("alice.example.com").unwrap())
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        resolver
+            .resolve_handle(&Handle::new("alice.example.com").unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(handles.calls(), 2);
+    }
+}
@@ -0,0 +1,244 @@
+//! Labeler negotiation via the `atproto-accept-labelers` header
+//!
+//! AppView reads only carry labels from the labelers the client asks for. The
+//! request header lists labeler DIDs, each optionally flagged `;redact` to have
+//! content taken down by that labeler removed from responses instead of just
+//! labeled:
+//!
+//! ```text
+//! atproto-accept-labelers: did:plc:ar7c4by46qjdydhdevvrndac;redact, did:plc:abc123
+//! ```
+//!
+//! The AppView answers with `atproto-content-labelers` in the same format,
+//! listing the labelers it actually applied.
+//!
+//! A [`LabelerProvider`] supplies the labelers to accept (typically the user's
+//! subscriptions), and [`AcceptLabelersMiddleware`] adds the header to queries.
+//!
+//! # Example
+//!
+//! ```rust
+//! use atproto_client::labelers::{AcceptLabelersMiddleware, AcceptedLabeler};
+//! use atproto_client::xrpc::{XrpcClient, XrpcClientConfig};
+//! use std::sync::Arc;
+//!
+//! let labelers = vec![
+//!     AcceptedLabeler::new("did:plc:ar7c4by46qjdydhdevvrndac").with_redact(true),
+//!     AcceptedLabeler::new("did:plc:abc123"),
+//! ];
+//! assert_eq!(
+//!     atproto_client::labelers::format_labelers(&labelers),
+//!     "did:plc:ar7c4by46qjdydhdevvrndac;redact, did:plc:abc123"
+//! );
+//!
+//! let client = XrpcClient::new(XrpcClientConfig::new("https://api.bsky.app"))
+//!     .with_middleware(AcceptLabelersMiddleware::new(Arc::new(labelers)));
+//! ```
+
+use crate::xrpc::{HttpMethod, HttpRequest, Middleware, XrpcError};
+use async_trait::async_trait;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+/// Request header listing the labelers the client accepts
+pub const ACCEPT_LABELERS_HEADER: &str = "atproto-accept-labelers";
+
+/// Response header listing the labelers that were applied
+pub const CONTENT_LABELERS_HEADER: &str = "atproto-content-labelers";
+
+/// A labeler entry in an `atproto-accept-labelers` or
+/// `atproto-content-labelers` header
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AcceptedLabeler {
+    /// DID of the labeler
+    pub did: String,
+    /// Whether content taken down by this labeler is removed from responses
+    pub redact: bool,
+}
+
+impl AcceptedLabeler {
+    /// Create an entry for a labeler without the redact flag
+    pub fn new(did: impl Into<String>) -> Self {
+        Self { did: did.into(), redact: false }
+    }
+
+    /// Set the redact flag
+    pub fn with_redact(mut self, redact: bool) -> Self {
+        self.redact = redact;
+        self
+    }
+}
+
+impl fmt::Display for AcceptedLabeler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.redact {
+            write!(f, "{};redact", self.did)
+        } else {
+            f.write_str(&self.did)
+        }
+    }
+}
+
+/// Format labelers as a header value
+pub fn format_labelers(labelers: &[AcceptedLabeler]) -> String {
+    labelers
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Parse a labeler header value
+///
+/// Entries that are not DIDs are skipped, as are unknown parameters.
+pub fn parse_labelers(value: &str) -> Vec<AcceptedLabeler> {
+    value
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';').map(str::trim);
+            let did = parts.next().filter(|did| did.starts_with("did:"))?;
+            let redact = parts.any(|param| param.eq_ignore_ascii_case("redact"));
+            Some(AcceptedLabeler::new(did).with_redact(redact))
+        })
+        .collect()
+}
+
+/// Source of the labelers to accept on AppView reads
+///
+/// Called for every request, so implementations backed by mutable state (e.g.
+/// the user's labeler subscriptions behind a lock) take effect immediately.
+pub trait LabelerProvider: Send + Sync + fmt::Debug {
+    /// Get the labelers to accept, in header order
+    fn accepted_labelers(&self) -> Vec<AcceptedLabeler>;
+}
+
+impl LabelerProvider for Vec<AcceptedLabeler> {
+    fn accepted_labelers(&self) -> Vec<AcceptedLabeler> {
+        self.clone()
+    }
+}
+
+impl<T: LabelerProvider> LabelerProvider for RwLock<T> {
+    fn accepted_labelers(&self) -> Vec<AcceptedLabeler> {
+        self.read().unwrap().accepted_labelers()
+    }
+}
+
+impl<T: LabelerProvider + ?Sized> LabelerProvider for Arc<T> {
+    fn accepted_labelers(&self) -> Vec<AcceptedLabeler> {
+        (**self).accepted_labelers()
+    }
+}
+
+/// Middleware setting the `atproto-accept-labelers` header on queries
+///
+/// Procedures are left alone, as are requests that already carry the header.
+/// No header is sent while the provider returns no labelers, so the AppView
+/// falls back to its defaults.
+#[derive(Debug, Clone, Default)]
+pub struct AcceptLabelersMiddleware {
+    provider: Arc<RwLock<Option<Arc<dyn LabelerProvider>>>>,
+}
+
+impl AcceptLabelersMiddleware {
+    /// Create a middleware using a provider
+    pub fn new(provider: Arc<dyn LabelerProvider>) -> Self {
+        Self { provider: Arc::new(RwLock::new(Some(provider))) }
+    }
+
+    /// Create a middleware sharing a provider slot, so it can be replaced later
+    pub fn shared(provider: Arc<RwLock<Option<Arc<dyn LabelerProvider>>>>) -> Self {
+        Self { provider }
+    }
+}
+
+#[async_trait]
+impl Middleware for AcceptLabelersMiddleware {
+    async fn before_request(&self, request: &mut HttpRequest) -> Result<(), XrpcError> {
+        if request.method != HttpMethod::Get || request.header(ACCEPT_LABELERS_HEADER).is_some() {
+            return Ok(());
+        }
+
+        let provider = self.provider.read().unwrap().clone();
+        let labelers = provider.map(|p| p.accepted_labelers()).unwrap_or_default();
+        if !labelers.is_empty() {
+            request.set_header(ACCEPT_LABELERS_HEADER, format_labelers(&labelers));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use crate::xrpc::{HttpResponse, XrpcClient, XrpcClientConfig, XrpcRequest};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_format_and_parse_labelers() {
+        let labelers = parse_labelers("did:plc:mod;redact, did:plc:abc ,not-a-did, did:web:x;foo");
+        assert_eq!(
+            labelers,
+            vec![
+                AcceptedLabeler::new("did:plc:mod").with_redact(true),
+                AcceptedLabeler::new("did:plc:abc"),
+                AcceptedLabeler::new("did:web:x"),
+            ]
+        );
+        assert_eq!(format_labelers(&labelers), "did:plc:mod;redact, did:plc:abc, did:web:x");
+        assert!(parse_labelers("").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_header_sent_on_queries_and_parsed_from_response() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(
+            "app.bsky.feed.getTimeline",
+            HttpResponse::new(
+                200,
+                HashMap::from([
+                    ("Atproto-Content-Labelers".to_string(), "did:plc:mod;redact".to_string()),
+                    ("content-type".to_string(), "application/json".to_string()),
+                ]),
+                br#"{"feed": []}"#.to_vec(),
+            ),
+        );
+        transport.push_json("com.atproto.repo.createRecord", 200, serde_json::json!({}));
+
+        let provider: Arc<RwLock<Vec<AcceptedLabeler>>> = Arc::new(RwLock::new(Vec::new()));
+        let client = XrpcClient::new(
+            XrpcClientConfig::new("https://api.bsky.app").with_transport(transport.clone()),
+        )
+        .with_middleware(AcceptLabelersMiddleware::new(provider.clone()));
+
+        // No labelers, no header
+        client
+            .query::<serde_json::Value>(XrpcRequest::query("app.bsky.feed.getTimeline"))
+            .await
+            .unwrap();
+
+        provider
+            .write()
+            .unwrap()
+            .push(AcceptedLabeler::new("did:plc:mod").with_redact(true));
+        let response = client
+            .query::<serde_json::Value>(XrpcRequest::query("app.bsky.feed.getTimeline"))
+            .await
+            .unwrap();
+        assert_eq!(
+            response.content_labelers(),
+            Some(vec![AcceptedLabeler::new("did:plc:mod").with_redact(true)])
+        );
+
+        client
+            .procedure::<serde_json::Value>(XrpcRequest::procedure("com.atproto.repo.createRecord"))
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].header(ACCEPT_LABELERS_HEADER), None);
+        assert_eq!(requests[1].header(ACCEPT_LABELERS_HEADER), Some("did:plc:mod;redact"));
+        assert_eq!(requests[2].header(ACCEPT_LABELERS_HEADER), None);
+    }
+}
@@ -0,0 +1,164 @@
+//! Bundled official lexicons
+//!
+//! The `com.atproto.*`, `app.bsky.*`, `chat.bsky.*` and `tools.ozone.*`
+//! lexicon documents are embedded from the crate's `lexicons/` directory when
+//! the `builtin-lexicons` feature is enabled (the default).
+//!
+//! # Example
+//!
+//! ```rust
+//! use atproto_client::lexicon::{validate_record, SchemaRegistry};
+//! use serde_json::json;
+//!
+//! let registry = SchemaRegistry::with_builtin();
+//! let like = json!({
+//!     "$type": "app.bsky.feed.like",
+//!     "subject": {
+//!         "uri": "at://did:plc:alice/app.bsky.feed.post/3jzfcijpj2z2a",
+//!         "cid": "bafyreig2fjxi3rptqdgylg7e5hmjl6mcke7rn2b6cugzlqq3i4zu6rq52q"
+//!     },
+//!     "createdAt": "2024-01-01T00:00:00.000Z"
+//! });
+//!
+//! assert!(validate_record(&registry, "app.bsky.feed.like", &like).is_ok());
+//! ```
+
+use super::resolution::SchemaRegistry;
+use super::schema::LexiconDoc;
+use std::sync::OnceLock;
+
+include!(concat!(env!("OUT_DIR"), "/builtin_lexicons.rs"));
+
+/// Get the bundled lexicon sources as `(nsid, json)` pairs, sorted by NSID
+pub fn sources() -> &'static [(&'static str, &'static str)] {
+    SOURCES
+}
+
+/// Get the registry of bundled lexicons
+///
+/// The documents are parsed on first use. Use [`SchemaRegistry::with_builtin`]
+/// for an owned copy that can be extended with application lexicons.
+///
+/// # Panics
+///
+/// Panics if a bundled document fails to parse, which the crate's tests rule
+/// out.
+pub fn registry() -> &'static SchemaRegistry {
+    static REGISTRY: OnceLock<SchemaRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let mut registry = SchemaRegistry::new();
+        for (nsid, json) in SOURCES {
+            let doc = LexiconDoc::from_json(json)
+                .unwrap_or_else(|e| panic!("Bundled lexicon {} is invalid: {}", nsid, e));
+            registry.register(doc);
+        }
+        registry
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexicon::schema::LexiconDef;
+    use crate::lexicon::validation::validate_record;
+    use serde_json::json;
+
+    #[test]
+    fn test_sources_parse_and_match_paths() {
+        assert!(SOURCES.len() > 200);
+        assert!(SOURCES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        for (nsid, json) in SOURCES {
+            let doc = LexiconDoc::from_json(json)
+                .unwrap_or_else(|e| panic!("{} failed to parse: {}", nsid, e));
+            assert_eq!(doc.id, *nsid);
+        }
+    }
+
+    #[test]
+    fn test_all_namespaces_bundled() {
+        for prefix in ["com.atproto.", "app.bsky.", "chat.bsky.", "tools.ozone."] {
+            assert!(
+                SOURCES.iter().any(|(nsid, _)| nsid.starts_with(prefix)),
+                "No lexicons under {}",
+                prefix
+            );
+        }
+    }
+
+    #[test]
+    fn test_all_refs_resolve() {
+        let registry = registry();
+        for (nsid, _) in SOURCES {
+            registry
+                .validate_schema(nsid)
+                .unwrap_or_else(|e| panic!("{} has unresolved refs: {}", nsid, e));
+        }
+    }
+
+    #[test]
+    fn test_record_definitions() {
+        let registry = SchemaRegistry::with_builtin();
+        for nsid in [
+            "app.bsky.actor.profile",
+            "app.bsky.feed.post",
+            "app.bsky.feed.like",
+            "app.bsky.graph.follow",
+            "app.bsky.graph.list",
+            "chat.bsky.actor.declaration",
+        ] {
+            assert!(
+                matches!(
+                    registry.get(nsid).and_then(|doc| doc.main_def()),
+                    Some(LexiconDef::Record(_))
+                ),
+                "{} is not a record",
+                nsid
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_bundled_records() {
+        let registry = SchemaRegistry::with_builtin();
+        let post = json!({
+            "$type": "app.bsky.feed.post",
+            "text": "Hello @bob.test",
+            "langs": ["en"],
+            "facets": [{
+                "index": {"byteStart": 6, "byteEnd": 15},
+                "features": [{
+                    "$type": "app.bsky.richtext.facet#mention",
+                    "did": "did:plc:bob"
+                }]
+            }],
+            "reply": {
+                "root": {
+                    "uri": "at://did:plc:alice/app.bsky.feed.post/3jzfcijpj2z2a",
+                    "cid": "bafyreig2fjxi3rptqdgylg7e5hmjl6mcke7rn2b6cugzlqq3i4zu6rq52q"
+                },
+                "parent": {
+                    "uri": "at://did:plc:alice/app.bsky.feed.post/3jzfcijpj2z2a",
+                    "cid": "bafyreig2fjxi3rptqdgylg7e5hmjl6mcke7rn2b6cugzlqq3i4zu6rq52q"
+                }
+            },
+            "createdAt": "2024-01-01T00:00:00.000Z"
+        });
+        assert_eq!(validate_record(&registry, "app.bsky.feed.post", &post), Ok(()));
+
+        let profile = json!({
+            "$type": "app.bsky.actor.profile",
+            "displayName": "Alice",
+            "description": "Hi"
+        });
+        assert_eq!(validate_record(&registry, "app.bsky.actor.profile", &profile), Ok(()));
+
+        let too_long = json!({
+            "$type": "app.bsky.feed.post",
+            "text": "a".repeat(301),
+            "createdAt": "2024-01-01T00:00:00.000Z"
+        });
+        let err = validate_record(&registry, "app.bsky.feed.post", &too_long).unwrap_err();
+        assert_eq!(err.errors[0].path, "text");
+    }
+}
@@ -32,7 +32,9 @@
 //! ```
 
 use serde::{Deserialize, Serialize};
+use serde_ipld_dagcbor::de::from_reader_once;
 use serde_ipld_dagcbor::{from_slice, to_vec};
+use std::io::Cursor;
 use thiserror::Error;
 
 /// Errors that can occur during CBOR encoding/decoding
@@ -128,6 +130,39 @@ where
     from_slice(bytes).map_err(|e| CborError::DecodingError(e.to_string()))
 }
 
+/// Decode one DAG-CBOR value from the front of a byte buffer
+///
+/// Unlike [`decode_record`], trailing bytes are allowed. This is used for
+/// concatenated values such as event stream frames, which are a header
+/// followed by a body.
+///
+/// # Returns
+///
+/// The decoded value and the bytes after it
+///
+/// # Example
+///
+/// ```rust
+/// use atproto_client::lexicon::cbor::{decode_prefix, encode_record};
+///
+/// let mut bytes = encode_record(&"first").unwrap();
+/// bytes.extend(encode_record(&2).unwrap());
+///
+/// let (first, rest): (String, _) = decode_prefix(&bytes).unwrap();
+/// assert_eq!(first, "first");
+/// assert_eq!(rest, encode_record(&2).unwrap().as_slice());
+/// ```
+pub fn decode_prefix<T>(bytes: &[u8]) -> Result<(T, &[u8])>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    let mut reader = Cursor::new(bytes);
+    let value =
+        from_reader_once(&mut reader).map_err(|e| CborError::DecodingError(e.to_string()))?;
+    let consumed = reader.position() as usize;
+    Ok((value, &bytes[consumed..]))
+}
+
 /// CID link representation
 ///
 /// CID (Content Identifier) links are represented in DAG-CBOR as a special
@@ -135,7 +170,9 @@ where
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CidLink {
     /// The CID string (e.g., "bafyreib...")
-    #[serde(rename = "/")]
+    ///
+    /// Also accepts the `$link` key used by the JSON data model.
+    #[serde(rename = "/", alias = "$link")]
     pub cid: String,
 }
 
@@ -154,9 +191,10 @@ impl CidLink {
 /// Blob reference representation
 ///
 /// Blobs in AT Protocol are represented as objects with metadata about
-/// the binary data (MIME type, size, and a CID reference).
+/// the binary data (MIME type, size, and a CID reference). They serialize
+/// with `"$type": "blob"`, which is part of the record data and its CID.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(tag = "$type", rename = "blob", rename_all = "camelCase")]
 pub struct BlobRef {
     /// MIME type of the blob
     pub mime_type: String,
@@ -287,6 +325,21 @@ mod tests {
         assert_eq!(record, decoded);
     }
 
+    #[test]
+    fn test_decode_prefix() {
+        let record = SimpleRecord { text: "header".to_string(), count: 1 };
+        let mut bytes = encode_record(&record).unwrap();
+        bytes.extend(encode_record(&"body").unwrap());
+
+        let (decoded, rest): (SimpleRecord, _) = decode_prefix(&bytes).unwrap();
+        assert_eq!(decoded, record);
+        assert_eq!(decode_record::<String>(rest).unwrap(), "body");
+
+        let (_, rest): (String, _) = decode_prefix(rest).unwrap();
+        assert!(rest.is_empty());
+        assert!(decode_prefix::<String>(&[]).is_err());
+    }
+
     #[test]
     fn test_cid_link() {
         let cid = CidLink::new("bafyreibjo4xmgaevkgud7mbifn3dzp4v4lyaui4yvqp3f2bqwtxcjrdqg4");
@@ -295,6 +348,9 @@ mod tests {
         let bytes = encode_record(&cid).unwrap();
         let decoded: CidLink = decode_record(&bytes).unwrap();
         assert_eq!(cid, decoded);
+
+        let json = serde_json::json!({ "$link": cid.as_str() });
+        assert_eq!(serde_json::from_value::<CidLink>(json).unwrap(), cid);
     }
 
     #[test]
@@ -0,0 +1,1473 @@
+//! Rust code generation from Lexicon schemas
+//!
+//! [`CodeGenerator`] turns the documents in a [`SchemaRegistry`] into a tree
+//! of Rust modules, one module per NSID (`app.bsky.feed.post` becomes
+//! `app::bsky::feed::post`):
+//!
+//! - `object` and `record` definitions become serde structs
+//! - unions become enums tagged by `$type`; open unions are wrapped in the
+//!   generated `Union` type so values of unknown types are preserved
+//! - tokens become string constants, and other reusable definitions become
+//!   type aliases
+//! - queries and procedures get `Parameters`, `Input` and `Output` types and
+//!   an async function that calls the endpoint through [`XrpcClient`]
+//! - declared XRPC errors become an `Error` enum implementing
+//!   [`XrpcErrorKind`], and the endpoint function returns
+//!   [`EndpointError<Error>`](crate::xrpc::EndpointError)
+//!
+//! The `lexgen` binary writes the generated tree to disk. The [`crate::api`]
+//! module is generated this way from the bundled lexicons.
+//!
+//! [`XrpcClient`]: crate::xrpc::XrpcClient
+//! [`XrpcErrorKind`]: crate::xrpc::XrpcErrorKind
+//!
+//! # Example
+//!
+//! ```rust
+//! use atproto_client::lexicon::codegen::CodeGenerator;
+//! use atproto_client::lexicon::{LexiconDoc, SchemaRegistry};
+//!
+//! let doc = LexiconDoc::from_json(r#"{
+//!   "lexicon": 1,
+//!   "id": "com.example.note",
+//!   "defs": {
+//!     "main": {
+//!       "type": "record",
+//!       "key": "tid",
+//!       "record": {
+//!         "type": "object",
+//!         "required": ["text"],
+//!         "properties": { "text": { "type": "string" } }
+//!       }
+//!     }
+//!   }
+//! }"#).unwrap();
+//!
+//! let mut registry = SchemaRegistry::new();
+//! registry.register(doc);
+//!
+//! let source = CodeGenerator::new(&registry).generate_lexicon("com.example.note").unwrap();
+//! assert!(source.contains("pub struct Note {"));
+//! ```
+
+use super::resolution::{collect_refs_from_def, parse_ref, RefResolutionError, SchemaRegistry};
+use super::schema::{LexBody, LexParams, LexXrpcError, LexiconDef, LexiconDoc};
+use super::types::{LexObject, LexType, LexUnion};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt::Write;
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Errors that can occur during code generation
+#[derive(Debug, Error)]
+pub enum CodegenError {
+    /// Schema not found in registry
+    #[error("Schema not found: {0}")]
+    SchemaNotFound(String),
+
+    /// A reference could not be resolved
+    #[error(transparent)]
+    Ref(#[from] RefResolutionError),
+
+    /// Two generated items in one module have the same name
+    #[error("Name collision in {nsid}: {name}")]
+    NameCollision {
+        /// The NSID of the schema
+        nsid: String,
+        /// The colliding Rust name
+        name: String,
+    },
+}
+
+/// Result type for code generation operations
+pub type Result<T> = std::result::Result<T, CodegenError>;
+
+/// A generated source file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedFile {
+    /// Path relative to the output directory (e.g., "app/bsky/feed/post.rs")
+    pub path: PathBuf,
+    /// Rust source
+    pub contents: String,
+}
+
+/// Generates Rust types and XRPC wrappers from Lexicon schemas
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use atproto_client::lexicon::codegen::CodeGenerator;
+/// use atproto_client::lexicon::SchemaRegistry;
+///
+/// let registry = SchemaRegistry::with_builtin();
+/// let files = CodeGenerator::new(&registry)
+///     .with_root_module("crate::bsky")
+///     .with_crate_path("atproto_client")
+///     .generate(&["app.bsky."])
+///     .unwrap();
+///
+/// for file in files {
+///     println!("{}", file.path.display());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CodeGenerator<'a> {
+    registry: &'a SchemaRegistry,
+    root_module: String,
+    crate_path: String,
+}
+
+impl<'a> CodeGenerator<'a> {
+    /// Create a generator for the documents in a registry
+    ///
+    /// Generated code lives under `crate::api` and refers to this crate as
+    /// `crate`, which is how [`crate::api`] is built.
+    pub fn new(registry: &'a SchemaRegistry) -> Self {
+        Self {
+            registry,
+            root_module: "crate::api".to_string(),
+            crate_path: "crate".to_string(),
+        }
+    }
+
+    /// Set the module path the generated tree is mounted at
+    pub fn with_root_module(mut self, root_module: impl Into<String>) -> Self {
+        self.root_module = root_module.into();
+        self
+    }
+
+    /// Set the path used to refer to this crate (e.g., "atproto_client")
+    pub fn with_crate_path(mut self, crate_path: impl Into<String>) -> Self {
+        self.crate_path = crate_path.into();
+        self
+    }
+
+    /// Generate the module tree for all documents under the given NSID prefixes
+    ///
+    /// Documents referenced from the selected ones are included as well, so
+    /// the tree always compiles on its own. An empty prefix list selects every
+    /// document in the registry.
+    ///
+    /// Returns one file per document plus a `mod.rs` for each directory. The
+    /// root `mod.rs` also defines the `Union` wrapper used by open unions.
+    ///
+    /// # Errors
+    ///
+    /// - `CodegenError::Ref` - A reference could not be resolved
+    /// - `CodegenError::NameCollision` - Two definitions map to the same Rust name
+    pub fn generate(&self, prefixes: &[&str]) -> Result<Vec<GeneratedFile>> {
+        let mut selected: BTreeSet<String> = self
+            .registry
+            .nsids()
+            .filter(|nsid| prefixes.is_empty() || prefixes.iter().any(|p| nsid.starts_with(p)))
+            .map(str::to_string)
+            .collect();
+
+        // Pull in every document reachable from the selection
+        let mut queue: Vec<String> = selected.iter().cloned().collect();
+        while let Some(nsid) = queue.pop() {
+            let doc = self.doc(&nsid)?;
+            for def in doc.defs.values() {
+                for ref_str in collect_refs_from_def(def) {
+                    let (target, _) = parse_ref(&ref_str, &nsid)?;
+                    if selected.insert(target.clone()) {
+                        queue.push(target);
+                    }
+                }
+            }
+        }
+
+        // Directory -> (child directories, child files)
+        let mut dirs: BTreeMap<Vec<String>, (BTreeSet<String>, BTreeSet<String>)> = BTreeMap::new();
+        let mut files = Vec::new();
+        for nsid in &selected {
+            let segments: Vec<String> = nsid.split('.').map(module_name).collect();
+            let (file, parents) = segments.split_last().expect("NSIDs have segments");
+            for depth in 0..parents.len() {
+                dirs.entry(parents[..depth].to_vec())
+                    .or_default()
+                    .0
+                    .insert(parents[depth].clone());
+            }
+            dirs.entry(parents.to_vec())
+                .or_default()
+                .1
+                .insert(file.clone());
+
+            let mut path: PathBuf = parents.iter().collect();
+            path.push(format!("{}.rs", file));
+            files.push(GeneratedFile { path, contents: self.generate_lexicon(nsid)? });
+        }
+
+        for (dir, (children, leaves)) in dirs {
+            let mut out = String::new();
+            if dir.is_empty() {
+                out.push_str(&self.root_header());
+            } else {
+                let _ = writeln!(out, "//! `{}.*`\n", dir.join("."));
+            }
+            for name in children.iter().chain(&leaves) {
+                let _ = writeln!(out, "pub mod {};", name);
+            }
+
+            let mut path: PathBuf = dir.iter().collect();
+            path.push("mod.rs");
+            files.push(GeneratedFile { path, contents: out });
+        }
+
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(files)
+    }
+
+    /// Generate the module for a single document
+    ///
+    /// References to other documents use paths under the root module, so the
+    /// result only compiles as part of a tree produced by [`Self::generate`].
+    ///
+    /// # Errors
+    ///
+    /// - `CodegenError::SchemaNotFound` - The document is not in the registry
+    /// - `CodegenError::Ref` - A reference could not be resolved
+    /// - `CodegenError::NameCollision` - Two definitions map to the same Rust name
+    pub fn generate_lexicon(&self, nsid: &str) -> Result<String> {
+        let doc = self.doc(nsid)?;
+        let mut module = Module::new(self, doc);
+        module.generate()?;
+        Ok(module.finish())
+    }
+
+    /// Get a document from the registry
+    fn doc(&self, nsid: &str) -> Result<&'a LexiconDoc> {
+        self.registry
+            .get(nsid)
+            .ok_or_else(|| CodegenError::SchemaNotFound(nsid.to_string()))
+    }
+
+    /// Header of the root `mod.rs`
+    fn root_header(&self) -> String {
+        let mut out = String::from(
+            "//! Types and XRPC methods generated from Lexicon schemas\n\
+             //!\n\
+             //! Generated by `lexgen`. Do not edit by hand.\n\n\
+             #![allow(missing_docs)]\n\n\
+             use serde::{Deserialize, Serialize};\n\n",
+        );
+        out.push_str(
+            "/// Value of an open union\n\
+             ///\n\
+             /// Open unions may hold types added after this code was generated. Those\n\
+             /// are kept as raw JSON.\n\
+             #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n\
+             #[serde(untagged)]\n\
+             pub enum Union<T> {\n\
+             \x20   /// One of the types listed in the schema\n\
+             \x20   Known(T),\n\
+             \x20   /// A type not listed in the schema\n\
+             \x20   Unknown(serde_json::Value),\n\
+             }\n\n",
+        );
+        out
+    }
+
+    /// Rust type name of a definition
+    ///
+    /// `main` takes the NSID's last segment (`app.bsky.feed.post` gives
+    /// `Post`), or `Main` when another definition already has that name.
+    /// Names that would shadow prelude types get a `Def` suffix.
+    fn type_name(&self, nsid: &str, def_name: &str) -> String {
+        if def_name != "main" {
+            let name = pascal_case(def_name);
+            // Generated code uses these unqualified
+            return match name.as_str() {
+                "Box" | "Option" | "Result" | "String" | "Vec" => name + "Def",
+                _ => name,
+            };
+        }
+        let name = pascal_case(nsid.rsplit('.').next().unwrap_or(nsid));
+        let taken = self.registry.get(nsid).is_some_and(|doc| {
+            doc.defs
+                .keys()
+                .any(|other| other != "main" && pascal_case(other) == name)
+        });
+        if taken {
+            "Main".to_string()
+        } else {
+            name
+        }
+    }
+
+    /// Rust module path of a document
+    fn module_path(&self, nsid: &str) -> String {
+        let segments: Vec<String> = nsid.split('.').map(module_name).collect();
+        format!("{}::{}", self.root_module, segments.join("::"))
+    }
+}
+
+/// Code generation state for one document
+struct Module<'g, 'a> {
+    generator: &'g CodeGenerator<'a>,
+    doc: &'a LexiconDoc,
+    /// Rust type names defined in this module
+    names: HashSet<String>,
+    /// Generated items, in output order
+    items: Vec<String>,
+}
+
+impl<'g, 'a> Module<'g, 'a> {
+    fn new(generator: &'g CodeGenerator<'a>, doc: &'a LexiconDoc) -> Self {
+        Self {
+            generator,
+            doc,
+            names: HashSet::new(),
+            items: Vec::new(),
+        }
+    }
+
+    fn nsid(&self) -> &'a str {
+        &self.doc.id
+    }
+
+    /// Generate every definition, `main` first and the rest by name
+    fn generate(&mut self) -> Result<()> {
+        let mut names: Vec<&String> = self.doc.defs.keys().collect();
+        names.sort_by_key(|name| (name.as_str() != "main", name.as_str()));
+        for name in names {
+            self.def(name, &self.doc.defs[name])?;
+        }
+        Ok(())
+    }
+
+    /// Assemble the module source
+    fn finish(self) -> String {
+        let mut out = format!("//! `{}`\n", self.nsid());
+        if let Some(description) = &self.doc.description {
+            out.push_str("//!\n");
+            for line in doc_lines(description) {
+                let _ = writeln!(out, "//! {}", line);
+            }
+        }
+        out.push('\n');
+
+        let source = self.items.join("\n");
+        if source.contains("Serialize, Deserialize)]") {
+            out.push_str("use serde::{Deserialize, Serialize};\n");
+        }
+        let idents: HashSet<&str> = source
+            .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .collect();
+        let xrpc: Vec<&str> =
+            ["EndpointError", "XrpcClient", "XrpcError", "XrpcErrorKind", "XrpcRequest"]
+                .into_iter()
+                .filter(|name| idents.contains(name))
+                .collect();
+        if !xrpc.is_empty() {
+            let _ =
+                writeln!(out, "use {}::xrpc::{{{}}};", self.generator.crate_path, xrpc.join(", "));
+        }
+        let _ = writeln!(
+            out,
+            "\n/// NSID of this lexicon\npub const NSID: &str = \"{}\";",
+            self.nsid()
+        );
+
+        for item in &self.items {
+            out.push('\n');
+            out.push_str(item);
+        }
+        out
+    }
+
+    /// Reserve a type name in this module
+    fn claim(&mut self, name: &str) -> Result<()> {
+        if !self.names.insert(name.to_string()) {
+            return Err(CodegenError::NameCollision {
+                nsid: self.nsid().to_string(),
+                name: name.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Rust type name of a definition in this module
+    fn def_type_name(&self, def_name: &str) -> String {
+        self.generator.type_name(self.nsid(), def_name)
+    }
+
+    fn def(&mut self, name: &str, def: &'a LexiconDef) -> Result<()> {
+        let type_name = self.def_type_name(name);
+        match def {
+            LexiconDef::Record(record) => {
+                let description = record
+                    .description
+                    .as_deref()
+                    .or(record.record.description.as_deref());
+                self.object(&type_name, description, &record.record)?;
+            }
+            LexiconDef::Object(object) => {
+                self.object(&type_name, object.description.as_deref(), object)?;
+            }
+            LexiconDef::Query(query) => {
+                let params = self.params(query.parameters.as_ref())?;
+                let output = self.body("Output", query.output.as_ref())?;
+                let errors = self.errors(query.errors.as_deref())?;
+                let signature = Signature {
+                    procedure: false,
+                    params,
+                    input: None,
+                    output,
+                    errors,
+                };
+                self.xrpc_fn(name, query.description.as_deref(), signature);
+            }
+            LexiconDef::Procedure(procedure) => {
+                let params = self.params(procedure.parameters.as_ref())?;
+                let input = self.body("Input", procedure.input.as_ref())?;
+                let output = self.body("Output", procedure.output.as_ref())?;
+                let errors = self.errors(procedure.errors.as_deref())?;
+                let input = Some(input.unwrap_or(Body::None));
+                let signature = Signature { procedure: true, params, input, output, errors };
+                self.xrpc_fn(name, procedure.description.as_deref(), signature);
+            }
+            LexiconDef::Subscription(subscription) => {
+                // Streams are consumed with `crate::stream`; only the types are generated
+                self.params(subscription.parameters.as_ref())?;
+                if let Some(message) = &subscription.message {
+                    self.named_type("Message", message.description.as_deref(), &message.schema)?;
+                }
+            }
+            LexiconDef::Token(token) => {
+                let mut item = doc_comment("", token.description.as_deref());
+                let value = if name == "main" {
+                    self.nsid().to_string()
+                } else {
+                    format!("{}#{}", self.nsid(), name)
+                };
+                let _ = writeln!(
+                    item,
+                    "pub const {}: &str = \"{}\";",
+                    const_name(name, self.nsid()),
+                    value
+                );
+                self.items.push(item);
+            }
+            LexiconDef::Union(union) => {
+                self.union_enum(&type_name, union.description.as_deref(), union)?;
+            }
+            LexiconDef::Array(array) => {
+                self.claim(&type_name)?;
+                let items = self.field_type(&type_name, "Item", &array.items)?;
+                self.alias(&type_name, array.description.as_deref(), &format!("Vec<{}>", items));
+            }
+            LexiconDef::String(s) => {
+                self.claim(&type_name)?;
+                self.alias(&type_name, s.description.as_deref(), "String");
+            }
+            LexiconDef::Integer(i) => {
+                self.claim(&type_name)?;
+                self.alias(&type_name, i.description.as_deref(), "i64");
+            }
+            LexiconDef::Boolean(b) => {
+                self.claim(&type_name)?;
+                self.alias(&type_name, b.description.as_deref(), "bool");
+            }
+            LexiconDef::Bytes(b) => {
+                self.claim(&type_name)?;
+                self.alias(&type_name, b.description.as_deref(), "serde_json::Value");
+            }
+            LexiconDef::CidLink(c) => {
+                self.claim(&type_name)?;
+                let target = format!("{}::lexicon::CidLink", self.generator.crate_path);
+                self.alias(&type_name, c.description.as_deref(), &target);
+            }
+            LexiconDef::Blob(b) => {
+                self.claim(&type_name)?;
+                let target = format!("{}::lexicon::BlobRef", self.generator.crate_path);
+                self.alias(&type_name, b.description.as_deref(), &target);
+            }
+            LexiconDef::Unknown(u) => {
+                self.claim(&type_name)?;
+                self.alias(&type_name, u.description.as_deref(), "serde_json::Value");
+            }
+        }
+        Ok(())
+    }
+
+    fn alias(&mut self, name: &str, description: Option<&str>, target: &str) {
+        let mut item = doc_comment("", description);
+        let _ = writeln!(item, "pub type {} = {};", name, target);
+        self.items.push(item);
+    }
+
+    /// Generate a named type for a body or message schema
+    fn named_type(
+        &mut self,
+        name: &str,
+        description: Option<&str>,
+        schema: &LexType,
+    ) -> Result<()> {
+        match schema {
+            LexType::Object(object) => {
+                self.object(name, description.or(object.description.as_deref()), object)
+            }
+            LexType::Union(union) => self.union_enum(name, description, union),
+            other => {
+                self.claim(name)?;
+                let target = self.field_type(name, "", other)?;
+                self.alias(name, description, &target);
+                Ok(())
+            }
+        }
+    }
+
+    /// Generate a struct for an object
+    fn object(&mut self, name: &str, description: Option<&str>, object: &LexObject) -> Result<()> {
+        self.claim(name)?;
+        // Keep the struct ahead of the inline types generated for its fields
+        let slot = self.items.len();
+        self.items.push(String::new());
+        let required: HashSet<&str> = object
+            .required
+            .iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        let nullable: HashSet<&str> = object
+            .nullable
+            .iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+
+        let mut fields = String::new();
+        let mut properties: Vec<(&String, &LexType)> = object.properties.iter().collect();
+        properties.sort_by_key(|(key, _)| key.as_str());
+        for (key, lex_type) in properties {
+            let is_required = required.contains(key.as_str());
+            let is_nullable = nullable.contains(key.as_str());
+            let rust_type = self.field_type(name, key, lex_type)?;
+            let (ident, rename) = field_ident(key);
+
+            fields.push_str(&doc_comment("    ", type_description(lex_type)));
+            let mut attrs = Vec::new();
+            if let Some(rename) = rename {
+                attrs.push(format!("rename = \"{}\"", rename));
+            }
+            let rust_type = if is_required && !is_nullable {
+                rust_type
+            } else {
+                if !is_required {
+                    attrs.push("default".to_string());
+                    attrs.push("skip_serializing_if = \"Option::is_none\"".to_string());
+                }
+                format!("Option<{}>", rust_type)
+            };
+            if !attrs.is_empty() {
+                let _ = writeln!(fields, "    #[serde({})]", attrs.join(", "));
+            }
+            let _ = writeln!(fields, "    pub {}: {},", ident, rust_type);
+        }
+
+        let mut item = doc_comment("", description);
+        item.push_str("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n");
+        item.push_str("#[serde(rename_all = \"camelCase\")]\n");
+        let _ = write!(item, "pub struct {} {{\n{}}}\n", name, fields);
+        self.items[slot] = item;
+        Ok(())
+    }
+
+    /// Generate an enum for a union
+    ///
+    /// Open unions are returned wrapped in the root `Union` type by
+    /// [`Self::field_type`]; the enum itself only lists the known types.
+    fn union_enum(
+        &mut self,
+        name: &str,
+        description: Option<&str>,
+        union: &LexUnion,
+    ) -> Result<()> {
+        self.claim(name)?;
+        let targets = union
+            .refs
+            .iter()
+            .map(|r| parse_ref(r, self.nsid()))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        // Prefer short variant names, qualifying them all on any collision
+        let short: Vec<String> = targets
+            .iter()
+            .map(|(nsid, def)| {
+                if def == "main" {
+                    pascal_case(nsid.rsplit('.').next().unwrap_or(nsid))
+                } else {
+                    pascal_case(def)
+                }
+            })
+            .collect();
+        let unique: HashSet<&String> = short.iter().collect();
+        let variants: Vec<String> = if unique.len() == short.len() {
+            short
+        } else {
+            targets
+                .iter()
+                .map(|(nsid, def)| {
+                    let last = nsid.rsplit('.').next().unwrap_or(nsid);
+                    if def == "main" {
+                        pascal_case(last)
+                    } else {
+                        pascal_case(last) + &pascal_case(def)
+                    }
+                })
+                .collect()
+        };
+
+        let mut body = String::new();
+        for ((nsid, def), variant) in targets.iter().zip(&variants) {
+            if def == "main" {
+                let _ = writeln!(
+                    body,
+                    "    #[serde(rename = \"{}\", alias = \"{}#main\")]",
+                    nsid, nsid
+                );
+            } else {
+                let _ = writeln!(body, "    #[serde(rename = \"{}#{}\")]", nsid, def);
+            }
+            let _ = writeln!(body, "    {}(Box<{}>),", variant, self.type_path(nsid, def)?);
+        }
+
+        let mut item = doc_comment("", description);
+        item.push_str("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n");
+        item.push_str("#[serde(tag = \"$type\")]\n");
+        let _ = write!(item, "pub enum {} {{\n{}}}\n", name, body);
+        self.items.push(item);
+        Ok(())
+    }
+
+    /// Rust type of a property, generating inline objects and unions
+    ///
+    /// `owner` and `field` name any generated type (e.g., `PostEmbed`).
+    fn field_type(&mut self, owner: &str, field: &str, lex_type: &LexType) -> Result<String> {
+        let crate_path = &self.generator.crate_path;
+        Ok(match lex_type {
+            LexType::Null => "()".to_string(),
+            LexType::Boolean(_) => "bool".to_string(),
+            LexType::Integer(_) => "i64".to_string(),
+            LexType::String(_) | LexType::Token(_) => "String".to_string(),
+            LexType::Bytes(_) | LexType::Unknown(_) => "serde_json::Value".to_string(),
+            LexType::CidLink(_) => format!("{}::lexicon::CidLink", crate_path),
+            LexType::Blob(_) => format!("{}::lexicon::BlobRef", crate_path),
+            LexType::Array(array) => {
+                let item = match array.items.as_ref() {
+                    // The Vec already boxes its elements
+                    LexType::Ref(ref_type) => {
+                        let (nsid, def) = parse_ref(&ref_type.ref_to, self.nsid())?;
+                        self.type_path(&nsid, &def)?
+                    }
+                    items => {
+                        self.field_type(owner, &format!("{}Item", pascal_case(field)), items)?
+                    }
+                };
+                format!("Vec<{}>", item)
+            }
+            LexType::Object(object) => {
+                let name = format!("{}{}", owner, pascal_case(field));
+                self.object(&name, object.description.as_deref(), object)?;
+                name
+            }
+            LexType::Union(union) => {
+                let name = format!("{}{}", owner, pascal_case(field));
+                let name = if field.ends_with("Item") {
+                    name
+                } else {
+                    name + "Union"
+                };
+                self.union_enum(&name, union.description.as_deref(), union)?;
+                if union.closed == Some(true) {
+                    name
+                } else {
+                    format!("{}::Union<{}>", self.generator.root_module, name)
+                }
+            }
+            LexType::Ref(ref_type) => {
+                let (nsid, def) = parse_ref(&ref_type.ref_to, self.nsid())?;
+                let path = self.type_path(&nsid, &def)?;
+                // Owner is a definition of this module when it is a plain def name
+                let owner_def = self
+                    .doc
+                    .defs
+                    .keys()
+                    .find(|name| self.def_type_name(name) == owner)
+                    .cloned();
+                match owner_def {
+                    Some(owner_def)
+                        if self.reaches(
+                            &nsid,
+                            &def,
+                            self.nsid(),
+                            &owner_def,
+                            &mut HashSet::new(),
+                        ) =>
+                    {
+                        format!("Box<{}>", path)
+                    }
+                    _ => path,
+                }
+            }
+        })
+    }
+
+    /// Rust path of the type generated for a definition
+    fn type_path(&self, nsid: &str, def: &str) -> Result<String> {
+        let target = self
+            .generator
+            .registry
+            .resolve_ref(nsid, &format!("#{}", def))?;
+        let name = match target {
+            // Refs to tokens are string values
+            LexiconDef::Token(_) => return Ok("String".to_string()),
+            LexiconDef::Query(_) | LexiconDef::Procedure(_) | LexiconDef::Subscription(_) => {
+                return Ok("serde_json::Value".to_string())
+            }
+            _ => self.generator.type_name(nsid, def),
+        };
+        if nsid == self.nsid() {
+            Ok(name)
+        } else {
+            Ok(format!("{}::{}", self.generator.module_path(nsid), name))
+        }
+    }
+
+    /// Check whether a definition contains another without indirection
+    ///
+    /// Used to box fields of recursive types. Arrays and unions already add
+    /// indirection, so only plain refs and inline objects are followed.
+    fn reaches(
+        &self,
+        from_nsid: &str,
+        from_def: &str,
+        to_nsid: &str,
+        to_def: &str,
+        visited: &mut HashSet<String>,
+    ) -> bool {
+        if from_nsid == to_nsid && from_def == to_def {
+            return true;
+        }
+        if !visited.insert(format!("{}#{}", from_nsid, from_def)) {
+            return false;
+        }
+        let object = match self
+            .generator
+            .registry
+            .get(from_nsid)
+            .and_then(|d| d.defs.get(from_def))
+        {
+            Some(LexiconDef::Object(object)) => object,
+            Some(LexiconDef::Record(record)) => &record.record,
+            _ => return false,
+        };
+        let mut refs = Vec::new();
+        direct_refs(object, &mut refs);
+        refs.into_iter()
+            .any(|ref_str| match parse_ref(&ref_str, from_nsid) {
+                Ok((nsid, def)) => self.reaches(&nsid, &def, to_nsid, to_def, visited),
+                Err(_) => false,
+            })
+    }
+
+    /// Generate the `Parameters` struct, returning its fields
+    fn params(&mut self, params: Option<&LexParams>) -> Result<Vec<Param>> {
+        let Some(params) = params.filter(|p| !p.properties.is_empty()) else {
+            return Ok(Vec::new());
+        };
+        let object = LexObject {
+            type_name: "object".to_string(),
+            description: params.description.clone(),
+            properties: params.properties.clone(),
+            required: params.required.clone(),
+            nullable: None,
+        };
+        self.object("Parameters", params.description.as_deref(), &object)?;
+
+        let required: HashSet<&str> = params
+            .required
+            .iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        let mut fields: Vec<Param> = params
+            .properties
+            .iter()
+            .map(|(key, lex_type)| {
+                let (array, item) = match lex_type {
+                    LexType::Array(array) => (true, array.items.as_ref()),
+                    other => (false, other),
+                };
+                Param {
+                    key: key.clone(),
+                    ident: field_ident(key).0,
+                    required: required.contains(key.as_str()),
+                    array,
+                    string: matches!(item, LexType::String(_)),
+                }
+            })
+            .collect();
+        fields.sort_by(|a, b| a.key.cmp(&b.key));
+        Ok(fields)
+    }
+
+    /// Generate the type for an input or output body
+    fn body(&mut self, name: &str, body: Option<&LexBody>) -> Result<Option<Body>> {
+        let Some(body) = body else {
+            return Ok(None);
+        };
+        if body.encoding != "application/json" {
+            return Ok(Some(Body::Raw));
+        }
+        match &body.schema {
+            Some(schema) => {
+                self.named_type(name, body.description.as_deref(), schema)?;
+                Ok(Some(Body::Json))
+            }
+            None => {
+                self.claim(name)?;
+                self.alias(name, body.description.as_deref(), "serde_json::Value");
+                Ok(Some(Body::Json))
+            }
+        }
+    }
+
+    /// Generate the `Error` enum for an endpoint's declared errors
+    ///
+    /// Returns whether any errors are declared.
+    fn errors(&mut self, errors: Option<&[LexXrpcError]>) -> Result<bool> {
+        let mut declared: Vec<&LexXrpcError> = Vec::new();
+        for error in errors.unwrap_or_default() {
+            if !declared.iter().any(|e| e.name == error.name) {
+                declared.push(error);
+            }
+        }
+        if declared.is_empty() {
+            return Ok(false);
+        }
+        self.claim("Error")?;
+
+        let mut variants = String::new();
+        let mut from_name = String::new();
+        let mut to_name = String::new();
+        for error in &declared {
+            let variant = pascal_case(&error.name);
+            variants.push_str(&doc_comment("    ", error.description.as_deref()));
+            let _ = writeln!(variants, "    {},", variant);
+            let _ =
+                writeln!(from_name, "            \"{}\" => Some(Self::{}),", error.name, variant);
+            let _ = writeln!(to_name, "            Self::{} => \"{}\",", variant, error.name);
+        }
+
+        let mut item = String::from("/// Errors declared by this endpoint\n");
+        item.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+        let _ = write!(item, "pub enum Error {{\n{}}}\n\n", variants);
+        let _ = write!(
+            item,
+            "impl XrpcErrorKind for Error {{\n\
+             \x20   fn from_error_name(name: &str) -> Option<Self> {{\n\
+             \x20       match name {{\n{}            _ => None,\n        }}\n    }}\n\n\
+             \x20   fn error_name(&self) -> &'static str {{\n\
+             \x20       match self {{\n{}        }}\n    }}\n}}\n",
+            from_name, to_name
+        );
+        self.items.push(item);
+        Ok(true)
+    }
+
+    /// Generate the function calling a query or procedure
+    fn xrpc_fn(&mut self, def_name: &str, description: Option<&str>, signature: Signature) {
+        let Signature { procedure, params, input, output, errors } = signature;
+        // Binary responses (CAR files, blobs) are not JSON and are left to callers
+        if def_name != "main" || output == Some(Body::Raw) {
+            return;
+        }
+
+        let fn_name = module_name(self.nsid().rsplit('.').next().unwrap_or_default());
+        let mut args = vec!["client: &XrpcClient".to_string()];
+        if !params.is_empty() {
+            args.push("params: &Parameters".to_string());
+        }
+        match input {
+            Some(Body::Json) => args.push("input: &Input".to_string()),
+            Some(Body::Raw) => {
+                args.push("body: Vec<u8>".to_string());
+                args.push("encoding: &str".to_string());
+            }
+            Some(Body::None) | None => {}
+        }
+        let output_type = if output.is_some() { "Output" } else { "()" };
+        let error_type = if errors {
+            "EndpointError<Error>".to_string()
+        } else {
+            "XrpcError".to_string()
+        };
+
+        let mut item = doc_comment("", description);
+        let _ = writeln!(
+            item,
+            "pub async fn {}({}) -> Result<{}, {}> {{",
+            fn_name,
+            args.join(", "),
+            output_type,
+            error_type
+        );
+
+        let constructor = if procedure { "procedure" } else { "query" };
+        let mutable = if params.is_empty() { "" } else { "mut " };
+        let _ = write!(item, "    let {}request = XrpcRequest::{}(NSID)", mutable, constructor);
+        match input {
+            Some(Body::Json) => {
+                item.push_str(
+                    "\n        .json_body(input)\n        .map_err(|e| XrpcError::new(0, \"SerializeError\", e.to_string()))?",
+                );
+            }
+            Some(Body::Raw) => item.push_str(".body(body).encoding(encoding)"),
+            Some(Body::None) | None => {}
+        }
+        item.push_str(";\n");
+
+        for param in &params {
+            let value = if param.string {
+                "value.clone()"
+            } else {
+                "value.to_string()"
+            };
+            let _ = match (param.array, param.required) {
+                (true, true) => writeln!(item, "    for value in &params.{} {{", param.ident),
+                (true, false) => {
+                    writeln!(item, "    for value in params.{}.iter().flatten() {{", param.ident)
+                }
+                (false, true) => {
+                    writeln!(item, "    {{\n        let value = &params.{};", param.ident)
+                }
+                (false, false) => {
+                    writeln!(item, "    if let Some(value) = &params.{} {{", param.ident)
+                }
+            };
+            let _ = writeln!(
+                item,
+                "        request = request.param(\"{}\", {});\n    }}",
+                param.key, value
+            );
+        }
+
+        let call = if procedure { "procedure" } else { "query" };
+        if output.is_some() {
+            let _ = writeln!(item, "    Ok(client.{}(request).await?.data)", call);
+        } else {
+            let _ = writeln!(
+                item,
+                "    client.{}::<serde::de::IgnoredAny>(request).await?;\n    Ok(())",
+                call
+            );
+        }
+        item.push_str("}\n");
+        self.items.push(item);
+    }
+}
+
+/// Shape of a generated XRPC function
+struct Signature {
+    procedure: bool,
+    params: Vec<Param>,
+    input: Option<Body>,
+    output: Option<Body>,
+    /// Whether the endpoint has a generated `Error` enum
+    errors: bool,
+}
+
+/// A query parameter of a generated XRPC function
+struct Param {
+    key: String,
+    ident: String,
+    required: bool,
+    array: bool,
+    string: bool,
+}
+
+/// Kind of an XRPC input or output body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Body {
+    /// JSON with a generated type
+    Json,
+    /// Raw bytes with a caller-chosen encoding
+    Raw,
+    /// Procedure without an input body
+    None,
+}
+
+/// Collect refs an object holds without indirection
+fn direct_refs(object: &LexObject, refs: &mut Vec<String>) {
+    for lex_type in object.properties.values() {
+        match lex_type {
+            LexType::Ref(r) => refs.push(r.ref_to.clone()),
+            LexType::Object(inner) => direct_refs(inner, refs),
+            _ => {}
+        }
+    }
+}
+
+/// Description of a property type
+fn type_description(lex_type: &LexType) -> Option<&str> {
+    match lex_type {
+        LexType::Null => None,
+        LexType::Boolean(t) => t.description.as_deref(),
+        LexType::Integer(t) => t.description.as_deref(),
+        LexType::String(t) => t.description.as_deref(),
+        LexType::Bytes(t) => t.description.as_deref(),
+        LexType::CidLink(t) => t.description.as_deref(),
+        LexType::Blob(t) => t.description.as_deref(),
+        LexType::Array(t) => t.description.as_deref(),
+        LexType::Object(t) => t.description.as_deref(),
+        LexType::Ref(t) => t.description.as_deref(),
+        LexType::Union(t) => t.description.as_deref(),
+        LexType::Token(t) => t.description.as_deref(),
+        LexType::Unknown(t) => t.description.as_deref(),
+    }
+}
+
+/// Format a description as a doc comment
+fn doc_comment(indent: &str, description: Option<&str>) -> String {
+    let mut out = String::new();
+    for line in description.map(doc_lines).unwrap_or_default() {
+        if line.is_empty() {
+            let _ = writeln!(out, "{}///", indent);
+        } else {
+            let _ = writeln!(out, "{}/// {}", indent, line);
+        }
+    }
+    out
+}
+
+/// Split a description into doc comment lines
+///
+/// Lines are trimmed so indented text is not mistaken for a doctest.
+fn doc_lines(description: &str) -> Vec<String> {
+    description
+        .trim()
+        .lines()
+        .map(|line| line.trim().to_string())
+        .collect()
+}
+
+/// Constant name of a token
+fn const_name(def_name: &str, nsid: &str) -> String {
+    let name = if def_name == "main" {
+        nsid.rsplit('.').next().unwrap_or(nsid)
+    } else {
+        def_name
+    };
+    snake_case(name).to_uppercase()
+}
+
+/// Module name of an NSID segment
+fn module_name(segment: &str) -> String {
+    escape_keyword(snake_case(segment))
+}
+
+/// Rust field name of a property, with the serde rename it needs
+///
+/// Structs use `rename_all = "camelCase"`, so an explicit rename is only
+/// needed when that conversion does not give back the property name.
+fn field_ident(key: &str) -> (String, Option<String>) {
+    let snake = snake_case(key);
+    let rename = (camel_case(&snake) != key).then(|| key.to_string());
+    (escape_keyword(snake), rename)
+}
+
+/// Convert `camelCase` to `snake_case`
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '-' || c == '.' {
+            out.push('_');
+            continue;
+        }
+        if c.is_ascii_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            if prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && next_lower)
+            {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+    out
+}
+
+/// Convert `snake_case` to `camelCase` the way serde does
+fn camel_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            out.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Convert `camelCase` or `snake_case` to `PascalCase`
+fn pascal_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut upper = true;
+    for c in name.chars() {
+        if c == '_' || c == '-' || c == '.' {
+            upper = true;
+        } else if upper {
+            out.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Make an identifier safe to use in Rust
+fn escape_keyword(ident: String) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as", "async", "await", "box", "break", "const", "continue", "dyn", "else", "enum",
+        "extern", "false", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "match", "mod",
+        "move", "mut", "priv", "pub", "ref", "return", "static", "struct", "trait", "true", "try",
+        "type", "unsafe", "use", "where", "while", "yield",
+    ];
+    match ident.as_str() {
+        // These can't be raw identifiers
+        "self" | "super" | "crate" | "Self" => format!("{}_", ident),
+        _ if KEYWORDS.contains(&ident.as_str()) => format!("r#{}", ident),
+        _ => ident,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> SchemaRegistry {
+        let docs = [
+            r##"{
+              "lexicon": 1,
+              "id": "com.example.thread",
+              "defs": {
+                "main": {
+                  "type": "query",
+                  "description": "Get a thread.",
+                  "parameters": {
+                    "type": "params",
+                    "required": ["uri"],
+                    "properties": {
+                      "uri": {"type": "string", "format": "at-uri"},
+                      "depth": {"type": "integer"},
+                      "tags": {"type": "array", "items": {"type": "string"}}
+                    }
+                  },
+                  "output": {
+                    "encoding": "application/json",
+                    "schema": {
+                      "type": "object",
+                      "required": ["thread"],
+                      "properties": {
+                        "thread": {"type": "union", "refs": ["#node", "com.example.defs#notFound"]}
+                      }
+                    }
+                  },
+                  "errors": [
+                    {"name": "NotFound"},
+                    {"name": "BlockedActor", "description": "The requester blocks the author."}
+                  ]
+                },
+                "node": {
+                  "type": "object",
+                  "required": ["post"],
+                  "properties": {
+                    "post": {"type": "ref", "ref": "com.example.defs#post"},
+                    "parent": {"type": "ref", "ref": "#node"},
+                    "replies": {"type": "array", "items": {"type": "ref", "ref": "#node"}},
+                    "ref": {"type": "string"}
+                  }
+                }
+              }
+            }"##,
+            r##"{
+              "lexicon": 1,
+              "id": "com.example.defs",
+              "defs": {
+                "post": {
+                  "type": "object",
+                  "required": ["uri", "likeCount"],
+                  "nullable": ["likeCount"],
+                  "properties": {
+                    "uri": {"type": "string"},
+                    "likeCount": {"type": "integer"},
+                    "embed": {"type": "union", "closed": true, "refs": ["com.example.mute"]}
+                  }
+                },
+                "notFound": {
+                  "type": "object",
+                  "properties": {"uri": {"type": "string"}}
+                },
+                "curated": {"type": "token", "description": "Curated list"}
+              }
+            }"##,
+            r##"{
+              "lexicon": 1,
+              "id": "com.example.mute",
+              "defs": {
+                "main": {
+                  "type": "procedure",
+                  "input": {
+                    "encoding": "application/json",
+                    "schema": {"type": "object", "required": ["actor"], "properties": {"actor": {"type": "string"}}}
+                  }
+                }
+              }
+            }"##,
+        ];
+        let mut registry = SchemaRegistry::new();
+        for json in docs {
+            registry.register(LexiconDoc::from_json(json).unwrap());
+        }
+        registry
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(snake_case("getTimeline"), "get_timeline");
+        assert_eq!(snake_case("putPreferencesV2"), "put_preferences_v2");
+        assert_eq!(snake_case("JOBState"), "job_state");
+        assert_eq!(pascal_case("listViewBasic"), "ListViewBasic");
+        assert_eq!(field_ident("likeCount"), ("like_count".to_string(), None));
+        assert_eq!(field_ident("ref"), ("r#ref".to_string(), None));
+        assert_eq!(
+            field_ident("JOBState"),
+            ("job_state".to_string(), Some("JOBState".to_string()))
+        );
+        assert_eq!(module_name("self"), "self_");
+        assert_eq!(const_name("curatelist", "app.bsky.graph.defs"), "CURATELIST");
+    }
+
+    #[test]
+    fn test_generate_query() {
+        let registry = registry();
+        let source = CodeGenerator::new(&registry)
+            .generate_lexicon("com.example.thread")
+            .unwrap();
+
+        assert!(source.contains("pub const NSID: &str = \"com.example.thread\";"));
+        assert!(source.contains("pub struct Parameters {"));
+        assert!(source.contains("pub struct Output {"));
+        assert!(source.contains("pub thread: crate::api::Union<OutputThreadUnion>,"));
+        assert!(
+            source.contains("#[serde(rename = \"com.example.thread#node\")]\n    Node(Box<Node>),")
+        );
+        assert!(source.contains("NotFound(Box<crate::api::com::example::defs::NotFound>),"));
+        // Recursive through a plain field, but not through the array
+        assert!(source.contains("pub parent: Option<Box<Node>>,"));
+        assert!(source.contains("pub replies: Option<Vec<Node>>,"));
+        assert!(source.contains("pub r#ref: Option<String>,"));
+        assert!(source.contains(
+            "pub async fn thread(client: &XrpcClient, params: &Parameters) -> Result<Output, EndpointError<Error>> {"
+        ));
+        assert!(source.contains(
+            "pub enum Error {\n    NotFound,\n    /// The requester blocks the author.\n    BlockedActor,\n}"
+        ));
+        assert!(source.contains("impl XrpcErrorKind for Error {"));
+        assert!(source
+            .contains("use crate::xrpc::{EndpointError, XrpcClient, XrpcErrorKind, XrpcRequest};"));
+        assert!(source.contains("\"BlockedActor\" => Some(Self::BlockedActor),"));
+        assert!(source.contains("request = request.param(\"uri\", value.clone());"));
+        assert!(source.contains("if let Some(value) = &params.depth {"));
+        assert!(source.contains("for value in params.tags.iter().flatten() {"));
+    }
+
+    #[test]
+    fn test_generate_defs_and_procedure() {
+        let registry = registry();
+        let generator = CodeGenerator::new(&registry);
+
+        let defs = generator.generate_lexicon("com.example.defs").unwrap();
+        assert!(defs
+            .contains("/// Curated list\npub const CURATED: &str = \"com.example.defs#curated\";"));
+        assert!(defs.contains("pub like_count: Option<i64>,"));
+        assert!(!defs.contains("XrpcClient"));
+        // Closed unions aren't wrapped, and main refs use the bare NSID
+        assert!(defs.contains("pub embed: Option<PostEmbedUnion>,"));
+        assert!(defs.contains(
+            "#[serde(rename = \"com.example.mute\", alias = \"com.example.mute#main\")]"
+        ));
+
+        let mute = generator.generate_lexicon("com.example.mute").unwrap();
+        assert!(mute.contains(
+            "pub async fn mute(client: &XrpcClient, input: &Input) -> Result<(), XrpcError> {"
+        ));
+        assert!(mute.contains("client.procedure::<serde::de::IgnoredAny>(request).await?;"));
+    }
+
+    #[test]
+    fn test_generate_tree_includes_referenced_docs() {
+        let registry = registry();
+        let files = CodeGenerator::new(&registry)
+            .generate(&["com.example.thread"])
+            .unwrap();
+        let paths: Vec<String> = files.iter().map(|f| f.path.display().to_string()).collect();
+
+        assert_eq!(
+            paths,
+            [
+                "com/example/defs.rs",
+                "com/example/mod.rs",
+                "com/example/mute.rs",
+                "com/example/thread.rs",
+                "com/mod.rs",
+                "mod.rs",
+            ]
+        );
+        let root = &files[5].contents;
+        assert!(root.contains("pub enum Union<T> {"));
+        assert!(root.contains("pub mod com;"));
+        assert_eq!(
+            files[1].contents,
+            "//! `com.example.*`\n\npub mod defs;\npub mod mute;\npub mod thread;\n"
+        );
+    }
+
+    #[test]
+    fn test_missing_schema() {
+        let registry = registry();
+        let result = CodeGenerator::new(&registry).generate_lexicon("com.example.missing");
+        assert!(matches!(result, Err(CodegenError::SchemaNotFound(_))));
+    }
+
+    #[test]
+    fn test_generated_api_roundtrip() {
+        use crate::api::app::bsky::embed::images;
+        use crate::api::app::bsky::feed::post::{Post, PostEmbedUnion};
+        use crate::api::app::bsky::richtext::facet::FacetFeaturesItem;
+        use crate::api::Union;
+
+        let json = serde_json::json!({
+            "$type": "app.bsky.feed.post",
+            "text": "Hello @bob.test",
+            "facets": [{
+                "index": {"byteStart": 6, "byteEnd": 15},
+                "features": [
+                    {"$type": "app.bsky.richtext.facet#mention", "did": "did:plc:bob"},
+                    {"$type": "com.example.facet#sparkle"}
+                ]
+            }],
+            "embed": {
+                "$type": "app.bsky.embed.images",
+                "images": [{
+                    "alt": "A cat",
+                    "image": {
+                        "$type": "blob",
+                        "ref": {"$link": "bafkreibjo4xmgaevkgud7mbifn3dzp4v4lyaui4yvqp3f2bqwtxcjrdqg4"},
+                        "mimeType": "image/jpeg",
+                        "size": 1024
+                    }
+                }]
+            },
+            "createdAt": "2024-01-01T00:00:00.000Z"
+        });
+
+        let post: Post = serde_json::from_value(json).unwrap();
+        let features = &post.facets.as_ref().unwrap()[0].features;
+        assert!(
+            matches!(&features[0], Union::Known(FacetFeaturesItem::Mention(m)) if m.did == "did:plc:bob")
+        );
+        assert!(matches!(&features[1], Union::Unknown(_)));
+
+        let Some(Union::Known(PostEmbedUnion::Images(embed))) = &post.embed else {
+            panic!("Expected an images embed: {:?}", post.embed);
+        };
+        let image: &images::Image = &embed.images[0];
+        assert_eq!(image.image.mime_type, "image/jpeg");
+
+        let value = serde_json::to_value(&post).unwrap();
+        assert_eq!(value["embed"]["$type"], "app.bsky.embed.images");
+        assert_eq!(value["facets"][0]["index"]["byteEnd"], 15);
+        assert_eq!(serde_json::from_value::<Post>(value).unwrap(), post);
+    }
+
+    /// The committed `api` tree must match what `lexgen` writes for the
+    /// built-in lexicons
+    #[cfg(feature = "builtin-lexicons")]
+    #[test]
+    fn test_committed_api_matches_generator() {
+        use std::path::Path;
+        use std::process::Command;
+
+        fn committed(dir: &Path, root: &Path, out: &mut BTreeMap<PathBuf, String>) {
+            for entry in std::fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    committed(&path, root, out);
+                } else if path.extension().is_some_and(|ext| ext == "rs") {
+                    let contents = std::fs::read_to_string(&path).unwrap();
+                    out.insert(path.strip_prefix(root).unwrap().to_path_buf(), contents);
+                }
+            }
+        }
+
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let api_dir = manifest_dir.join("src/api");
+        let registry = SchemaRegistry::with_builtin();
+        let files = CodeGenerator::new(&registry).generate(&[]).unwrap();
+
+        // Format like lexgen does, with the workspace's rustfmt settings
+        let out = tempfile::TempDir::new().unwrap();
+        let mut written = Vec::with_capacity(files.len());
+        for file in &files {
+            let path = out.path().join(&file.path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, &file.contents).unwrap();
+            written.push(path);
+        }
+        let formatted = Command::new("rustfmt")
+            .args(["--edition", "2021", "--config-path"])
+            .arg(manifest_dir.join("../../rustfmt.toml"))
+            .args(&written)
+            .status();
+        match formatted {
+            Ok(status) => assert!(status.success(), "rustfmt failed on generated code"),
+            Err(e) => {
+                eprintln!("skipping: rustfmt is not available ({})", e);
+                return;
+            }
+        }
+
+        let mut generated = BTreeMap::new();
+        committed(out.path(), out.path(), &mut generated);
+        let mut expected = BTreeMap::new();
+        committed(&api_dir, &api_dir, &mut expected);
+
+        let stale: Vec<_> = generated
+            .keys()
+            .chain(expected.keys())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .filter(|path| generated.get(*path) != expected.get(*path))
+            .collect();
+        assert!(
+            stale.is_empty(),
+            "src/api is out of date with the generator; regenerate it with \
+             `cargo run -p atproto-client --bin lexgen -- --out crates/atproto-client/src/api`. \
+             Differing files: {:?}",
+            stale
+        );
+    }
+}
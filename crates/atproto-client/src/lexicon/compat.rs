@@ -0,0 +1,962 @@
+//! Compatibility checking between versions of a Lexicon schema
+//!
+//! Lexicons evolve under the rules in the spec: new fields must be optional,
+//! required fields can't be removed, and types can't change. [`diff_lexicons`]
+//! compares two versions of a document and classifies every difference as
+//! [`Compatibility::Compatible`] or [`Compatibility::Breaking`].
+//!
+//! Paths in the report start at the definition name and follow properties
+//! with `.` (e.g., `main.embed`). Array items are marked with `[]`, and XRPC
+//! parts use `parameters`, `input`, `output` and `message`.
+//!
+//! Reference: <https://atproto.com/specs/lexicon#lexicon-evolution>
+//!
+//! # Example
+//!
+//! ```rust
+//! use atproto_client::lexicon::{diff_lexicons, ChangeKind, LexiconDoc};
+//!
+//! let old = LexiconDoc::from_json(r#"{
+//!   "lexicon": 1,
+//!   "id": "com.example.note",
+//!   "defs": {
+//!     "main": {
+//!       "type": "record",
+//!       "key": "tid",
+//!       "record": {
+//!         "type": "object",
+//!         "required": ["text"],
+//!         "properties": { "text": { "type": "string", "maxLength": 300 } }
+//!       }
+//!     }
+//!   }
+//! }"#).unwrap();
+//! let new = LexiconDoc::from_json(r#"{
+//!   "lexicon": 1,
+//!   "id": "com.example.note",
+//!   "defs": {
+//!     "main": {
+//!       "type": "record",
+//!       "key": "tid",
+//!       "record": {
+//!         "type": "object",
+//!         "required": ["text"],
+//!         "properties": {
+//!           "text": { "type": "string", "maxLength": 3000 },
+//!           "title": { "type": "string" }
+//!         }
+//!       }
+//!     }
+//!   }
+//! }"#).unwrap();
+//!
+//! let report = diff_lexicons(&old, &new);
+//! assert!(report.is_compatible());
+//! assert_eq!(report.changes[0].kind, ChangeKind::ConstraintWidened);
+//! assert_eq!(report.changes[1].kind, ChangeKind::FieldAdded);
+//!
+//! // Reports serialize to JSON for tooling
+//! let json = serde_json::to_value(&report).unwrap();
+//! assert_eq!(json["changes"][1]["path"], "main.title");
+//! ```
+
+use super::constraints::{BlobConstraints, StringConstraints};
+use super::resolution::parse_ref;
+use super::schema::{LexBody, LexParams, LexXrpcError, LexiconDef, LexiconDoc};
+use super::types::{LexObject, LexType, LexUnion};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Display;
+
+/// Whether a change keeps existing clients and data working
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Compatibility {
+    /// Existing clients and data keep working
+    Compatible,
+    /// Existing clients or data may be rejected or misread
+    Breaking,
+}
+
+/// Kind of schema change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeKind {
+    /// The document ID changed
+    IdChanged,
+    /// A definition was added
+    DefinitionAdded,
+    /// A definition was removed
+    DefinitionRemoved,
+    /// A definition or field changed type
+    TypeChanged,
+    /// An object property or parameter was added
+    FieldAdded,
+    /// An object property or parameter was removed
+    FieldRemoved,
+    /// A field became required or optional
+    RequirementChanged,
+    /// A field became nullable or non-nullable
+    NullabilityChanged,
+    /// A constraint now accepts fewer values
+    ConstraintTightened,
+    /// A constraint now accepts more values
+    ConstraintWidened,
+    /// A `const` or `default` value changed
+    ValueChanged,
+    /// `knownValues` changed (never enforced)
+    KnownValuesChanged,
+    /// A string format was added, removed or changed
+    FormatChanged,
+    /// A union gained a member
+    UnionMemberAdded,
+    /// A union lost a member
+    UnionMemberRemoved,
+    /// A union switched between open and closed
+    UnionClosedChanged,
+    /// A reference points at a different definition
+    RefChanged,
+    /// A record's key type changed
+    RecordKeyChanged,
+    /// An input or output body was added
+    BodyAdded,
+    /// An input or output body was removed
+    BodyRemoved,
+    /// A body's encoding changed
+    EncodingChanged,
+    /// An XRPC error was declared
+    ErrorAdded,
+    /// An XRPC error declaration was removed
+    ErrorRemoved,
+}
+
+/// A single difference between two schema versions
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaChange {
+    /// Location of the change (e.g., "main.embed")
+    pub path: String,
+    /// Kind of change
+    pub kind: ChangeKind,
+    /// Whether the change is breaking
+    pub compatibility: Compatibility,
+    /// Human-readable description
+    pub message: String,
+}
+
+/// All differences between two versions of a Lexicon document
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompatibilityReport {
+    /// NSID of the new version
+    pub nsid: String,
+    /// Changes, ordered by definition and path
+    pub changes: Vec<SchemaChange>,
+}
+
+impl CompatibilityReport {
+    /// Check whether every change is compatible
+    pub fn is_compatible(&self) -> bool {
+        self.compatibility() == Compatibility::Compatible
+    }
+
+    /// Overall compatibility (breaking if any change is)
+    pub fn compatibility(&self) -> Compatibility {
+        if self
+            .changes
+            .iter()
+            .any(|c| c.compatibility == Compatibility::Breaking)
+        {
+            Compatibility::Breaking
+        } else {
+            Compatibility::Compatible
+        }
+    }
+
+    /// Iterate over breaking changes
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &SchemaChange> {
+        self.changes
+            .iter()
+            .filter(|c| c.compatibility == Compatibility::Breaking)
+    }
+
+    /// Check whether the versions are structurally identical
+    ///
+    /// Description changes are not reported.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Compare two versions of a Lexicon document
+///
+/// # Arguments
+///
+/// * `old` - The currently deployed version
+/// * `new` - The proposed version
+///
+/// # Example
+///
+/// ```rust
+/// use atproto_client::lexicon::{diff_lexicons, LexiconDoc};
+///
+/// let doc = LexiconDoc::new("com.example.foo");
+/// assert!(diff_lexicons(&doc, &doc).is_empty());
+/// ```
+pub fn diff_lexicons(old: &LexiconDoc, new: &LexiconDoc) -> CompatibilityReport {
+    let mut differ = Differ {
+        old_nsid: &old.id,
+        new_nsid: &new.id,
+        changes: Vec::new(),
+    };
+
+    if old.id != new.id {
+        differ.breaking(
+            "",
+            ChangeKind::IdChanged,
+            format!("Document ID changed from {} to {}", old.id, new.id),
+        );
+    }
+
+    let names: BTreeSet<&String> = old.defs.keys().chain(new.defs.keys()).collect();
+    for name in names {
+        match (old.defs.get(name), new.defs.get(name)) {
+            (Some(_), None) => differ.breaking(
+                name,
+                ChangeKind::DefinitionRemoved,
+                format!("Definition '{}' was removed", name),
+            ),
+            (None, Some(_)) => differ.compatible(
+                name,
+                ChangeKind::DefinitionAdded,
+                format!("Definition '{}' was added", name),
+            ),
+            (Some(old_def), Some(new_def)) => differ.def(name, old_def, new_def),
+            (None, None) => unreachable!("name comes from one of the maps"),
+        }
+    }
+
+    CompatibilityReport { nsid: new.id.clone(), changes: differ.changes }
+}
+
+/// Collects changes while walking both versions
+struct Differ<'a> {
+    old_nsid: &'a str,
+    new_nsid: &'a str,
+    changes: Vec<SchemaChange>,
+}
+
+impl Differ<'_> {
+    fn push(
+        &mut self,
+        path: &str,
+        kind: ChangeKind,
+        compatibility: Compatibility,
+        message: String,
+    ) {
+        self.changes.push(SchemaChange {
+            path: path.to_string(),
+            kind,
+            compatibility,
+            message,
+        });
+    }
+
+    fn breaking(&mut self, path: &str, kind: ChangeKind, message: String) {
+        self.push(path, kind, Compatibility::Breaking, message);
+    }
+
+    fn compatible(&mut self, path: &str, kind: ChangeKind, message: String) {
+        self.push(path, kind, Compatibility::Compatible, message);
+    }
+
+    fn def(&mut self, path: &str, old: &LexiconDef, new: &LexiconDef) {
+        match (old, new) {
+            (LexiconDef::Record(a), LexiconDef::Record(b)) => {
+                if a.key != b.key {
+                    self.breaking(
+                        path,
+                        ChangeKind::RecordKeyChanged,
+                        format!("Record key changed from {:?} to {:?}", a.key, b.key),
+                    );
+                }
+                self.object(path, &a.record, &b.record);
+            }
+            (LexiconDef::Query(a), LexiconDef::Query(b)) => {
+                self.params(path, a.parameters.as_ref(), b.parameters.as_ref());
+                self.body(&join(path, "output"), a.output.as_ref(), b.output.as_ref(), false);
+                self.errors(path, a.errors.as_deref(), b.errors.as_deref());
+            }
+            (LexiconDef::Procedure(a), LexiconDef::Procedure(b)) => {
+                self.params(path, a.parameters.as_ref(), b.parameters.as_ref());
+                self.body(&join(path, "input"), a.input.as_ref(), b.input.as_ref(), true);
+                self.body(&join(path, "output"), a.output.as_ref(), b.output.as_ref(), false);
+                self.errors(path, a.errors.as_deref(), b.errors.as_deref());
+            }
+            (LexiconDef::Subscription(a), LexiconDef::Subscription(b)) => {
+                self.params(path, a.parameters.as_ref(), b.parameters.as_ref());
+                let message = join(path, "message");
+                match (&a.message, &b.message) {
+                    (Some(a), Some(b)) => self.lex_type(&message, &a.schema, &b.schema),
+                    (None, None) => {}
+                    _ => self.breaking(
+                        &message,
+                        ChangeKind::TypeChanged,
+                        "Message schema was added or removed".to_string(),
+                    ),
+                }
+                self.errors(path, a.errors.as_deref(), b.errors.as_deref());
+            }
+            _ => match (def_as_type(old), def_as_type(new)) {
+                (Some(a), Some(b)) => self.lex_type(path, &a, &b),
+                _ => self.type_changed(path, def_kind(old), def_kind(new)),
+            },
+        }
+    }
+
+    fn type_changed(&mut self, path: &str, old: &str, new: &str) {
+        self.breaking(
+            path,
+            ChangeKind::TypeChanged,
+            format!("Type changed from {} to {}", old, new),
+        );
+    }
+
+    fn lex_type(&mut self, path: &str, old: &LexType, new: &LexType) {
+        match (old, new) {
+            (LexType::Null, LexType::Null)
+            | (LexType::CidLink(_), LexType::CidLink(_))
+            | (LexType::Token(_), LexType::Token(_))
+            | (LexType::Unknown(_), LexType::Unknown(_)) => {}
+            (LexType::Boolean(a), LexType::Boolean(b)) => {
+                self.value(path, "const", a.constraints.r#const, b.constraints.r#const);
+                self.value(path, "default", a.constraints.default, b.constraints.default);
+            }
+            (LexType::Integer(a), LexType::Integer(b)) => {
+                let (a, b) = (&a.constraints, &b.constraints);
+                self.lower_bound(path, "minimum", a.minimum, b.minimum);
+                self.upper_bound(path, "maximum", a.maximum, b.maximum);
+                self.closed_set(path, "enum", a.r#enum.as_deref(), b.r#enum.as_deref());
+                self.value(path, "const", a.r#const, b.r#const);
+                self.value(path, "default", a.default, b.default);
+            }
+            (LexType::String(a), LexType::String(b)) => {
+                self.format(path, a.format, b.format);
+                self.string_constraints(path, &a.constraints, &b.constraints);
+            }
+            (LexType::Bytes(a), LexType::Bytes(b)) => {
+                let (a, b) = (&a.constraints, &b.constraints);
+                self.lower_bound(path, "minLength", a.min_length, b.min_length);
+                self.upper_bound(path, "maxLength", a.max_length, b.max_length);
+            }
+            (LexType::Blob(a), LexType::Blob(b)) => {
+                self.blob_constraints(path, &a.constraints, &b.constraints);
+            }
+            (LexType::Array(a), LexType::Array(b)) => {
+                let (ac, bc) = (&a.constraints, &b.constraints);
+                self.lower_bound(path, "minLength", ac.min_length, bc.min_length);
+                self.upper_bound(path, "maxLength", ac.max_length, bc.max_length);
+                self.lex_type(&format!("{}[]", path), &a.items, &b.items);
+            }
+            (LexType::Object(a), LexType::Object(b)) => self.object(path, a, b),
+            (LexType::Ref(a), LexType::Ref(b)) => {
+                let (old_ref, new_ref) =
+                    (self.normalize(&a.ref_to, true), self.normalize(&b.ref_to, false));
+                if old_ref != new_ref {
+                    self.breaking(
+                        path,
+                        ChangeKind::RefChanged,
+                        format!("Reference changed from {} to {}", old_ref, new_ref),
+                    );
+                }
+            }
+            (LexType::Union(a), LexType::Union(b)) => self.union(path, a, b),
+            _ => self.type_changed(path, type_kind(old), type_kind(new)),
+        }
+    }
+
+    fn object(&mut self, path: &str, old: &LexObject, new: &LexObject) {
+        self.properties(
+            path,
+            (&old.properties, old.required.as_deref(), old.nullable.as_deref()),
+            (&new.properties, new.required.as_deref(), new.nullable.as_deref()),
+        );
+    }
+
+    fn params(&mut self, path: &str, old: Option<&LexParams>, new: Option<&LexParams>) {
+        fn fields<'a>(
+            params: Option<&'a LexParams>,
+            empty: &'a HashMap<String, LexType>,
+        ) -> Fields<'a> {
+            match params {
+                Some(p) => (&p.properties, p.required.as_deref(), None),
+                None => (empty, None, None),
+            }
+        }
+        let empty = HashMap::new();
+        self.properties(&join(path, "parameters"), fields(old, &empty), fields(new, &empty));
+    }
+
+    fn properties(&mut self, path: &str, old: Fields<'_>, new: Fields<'_>) {
+        let listed =
+            |list: Option<&[String]>, key: &str| list.is_some_and(|l| l.iter().any(|k| k == key));
+        let keys: BTreeSet<&String> = old.0.keys().chain(new.0.keys()).collect();
+
+        for key in keys {
+            let field = join(path, key);
+            let (old_required, new_required) = (listed(old.1, key), listed(new.1, key));
+            match (old.0.get(key), new.0.get(key)) {
+                (Some(_), None) => {
+                    let message = format!("Field '{}' was removed", key);
+                    if old_required {
+                        self.breaking(&field, ChangeKind::FieldRemoved, message);
+                    } else {
+                        self.compatible(&field, ChangeKind::FieldRemoved, message);
+                    }
+                }
+                (None, Some(_)) => {
+                    if new_required {
+                        let message = format!("Required field '{}' was added", key);
+                        self.breaking(&field, ChangeKind::FieldAdded, message);
+                    } else {
+                        let message = format!("Optional field '{}' was added", key);
+                        self.compatible(&field, ChangeKind::FieldAdded, message);
+                    }
+                }
+                (Some(a), Some(b)) => {
+                    if old_required != new_required {
+                        let state = if new_required { "required" } else { "optional" };
+                        self.breaking(
+                            &field,
+                            ChangeKind::RequirementChanged,
+                            format!("Field '{}' is now {}", key, state),
+                        );
+                    }
+                    let new_nullable = listed(new.2, key);
+                    if listed(old.2, key) != new_nullable {
+                        let state = if new_nullable {
+                            "nullable"
+                        } else {
+                            "non-nullable"
+                        };
+                        self.breaking(
+                            &field,
+                            ChangeKind::NullabilityChanged,
+                            format!("Field '{}' is now {}", key, state),
+                        );
+                    }
+                    self.lex_type(&field, a, b);
+                }
+                (None, None) => unreachable!("key comes from one of the maps"),
+            }
+        }
+    }
+
+    fn union(&mut self, path: &str, old: &LexUnion, new: &LexUnion) {
+        let old_refs: BTreeSet<String> = old.refs.iter().map(|r| self.normalize(r, true)).collect();
+        let new_refs: BTreeSet<String> =
+            new.refs.iter().map(|r| self.normalize(r, false)).collect();
+        let new_closed = new.closed == Some(true);
+
+        if (old.closed == Some(true)) != new_closed {
+            let state = if new_closed { "closed" } else { "open" };
+            self.breaking(path, ChangeKind::UnionClosedChanged, format!("Union is now {}", state));
+        }
+        for removed in old_refs.difference(&new_refs) {
+            self.breaking(
+                path,
+                ChangeKind::UnionMemberRemoved,
+                format!("Union member {} was removed", removed),
+            );
+        }
+        for added in new_refs.difference(&old_refs) {
+            // Readers of closed unions don't expect other types
+            let message = format!("Union member {} was added", added);
+            if new_closed {
+                self.breaking(path, ChangeKind::UnionMemberAdded, message);
+            } else {
+                self.compatible(path, ChangeKind::UnionMemberAdded, message);
+            }
+        }
+    }
+
+    fn body(&mut self, path: &str, old: Option<&LexBody>, new: Option<&LexBody>, input: bool) {
+        let (old, new) = match (old, new) {
+            (None, None) => return,
+            (Some(_), None) => {
+                return self.breaking(path, ChangeKind::BodyRemoved, "Body was removed".to_string())
+            }
+            (None, Some(_)) => {
+                // Old clients don't send a new input, but ignore a new output
+                let message = "Body was added".to_string();
+                return if input {
+                    self.breaking(path, ChangeKind::BodyAdded, message)
+                } else {
+                    self.compatible(path, ChangeKind::BodyAdded, message)
+                };
+            }
+            (Some(old), Some(new)) => (old, new),
+        };
+
+        if old.encoding != new.encoding {
+            self.breaking(
+                path,
+                ChangeKind::EncodingChanged,
+                format!("Encoding changed from {} to {}", old.encoding, new.encoding),
+            );
+        }
+        match (&old.schema, &new.schema) {
+            (Some(a), Some(b)) => self.lex_type(path, a, b),
+            (None, None) => {}
+            (a, b) => self.type_changed(
+                path,
+                a.as_deref().map_or("no schema", type_kind),
+                b.as_deref().map_or("no schema", type_kind),
+            ),
+        }
+    }
+
+    fn errors(&mut self, path: &str, old: Option<&[LexXrpcError]>, new: Option<&[LexXrpcError]>) {
+        let names = |errors: Option<&[LexXrpcError]>| -> BTreeSet<String> {
+            errors
+                .unwrap_or_default()
+                .iter()
+                .map(|e| e.name.clone())
+                .collect()
+        };
+        let (old, new) = (names(old), names(new));
+        let path = join(path, "errors");
+
+        // Clients must already handle undeclared errors
+        for removed in old.difference(&new) {
+            self.compatible(
+                &path,
+                ChangeKind::ErrorRemoved,
+                format!("Error {} was removed", removed),
+            );
+        }
+        for added in new.difference(&old) {
+            self.compatible(&path, ChangeKind::ErrorAdded, format!("Error {} was added", added));
+        }
+    }
+
+    fn string_constraints(&mut self, path: &str, old: &StringConstraints, new: &StringConstraints) {
+        self.lower_bound(path, "minLength", old.min_length, new.min_length);
+        self.upper_bound(path, "maxLength", old.max_length, new.max_length);
+        self.lower_bound(path, "minGraphemes", old.min_graphemes, new.min_graphemes);
+        self.upper_bound(path, "maxGraphemes", old.max_graphemes, new.max_graphemes);
+        self.closed_set(path, "enum", old.r#enum.as_deref(), new.r#enum.as_deref());
+        self.value(path, "const", old.r#const.as_deref(), new.r#const.as_deref());
+        self.value(path, "default", old.default.as_deref(), new.default.as_deref());
+        if old.known_values != new.known_values {
+            self.compatible(
+                path,
+                ChangeKind::KnownValuesChanged,
+                "knownValues changed".to_string(),
+            );
+        }
+    }
+
+    fn blob_constraints(&mut self, path: &str, old: &BlobConstraints, new: &BlobConstraints) {
+        self.upper_bound(path, "maxSize", old.max_size, new.max_size);
+        self.closed_set(path, "accept", old.accept.as_deref(), new.accept.as_deref());
+    }
+
+    fn format<T: Display + PartialEq>(&mut self, path: &str, old: Option<T>, new: Option<T>) {
+        match (old, new) {
+            (Some(a), Some(b)) if a != b => self.breaking(
+                path,
+                ChangeKind::FormatChanged,
+                format!("Format changed from {} to {}", a, b),
+            ),
+            (None, Some(b)) => {
+                self.breaking(path, ChangeKind::FormatChanged, format!("Format {} was added", b))
+            }
+            (Some(a), None) => self.compatible(
+                path,
+                ChangeKind::FormatChanged,
+                format!("Format {} was removed", a),
+            ),
+            _ => {}
+        }
+    }
+
+    /// Compare a maximum; lowering or adding one is breaking
+    fn upper_bound<T: Ord + Display>(
+        &mut self,
+        path: &str,
+        name: &str,
+        old: Option<T>,
+        new: Option<T>,
+    ) {
+        self.bound(path, name, old, new, |old, new| new < old);
+    }
+
+    /// Compare a minimum; raising or adding one is breaking
+    fn lower_bound<T: Ord + Display>(
+        &mut self,
+        path: &str,
+        name: &str,
+        old: Option<T>,
+        new: Option<T>,
+    ) {
+        self.bound(path, name, old, new, |old, new| new > old);
+    }
+
+    fn bound<T: Ord + Display>(
+        &mut self,
+        path: &str,
+        name: &str,
+        old: Option<T>,
+        new: Option<T>,
+        tighter: impl Fn(&T, &T) -> bool,
+    ) {
+        let (tightened, message) = match (old, new) {
+            (Some(a), Some(b)) if a != b => {
+                (tighter(&a, &b), format!("{} changed from {} to {}", name, a, b))
+            }
+            (None, Some(b)) => (true, format!("{} {} was added", name, b)),
+            (Some(a), None) => (false, format!("{} {} was removed", name, a)),
+            _ => return,
+        };
+        self.constraint(path, tightened, message);
+    }
+
+    /// Compare an allowed-values list, where `None` allows anything
+    fn closed_set<T: PartialEq + Display>(
+        &mut self,
+        path: &str,
+        name: &str,
+        old: Option<&[T]>,
+        new: Option<&[T]>,
+    ) {
+        match (old, new) {
+            (None, None) => {}
+            (None, Some(_)) => self.constraint(path, true, format!("{} was added", name)),
+            (Some(_), None) => self.constraint(path, false, format!("{} was removed", name)),
+            (Some(a), Some(b)) => {
+                for removed in a.iter().filter(|v| !b.contains(v)) {
+                    self.constraint(path, true, format!("{} value {} was removed", name, removed));
+                }
+                for added in b.iter().filter(|v| !a.contains(v)) {
+                    self.constraint(path, false, format!("{} value {} was added", name, added));
+                }
+            }
+        }
+    }
+
+    fn constraint(&mut self, path: &str, tightened: bool, message: String) {
+        if tightened {
+            self.breaking(path, ChangeKind::ConstraintTightened, message);
+        } else {
+            self.compatible(path, ChangeKind::ConstraintWidened, message);
+        }
+    }
+
+    /// Compare a `const` or `default`; any change alters accepted or implied data
+    fn value<T: PartialEq + std::fmt::Debug>(
+        &mut self,
+        path: &str,
+        name: &str,
+        old: Option<T>,
+        new: Option<T>,
+    ) {
+        if old != new {
+            self.breaking(
+                path,
+                ChangeKind::ValueChanged,
+                format!("{} changed from {:?} to {:?}", name, old, new),
+            );
+        }
+    }
+
+    /// Normalize a ref to `nsid#def`, with `#main` dropped
+    fn normalize(&self, ref_str: &str, old: bool) -> String {
+        let context = if old { self.old_nsid } else { self.new_nsid };
+        match parse_ref(ref_str, context) {
+            Ok((nsid, def)) if def == "main" => nsid,
+            Ok((nsid, def)) => format!("{}#{}", nsid, def),
+            Err(_) => ref_str.to_string(),
+        }
+    }
+}
+
+/// Properties, required names and nullable names of an object or params
+type Fields<'a> = (&'a HashMap<String, LexType>, Option<&'a [String]>, Option<&'a [String]>);
+
+fn join(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
+/// View a reusable definition as the field type it defines
+fn def_as_type(def: &LexiconDef) -> Option<LexType> {
+    Some(match def {
+        LexiconDef::Token(t) => LexType::Token(t.clone()),
+        LexiconDef::Object(t) => LexType::Object(t.clone()),
+        LexiconDef::Array(t) => LexType::Array(t.clone()),
+        LexiconDef::String(t) => LexType::String(t.clone()),
+        LexiconDef::Integer(t) => LexType::Integer(t.clone()),
+        LexiconDef::Boolean(t) => LexType::Boolean(t.clone()),
+        LexiconDef::Bytes(t) => LexType::Bytes(t.clone()),
+        LexiconDef::CidLink(t) => LexType::CidLink(t.clone()),
+        LexiconDef::Blob(t) => LexType::Blob(t.clone()),
+        LexiconDef::Union(t) => LexType::Union(t.clone()),
+        LexiconDef::Unknown(t) => LexType::Unknown(t.clone()),
+        LexiconDef::Record(_)
+        | LexiconDef::Query(_)
+        | LexiconDef::Procedure(_)
+        | LexiconDef::Subscription(_) => return None,
+    })
+}
+
+fn def_kind(def: &LexiconDef) -> &'static str {
+    match def {
+        LexiconDef::Record(_) => "record",
+        LexiconDef::Query(_) => "query",
+        LexiconDef::Procedure(_) => "procedure",
+        LexiconDef::Subscription(_) => "subscription",
+        other => def_as_type(other).as_ref().map_or("unknown", type_kind),
+    }
+}
+
+fn type_kind(lex_type: &LexType) -> &'static str {
+    match lex_type {
+        LexType::Null => "null",
+        LexType::Boolean(_) => "boolean",
+        LexType::Integer(_) => "integer",
+        LexType::String(_) => "string",
+        LexType::Bytes(_) => "bytes",
+        LexType::CidLink(_) => "cid-link",
+        LexType::Blob(_) => "blob",
+        LexType::Array(_) => "array",
+        LexType::Object(_) => "object",
+        LexType::Token(_) => "token",
+        LexType::Union(_) => "union",
+        LexType::Unknown(_) => "unknown",
+        LexType::Ref(_) => "ref",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn doc(defs: Value) -> LexiconDoc {
+        let json = json!({"lexicon": 1, "id": "com.example.test", "defs": defs});
+        LexiconDoc::from_json(&json.to_string()).unwrap()
+    }
+
+    fn record(required: &[&str], properties: Value) -> LexiconDoc {
+        doc(json!({
+            "main": {
+                "type": "record",
+                "key": "tid",
+                "record": {"type": "object", "required": required, "properties": properties}
+            }
+        }))
+    }
+
+    fn kinds(report: &CompatibilityReport) -> Vec<(&str, ChangeKind, Compatibility)> {
+        report
+            .changes
+            .iter()
+            .map(|c| (c.path.as_str(), c.kind, c.compatibility))
+            .collect()
+    }
+
+    #[test]
+    fn test_identical() {
+        let old = record(&["text"], json!({"text": {"type": "string", "maxLength": 300}}));
+        let report = diff_lexicons(&old, &old.clone());
+        assert!(report.is_empty());
+        assert!(report.is_compatible());
+    }
+
+    #[test]
+    fn test_fields() {
+        use ChangeKind::*;
+        use Compatibility::*;
+
+        let old = record(
+            &["a", "b"],
+            json!({
+                "a": {"type": "string"},
+                "b": {"type": "string"},
+                "c": {"type": "string"},
+                "d": {"type": "integer"}
+            }),
+        );
+        let new = record(
+            &["a", "c", "x"],
+            json!({
+                "a": {"type": "string"},
+                "c": {"type": "string"},
+                "d": {"type": "string"},
+                "x": {"type": "string"},
+                "y": {"type": "string"}
+            }),
+        );
+
+        let report = diff_lexicons(&old, &new);
+        assert_eq!(
+            kinds(&report),
+            [
+                ("main.b", FieldRemoved, Breaking),
+                ("main.c", RequirementChanged, Breaking),
+                ("main.d", TypeChanged, Breaking),
+                ("main.x", FieldAdded, Breaking),
+                ("main.y", FieldAdded, Compatible),
+            ]
+        );
+        assert_eq!(report.breaking_changes().count(), 4);
+        assert_eq!(report.changes[2].message, "Type changed from integer to string");
+    }
+
+    #[test]
+    fn test_constraints() {
+        use ChangeKind::*;
+        use Compatibility::*;
+
+        let old = record(
+            &[],
+            json!({
+                "text": {"type": "string", "maxLength": 300, "maxGraphemes": 30},
+                "count": {"type": "integer", "minimum": 0, "maximum": 10},
+                "lang": {"type": "string", "enum": ["en", "fr"]},
+                "tags": {"type": "array", "items": {"type": "string", "maxLength": 64}}
+            }),
+        );
+        let new = record(
+            &[],
+            json!({
+                "text": {"type": "string", "maxLength": 3000, "maxGraphemes": 20, "format": "uri"},
+                "count": {"type": "integer", "minimum": 1},
+                "lang": {"type": "string", "enum": ["en", "de"]},
+                "tags": {"type": "array", "maxLength": 8, "items": {"type": "string", "maxLength": 128}}
+            }),
+        );
+
+        let report = diff_lexicons(&old, &new);
+        assert_eq!(
+            kinds(&report),
+            [
+                ("main.count", ConstraintTightened, Breaking),
+                ("main.count", ConstraintWidened, Compatible),
+                ("main.lang", ConstraintTightened, Breaking),
+                ("main.lang", ConstraintWidened, Compatible),
+                ("main.tags", ConstraintTightened, Breaking),
+                ("main.tags[]", ConstraintWidened, Compatible),
+                ("main.text", FormatChanged, Breaking),
+                ("main.text", ConstraintWidened, Compatible),
+                ("main.text", ConstraintTightened, Breaking),
+            ]
+        );
+        assert_eq!(report.changes[0].message, "minimum changed from 0 to 1");
+        assert_eq!(report.changes[2].message, "enum value fr was removed");
+    }
+
+    #[test]
+    fn test_unions() {
+        use ChangeKind::*;
+        use Compatibility::*;
+
+        let old = doc(json!({
+            "main": {"type": "object", "properties": {
+                "open": {"type": "union", "refs": ["#a", "com.example.test#b"]},
+                "closed": {"type": "union", "closed": true, "refs": ["#a"]}
+            }},
+            "a": {"type": "object", "properties": {}},
+            "b": {"type": "object", "properties": {}}
+        }));
+        let new = doc(json!({
+            "main": {"type": "object", "properties": {
+                "open": {"type": "union", "refs": ["com.example.test#a", "#c"]},
+                "closed": {"type": "union", "closed": true, "refs": ["#a", "#b"]}
+            }},
+            "a": {"type": "object", "properties": {}},
+            "b": {"type": "object", "properties": {}},
+            "c": {"type": "object", "properties": {}}
+        }));
+
+        let report = diff_lexicons(&old, &new);
+        assert_eq!(
+            kinds(&report),
+            [
+                ("c", DefinitionAdded, Compatible),
+                ("main.closed", UnionMemberAdded, Breaking),
+                ("main.open", UnionMemberRemoved, Breaking),
+                ("main.open", UnionMemberAdded, Compatible),
+            ]
+        );
+        assert_eq!(report.changes[2].message, "Union member com.example.test#b was removed");
+    }
+
+    #[test]
+    fn test_record_key_and_xrpc() {
+        use ChangeKind::*;
+        use Compatibility::*;
+
+        let mut new = record(&[], json!({}));
+        if let Some(LexiconDef::Record(r)) = new.defs.get_mut("main") {
+            r.key = crate::lexicon::RecordKeyType::LiteralSelf;
+        }
+        let report = diff_lexicons(&record(&[], json!({})), &new);
+        assert_eq!(kinds(&report), [("main", RecordKeyChanged, Breaking)]);
+
+        let old = doc(json!({"main": {
+            "type": "query",
+            "parameters": {"type": "params", "properties": {"limit": {"type": "integer"}}},
+            "output": {"encoding": "application/json"},
+            "errors": [{"name": "NotFound"}]
+        }}));
+        let new = doc(json!({"main": {
+            "type": "query",
+            "parameters": {"type": "params", "required": ["actor"], "properties": {
+                "actor": {"type": "string"},
+                "limit": {"type": "integer", "maximum": 100}
+            }},
+            "output": {"encoding": "application/json"},
+            "errors": [{"name": "NotFound"}, {"name": "Blocked"}]
+        }}));
+        let report = diff_lexicons(&old, &new);
+        assert_eq!(
+            kinds(&report),
+            [
+                ("main.parameters.actor", FieldAdded, Breaking),
+                ("main.parameters.limit", ConstraintTightened, Breaking),
+                ("main.errors", ErrorAdded, Compatible),
+            ]
+        );
+
+        let procedure = doc(json!({"main": {"type": "procedure"}}));
+        let report = diff_lexicons(&procedure, &old);
+        assert_eq!(kinds(&report), [("main", TypeChanged, Breaking)]);
+        assert_eq!(report.changes[0].message, "Type changed from procedure to query");
+    }
+
+    #[test]
+    fn test_report_json() {
+        let old = record(&[], json!({"text": {"type": "string"}}));
+        let new = record(&["text"], json!({"text": {"type": "string"}}));
+        let report = diff_lexicons(&old, &new);
+
+        assert_eq!(report.compatibility(), Compatibility::Breaking);
+        assert_eq!(
+            serde_json::to_value(&report).unwrap(),
+            json!({
+                "nsid": "com.example.test",
+                "changes": [{
+                    "path": "main.text",
+                    "kind": "requirementChanged",
+                    "compatibility": "breaking",
+                    "message": "Field 'text' is now required"
+                }]
+            })
+        );
+    }
+}
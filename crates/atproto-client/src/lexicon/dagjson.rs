@@ -0,0 +1,502 @@
+//! DAG-JSON conversion and local record CIDs
+//!
+//! Records travel over XRPC in the atproto JSON data model, a DAG-JSON
+//! dialect: CID links are `{"$link": "bafy..."}`, bytes are
+//! `{"$bytes": "<base64>"}`, and blobs are `{"$type": "blob", "ref": {"$link":
+//! ...}, "mimeType", "size"}`. Repositories store the same data as DAG-CBOR,
+//! where links are CBOR tag 42 and bytes are byte strings.
+//!
+//! This module converts between the two representations and computes a
+//! record's CID locally, so the CID of a record is known before the PDS
+//! confirms a write (for optimistic UI or `swapRecord`).
+//!
+//! The IPLD DAG-JSON forms `{"/": "bafy..."}` and `{"/": {"bytes": "..."}}`
+//! are accepted as input too, which covers [`CidLink`](super::CidLink) values
+//! serialized by this crate. Output always uses `$link` and `$bytes`.
+//!
+//! Reference: <https://atproto.com/specs/data-model>
+//!
+//! # Example
+//!
+//! ```rust
+//! use atproto_client::lexicon::dagjson::{cbor_to_dag_json, compute_record_cid, dag_json_to_cbor};
+//! use serde_json::json;
+//!
+//! let like = json!({
+//!     "$type": "app.bsky.feed.like",
+//!     "subject": {
+//!         "uri": "at://did:plc:alice/app.bsky.feed.post/3jzfcijpj2z2a",
+//!         "cid": "bafyreig2fjxi3rptqdgylg7e5hmjl6mcke7rn2b6cugzlqq3i4zu6rq52q"
+//!     },
+//!     "createdAt": "2024-01-01T00:00:00.000Z"
+//! });
+//!
+//! let bytes = dag_json_to_cbor(&like).unwrap();
+//! assert_eq!(cbor_to_dag_json(&bytes).unwrap(), like);
+//!
+//! // The CID the PDS will report for this record
+//! let cid = compute_record_cid(&like).unwrap();
+//! assert!(cid.to_string().starts_with("bafyrei"));
+//! ```
+
+use crate::cid::{generate_cid, CidError};
+use base64::engine::general_purpose::STANDARD_NO_PAD;
+use base64::Engine;
+use cid::Cid;
+use ipld_core::ipld::Ipld;
+use serde::Serialize;
+use serde_json::{Map, Number, Value};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use thiserror::Error;
+
+/// Errors that can occur during DAG-JSON conversion
+#[derive(Debug, Error)]
+pub enum DagJsonError {
+    /// A `$link` value is not a valid CID
+    #[error("Invalid link at {path}: {reason}")]
+    InvalidLink {
+        /// Location of the value (e.g., "subject.cid")
+        path: String,
+        /// Why the link is invalid
+        reason: String,
+    },
+
+    /// A `$bytes` value is not valid base64
+    #[error("Invalid bytes at {path}: {reason}")]
+    InvalidBytes {
+        /// Location of the value
+        path: String,
+        /// Why the bytes are invalid
+        reason: String,
+    },
+
+    /// Floats are not part of the atproto data model
+    #[error("Unsupported number at {path}: {value}")]
+    UnsupportedNumber {
+        /// Location of the value
+        path: String,
+        /// The number as written
+        value: String,
+    },
+
+    /// Value could not be converted to or from JSON
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// DAG-CBOR encoding or decoding failed
+    #[error("DAG-CBOR error: {0}")]
+    Cbor(String),
+
+    /// CID could not be computed
+    #[error("CID error: {0}")]
+    Cid(#[from] CidError),
+}
+
+/// Result type for DAG-JSON operations
+pub type Result<T> = std::result::Result<T, DagJsonError>;
+
+/// Convert a DAG-JSON value to the IPLD data model
+///
+/// # Errors
+///
+/// - `DagJsonError::InvalidLink` - A `$link` is not a valid CID
+/// - `DagJsonError::InvalidBytes` - A `$bytes` value is not base64
+/// - `DagJsonError::UnsupportedNumber` - A number is not an integer
+pub fn json_to_ipld(value: &Value) -> Result<Ipld> {
+    to_ipld(value, "")
+}
+
+/// Convert an IPLD value to DAG-JSON
+///
+/// # Errors
+///
+/// - `DagJsonError::UnsupportedNumber` - The value contains a float or an
+///   integer outside the JSON-safe range
+pub fn ipld_to_json(ipld: &Ipld) -> Result<Value> {
+    from_ipld(ipld, "")
+}
+
+/// Encode a DAG-JSON value as canonical DAG-CBOR
+///
+/// Map keys are sorted as DAG-CBOR requires, so the output is byte-for-byte
+/// what a PDS stores for the same record.
+///
+/// # Errors
+///
+/// Same as [`json_to_ipld`], plus `DagJsonError::Cbor` if encoding fails.
+pub fn dag_json_to_cbor(value: &Value) -> Result<Vec<u8>> {
+    let ipld = json_to_ipld(value)?;
+    serde_ipld_dagcbor::to_vec(&ipld).map_err(|e| DagJsonError::Cbor(e.to_string()))
+}
+
+/// Decode DAG-CBOR bytes to DAG-JSON
+///
+/// # Errors
+///
+/// - `DagJsonError::Cbor` - The bytes are not valid DAG-CBOR
+/// - `DagJsonError::UnsupportedNumber` - The data contains a float
+pub fn cbor_to_dag_json(bytes: &[u8]) -> Result<Value> {
+    let ipld: Ipld =
+        serde_ipld_dagcbor::from_slice(bytes).map_err(|e| DagJsonError::Cbor(e.to_string()))?;
+    ipld_to_json(&ipld)
+}
+
+/// Serialize a value to DAG-JSON
+///
+/// Links serialized in the IPLD `{"/": ...}` form (such as
+/// [`CidLink`](super::CidLink)) are normalized to `$link`.
+///
+/// # Errors
+///
+/// Same as [`json_to_ipld`], plus `DagJsonError::Json` if serialization fails.
+pub fn to_dag_json<T: Serialize>(value: &T) -> Result<Value> {
+    let value = serde_json::to_value(value)?;
+    ipld_to_json(&json_to_ipld(&value)?)
+}
+
+/// Format a DAG-JSON value as canonical DAG-JSON text
+///
+/// The output has no whitespace and map keys sorted by their UTF-8 bytes.
+///
+/// # Errors
+///
+/// Same as [`json_to_ipld`].
+///
+/// # Example
+///
+/// ```rust
+/// use atproto_client::lexicon::dagjson::to_canonical_string;
+/// use serde_json::json;
+///
+/// let value = json!({"text": "hi", "$type": "app.bsky.feed.post", "langs": ["en"]});
+/// assert_eq!(
+///     to_canonical_string(&value).unwrap(),
+///     r#"{"$type":"app.bsky.feed.post","langs":["en"],"text":"hi"}"#
+/// );
+/// ```
+pub fn to_canonical_string(value: &Value) -> Result<String> {
+    let normalized = ipld_to_json(&json_to_ipld(value)?)?;
+    let mut out = String::new();
+    write_canonical(&normalized, &mut out);
+    Ok(out)
+}
+
+/// Compute the CID a record will have once written to a repository
+///
+/// The record is serialized to DAG-JSON, encoded as canonical DAG-CBOR and
+/// hashed with SHA-256, exactly as the PDS does. Include `$type` in the record
+/// for the CID to match.
+///
+/// Byte fields must serialize as `{"$bytes": ...}`; plain byte arrays become
+/// lists of integers.
+///
+/// # Arguments
+///
+/// * `record` - The record, as JSON or any `Serialize` type
+///
+/// # Errors
+///
+/// - `DagJsonError::Json` - The record could not be serialized
+/// - `DagJsonError::InvalidLink` - A link is not a valid CID
+/// - `DagJsonError::UnsupportedNumber` - The record contains a float
+///
+/// # Example
+///
+/// ```rust
+/// use atproto_client::lexicon::compute_record_cid;
+/// use serde_json::json;
+///
+/// let post = json!({
+///     "$type": "app.bsky.feed.post",
+///     "text": "Hello",
+///     "createdAt": "2024-01-01T00:00:00.000Z"
+/// });
+///
+/// // Key order doesn't matter
+/// let reordered = json!({
+///     "createdAt": "2024-01-01T00:00:00.000Z",
+///     "text": "Hello",
+///     "$type": "app.bsky.feed.post"
+/// });
+/// assert_eq!(compute_record_cid(&post).unwrap(), compute_record_cid(&reordered).unwrap());
+/// ```
+pub fn compute_record_cid<T: Serialize>(record: &T) -> Result<Cid> {
+    let value = serde_json::to_value(record)?;
+    let bytes = dag_json_to_cbor(&value)?;
+    Ok(generate_cid(&bytes)?)
+}
+
+fn join(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
+fn to_ipld(value: &Value, path: &str) -> Result<Ipld> {
+    Ok(match value {
+        Value::Null => Ipld::Null,
+        Value::Bool(b) => Ipld::Bool(*b),
+        Value::Number(n) => {
+            let integer = n
+                .as_i64()
+                .map(i128::from)
+                .or_else(|| n.as_u64().map(i128::from));
+            match integer {
+                Some(i) => Ipld::Integer(i),
+                None => {
+                    return Err(DagJsonError::UnsupportedNumber {
+                        path: path.to_string(),
+                        value: n.to_string(),
+                    })
+                }
+            }
+        }
+        Value::String(s) => Ipld::String(s.clone()),
+        Value::Array(items) => Ipld::List(
+            items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| to_ipld(item, &format!("{}[{}]", path, i)))
+                .collect::<Result<_>>()?,
+        ),
+        Value::Object(map) => {
+            if let Some(ipld) = special_object(map, path)? {
+                return Ok(ipld);
+            }
+            Ipld::Map(
+                map.iter()
+                    .map(|(key, value)| Ok((key.clone(), to_ipld(value, &join(path, key))?)))
+                    .collect::<Result<BTreeMap<_, _>>>()?,
+            )
+        }
+    })
+}
+
+/// Decode a single-key link or bytes object
+fn special_object(map: &Map<String, Value>, path: &str) -> Result<Option<Ipld>> {
+    if map.len() != 1 {
+        return Ok(None);
+    }
+    let (key, value) = map.iter().next().expect("map has one entry");
+    match (key.as_str(), value) {
+        ("$link", Value::String(cid)) => parse_link(cid, path).map(Some),
+        ("$bytes", Value::String(b64)) => parse_bytes(b64, path).map(Some),
+        ("/", Value::String(cid)) => parse_link(cid, path).map(Some),
+        ("/", Value::Object(inner)) => match inner.get("bytes") {
+            Some(Value::String(b64)) if inner.len() == 1 => parse_bytes(b64, path).map(Some),
+            _ => Ok(None),
+        },
+        _ => Ok(None),
+    }
+}
+
+fn parse_link(cid: &str, path: &str) -> Result<Ipld> {
+    Cid::try_from(cid)
+        .map(Ipld::Link)
+        .map_err(|e| DagJsonError::InvalidLink { path: path.to_string(), reason: e.to_string() })
+}
+
+fn parse_bytes(b64: &str, path: &str) -> Result<Ipld> {
+    // atproto writes unpadded base64, but padded input is common
+    STANDARD_NO_PAD
+        .decode(b64.trim_end_matches('='))
+        .map(Ipld::Bytes)
+        .map_err(|e| DagJsonError::InvalidBytes { path: path.to_string(), reason: e.to_string() })
+}
+
+fn from_ipld(ipld: &Ipld, path: &str) -> Result<Value> {
+    Ok(match ipld {
+        Ipld::Null => Value::Null,
+        Ipld::Bool(b) => Value::Bool(*b),
+        Ipld::Integer(i) => {
+            let number = i64::try_from(*i)
+                .map(Number::from)
+                .or_else(|_| u64::try_from(*i).map(Number::from));
+            match number {
+                Ok(n) => Value::Number(n),
+                Err(_) => {
+                    return Err(DagJsonError::UnsupportedNumber {
+                        path: path.to_string(),
+                        value: i.to_string(),
+                    })
+                }
+            }
+        }
+        Ipld::Float(f) => {
+            return Err(DagJsonError::UnsupportedNumber {
+                path: path.to_string(),
+                value: f.to_string(),
+            })
+        }
+        Ipld::String(s) => Value::String(s.clone()),
+        Ipld::Bytes(bytes) => {
+            let mut map = Map::new();
+            map.insert("$bytes".to_string(), Value::String(STANDARD_NO_PAD.encode(bytes)));
+            Value::Object(map)
+        }
+        Ipld::List(items) => Value::Array(
+            items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| from_ipld(item, &format!("{}[{}]", path, i)))
+                .collect::<Result<_>>()?,
+        ),
+        Ipld::Map(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| Ok((key.clone(), from_ipld(value, &join(path, key))?)))
+                .collect::<Result<Map<_, _>>>()?,
+        ),
+        Ipld::Link(cid) => {
+            let mut map = Map::new();
+            map.insert("$link".to_string(), Value::String(cid.to_string()));
+            Value::Object(map)
+        }
+    })
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.as_bytes().cmp(b.0.as_bytes()));
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                let _ = write!(out, "{}:", Value::String(key.clone()));
+                write_canonical(value, out);
+            }
+            out.push('}');
+        }
+        scalar => {
+            let _ = write!(out, "{}", scalar);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexicon::{BlobRef, CidLink};
+    use serde_json::json;
+
+    const CID: &str = "bafyreig2fjxi3rptqdgylg7e5hmjl6mcke7rn2b6cugzlqq3i4zu6rq52q";
+    const BLOB_CID: &str = "bafkreibjo4xmgaevkgud7mbifn3dzp4v4lyaui4yvqp3f2bqwtxcjrdqg4";
+
+    #[test]
+    fn test_roundtrip() {
+        let value = json!({
+            "$type": "app.bsky.feed.post",
+            "text": "Hello",
+            "count": -3,
+            "big": u64::MAX,
+            "flag": true,
+            "nothing": null,
+            "prev": {"$link": CID},
+            "sig": {"$bytes": "nFERjvLLiw9qm45JrqH9QTzyC2Lu1Xb4ne6+sBrCzI0"},
+            "embed": {
+                "$type": "blob",
+                "ref": {"$link": BLOB_CID},
+                "mimeType": "image/png",
+                "size": 1024
+            },
+            "list": [[], {}, {"$link": CID}]
+        });
+
+        let bytes = dag_json_to_cbor(&value).unwrap();
+        assert_eq!(cbor_to_dag_json(&bytes).unwrap(), value);
+
+        // Links are CBOR tag 42, not maps
+        let ipld: Ipld = serde_ipld_dagcbor::from_slice(&bytes).unwrap();
+        let Ipld::Map(map) = ipld else {
+            panic!("expected a map")
+        };
+        assert!(matches!(&map["prev"], Ipld::Link(cid) if cid.to_string() == CID));
+        assert!(matches!(&map["sig"], Ipld::Bytes(b) if b.len() == 32));
+    }
+
+    #[test]
+    fn test_ipld_forms_normalized() {
+        let value = json!({
+            "link": {"/": CID},
+            "bytes": {"/": {"bytes": "aGk="}},
+            "notLink": {"$link": CID, "extra": 1}
+        });
+        assert_eq!(
+            to_canonical_string(&value).unwrap(),
+            format!(
+                r#"{{"bytes":{{"$bytes":"aGk"}},"link":{{"$link":"{}"}},"notLink":{{"$link":"{}","extra":1}}}}"#,
+                CID, CID
+            )
+        );
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        let err = dag_json_to_cbor(&json!({"a": {"b": 1.5}})).unwrap_err();
+        assert!(matches!(err, DagJsonError::UnsupportedNumber { ref path, .. } if path == "a.b"));
+
+        let err = dag_json_to_cbor(&json!({"items": [{"$link": "nope"}]})).unwrap_err();
+        assert!(matches!(err, DagJsonError::InvalidLink { ref path, .. } if path == "items[0]"));
+
+        let err = dag_json_to_cbor(&json!({"$bytes": "!!"})).unwrap_err();
+        assert!(matches!(err, DagJsonError::InvalidBytes { .. }));
+
+        assert!(matches!(cbor_to_dag_json(&[0xff]), Err(DagJsonError::Cbor(_))));
+    }
+
+    #[test]
+    fn test_compute_record_cid() {
+        // CID of the empty DAG-CBOR map
+        assert_eq!(
+            compute_record_cid(&json!({})).unwrap().to_string(),
+            "bafyreigbtj4x7ip5legnfznufuopl4sg4knzc2cof6duas4b3q2fy6swua"
+        );
+
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Image {
+            #[serde(rename = "$type")]
+            type_name: &'static str,
+            image: BlobRef,
+            prev: CidLink,
+        }
+
+        let typed = Image {
+            type_name: "com.example.image",
+            image: BlobRef::new("image/png", 1024, BLOB_CID),
+            prev: CidLink::new(CID),
+        };
+        let json = json!({
+            "$type": "com.example.image",
+            "image": {
+                "$type": "blob",
+                "ref": {"$link": BLOB_CID},
+                "mimeType": "image/png",
+                "size": 1024
+            },
+            "prev": {"$link": CID}
+        });
+
+        assert_eq!(to_dag_json(&typed).unwrap(), json);
+        assert_eq!(compute_record_cid(&typed).unwrap(), compute_record_cid(&json).unwrap());
+        assert_ne!(
+            compute_record_cid(&json).unwrap(),
+            compute_record_cid(&json!({"$type": "com.example.image"})).unwrap()
+        );
+    }
+}
@@ -44,8 +44,13 @@
 //! // let doc: LexiconDoc = serde_json::from_str(json)?;
 //! ```
 
+#[cfg(feature = "builtin-lexicons")]
+pub mod builtin;
 pub mod cbor;
+pub mod codegen;
+pub mod compat;
 pub mod constraints;
+pub mod dagjson;
 pub mod formats;
 pub mod parsing;
 pub mod resolution;
@@ -62,11 +67,22 @@ pub use types::*;
 // Re-export parsing types (excluding Result to avoid ambiguity)
 pub use parsing::LexiconParseError;
 
+// Re-export compatibility checking
+pub use compat::{diff_lexicons, ChangeKind, Compatibility, CompatibilityReport, SchemaChange};
+
 // Re-export resolution types (excluding Result to avoid ambiguity)
 pub use resolution::{parse_ref, RefResolutionError, SchemaRegistry};
 
 // Re-export validation types (excluding Result to avoid ambiguity)
-pub use validation::{validate_array_length, validate_integer, validate_string, ValidationError};
+pub use validation::{
+    validate_array_length, validate_integer, validate_record, validate_string, FieldError,
+    RecordValidationError, ValidationError,
+};
+
+// Re-export DAG-JSON conversion (excluding Result to avoid ambiguity)
+pub use dagjson::{compute_record_cid, DagJsonError};
 
 // Re-export CBOR types (excluding Result to avoid ambiguity)
-pub use cbor::{decode_record, encode_record, BlobRef, CborError, CidLink, TypedRecord};
+pub use cbor::{
+    decode_prefix, decode_record, encode_record, BlobRef, CborError, CidLink, TypedRecord,
+};
@@ -19,6 +19,7 @@
 //! let resolved = registry.resolve_ref("com.example.test", "#main");
 //! ```
 
+use super::parsing::is_valid_nsid;
 use super::schema::{LexiconDef, LexiconDoc};
 use super::types::LexType;
 use std::collections::{HashMap, HashSet};
@@ -108,6 +109,26 @@ impl SchemaRegistry {
         Self { schemas: HashMap::new(), max_depth }
     }
 
+    /// Create a schema registry preloaded with the bundled official lexicons
+    ///
+    /// Includes every `com.atproto.*`, `app.bsky.*`, `chat.bsky.*` and
+    /// `tools.ozone.*` document, with all references resolvable. The bundled
+    /// documents are parsed once and cloned on each call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use atproto_client::lexicon::SchemaRegistry;
+    ///
+    /// let registry = SchemaRegistry::with_builtin();
+    /// assert!(registry.contains("app.bsky.feed.post"));
+    /// assert!(registry.validate_schema("app.bsky.feed.defs").is_ok());
+    /// ```
+    #[cfg(feature = "builtin-lexicons")]
+    pub fn with_builtin() -> Self {
+        super::builtin::registry().clone()
+    }
+
     /// Register a schema in the registry
     ///
     /// If a schema with the same NSID already exists, it will be replaced.
@@ -125,6 +146,11 @@ impl SchemaRegistry {
         self.schemas.get(nsid)
     }
 
+    /// Iterate over the NSIDs of all registered schemas, in no particular order
+    pub fn nsids(&self) -> impl Iterator<Item = &str> {
+        self.schemas.keys().map(String::as_str)
+    }
+
     /// Get a mutable reference to a schema by NSID
     pub fn get_mut(&mut self, nsid: &str) -> Option<&mut LexiconDoc> {
         self.schemas.get_mut(nsid)
@@ -223,7 +249,12 @@ impl SchemaRegistry {
         // Collect all refs from the definition and validate they can be resolved
         let refs = collect_refs_from_def(def);
         for ref_str in refs {
-            // Recursively resolve each ref found within the definition
+            // Definitions may legitimately refer back to themselves (e.g. thread
+            // replies) or be shared by several properties, so each one only
+            // needs to be checked once
+            if visited.contains(&ref_key_for(&ref_str, &nsid)?) {
+                continue;
+            }
             self.resolve_ref_internal(&nsid, &ref_str, visited, depth + 1)?;
         }
 
@@ -240,12 +271,18 @@ impl SchemaRegistry {
             .get(nsid)
             .ok_or_else(|| RefResolutionError::SchemaNotFound(nsid.to_string()))?;
 
-        // Walk through all definitions and resolve all refs
+        // Walk through all definitions and resolve all refs, sharing the
+        // visited set so definitions reachable from several places are only
+        // checked once
+        let mut visited = HashSet::new();
         for def in schema.defs.values() {
             let refs = collect_refs_from_def(def);
             for ref_str in refs {
+                if visited.contains(&ref_key_for(&ref_str, nsid)?) {
+                    continue;
+                }
                 // Attempt to resolve each ref to ensure it's valid
-                self.resolve_ref(nsid, &ref_str)?;
+                self.resolve_ref_internal(nsid, &ref_str, &mut visited, 0)?;
             }
         }
 
@@ -265,6 +302,7 @@ impl Default for SchemaRegistry {
 ///
 /// - Local reference: `#defName` -> Uses context_nsid
 /// - External reference: `nsid#defName` -> Uses specified nsid
+/// - Bare NSID: `nsid` -> Refers to `nsid#main`
 ///
 /// # Examples
 ///
@@ -277,8 +315,15 @@ impl Default for SchemaRegistry {
 /// let (nsid, def) = parse_ref("com.atproto.repo.strongRef#main", "com.example.test").unwrap();
 /// assert_eq!(nsid, "com.atproto.repo.strongRef");
 /// assert_eq!(def, "main");
+///
+/// let (nsid, def) = parse_ref("com.atproto.repo.strongRef", "com.example.test").unwrap();
+/// assert_eq!(nsid, "com.atproto.repo.strongRef");
+/// assert_eq!(def, "main");
 /// ```
 pub fn parse_ref(ref_str: &str, context_nsid: &str) -> Result<(String, String)> {
+    if !ref_str.contains('#') && is_valid_nsid(ref_str) {
+        return Ok((ref_str.to_string(), "main".to_string()));
+    }
     if !ref_str.contains('#') {
         return Err(RefResolutionError::InvalidRef(format!(
             "Reference must contain '#': {}",
@@ -314,11 +359,17 @@ pub fn parse_ref(ref_str: &str, context_nsid: &str) -> Result<(String, String)>
     Ok((nsid, def_name.to_string()))
 }
 
+/// Build the `nsid#def` key used to track visited references
+fn ref_key_for(ref_str: &str, context_nsid: &str) -> Result<String> {
+    let (nsid, def_name) = parse_ref(ref_str, context_nsid)?;
+    Ok(format!("{}#{}", nsid, def_name))
+}
+
 /// Collect all reference strings from a LexiconDef
 ///
 /// This recursively traverses the definition structure and extracts
 /// all reference strings found in any nested types.
-fn collect_refs_from_def(def: &LexiconDef) -> Vec<String> {
+pub(crate) fn collect_refs_from_def(def: &LexiconDef) -> Vec<String> {
     let mut refs = Vec::new();
 
     match def {
@@ -371,7 +422,7 @@ fn collect_refs_from_def(def: &LexiconDef) -> Vec<String> {
             }
             // Collect refs from message schema
             if let Some(message) = &subscription.message {
-                refs.extend(collect_refs_from_type(message));
+                refs.extend(collect_refs_from_type(&message.schema));
             }
         }
         LexiconDef::Object(object) => {
@@ -473,6 +524,13 @@ mod tests {
         assert!(matches!(result.unwrap_err(), RefResolutionError::InvalidRef(_)));
     }
 
+    #[test]
+    fn test_parse_ref_bare_nsid() {
+        let (nsid, def) = parse_ref("com.atproto.repo.strongRef", "com.example.test").unwrap();
+        assert_eq!(nsid, "com.atproto.repo.strongRef");
+        assert_eq!(def, "main");
+    }
+
     #[test]
     fn test_parse_ref_invalid_multiple_hash() {
         let result = parse_ref("com.example#test#main", "com.example.test");
@@ -1167,4 +1225,55 @@ mod tests {
         assert!(registry.validate_schema("com.example.level2").is_ok());
         assert!(registry.validate_schema("com.example.level3").is_ok());
     }
+
+    #[test]
+    fn test_self_referencing_and_shared_defs() {
+        use crate::lexicon::types::{LexArray, LexObject, LexRefType, LexType};
+        use std::collections::HashMap;
+
+        let node_ref = |ref_to: &str| {
+            LexType::Ref(LexRefType {
+                type_name: "ref".to_string(),
+                description: None,
+                ref_to: ref_to.to_string(),
+            })
+        };
+
+        // A thread node whose replies are thread nodes, and which refers to
+        // the same author definition from two properties
+        let mut properties = HashMap::new();
+        properties.insert("author".to_string(), node_ref("#author"));
+        properties.insert("parentAuthor".to_string(), node_ref("#author"));
+        properties.insert(
+            "replies".to_string(),
+            LexType::Array(LexArray {
+                type_name: "array".to_string(),
+                description: None,
+                items: Box::new(node_ref("#node")),
+                constraints: Default::default(),
+            }),
+        );
+
+        let schema = LexiconDoc::new("com.example.thread")
+            .with_def(
+                "node",
+                LexiconDef::Object(LexObject {
+                    type_name: "object".to_string(),
+                    description: None,
+                    properties,
+                    required: None,
+                    nullable: None,
+                }),
+            )
+            .with_def(
+                "author",
+                LexiconDef::Token(LexToken { type_name: "token".to_string(), description: None }),
+            );
+
+        let mut registry = SchemaRegistry::new();
+        registry.register(schema);
+
+        assert!(registry.resolve_ref("com.example.thread", "#node").is_ok());
+        assert!(registry.validate_schema("com.example.thread").is_ok());
+    }
 }
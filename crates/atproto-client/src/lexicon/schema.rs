@@ -79,6 +79,13 @@ pub enum RecordKeyType {
 
     /// Any valid record key
     Any,
+
+    /// A valid NSID
+    Nsid,
+
+    /// The fixed key `self`, for singleton records such as profiles
+    #[serde(rename = "literal:self")]
+    LiteralSelf,
 }
 
 fn default_record_type() -> String {
@@ -239,15 +246,27 @@ pub struct LexSubscription {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parameters: Option<LexParams>,
 
-    /// Message schema (union of possible message types)
+    /// Message schema
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<Box<LexType>>,
+    pub message: Option<LexSubscriptionMessage>,
 
     /// Possible errors
     #[serde(skip_serializing_if = "Option::is_none")]
     pub errors: Option<Vec<LexXrpcError>>,
 }
 
+/// Subscription message body
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LexSubscriptionMessage {
+    /// Optional description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Message schema (union of possible message types)
+    pub schema: Box<LexType>,
+}
+
 /// All possible Lexicon definition types
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "lowercase")]
@@ -346,6 +365,12 @@ mod tests {
         let any = RecordKeyType::Any;
         let json = serde_json::to_string(&any).unwrap();
         assert_eq!(json, "\"any\"");
+
+        let key: RecordKeyType = serde_json::from_str("\"literal:self\"").unwrap();
+        assert_eq!(key, RecordKeyType::LiteralSelf);
+
+        let key: RecordKeyType = serde_json::from_str("\"nsid\"").unwrap();
+        assert_eq!(key, RecordKeyType::Nsid);
     }
 
     #[test]
@@ -24,7 +24,11 @@
 
 use super::constraints::*;
 use super::formats::StringFormat;
-use super::types::LexString;
+use super::resolution::{parse_ref, SchemaRegistry};
+use super::schema::LexiconDef;
+use super::types::*;
+use base64::Engine;
+use serde_json::{Map, Value};
 use thiserror::Error;
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -147,6 +151,111 @@ pub enum ValidationError {
         /// The MIME type
         mime_type: String,
     },
+
+    /// Value has the wrong JSON type
+    #[error("Expected {expected}, got {actual}")]
+    WrongType {
+        /// The type the schema requires
+        expected: String,
+        /// The type of the value
+        actual: String,
+    },
+
+    /// Null value for a field that is not nullable
+    #[error("Value cannot be null")]
+    UnexpectedNull,
+
+    /// Union value without a `$type`
+    #[error("Union value is missing $type")]
+    MissingUnionType,
+
+    /// Union value whose `$type` is not one of the closed union's refs
+    #[error("Type '{type_name}' is not allowed in this union")]
+    UnionTypeNotAllowed {
+        /// The `$type` of the value
+        type_name: String,
+    },
+
+    /// A schema reference could not be resolved
+    #[error("Unresolved reference: {0}")]
+    UnresolvedRef(String),
+
+    /// The NSID does not name a record schema
+    #[error("Not a known record type: {0}")]
+    UnknownRecordType(String),
+
+    /// The schema uses a type that can't describe data
+    #[error("Unsupported schema type: {0}")]
+    UnsupportedType(String),
+
+    /// Malformed blob reference
+    #[error("Invalid blob: {0}")]
+    InvalidBlob(String),
+
+    /// Malformed `$bytes` value
+    #[error("Invalid bytes: {0}")]
+    InvalidBytes(String),
+
+    /// Bytes value is too long
+    #[error("Bytes exceed maximum length: {actual} > {max}")]
+    BytesTooLong {
+        /// Actual length
+        actual: usize,
+        /// Maximum allowed length
+        max: usize,
+    },
+
+    /// Bytes value is too short
+    #[error("Bytes are shorter than minimum length: {actual} < {min}")]
+    BytesTooShort {
+        /// Actual length
+        actual: usize,
+        /// Minimum required length
+        min: usize,
+    },
+
+    /// Malformed `$link` value
+    #[error("Invalid CID link: {0}")]
+    InvalidCidLink(String),
+}
+
+/// A validation error at a location within a record
+#[derive(Debug, PartialEq)]
+pub struct FieldError {
+    /// Path to the value (e.g., `embed.images[0].alt`), empty for the record itself
+    pub path: String,
+    /// What is wrong with the value
+    pub error: ValidationError,
+}
+
+impl std::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.error)
+        } else {
+            write!(f, "{}: {}", self.path, self.error)
+        }
+    }
+}
+
+/// Errors from validating a record against its lexicon
+#[derive(Debug, PartialEq, Error)]
+pub struct RecordValidationError {
+    /// Every problem found, in document order
+    pub errors: Vec<FieldError>,
+}
+
+impl std::fmt::Display for RecordValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid record: ")?;
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
 }
 
 /// Result type for validation operations
@@ -537,6 +646,436 @@ pub fn validate_array_length(length: usize, constraints: &ArrayConstraints) -> R
     Ok(())
 }
 
+/// Validate a record against its lexicon
+///
+/// The record is checked against the `main` definition of `nsid`, which must
+/// be a record definition. Refs and unions are resolved through the registry,
+/// so every lexicon the record refers to must be registered. Members of open
+/// unions whose `$type` isn't listed are accepted without further checks.
+///
+/// Values use the JSON data model: bytes as `{"$bytes": base64}`, CID links
+/// as `{"$link": cid}` and blobs as `{"$type": "blob", "ref", "mimeType", "size"}`.
+///
+/// # Arguments
+///
+/// * `registry` - Registry holding the record's lexicon and its dependencies
+/// * `nsid` - The record type (collection NSID)
+/// * `record` - The record value
+///
+/// # Errors
+///
+/// Returns every problem found, each with the path of the offending value.
+///
+/// # Example
+///
+/// ```rust
+/// use atproto_client::lexicon::{validate_record, LexiconDoc, SchemaRegistry};
+/// use serde_json::json;
+///
+/// let doc: LexiconDoc = serde_json::from_value(json!({
+///     "lexicon": 1,
+///     "id": "com.example.note",
+///     "defs": {
+///         "main": {
+///             "type": "record",
+///             "key": "tid",
+///             "record": {
+///                 "type": "object",
+///                 "required": ["text"],
+///                 "properties": {
+///                     "text": {"type": "string", "maxGraphemes": 10}
+///                 }
+///             }
+///         }
+///     }
+/// }))
+/// .unwrap();
+///
+/// let mut registry = SchemaRegistry::new();
+/// registry.register(doc);
+///
+/// assert!(validate_record(&registry, "com.example.note", &json!({"text": "hi"})).is_ok());
+///
+/// let err = validate_record(&registry, "com.example.note", &json!({"text": "much too long"}))
+///     .unwrap_err();
+/// assert_eq!(err.errors[0].path, "text");
+/// ```
+pub fn validate_record(
+    registry: &SchemaRegistry,
+    nsid: &str,
+    record: &Value,
+) -> std::result::Result<(), RecordValidationError> {
+    let mut validator = RecordValidator { registry, errors: Vec::new() };
+
+    match registry.get(nsid).and_then(|doc| doc.main_def()) {
+        Some(LexiconDef::Record(def)) => {
+            if let Some(record_type) = record.get("$type") {
+                if record_type.as_str() != Some(nsid) {
+                    validator.fail(
+                        "$type".to_string(),
+                        ValidationError::ConstMismatch {
+                            actual: display_value(record_type),
+                            expected: nsid.to_string(),
+                        },
+                    );
+                }
+            }
+            validator.object(nsid, &def.record, record, "");
+        }
+        _ => validator.fail(String::new(), ValidationError::UnknownRecordType(nsid.to_string())),
+    }
+
+    if validator.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(RecordValidationError { errors: validator.errors })
+    }
+}
+
+/// Walks a value alongside its schema, collecting errors
+struct RecordValidator<'a> {
+    registry: &'a SchemaRegistry,
+    errors: Vec<FieldError>,
+}
+
+impl RecordValidator<'_> {
+    fn fail(&mut self, path: String, error: ValidationError) {
+        self.errors.push(FieldError { path, error });
+    }
+
+    fn check(&mut self, path: &str, result: Result<()>) {
+        if let Err(error) = result {
+            self.fail(path.to_string(), error);
+        }
+    }
+
+    fn wrong_type(&mut self, path: &str, expected: &str, value: &Value) {
+        self.fail(
+            path.to_string(),
+            ValidationError::WrongType {
+                expected: expected.to_string(),
+                actual: type_name(value).to_string(),
+            },
+        );
+    }
+
+    /// Validate a value against an inline type
+    ///
+    /// `context` is the NSID that local refs are resolved against.
+    fn value(&mut self, context: &str, ty: &LexType, value: &Value, path: &str) {
+        match ty {
+            LexType::Null => {
+                if !value.is_null() {
+                    self.wrong_type(path, "null", value);
+                }
+            }
+            LexType::Boolean(def) => self.boolean(def, value, path),
+            LexType::Integer(def) => self.integer(def, value, path),
+            LexType::String(def) => self.string(def, value, path),
+            LexType::Bytes(def) => self.bytes(def, value, path),
+            LexType::CidLink(_) => self.cid_link(value, path),
+            LexType::Blob(def) => self.blob(def, value, path),
+            LexType::Array(def) => self.array(context, def, value, path),
+            LexType::Object(def) => self.object(context, def, value, path),
+            LexType::Union(def) => self.union(context, def, value, path),
+            LexType::Unknown(_) => self.unknown(value, path),
+            LexType::Ref(def) => self.reference(context, &def.ref_to, value, path),
+            LexType::Token(_) => {
+                self.fail(path.to_string(), ValidationError::UnsupportedType("token".to_string()))
+            }
+        }
+    }
+
+    /// Validate a value against a named definition
+    fn def(&mut self, context: &str, def: &LexiconDef, value: &Value, path: &str) {
+        match def {
+            LexiconDef::Record(def) => self.object(context, &def.record, value, path),
+            LexiconDef::Object(def) => self.object(context, def, value, path),
+            LexiconDef::Array(def) => self.array(context, def, value, path),
+            LexiconDef::String(def) => self.string(def, value, path),
+            LexiconDef::Integer(def) => self.integer(def, value, path),
+            LexiconDef::Boolean(def) => self.boolean(def, value, path),
+            LexiconDef::Bytes(def) => self.bytes(def, value, path),
+            LexiconDef::CidLink(_) => self.cid_link(value, path),
+            LexiconDef::Blob(def) => self.blob(def, value, path),
+            LexiconDef::Union(def) => self.union(context, def, value, path),
+            LexiconDef::Unknown(_) => self.unknown(value, path),
+            LexiconDef::Token(_)
+            | LexiconDef::Query(_)
+            | LexiconDef::Procedure(_)
+            | LexiconDef::Subscription(_) => {
+                let kind = match def {
+                    LexiconDef::Token(_) => "token",
+                    LexiconDef::Query(_) => "query",
+                    LexiconDef::Procedure(_) => "procedure",
+                    _ => "subscription",
+                };
+                self.fail(path.to_string(), ValidationError::UnsupportedType(kind.to_string()))
+            }
+        }
+    }
+
+    fn reference(&mut self, context: &str, ref_str: &str, value: &Value, path: &str) {
+        let resolved = parse_ref(ref_str, context).and_then(|(nsid, _)| {
+            self.registry
+                .resolve_ref(context, ref_str)
+                .map(|def| (nsid, def))
+        });
+        match resolved {
+            Ok((nsid, def)) => self.def(&nsid, def, value, path),
+            Err(e) => self.fail(path.to_string(), ValidationError::UnresolvedRef(e.to_string())),
+        }
+    }
+
+    fn boolean(&mut self, def: &LexBoolean, value: &Value, path: &str) {
+        let Some(value) = value.as_bool() else {
+            return self.wrong_type(path, "boolean", value);
+        };
+        if let Some(constant) = def.constraints.r#const {
+            if value != constant {
+                self.fail(
+                    path.to_string(),
+                    ValidationError::ConstMismatch {
+                        actual: value.to_string(),
+                        expected: constant.to_string(),
+                    },
+                );
+            }
+        }
+    }
+
+    fn integer(&mut self, def: &LexInteger, value: &Value, path: &str) {
+        match value.as_i64() {
+            Some(value) => self.check(path, validate_integer(value, &def.constraints)),
+            None => self.wrong_type(path, "integer", value),
+        }
+    }
+
+    fn string(&mut self, def: &LexString, value: &Value, path: &str) {
+        match value.as_str() {
+            Some(value) => self.check(path, validate_string(value, def)),
+            None => self.wrong_type(path, "string", value),
+        }
+    }
+
+    fn bytes(&mut self, def: &LexBytes, value: &Value, path: &str) {
+        let Some(encoded) = single_key(value, "$bytes").and_then(Value::as_str) else {
+            return self.wrong_type(path, "bytes", value);
+        };
+        let decoded =
+            base64::engine::general_purpose::STANDARD_NO_PAD.decode(encoded.trim_end_matches('='));
+        let length = match decoded {
+            Ok(bytes) => bytes.len(),
+            Err(e) => {
+                return self.fail(path.to_string(), ValidationError::InvalidBytes(e.to_string()))
+            }
+        };
+        if let Some(max) = def.constraints.max_length {
+            if length > max {
+                self.fail(path.to_string(), ValidationError::BytesTooLong { actual: length, max });
+            }
+        }
+        if let Some(min) = def.constraints.min_length {
+            if length < min {
+                self.fail(path.to_string(), ValidationError::BytesTooShort { actual: length, min });
+            }
+        }
+    }
+
+    fn cid_link(&mut self, value: &Value, path: &str) {
+        let Some(link) = single_key(value, "$link").and_then(Value::as_str) else {
+            return self.wrong_type(path, "cid-link", value);
+        };
+        if let Err(e) = crate::cid::validate_cid_string(link) {
+            self.fail(path.to_string(), ValidationError::InvalidCidLink(e.to_string()));
+        }
+    }
+
+    fn blob(&mut self, def: &LexBlob, value: &Value, path: &str) {
+        let Some(blob) = value.as_object() else {
+            return self.wrong_type(path, "blob", value);
+        };
+
+        let mime_type = blob.get("mimeType").and_then(Value::as_str);
+        let (mime_type, size) = if blob.get("$type").and_then(Value::as_str) == Some("blob") {
+            let link = blob.get("ref").and_then(|r| single_key(r, "$link"));
+            match link.and_then(Value::as_str) {
+                Some(cid) => {
+                    self.cid_link(&serde_json::json!({ "$link": cid }), &join(path, "ref"))
+                }
+                None => {
+                    return self.fail(
+                        path.to_string(),
+                        ValidationError::InvalidBlob("Missing ref".to_string()),
+                    )
+                }
+            }
+            let Some(size) = blob.get("size").and_then(Value::as_u64) else {
+                return self.fail(
+                    path.to_string(),
+                    ValidationError::InvalidBlob("Missing size".to_string()),
+                );
+            };
+            (mime_type, Some(size as usize))
+        } else if blob.get("cid").and_then(Value::as_str).is_some() {
+            // Legacy blob reference without size
+            (mime_type, None)
+        } else {
+            return self.fail(
+                path.to_string(),
+                ValidationError::InvalidBlob("Missing $type or cid".to_string()),
+            );
+        };
+
+        let Some(mime_type) = mime_type else {
+            return self.fail(
+                path.to_string(),
+                ValidationError::InvalidBlob("Missing mimeType".to_string()),
+            );
+        };
+        if let Some(accept) = &def.constraints.accept {
+            if !accept
+                .iter()
+                .any(|pattern| mime_type_matches(pattern, mime_type))
+            {
+                self.fail(
+                    path.to_string(),
+                    ValidationError::BlobMimeTypeNotAccepted { mime_type: mime_type.to_string() },
+                );
+            }
+        }
+        if let (Some(max), Some(size)) = (def.constraints.max_size, size) {
+            if size > max {
+                self.fail(path.to_string(), ValidationError::BlobTooLarge { actual: size, max });
+            }
+        }
+    }
+
+    fn array(&mut self, context: &str, def: &LexArray, value: &Value, path: &str) {
+        let Some(items) = value.as_array() else {
+            return self.wrong_type(path, "array", value);
+        };
+        self.check(path, validate_array_length(items.len(), &def.constraints));
+        for (i, item) in items.iter().enumerate() {
+            self.value(context, &def.items, item, &format!("{}[{}]", path, i));
+        }
+    }
+
+    fn object(&mut self, context: &str, def: &LexObject, value: &Value, path: &str) {
+        let Some(object) = value.as_object() else {
+            return self.wrong_type(path, "object", value);
+        };
+        let nullable = def.nullable.as_deref().unwrap_or_default();
+
+        for field in def.required.as_deref().unwrap_or_default() {
+            if !object.contains_key(field) {
+                self.fail(
+                    join(path, field),
+                    ValidationError::MissingRequiredField { field: field.clone() },
+                );
+            }
+        }
+
+        let mut properties: Vec<_> = def.properties.iter().collect();
+        properties.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, ty) in properties {
+            let Some(field) = object.get(name) else {
+                continue;
+            };
+            let field_path = join(path, name);
+            if field.is_null() && !matches!(ty, LexType::Null) {
+                if !nullable.contains(name) {
+                    self.fail(field_path, ValidationError::UnexpectedNull);
+                }
+                continue;
+            }
+            self.value(context, ty, field, &field_path);
+        }
+    }
+
+    fn union(&mut self, context: &str, def: &LexUnion, value: &Value, path: &str) {
+        let Some(object) = value.as_object() else {
+            return self.wrong_type(path, "object", value);
+        };
+        let Some(type_name) = object.get("$type").and_then(Value::as_str) else {
+            return self.fail(path.to_string(), ValidationError::MissingUnionType);
+        };
+
+        let target = normalize_ref(type_name, context);
+        let member = def
+            .refs
+            .iter()
+            .find(|ref_str| target.is_some() && normalize_ref(ref_str, context) == target);
+        match member {
+            Some(ref_str) => self.reference(context, ref_str, value, path),
+            None if def.closed.unwrap_or(false) => self.fail(
+                path.to_string(),
+                ValidationError::UnionTypeNotAllowed { type_name: type_name.to_string() },
+            ),
+            // Open unions accept types this schema doesn't know about
+            None => {}
+        }
+    }
+
+    fn unknown(&mut self, value: &Value, path: &str) {
+        if !value.is_object() {
+            self.wrong_type(path, "object", value);
+        }
+    }
+}
+
+/// Get the value of an object's only key, if it is `key`
+fn single_key<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    value
+        .as_object()
+        .filter(|object: &&Map<String, Value>| object.len() == 1)
+        .and_then(|object| object.get(key))
+}
+
+/// Normalize a ref or `$type` to `nsid#name`
+fn normalize_ref(ref_str: &str, context: &str) -> Option<String> {
+    parse_ref(ref_str, context)
+        .ok()
+        .map(|(nsid, name)| format!("{}#{}", nsid, name))
+}
+
+/// Check a MIME type against an accept pattern such as `image/*`
+pub(crate) fn mime_type_matches(pattern: &str, mime_type: &str) -> bool {
+    match pattern.strip_suffix("/*") {
+        Some("*") => true,
+        Some(prefix) => mime_type
+            .split_once('/')
+            .is_some_and(|(kind, _)| kind == prefix),
+        None => pattern == mime_type,
+    }
+}
+
+fn join(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn display_value(value: &Value) -> String {
+    value
+        .as_str()
+        .map_or_else(|| value.to_string(), str::to_string)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -786,4 +1325,259 @@ mod tests {
         assert_eq!(family_emoji.graphemes(true).count(), 1); // 1 grapheme
         assert!(validate_string_constraints(family_emoji, &constraints).is_ok());
     }
+
+    fn record_registry() -> SchemaRegistry {
+        use super::super::schema::LexiconDoc;
+        use serde_json::json;
+
+        let note: LexiconDoc = serde_json::from_value(json!({
+            "lexicon": 1,
+            "id": "com.example.note",
+            "defs": {
+                "main": {
+                    "type": "record",
+                    "key": "tid",
+                    "record": {
+                        "type": "object",
+                        "required": ["text", "createdAt"],
+                        "nullable": ["subtitle"],
+                        "properties": {
+                            "text": {"type": "string", "maxLength": 20},
+                            "subtitle": {"type": "string"},
+                            "createdAt": {"type": "string", "format": "datetime"},
+                            "tags": {
+                                "type": "array",
+                                "maxLength": 2,
+                                "items": {"type": "string", "maxLength": 5}
+                            },
+                            "embed": {"type": "union", "refs": ["#image", "com.example.link"]},
+                            "labels": {"type": "union", "refs": ["#selfLabels"], "closed": true},
+                            "sig": {"type": "bytes", "maxLength": 4},
+                            "prev": {"type": "cid-link"},
+                            "extra": {"type": "unknown"}
+                        }
+                    }
+                },
+                "image": {
+                    "type": "object",
+                    "required": ["image", "alt"],
+                    "properties": {
+                        "image": {"type": "blob", "accept": ["image/*"], "maxSize": 1000},
+                        "alt": {"type": "string"}
+                    }
+                },
+                "selfLabels": {
+                    "type": "object",
+                    "properties": {"values": {"type": "array", "items": {"type": "string"}}}
+                }
+            }
+        }))
+        .unwrap();
+        let link: LexiconDoc = serde_json::from_value(json!({
+            "lexicon": 1,
+            "id": "com.example.link",
+            "defs": {
+                "main": {
+                    "type": "object",
+                    "required": ["uri"],
+                    "properties": {"uri": {"type": "string", "format": "uri"}}
+                }
+            }
+        }))
+        .unwrap();
+
+        let mut registry = SchemaRegistry::new();
+        registry.register(note);
+        registry.register(link);
+        registry
+    }
+
+    const CID: &str = "bafyreie5737gdxlw5i64vzichcalba3z2v5n6icifvx5xytvske7mr3hpm";
+
+    fn record_errors(record: serde_json::Value) -> Vec<(String, ValidationError)> {
+        match validate_record(&record_registry(), "com.example.note", &record) {
+            Ok(()) => Vec::new(),
+            Err(e) => e.errors.into_iter().map(|e| (e.path, e.error)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_validate_record_valid() {
+        use serde_json::json;
+
+        let errors = record_errors(json!({
+            "$type": "com.example.note",
+            "text": "hello",
+            "subtitle": null,
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "tags": ["a", "b"],
+            "embed": {
+                "$type": "com.example.note#image",
+                "image": {
+                    "$type": "blob",
+                    "ref": {"$link": CID},
+                    "mimeType": "image/png",
+                    "size": 100
+                },
+                "alt": "a picture"
+            },
+            "labels": {"$type": "com.example.note#selfLabels", "values": []},
+            "sig": {"$bytes": "AQID"},
+            "prev": {"$link": CID},
+            "extra": {"anything": [1, 2, 3]}
+        }));
+        assert_eq!(errors, vec![]);
+    }
+
+    #[test]
+    fn test_validate_record_required_and_nullable() {
+        use serde_json::json;
+
+        let errors = record_errors(json!({"text": null, "subtitle": null}));
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "createdAt".to_string(),
+                    ValidationError::MissingRequiredField { field: "createdAt".to_string() }
+                ),
+                ("text".to_string(), ValidationError::UnexpectedNull),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_record_nested_paths() {
+        use serde_json::json;
+
+        let errors = record_errors(json!({
+            "text": 42,
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "tags": ["ok", "too long"],
+            "embed": {"$type": "#image", "image": {"$type": "blob"}, "alt": "x"}
+        }));
+        let paths: Vec<_> = errors.iter().map(|(path, _)| path.as_str()).collect();
+        assert_eq!(paths, vec!["embed.image", "tags[1]", "text"]);
+        assert!(matches!(errors[0].1, ValidationError::InvalidBlob(_)));
+        assert!(matches!(errors[1].1, ValidationError::StringTooLong { .. }));
+        assert!(matches!(errors[2].1, ValidationError::WrongType { .. }));
+    }
+
+    #[test]
+    fn test_validate_record_unions() {
+        use serde_json::json;
+        let base = |field: &str, value: serde_json::Value| {
+            let mut record = json!({"text": "hi", "createdAt": "2024-01-01T00:00:00.000Z"});
+            record[field] = value;
+            record_errors(record)
+        };
+
+        // Open union accepts unknown types, and resolves cross-lexicon refs
+        assert_eq!(base("embed", json!({"$type": "com.other.thing", "x": 1})), vec![]);
+        assert_eq!(
+            base("embed", json!({"$type": "com.example.link", "uri": "https://example.com"})),
+            vec![]
+        );
+        assert_eq!(
+            base("embed", json!({"$type": "com.example.link#main"}))[0],
+            (
+                "embed.uri".to_string(),
+                ValidationError::MissingRequiredField { field: "uri".to_string() }
+            )
+        );
+        assert_eq!(
+            base("embed", json!({"uri": "https://example.com"})),
+            vec![("embed".to_string(), ValidationError::MissingUnionType)]
+        );
+
+        // Closed union rejects unlisted types
+        assert_eq!(
+            base("labels", json!({"$type": "com.other.labels"})),
+            vec![(
+                "labels".to_string(),
+                ValidationError::UnionTypeNotAllowed { type_name: "com.other.labels".to_string() }
+            )]
+        );
+    }
+
+    #[test]
+    fn test_validate_record_blobs_bytes_and_links() {
+        use serde_json::json;
+        let with_embed = |image: serde_json::Value| {
+            record_errors(json!({
+                "text": "hi",
+                "createdAt": "2024-01-01T00:00:00.000Z",
+                "embed": {"$type": "com.example.note#image", "image": image, "alt": ""}
+            }))
+        };
+
+        let blob = |mime: &str, size: u64| json!({"$type": "blob", "ref": {"$link": CID}, "mimeType": mime, "size": size});
+        assert_eq!(
+            with_embed(blob("video/mp4", 10)),
+            vec![(
+                "embed.image".to_string(),
+                ValidationError::BlobMimeTypeNotAccepted { mime_type: "video/mp4".to_string() }
+            )]
+        );
+        assert_eq!(
+            with_embed(blob("image/jpeg", 2000)),
+            vec![(
+                "embed.image".to_string(),
+                ValidationError::BlobTooLarge { actual: 2000, max: 1000 }
+            )]
+        );
+        // Legacy blob references have no size
+        assert_eq!(with_embed(json!({"cid": CID, "mimeType": "image/jpeg"})), vec![]);
+
+        let errors = record_errors(json!({
+            "text": "hi",
+            "createdAt": "2024-01-01T00:00:00.000Z",
+            "sig": {"$bytes": "AQIDBAU="},
+            "prev": {"$link": "not-a-cid"},
+            "extra": "string"
+        }));
+        assert_eq!(
+            errors[0],
+            (
+                "extra".to_string(),
+                ValidationError::WrongType {
+                    expected: "object".to_string(),
+                    actual: "string".to_string()
+                }
+            )
+        );
+        assert!(matches!(errors[1], (ref p, ValidationError::InvalidCidLink(_)) if p == "prev"));
+        assert_eq!(
+            errors[2],
+            ("sig".to_string(), ValidationError::BytesTooLong { actual: 5, max: 4 })
+        );
+    }
+
+    #[test]
+    fn test_validate_record_type_mismatch_and_unknown_nsid() {
+        use serde_json::json;
+        let registry = record_registry();
+
+        let err = validate_record(
+            &registry,
+            "com.example.note",
+            &json!({"$type": "com.example.other", "text": "", "createdAt": "2024-01-01T00:00:00Z"}),
+        )
+        .unwrap_err();
+        assert_eq!(err.errors.len(), 1);
+        assert_eq!(err.errors[0].path, "$type");
+        assert_eq!(
+            err.to_string(),
+            "Invalid record: $type: Value 'com.example.other' does not match required constant 'com.example.note'"
+        );
+
+        let err = validate_record(&registry, "com.example.link", &json!({})).unwrap_err();
+        assert_eq!(
+            err.errors,
+            vec![FieldError {
+                path: String::new(),
+                error: ValidationError::UnknownRecordType("com.example.link".to_string()),
+            }]
+        );
+    }
 }
@@ -7,10 +7,22 @@
 #![warn(clippy::all)]
 
 pub mod agent;
+pub mod api;
 pub mod cid;
+pub mod crypto;
+pub mod identity;
+pub mod labelers;
 pub mod lexicon;
+pub mod migration;
+pub mod oauth;
+pub mod proxy;
+pub mod rate_limit;
+pub mod repo;
 pub mod session;
+pub mod stream;
+pub mod transport;
 pub mod types;
+pub mod upload;
 pub mod xrpc;
 
 #[cfg(test)]
@@ -20,14 +32,34 @@ pub use agent::{
     AgentError, BskyAgent, BskyAgentConfig, CreateAccountRequest, CreateAccountResponse,
     LoginRequest, LoginResponse, RefreshSessionResponse, SessionEvent,
 };
+pub use crypto::{CryptoError, PublicKey};
+pub use identity::{
+    DidDocument, IdentityError, IdentityResolver, IdentityResolverConfig, ResolvedIdentity,
+};
+pub use labelers::{AcceptLabelersMiddleware, AcceptedLabeler, LabelerProvider};
+pub use migration::{
+    AccountMigration, MigrationCheckpoint, MigrationError, MigrationPlan, MigrationStatus,
+    MigrationStep,
+};
+pub use oauth::{OAuthClient, OAuthClientConfig, OAuthError, OAuthSession, OAuthSessionData};
+pub use proxy::{ProxyError, ProxyRouter, ServiceProxy, ServiceProxyMiddleware};
+pub use rate_limit::{RateLimitBudget, RateLimitConfig, RateLimiter};
+pub use repo::{CarFile, Commit, RepoError, RepoRecord, Repository};
 pub use session::{
     get_jwt_expiration, is_jwt_expired, is_jwt_expiring_soon, is_session_expired, is_signup_queued,
     parse_jwt_claims, AtpSessionData, JwtClaims, SessionAccount, SessionError,
 };
+pub use stream::{
+    Firehose, FirehoseConfig, FirehoseEvent, Jetstream, JetstreamConfig, JetstreamEvent,
+    StreamError,
+};
+pub use transport::{MockTransport, RecordReplayTransport, ReqwestTransport, Transport};
 pub use types::{AtUri, Did, Handle, StrongRef, Tid};
+pub use upload::{BlobLimits, BlobUpload, UploadError, UploadProgress};
 pub use xrpc::{
-    network_retry, retry, HttpMethod, RetryConfig, XrpcClient, XrpcClientConfig, XrpcError,
-    XrpcErrorResponse, XrpcRequest, XrpcResponse,
+    network_retry, retry, HttpMethod, HttpRequest, HttpResponse, LoggingMiddleware, Middleware,
+    Next, RetryConfig, RetryMiddleware, XrpcClient, XrpcClientConfig, XrpcError, XrpcErrorResponse,
+    XrpcRequest, XrpcResponse,
 };
 
 /// Result type for AT Protocol operations
@@ -0,0 +1,851 @@
+//! OAuth client flow
+//!
+//! Implements discovery, pushed authorization requests, the authorization
+//! code exchange and token refresh for the AT Protocol OAuth profile.
+
+use super::dpop::{is_use_dpop_nonce_error, DpopKey, DPOP_NONCE_HEADER};
+use super::pkce::PkceChallenge;
+use super::{random_token, OAuthError, OAuthSession, OAuthSessionData, Result};
+use crate::identity::IdentityResolver;
+use chrono::{Duration, Utc};
+use reqwest::{Client as ReqwestClient, Url};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Default scope requested by the client
+pub const DEFAULT_SCOPE: &str = "atproto transition:generic";
+
+/// Configuration for the OAuth client
+#[derive(Debug, Clone)]
+pub struct OAuthClientConfig {
+    /// Client ID (URL of the client metadata document, or "http://localhost" for development)
+    pub client_id: String,
+    /// Redirect URI registered in the client metadata
+    pub redirect_uri: String,
+    /// Requested scope
+    pub scope: String,
+    /// HTTP request timeout
+    pub timeout: std::time::Duration,
+}
+
+impl OAuthClientConfig {
+    /// Create a new configuration
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - Client ID URL
+    /// * `redirect_uri` - Redirect URI for the authorization callback
+    pub fn new(client_id: impl Into<String>, redirect_uri: impl Into<String>) -> Self {
+        Self {
+            client_id: client_id.into(),
+            redirect_uri: redirect_uri.into(),
+            scope: DEFAULT_SCOPE.to_string(),
+            timeout: std::time::Duration::from_secs(30),
+        }
+    }
+
+    /// Set the requested scope
+    pub fn with_scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = scope.into();
+        self
+    }
+
+    /// Set the HTTP request timeout
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// OAuth protected resource metadata (served by the PDS)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProtectedResourceMetadata {
+    /// Resource server URL
+    pub resource: String,
+    /// Authorization servers that issue tokens for this resource
+    #[serde(default)]
+    pub authorization_servers: Vec<String>,
+}
+
+/// OAuth authorization server metadata
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuthorizationServerMetadata {
+    /// Issuer identifier
+    pub issuer: String,
+    /// Authorization endpoint
+    pub authorization_endpoint: String,
+    /// Token endpoint
+    pub token_endpoint: String,
+    /// Pushed authorization request endpoint
+    #[serde(default)]
+    pub pushed_authorization_request_endpoint: Option<String>,
+    /// Token revocation endpoint
+    #[serde(default)]
+    pub revocation_endpoint: Option<String>,
+    /// Supported scopes
+    #[serde(default)]
+    pub scopes_supported: Vec<String>,
+    /// Supported DPoP signing algorithms
+    #[serde(default)]
+    pub dpop_signing_alg_values_supported: Vec<String>,
+}
+
+/// State kept between starting the authorization and handling the callback
+///
+/// This is serializable so it can be persisted while the user is in the browser.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingAuthorization {
+    /// OAuth state parameter
+    pub state: String,
+    /// PKCE code verifier
+    pub code_verifier: String,
+    /// DPoP key the tokens will be bound to
+    pub dpop_key: DpopKey,
+    /// Expected issuer
+    pub issuer: String,
+    /// Token endpoint
+    pub token_endpoint: String,
+    /// Redirect URI used for the request
+    pub redirect_uri: String,
+    /// Expected account DID, if the flow started from a handle or DID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_did: Option<String>,
+    /// Latest DPoP nonce from the authorization server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_server_nonce: Option<String>,
+}
+
+/// A started authorization
+#[derive(Debug, Clone)]
+pub struct AuthorizationRequest {
+    /// URL to open in the user's browser
+    pub authorization_url: String,
+    /// State needed to complete the flow
+    pub pending: PendingAuthorization,
+}
+
+/// Parameters received on the redirect URI
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallbackParams {
+    /// Authorization code
+    pub code: String,
+    /// OAuth state parameter
+    pub state: String,
+    /// Issuer identifier
+    pub iss: Option<String>,
+}
+
+impl CallbackParams {
+    /// Parse callback parameters from a query string
+    ///
+    /// Returns `OAuthError::Server` if the redirect carries an OAuth error.
+    pub fn from_query(query: &str) -> Result<Self> {
+        let query = query.trim_start_matches('?');
+        let url = Url::parse(&format!("http://localhost/?{}", query))
+            .map_err(|e| OAuthError::InvalidResponse(e.to_string()))?;
+
+        let mut code = None;
+        let mut state = None;
+        let mut iss = None;
+        let mut error = None;
+        let mut error_description = None;
+
+        for (key, value) in url.query_pairs() {
+            match key.as_ref() {
+                "code" => code = Some(value.into_owned()),
+                "state" => state = Some(value.into_owned()),
+                "iss" => iss = Some(value.into_owned()),
+                "error" => error = Some(value.into_owned()),
+                "error_description" => error_description = Some(value.into_owned()),
+                _ => {}
+            }
+        }
+
+        if let Some(error) = error {
+            return Err(OAuthError::Server {
+                error,
+                description: error_description.unwrap_or_default(),
+            });
+        }
+
+        Ok(Self {
+            code: code.ok_or_else(|| OAuthError::InvalidResponse("Missing code".to_string()))?,
+            state: state.ok_or_else(|| OAuthError::InvalidResponse("Missing state".to_string()))?,
+            iss,
+        })
+    }
+}
+
+/// Token endpoint response
+#[derive(Debug, Clone, Deserialize)]
+pub struct TokenResponse {
+    /// Access token
+    pub access_token: String,
+    /// Token type (must be "DPoP")
+    pub token_type: String,
+    /// Refresh token
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    /// Lifetime of the access token in seconds
+    #[serde(default)]
+    pub expires_in: Option<i64>,
+    /// Granted scope
+    #[serde(default)]
+    pub scope: Option<String>,
+    /// Account DID
+    pub sub: String,
+}
+
+/// OAuth error response body
+#[derive(Debug, Deserialize)]
+struct OAuthErrorResponse {
+    error: String,
+    #[serde(default)]
+    error_description: Option<String>,
+}
+
+/// AT Protocol OAuth client
+///
+/// Drives the authorization code flow against the authorization server that
+/// protects an account's PDS.
+pub struct OAuthClient {
+    config: OAuthClientConfig,
+    http: ReqwestClient,
+    identity_resolver: Arc<IdentityResolver>,
+}
+
+impl OAuthClient {
+    /// Create a new OAuth client
+    pub fn new(config: OAuthClientConfig) -> Self {
+        Self {
+            http: build_http_client(config.timeout),
+            config,
+            identity_resolver: Arc::new(IdentityResolver::default()),
+        }
+    }
+
+    /// Use a specific identity resolver
+    pub fn with_identity_resolver(mut self, resolver: Arc<IdentityResolver>) -> Self {
+        self.identity_resolver = resolver;
+        self
+    }
+
+    /// Get the client configuration
+    pub fn config(&self) -> &OAuthClientConfig {
+        &self.config
+    }
+
+    /// Fetch the authorization server metadata for an issuer
+    pub async fn authorization_server_metadata(
+        &self,
+        issuer: &str,
+    ) -> Result<AuthorizationServerMetadata> {
+        let issuer = issuer.trim_end_matches('/');
+        let url = format!("{}/.well-known/oauth-authorization-server", issuer);
+        let metadata: AuthorizationServerMetadata = self.fetch_json(&url).await?;
+
+        if metadata.issuer.trim_end_matches('/') != issuer {
+            return Err(OAuthError::IssuerMismatch {
+                expected: issuer.to_string(),
+                actual: metadata.issuer,
+            });
+        }
+
+        Ok(metadata)
+    }
+
+    /// Find the authorization server protecting a PDS
+    pub async fn resolve_authorization_server(
+        &self,
+        pds_url: &str,
+    ) -> Result<AuthorizationServerMetadata> {
+        let url = format!("{}/.well-known/oauth-protected-resource", pds_url.trim_end_matches('/'));
+        let resource: ProtectedResourceMetadata = self.fetch_json(&url).await?;
+
+        let issuer = resource.authorization_servers.first().ok_or_else(|| {
+            OAuthError::Discovery(format!("No authorization server listed by {}", pds_url))
+        })?;
+
+        self.authorization_server_metadata(issuer).await
+    }
+
+    /// Start an authorization
+    ///
+    /// # Arguments
+    ///
+    /// * `identifier` - A handle or DID (resolved to its PDS and used as the
+    ///   login hint), or a server URL (PDS or entryway) for account selection
+    ///   on the authorization page
+    pub async fn authorize(&self, identifier: &str) -> Result<AuthorizationRequest> {
+        let identifier = identifier.trim();
+
+        if identifier.starts_with("https://") || identifier.starts_with("http://") {
+            let metadata = match self.resolve_authorization_server(identifier).await {
+                Ok(metadata) => metadata,
+                // The URL may be the authorization server itself
+                Err(_) => self.authorization_server_metadata(identifier).await?,
+            };
+            return self.start_authorization(metadata, None, None).await;
+        }
+
+        let identity = self.identity_resolver.resolve(identifier).await?;
+        let pds_url = identity.pds_endpoint.ok_or_else(|| {
+            OAuthError::Discovery(format!("No PDS endpoint for {}", identity.did))
+        })?;
+        let metadata = self.resolve_authorization_server(&pds_url).await?;
+
+        self.start_authorization(
+            metadata,
+            Some(identifier.trim_start_matches('@')),
+            Some(identity.did.to_string()),
+        )
+        .await
+    }
+
+    /// Complete an authorization with the callback parameters
+    ///
+    /// Exchanges the code for DPoP-bound tokens and verifies that the issuer is
+    /// authoritative for the returned account.
+    pub async fn callback(
+        &self,
+        pending: &PendingAuthorization,
+        params: &CallbackParams,
+    ) -> Result<OAuthSession> {
+        if params.state != pending.state {
+            return Err(OAuthError::StateMismatch);
+        }
+        if let Some(iss) = &params.iss {
+            if iss.trim_end_matches('/') != pending.issuer.trim_end_matches('/') {
+                return Err(OAuthError::IssuerMismatch {
+                    expected: pending.issuer.clone(),
+                    actual: iss.clone(),
+                });
+            }
+        }
+
+        let form = vec![
+            ("grant_type", "authorization_code".to_string()),
+            ("code", params.code.clone()),
+            ("redirect_uri", pending.redirect_uri.clone()),
+            ("code_verifier", pending.code_verifier.clone()),
+            ("client_id", self.config.client_id.clone()),
+        ];
+
+        let mut nonce = pending.auth_server_nonce.clone();
+        let token: TokenResponse = post_form_with_dpop(
+            &self.http,
+            &pending.token_endpoint,
+            &form,
+            &pending.dpop_key,
+            &mut nonce,
+        )
+        .await?;
+        validate_token_response(&token)?;
+
+        if let Some(expected) = &pending.expected_did {
+            if &token.sub != expected {
+                return Err(OAuthError::SubjectMismatch {
+                    expected: expected.clone(),
+                    actual: token.sub,
+                });
+            }
+        }
+
+        // The issuer must be the authorization server of the account's own PDS
+        let identity = self.identity_resolver.resolve(&token.sub).await?;
+        let pds_url = identity.pds_endpoint.ok_or_else(|| {
+            OAuthError::Discovery(format!("No PDS endpoint for {}", identity.did))
+        })?;
+        let metadata = self.resolve_authorization_server(&pds_url).await?;
+        if metadata.issuer.trim_end_matches('/') != pending.issuer.trim_end_matches('/') {
+            return Err(OAuthError::IssuerMismatch {
+                expected: metadata.issuer,
+                actual: pending.issuer.clone(),
+            });
+        }
+
+        let refresh_token = token
+            .refresh_token
+            .clone()
+            .ok_or_else(|| OAuthError::InvalidResponse("Missing refresh_token".to_string()))?;
+
+        Ok(OAuthSession {
+            did: token.sub.clone(),
+            handle: identity
+                .handle
+                .map(|h| h.to_string())
+                .unwrap_or_else(|| "handle.invalid".to_string()),
+            pds_url,
+            access_token: token.access_token.clone(),
+            refresh_token,
+            data: OAuthSessionData {
+                issuer: pending.issuer.clone(),
+                token_endpoint: pending.token_endpoint.clone(),
+                client_id: self.config.client_id.clone(),
+                dpop_key: pending.dpop_key.clone(),
+                auth_server_nonce: nonce,
+                expires_at: token.expires_in.map(|s| Utc::now() + Duration::seconds(s)),
+                scope: token.scope,
+            },
+        })
+    }
+
+    /// Refresh the tokens of an OAuth session
+    ///
+    /// See [`OAuthSessionData::refresh`].
+    pub async fn refresh(
+        &self,
+        data: &mut OAuthSessionData,
+        refresh_token: &str,
+        did: &str,
+    ) -> Result<TokenResponse> {
+        data.refresh_with(&self.http, refresh_token, did).await
+    }
+
+    /// Send the pushed authorization request and build the authorization URL
+    async fn start_authorization(
+        &self,
+        metadata: AuthorizationServerMetadata,
+        login_hint: Option<&str>,
+        expected_did: Option<String>,
+    ) -> Result<AuthorizationRequest> {
+        let par_endpoint = metadata
+            .pushed_authorization_request_endpoint
+            .as_deref()
+            .ok_or_else(|| {
+                OAuthError::Discovery(format!("{} does not support PAR", metadata.issuer))
+            })?;
+
+        let pkce = PkceChallenge::generate();
+        let state = random_token(16);
+        let dpop_key = DpopKey::generate();
+
+        let mut form = vec![
+            ("client_id", self.config.client_id.clone()),
+            ("response_type", "code".to_string()),
+            ("code_challenge", pkce.challenge.clone()),
+            ("code_challenge_method", PkceChallenge::METHOD.to_string()),
+            ("redirect_uri", self.config.redirect_uri.clone()),
+            ("scope", self.config.scope.clone()),
+            ("state", state.clone()),
+        ];
+        if let Some(hint) = login_hint {
+            form.push(("login_hint", hint.to_string()));
+        }
+
+        #[derive(Deserialize)]
+        struct ParResponse {
+            request_uri: String,
+        }
+
+        let mut nonce = None;
+        let par: ParResponse =
+            post_form_with_dpop(&self.http, par_endpoint, &form, &dpop_key, &mut nonce).await?;
+
+        let authorization_url = Url::parse_with_params(
+            &metadata.authorization_endpoint,
+            &[
+                ("client_id", self.config.client_id.as_str()),
+                ("request_uri", par.request_uri.as_str()),
+            ],
+        )
+        .map_err(|e| OAuthError::Discovery(format!("Invalid authorization endpoint: {}", e)))?;
+
+        Ok(AuthorizationRequest {
+            authorization_url: authorization_url.to_string(),
+            pending: PendingAuthorization {
+                state,
+                code_verifier: pkce.verifier,
+                dpop_key,
+                issuer: metadata.issuer,
+                token_endpoint: metadata.token_endpoint,
+                redirect_uri: self.config.redirect_uri.clone(),
+                expected_did,
+                auth_server_nonce: nonce,
+            },
+        })
+    }
+
+    /// GET a JSON metadata document
+    async fn fetch_json<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
+        let response = self
+            .http
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| OAuthError::Network(format!("Request failed: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(OAuthError::Discovery(format!(
+                "HTTP {} fetching {}",
+                response.status().as_u16(),
+                url
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| OAuthError::Discovery(format!("Invalid metadata at {}: {}", url, e)))
+    }
+}
+
+impl OAuthSessionData {
+    /// Refresh the tokens
+    ///
+    /// Updates the expiration, scope and nonce in place and returns the new
+    /// tokens. The refresh token is rotated by the server, so the returned
+    /// `refresh_token` must replace the old one.
+    ///
+    /// # Arguments
+    ///
+    /// * `refresh_token` - Current refresh token
+    /// * `did` - Account DID; the response subject must match
+    pub async fn refresh(&mut self, refresh_token: &str, did: &str) -> Result<TokenResponse> {
+        let http = build_http_client(std::time::Duration::from_secs(30));
+        self.refresh_with(&http, refresh_token, did).await
+    }
+
+    async fn refresh_with(
+        &mut self,
+        http: &ReqwestClient,
+        refresh_token: &str,
+        did: &str,
+    ) -> Result<TokenResponse> {
+        let form = vec![
+            ("grant_type", "refresh_token".to_string()),
+            ("refresh_token", refresh_token.to_string()),
+            ("client_id", self.client_id.clone()),
+        ];
+
+        let mut nonce = self.auth_server_nonce.clone();
+        let token: TokenResponse =
+            post_form_with_dpop(http, &self.token_endpoint, &form, &self.dpop_key, &mut nonce)
+                .await?;
+        self.auth_server_nonce = nonce;
+
+        validate_token_response(&token)?;
+        if token.sub != did {
+            return Err(OAuthError::SubjectMismatch {
+                expected: did.to_string(),
+                actual: token.sub,
+            });
+        }
+
+        self.expires_at = token.expires_in.map(|s| Utc::now() + Duration::seconds(s));
+        if token.scope.is_some() {
+            self.scope = token.scope.clone();
+        }
+
+        Ok(token)
+    }
+}
+
+fn build_http_client(timeout: std::time::Duration) -> ReqwestClient {
+    ReqwestClient::builder()
+        .timeout(timeout)
+        .user_agent(format!("Aurora-Compass/{}", env!("CARGO_PKG_VERSION")))
+        .build()
+        .expect("Failed to build HTTP client")
+}
+
+/// Check the token type and scope of a token response
+fn validate_token_response(token: &TokenResponse) -> Result<()> {
+    if !token.token_type.eq_ignore_ascii_case("DPoP") {
+        return Err(OAuthError::InvalidResponse(format!(
+            "Expected DPoP token, got {}",
+            token.token_type
+        )));
+    }
+    if let Some(scope) = &token.scope {
+        if !scope.split(' ').any(|s| s == "atproto") {
+            return Err(OAuthError::InvalidResponse(format!(
+                "Granted scope lacks atproto: {}",
+                scope
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// POST a form to the authorization server with a DPoP proof
+///
+/// Records any `DPoP-Nonce` the server returns and retries once if the server
+/// rejected the proof with `use_dpop_nonce`.
+async fn post_form_with_dpop<T: DeserializeOwned>(
+    http: &ReqwestClient,
+    url: &str,
+    form: &[(&str, String)],
+    key: &DpopKey,
+    nonce: &mut Option<String>,
+) -> Result<T> {
+    let mut retried = false;
+
+    loop {
+        let proof = key.proof("POST", url, nonce.as_deref(), None)?;
+        let response = http
+            .post(url)
+            .header("DPoP", proof)
+            .form(form)
+            .send()
+            .await
+            .map_err(|e| OAuthError::Network(format!("Request failed: {}", e)))?;
+
+        let mut nonce_changed = false;
+        if let Some(new_nonce) = response
+            .headers()
+            .get(DPOP_NONCE_HEADER)
+            .and_then(|v| v.to_str().ok())
+        {
+            nonce_changed = nonce.as_deref() != Some(new_nonce);
+            *nonce = Some(new_nonce.to_string());
+        }
+
+        let status = response.status();
+        let www_authenticate = response
+            .headers()
+            .get("WWW-Authenticate")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let body = response
+            .text()
+            .await
+            .map_err(|e| OAuthError::Network(format!("Failed to read response: {}", e)))?;
+
+        if status.is_success() {
+            return serde_json::from_str(&body)
+                .map_err(|e| OAuthError::InvalidResponse(format!("{}: {}", e, body)));
+        }
+
+        if !retried && nonce_changed && is_use_dpop_nonce_error(&body, www_authenticate.as_deref())
+        {
+            retried = true;
+            continue;
+        }
+
+        return Err(match serde_json::from_str::<OAuthErrorResponse>(&body) {
+            Ok(err) => OAuthError::Server {
+                error: err.error,
+                description: err.error_description.unwrap_or_default(),
+            },
+            Err(_) => OAuthError::Network(format!("HTTP {}: {}", status.as_u16(), body)),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::identity::resolver_for;
+    use wiremock::matchers::{body_string_contains, header_exists, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Mount protected resource and authorization server metadata on one server
+    async fn mount_metadata(server: &MockServer) {
+        let uri = server.uri();
+
+        Mock::given(method("GET"))
+            .and(path("/.well-known/oauth-protected-resource"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "resource": uri,
+                "authorization_servers": [uri]
+            })))
+            .mount(server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/.well-known/oauth-authorization-server"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issuer": uri,
+                "authorization_endpoint": format!("{}/oauth/authorize", uri),
+                "token_endpoint": format!("{}/oauth/token", uri),
+                "pushed_authorization_request_endpoint": format!("{}/oauth/par", uri),
+                "dpop_signing_alg_values_supported": ["ES256"]
+            })))
+            .mount(server)
+            .await;
+    }
+
+    fn client_for(server: &MockServer) -> OAuthClient {
+        let config = OAuthClientConfig::new("http://localhost", "http://127.0.0.1/callback");
+        OAuthClient::new(config).with_identity_resolver(Arc::new(resolver_for(
+            "did:plc:alice",
+            "alice.example.com",
+            &server.uri(),
+        )))
+    }
+
+    #[test]
+    fn test_callback_params_from_query() {
+        let params =
+            CallbackParams::from_query("?code=abc&state=xyz&iss=https%3A%2F%2Fauth.example.com")
+                .unwrap();
+        assert_eq!(params.code, "abc");
+        assert_eq!(params.state, "xyz");
+        assert_eq!(params.iss.as_deref(), Some("https://auth.example.com"));
+
+        let err = CallbackParams::from_query("error=access_denied&error_description=Nope");
+        assert!(matches!(err, Err(OAuthError::Server { error, .. }) if error == "access_denied"));
+
+        assert!(CallbackParams::from_query("state=xyz").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_authorize_sends_par_with_nonce_retry() {
+        let server = MockServer::start().await;
+        mount_metadata(&server).await;
+
+        // First PAR attempt is rejected with a fresh nonce
+        Mock::given(method("POST"))
+            .and(path("/oauth/par"))
+            .and(header_exists("DPoP"))
+            .respond_with(
+                ResponseTemplate::new(400)
+                    .insert_header("DPoP-Nonce", "nonce-1")
+                    .set_body_json(serde_json::json!({ "error": "use_dpop_nonce" })),
+            )
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/oauth/par"))
+            .and(body_string_contains("code_challenge_method=S256"))
+            .and(body_string_contains("login_hint=alice.example.com"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "request_uri": "urn:ietf:params:oauth:request_uri:req-1",
+                "expires_in": 299
+            })))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let request = client.authorize("alice.example.com").await.unwrap();
+
+        assert!(request
+            .authorization_url
+            .starts_with(&format!("{}/oauth/authorize?", server.uri())));
+        assert!(request
+            .authorization_url
+            .contains("request_uri=urn%3Aietf%3Aparams%3Aoauth%3Arequest_uri%3Areq-1"));
+        assert_eq!(request.pending.expected_did.as_deref(), Some("did:plc:alice"));
+        assert_eq!(request.pending.auth_server_nonce.as_deref(), Some("nonce-1"));
+        assert_eq!(request.pending.issuer, server.uri());
+    }
+
+    #[tokio::test]
+    async fn test_callback_exchanges_code() {
+        let server = MockServer::start().await;
+        mount_metadata(&server).await;
+
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .and(header_exists("DPoP"))
+            .and(body_string_contains("grant_type=authorization_code"))
+            .and(body_string_contains("code_verifier=verifier"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "access-1",
+                "token_type": "DPoP",
+                "refresh_token": "refresh-1",
+                "expires_in": 3600,
+                "scope": "atproto transition:generic",
+                "sub": "did:plc:alice"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let pending = PendingAuthorization {
+            state: "state-1".to_string(),
+            code_verifier: "verifier".to_string(),
+            dpop_key: DpopKey::generate(),
+            issuer: server.uri(),
+            token_endpoint: format!("{}/oauth/token", server.uri()),
+            redirect_uri: "http://127.0.0.1/callback".to_string(),
+            expected_did: Some("did:plc:alice".to_string()),
+            auth_server_nonce: None,
+        };
+        let params = CallbackParams {
+            code: "code-1".to_string(),
+            state: "state-1".to_string(),
+            iss: Some(server.uri()),
+        };
+
+        let session = client.callback(&pending, &params).await.unwrap();
+        assert_eq!(session.did, "did:plc:alice");
+        assert_eq!(session.handle, "alice.example.com");
+        assert_eq!(session.pds_url, server.uri());
+        assert_eq!(session.access_token, "access-1");
+        assert_eq!(session.refresh_token, "refresh-1");
+        assert!(session.data.expires_at.is_some());
+
+        let session_data = session.to_session_data();
+        assert_eq!(session_data.oauth, Some(session.data.clone()));
+    }
+
+    #[tokio::test]
+    async fn test_callback_rejects_state_mismatch() {
+        let server = MockServer::start().await;
+        let client = client_for(&server);
+        let pending = PendingAuthorization {
+            state: "expected".to_string(),
+            code_verifier: "verifier".to_string(),
+            dpop_key: DpopKey::generate(),
+            issuer: server.uri(),
+            token_endpoint: format!("{}/oauth/token", server.uri()),
+            redirect_uri: "http://127.0.0.1/callback".to_string(),
+            expected_did: None,
+            auth_server_nonce: None,
+        };
+        let params = CallbackParams {
+            code: "code".to_string(),
+            state: "other".to_string(),
+            iss: None,
+        };
+
+        let result = client.callback(&pending, &params).await;
+        assert!(matches!(result, Err(OAuthError::StateMismatch)));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_updates_session_data() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .and(body_string_contains("grant_type=refresh_token"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("DPoP-Nonce", "nonce-2")
+                    .set_body_json(serde_json::json!({
+                        "access_token": "access-2",
+                        "token_type": "DPoP",
+                        "refresh_token": "refresh-2",
+                        "expires_in": 3600,
+                        "sub": "did:plc:alice"
+                    })),
+            )
+            .mount(&server)
+            .await;
+
+        let mut data = OAuthSessionData {
+            issuer: server.uri(),
+            token_endpoint: format!("{}/oauth/token", server.uri()),
+            client_id: "http://localhost".to_string(),
+            dpop_key: DpopKey::generate(),
+            auth_server_nonce: None,
+            expires_at: None,
+            scope: None,
+        };
+
+        let token = data.refresh("refresh-1", "did:plc:alice").await.unwrap();
+        assert_eq!(token.access_token, "access-2");
+        assert_eq!(token.refresh_token.as_deref(), Some("refresh-2"));
+        assert_eq!(data.auth_server_nonce.as_deref(), Some("nonce-2"));
+        assert!(data.expires_at.is_some());
+
+        let result = data.refresh("refresh-2", "did:plc:bob").await;
+        assert!(matches!(result, Err(OAuthError::SubjectMismatch { .. })));
+    }
+}
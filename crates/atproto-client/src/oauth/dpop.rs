@@ -0,0 +1,370 @@
+//! DPoP (Demonstrating Proof of Possession) proofs
+//!
+//! AT Protocol OAuth binds every token to a per-session P-256 key. Each request
+//! to the authorization server or PDS carries a freshly signed `DPoP` JWT, and
+//! servers rotate a `DPoP-Nonce` that must be echoed back in later proofs.
+//!
+//! Reference: <https://datatracker.ietf.org/doc/html/rfc9449>
+
+use super::{base64url, random_token, OAuthError, Result};
+use crate::xrpc::{HttpRequest, HttpResponse, Middleware, Next, XrpcError};
+use async_trait::async_trait;
+use p256::ecdsa::signature::Signer;
+use p256::ecdsa::{Signature, SigningKey};
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::sync::{Arc, Mutex};
+
+type XrpcResult<T> = std::result::Result<T, XrpcError>;
+
+/// Name of the response header carrying a fresh DPoP nonce
+pub const DPOP_NONCE_HEADER: &str = "DPoP-Nonce";
+
+/// P-256 key used to sign DPoP proofs
+///
+/// Serializes as a private JWK so the key can be persisted together with the
+/// tokens it is bound to.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(into = "PrivateJwk", try_from = "PrivateJwk")]
+pub struct DpopKey {
+    signing_key: SigningKey,
+}
+
+/// JWK representation of a P-256 key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PrivateJwk {
+    kty: String,
+    crv: String,
+    x: String,
+    y: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    d: Option<String>,
+}
+
+impl DpopKey {
+    /// Generate a new random key
+    pub fn generate() -> Self {
+        Self { signing_key: SigningKey::random(&mut OsRng) }
+    }
+
+    /// Get the public key as a JWK value (for the proof header)
+    pub fn public_jwk(&self) -> serde_json::Value {
+        let jwk = self.jwk(false);
+        serde_json::json!({ "kty": jwk.kty, "crv": jwk.crv, "x": jwk.x, "y": jwk.y })
+    }
+
+    /// Create a DPoP proof JWT
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - HTTP method of the request (e.g., "POST")
+    /// * `url` - Request URL; query and fragment are stripped for the `htu` claim
+    /// * `nonce` - Latest server-provided nonce, if any
+    /// * `access_token` - Access token to bind via the `ath` claim, if any
+    pub fn proof(
+        &self,
+        method: &str,
+        url: &str,
+        nonce: Option<&str>,
+        access_token: Option<&str>,
+    ) -> Result<String> {
+        let htu = url.split(['?', '#']).next().unwrap_or(url);
+
+        let header = serde_json::json!({
+            "typ": "dpop+jwt",
+            "alg": "ES256",
+            "jwk": self.public_jwk(),
+        });
+
+        let mut claims = serde_json::json!({
+            "jti": random_token(16),
+            "htm": method,
+            "htu": htu,
+            "iat": chrono::Utc::now().timestamp(),
+        });
+        if let Some(nonce) = nonce {
+            claims["nonce"] = serde_json::Value::String(nonce.to_string());
+        }
+        if let Some(token) = access_token {
+            claims["ath"] = serde_json::Value::String(base64url(&Sha256::digest(token.as_bytes())));
+        }
+
+        let encode = |value: &serde_json::Value| -> Result<String> {
+            serde_json::to_vec(value)
+                .map(|bytes| base64url(&bytes))
+                .map_err(|e| OAuthError::Dpop(e.to_string()))
+        };
+
+        let signing_input = format!("{}.{}", encode(&header)?, encode(&claims)?);
+        let signature: Signature = self.signing_key.sign(signing_input.as_bytes());
+
+        Ok(format!("{}.{}", signing_input, base64url(&signature.to_bytes())))
+    }
+
+    fn jwk(&self, include_private: bool) -> PrivateJwk {
+        let point = self.signing_key.verifying_key().to_encoded_point(false);
+
+        PrivateJwk {
+            kty: "EC".to_string(),
+            crv: "P-256".to_string(),
+            x: base64url(point.x().map(|x| x.as_slice()).unwrap_or_default()),
+            y: base64url(point.y().map(|y| y.as_slice()).unwrap_or_default()),
+            d: include_private.then(|| base64url(&self.signing_key.to_bytes())),
+        }
+    }
+}
+
+impl From<DpopKey> for PrivateJwk {
+    fn from(key: DpopKey) -> Self {
+        key.jwk(true)
+    }
+}
+
+impl TryFrom<PrivateJwk> for DpopKey {
+    type Error = OAuthError;
+
+    fn try_from(jwk: PrivateJwk) -> Result<Self> {
+        use base64::Engine;
+
+        if jwk.kty != "EC" || jwk.crv != "P-256" {
+            return Err(OAuthError::Dpop(format!("Unsupported key type {} {}", jwk.kty, jwk.crv)));
+        }
+
+        let d = jwk
+            .d
+            .ok_or_else(|| OAuthError::Dpop("JWK is missing the private key".to_string()))?;
+        let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(d)
+            .map_err(|e| OAuthError::Dpop(e.to_string()))?;
+        let signing_key =
+            SigningKey::from_slice(&bytes).map_err(|e| OAuthError::Dpop(e.to_string()))?;
+
+        Ok(Self { signing_key })
+    }
+}
+
+impl PartialEq for DpopKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.signing_key.verifying_key() == other.signing_key.verifying_key()
+    }
+}
+
+impl std::fmt::Debug for DpopKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DpopKey")
+            .field("public_jwk", &self.public_jwk())
+            .finish_non_exhaustive()
+    }
+}
+
+/// DPoP credentials for authenticating XRPC requests
+///
+/// Holds the access token, the key it is bound to and the most recent nonce
+/// issued by the resource server. Clones share the same token and nonce.
+#[derive(Debug, Clone)]
+pub struct DpopAuth {
+    access_token: Arc<Mutex<String>>,
+    key: DpopKey,
+    nonce: Arc<Mutex<Option<String>>>,
+}
+
+impl DpopAuth {
+    /// Create DPoP credentials
+    pub fn new(access_token: impl Into<String>, key: DpopKey) -> Self {
+        Self {
+            access_token: Arc::new(Mutex::new(access_token.into())),
+            key,
+            nonce: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Get the `Authorization` header value
+    pub fn authorization(&self) -> String {
+        format!("DPoP {}", self.access_token())
+    }
+
+    /// Get the access token
+    pub fn access_token(&self) -> String {
+        self.access_token.lock().unwrap().clone()
+    }
+
+    /// Replace the access token after a refresh
+    ///
+    /// The key is unchanged since refreshed tokens stay bound to it.
+    pub fn set_access_token(&self, access_token: impl Into<String>) {
+        *self.access_token.lock().unwrap() = access_token.into();
+    }
+
+    /// Create a proof for a request to the resource server
+    pub fn proof(&self, method: &str, url: &str) -> Result<String> {
+        let nonce = self.nonce();
+        let access_token = self.access_token();
+        self.key
+            .proof(method, url, nonce.as_deref(), Some(&access_token))
+    }
+
+    /// Get the current nonce
+    pub fn nonce(&self) -> Option<String> {
+        self.nonce.lock().unwrap().clone()
+    }
+
+    /// Record a nonce returned by the server
+    ///
+    /// Returns `true` if the nonce changed.
+    pub fn update_nonce(&self, nonce: &str) -> bool {
+        let mut current = self.nonce.lock().unwrap();
+        if current.as_deref() == Some(nonce) {
+            return false;
+        }
+        *current = Some(nonce.to_string());
+        true
+    }
+}
+
+#[async_trait]
+impl Middleware for DpopAuth {
+    async fn handle(&self, mut request: HttpRequest, next: Next<'_>) -> XrpcResult<HttpResponse> {
+        let mut nonce_retried = false;
+
+        loop {
+            let proof = self
+                .proof(request.method.as_str(), &request.url)
+                .map_err(|e| XrpcError::new(0, "DpopError", e.to_string()))?;
+            request.set_header("Authorization", self.authorization());
+            request.set_header("DPoP", proof);
+
+            let response = next.run(request.clone()).await?;
+
+            let nonce_changed = response
+                .header(DPOP_NONCE_HEADER)
+                .map(|nonce| self.update_nonce(nonce))
+                .unwrap_or(false);
+
+            // Resource servers signal a stale nonce with 401 and WWW-Authenticate
+            if !nonce_retried
+                && nonce_changed
+                && response.status == 401
+                && is_use_dpop_nonce_error("", response.header("WWW-Authenticate"))
+            {
+                nonce_retried = true;
+                continue;
+            }
+
+            return Ok(response);
+        }
+    }
+}
+
+/// Check whether an error response asks the client to retry with a new nonce
+///
+/// Authorization servers report `use_dpop_nonce` in the JSON body, resource
+/// servers in the `WWW-Authenticate` header.
+pub fn is_use_dpop_nonce_error(body: &str, www_authenticate: Option<&str>) -> bool {
+    if www_authenticate.is_some_and(|h| h.contains("use_dpop_nonce")) {
+        return true;
+    }
+
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|v| {
+            v.get("error")
+                .and_then(|e| e.as_str())
+                .map(|e| e == "use_dpop_nonce")
+        })
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::Engine;
+    use p256::ecdsa::signature::Verifier;
+
+    fn decode_part(part: &str) -> serde_json::Value {
+        let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(part)
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[test]
+    fn test_proof_structure_and_signature() {
+        let key = DpopKey::generate();
+        let proof = key
+            .proof("POST", "https://pds.example.com/xrpc/foo?bar=1", Some("n1"), Some("tok"))
+            .unwrap();
+
+        let parts: Vec<&str> = proof.split('.').collect();
+        assert_eq!(parts.len(), 3);
+
+        let header = decode_part(parts[0]);
+        assert_eq!(header["typ"], "dpop+jwt");
+        assert_eq!(header["alg"], "ES256");
+        assert!(header["jwk"].get("d").is_none());
+
+        let claims = decode_part(parts[1]);
+        assert_eq!(claims["htm"], "POST");
+        assert_eq!(claims["htu"], "https://pds.example.com/xrpc/foo");
+        assert_eq!(claims["nonce"], "n1");
+        assert_eq!(claims["ath"], base64url(&Sha256::digest(b"tok")));
+
+        let signature_bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(parts[2])
+            .unwrap();
+        let signature = Signature::from_slice(&signature_bytes).unwrap();
+        let message = format!("{}.{}", parts[0], parts[1]);
+        assert!(key
+            .signing_key
+            .verifying_key()
+            .verify(message.as_bytes(), &signature)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_key_jwk_roundtrip() {
+        let key = DpopKey::generate();
+        let json = serde_json::to_string(&key).unwrap();
+        assert!(json.contains("\"d\""));
+
+        let restored: DpopKey = serde_json::from_str(&json).unwrap();
+        assert_eq!(key, restored);
+    }
+
+    #[test]
+    fn test_key_debug_hides_private_key() {
+        let key = DpopKey::generate();
+        let debug = format!("{:?}", key);
+        let private = base64url(&key.signing_key.to_bytes());
+        assert!(!debug.contains(&private));
+    }
+
+    #[test]
+    fn test_dpop_auth_shared_state() {
+        let auth = DpopAuth::new("token", DpopKey::generate());
+        assert!(auth.nonce().is_none());
+        assert!(auth.update_nonce("a"));
+        assert!(!auth.update_nonce("a"));
+
+        // Clones share the nonce
+        let clone = auth.clone();
+        assert!(clone.update_nonce("b"));
+        assert_eq!(auth.nonce().as_deref(), Some("b"));
+        assert_eq!(auth.authorization(), "DPoP token");
+
+        clone.set_access_token("refreshed");
+        assert_eq!(auth.authorization(), "DPoP refreshed");
+    }
+
+    #[test]
+    fn test_is_use_dpop_nonce_error() {
+        assert!(is_use_dpop_nonce_error(r#"{"error":"use_dpop_nonce"}"#, None));
+        assert!(is_use_dpop_nonce_error(
+            "",
+            Some(
+                r#"DPoP error="use_dpop_nonce", error_description="Resource server requires nonce""#
+            )
+        ));
+        assert!(!is_use_dpop_nonce_error(r#"{"error":"invalid_grant"}"#, None));
+    }
+}
@@ -0,0 +1,265 @@
+//! AT Protocol OAuth Client
+//!
+//! This module implements the AT Protocol OAuth profile, including:
+//! - Protected resource and authorization server metadata discovery
+//! - Pushed authorization requests (PAR)
+//! - PKCE (S256) code challenges
+//! - DPoP-bound access tokens with server nonce rotation
+//! - Token refresh
+//!
+//! OAuth sessions are stored alongside legacy app-password sessions: an
+//! [`AtpSessionData`] or [`SessionAccount`](crate::session::SessionAccount)
+//! with `oauth` set uses DPoP, otherwise Bearer JWTs are used.
+//!
+//! Reference: <https://atproto.com/specs/oauth>
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use atproto_client::oauth::{CallbackParams, OAuthClient, OAuthClientConfig};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let config = OAuthClientConfig::new(
+//!         "https://app.example.com/client-metadata.json",
+//!         "https://app.example.com/callback",
+//!     );
+//!     let client = OAuthClient::new(config);
+//!
+//!     // Start the flow and send the user to the authorization page
+//!     let request = client.authorize("alice.example.com").await?;
+//!     println!("Visit: {}", request.authorization_url);
+//!
+//!     // Later, complete the flow with the redirect parameters
+//!     let params = CallbackParams::from_query("code=abc&state=xyz&iss=https%3A%2F%2Fbsky.social")?;
+//!     let session = client.callback(&request.pending, &params).await?;
+//!     println!("Logged in as {}", session.did);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod client;
+pub mod dpop;
+pub mod pkce;
+
+pub use client::{
+    AuthorizationRequest, AuthorizationServerMetadata, CallbackParams, OAuthClient,
+    OAuthClientConfig, PendingAuthorization, ProtectedResourceMetadata, TokenResponse,
+};
+pub use dpop::{DpopAuth, DpopKey};
+pub use pkce::PkceChallenge;
+
+use crate::identity::IdentityError;
+use crate::session::AtpSessionData;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Errors that can occur during OAuth operations
+#[derive(Debug, Error)]
+pub enum OAuthError {
+    /// Metadata discovery failed
+    #[error("Discovery error: {0}")]
+    Discovery(String),
+
+    /// Identity resolution failed
+    #[error("Identity error: {0}")]
+    Identity(#[from] IdentityError),
+
+    /// The callback state does not match the pending authorization
+    #[error("OAuth state mismatch")]
+    StateMismatch,
+
+    /// The issuer is not the expected authorization server
+    #[error("Issuer mismatch: expected {expected}, got {actual}")]
+    IssuerMismatch {
+        /// Expected issuer
+        expected: String,
+        /// Issuer that was returned
+        actual: String,
+    },
+
+    /// The token subject is not the expected account
+    #[error("Token subject mismatch: expected {expected}, got {actual}")]
+    SubjectMismatch {
+        /// Expected DID
+        expected: String,
+        /// DID that was returned
+        actual: String,
+    },
+
+    /// The authorization server returned an OAuth error
+    #[error("OAuth error {error}: {description}")]
+    Server {
+        /// OAuth error code (e.g., "invalid_grant")
+        error: String,
+        /// Human-readable description
+        description: String,
+    },
+
+    /// DPoP key or proof error
+    #[error("DPoP error: {0}")]
+    Dpop(String),
+
+    /// Invalid response or callback parameters
+    #[error("Invalid response: {0}")]
+    InvalidResponse(String),
+
+    /// HTTP request failure
+    #[error("Network error: {0}")]
+    Network(String),
+}
+
+/// Result type for OAuth operations
+pub type Result<T> = std::result::Result<T, OAuthError>;
+
+/// OAuth state persisted with a session
+///
+/// The access and refresh tokens live in the session's `access_jwt` and
+/// `refresh_jwt` fields; this holds everything else needed to use and refresh
+/// them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthSessionData {
+    /// Authorization server issuer URL
+    pub issuer: String,
+
+    /// Token endpoint used for refresh
+    pub token_endpoint: String,
+
+    /// OAuth client ID the tokens were issued to
+    pub client_id: String,
+
+    /// Key the tokens are bound to
+    pub dpop_key: DpopKey,
+
+    /// Latest DPoP nonce from the authorization server
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_server_nonce: Option<String>,
+
+    /// Access token expiration time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+
+    /// Granted scope
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+}
+
+impl OAuthSessionData {
+    /// Check if the access token is expired
+    ///
+    /// Tokens without a known expiration are treated as valid; the resource
+    /// server will reject them if they are not.
+    pub fn is_expired(&self) -> bool {
+        self.is_expiring_soon(Duration::zero())
+    }
+
+    /// Check if the access token expires within the given duration
+    pub fn is_expiring_soon(&self, threshold: Duration) -> bool {
+        self.expires_at
+            .map(|exp| exp <= Utc::now() + threshold)
+            .unwrap_or(false)
+    }
+}
+
+/// A completed OAuth login
+#[derive(Debug, Clone, PartialEq)]
+pub struct OAuthSession {
+    /// Account DID (the token subject)
+    pub did: String,
+
+    /// Verified handle, or "handle.invalid" if it could not be verified
+    pub handle: String,
+
+    /// The account's PDS URL
+    pub pds_url: String,
+
+    /// DPoP-bound access token
+    pub access_token: String,
+
+    /// Refresh token
+    pub refresh_token: String,
+
+    /// OAuth session state
+    pub data: OAuthSessionData,
+}
+
+impl OAuthSession {
+    /// Convert to ATP session data
+    pub fn to_session_data(&self) -> AtpSessionData {
+        AtpSessionData {
+            access_jwt: self.access_token.clone(),
+            did: self.did.clone(),
+            email: None,
+            email_auth_factor: None,
+            email_confirmed: None,
+            handle: self.handle.clone(),
+            refresh_jwt: self.refresh_token.clone(),
+            active: true,
+            status: None,
+            oauth: Some(self.data.clone()),
+        }
+    }
+}
+
+/// Encode bytes as unpadded base64url
+pub(crate) fn base64url(bytes: &[u8]) -> String {
+    use base64::Engine;
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Generate a random base64url token from `len` random bytes
+pub(crate) fn random_token(len: usize) -> String {
+    use rand::RngCore;
+    let mut bytes = vec![0u8; len];
+    rand::rngs::OsRng.fill_bytes(&mut bytes);
+    base64url(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oauth_session_data_expiry() {
+        let mut data = OAuthSessionData {
+            issuer: "https://auth.example.com".to_string(),
+            token_endpoint: "https://auth.example.com/oauth/token".to_string(),
+            client_id: "https://app.example.com/client-metadata.json".to_string(),
+            dpop_key: DpopKey::generate(),
+            auth_server_nonce: None,
+            expires_at: None,
+            scope: None,
+        };
+        assert!(!data.is_expired());
+
+        data.expires_at = Some(Utc::now() - Duration::seconds(1));
+        assert!(data.is_expired());
+
+        data.expires_at = Some(Utc::now() + Duration::minutes(2));
+        assert!(!data.is_expired());
+        assert!(data.is_expiring_soon(Duration::minutes(5)));
+    }
+
+    #[test]
+    fn test_oauth_session_data_serialization() {
+        let data = OAuthSessionData {
+            issuer: "https://auth.example.com".to_string(),
+            token_endpoint: "https://auth.example.com/oauth/token".to_string(),
+            client_id: "http://localhost".to_string(),
+            dpop_key: DpopKey::generate(),
+            auth_server_nonce: Some("nonce".to_string()),
+            expires_at: None,
+            scope: Some("atproto".to_string()),
+        };
+
+        let json = serde_json::to_string(&data).unwrap();
+        assert!(json.contains("tokenEndpoint"));
+        assert!(json.contains("dpopKey"));
+
+        let restored: OAuthSessionData = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, data);
+    }
+}
@@ -0,0 +1,54 @@
+//! PKCE (Proof Key for Code Exchange)
+//!
+//! Reference: <https://datatracker.ietf.org/doc/html/rfc7636>
+
+use super::{base64url, random_token};
+use sha2::{Digest, Sha256};
+
+/// A PKCE verifier and its S256 challenge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PkceChallenge {
+    /// Secret code verifier, sent with the token request
+    pub verifier: String,
+    /// S256 code challenge, sent with the authorization request
+    pub challenge: String,
+}
+
+impl PkceChallenge {
+    /// Challenge method used by AT Protocol (only S256 is allowed)
+    pub const METHOD: &'static str = "S256";
+
+    /// Generate a new random verifier and challenge
+    pub fn generate() -> Self {
+        // 32 random bytes encode to a 43 character verifier
+        Self::from_verifier(random_token(32))
+    }
+
+    /// Build the challenge for an existing verifier
+    pub fn from_verifier(verifier: impl Into<String>) -> Self {
+        let verifier = verifier.into();
+        let challenge = base64url(&Sha256::digest(verifier.as_bytes()));
+        Self { verifier, challenge }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_s256_challenge() {
+        // BASE64URL(SHA256(verifier)) without padding
+        let pkce = PkceChallenge::from_verifier("dBjftJeZ4CVP-mJ92K9rtJkVzt7iHXnxMQ5mqrJvxnQ");
+        assert_eq!(pkce.challenge, "FuPLPEcYbHfqb1rQBP9nWOJ7F1ZtfgSr6n5aYrBxGBk");
+    }
+
+    #[test]
+    fn test_generate() {
+        let a = PkceChallenge::generate();
+        let b = PkceChallenge::generate();
+        assert_eq!(a.verifier.len(), 43);
+        assert_ne!(a.verifier, b.verifier);
+        assert_eq!(PkceChallenge::from_verifier(a.verifier.clone()), a);
+    }
+}
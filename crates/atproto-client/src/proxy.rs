@@ -0,0 +1,489 @@
+//! Service proxying via the `atproto-proxy` header
+//!
+//! A PDS forwards requests carrying an `atproto-proxy: <did>#<service_id>`
+//! header to the service declared under that id in the DID's document, signing
+//! them with the account's credentials. This is how clients reach chat, labeler
+//! and alternative AppView services through their PDS.
+//!
+//! A [`ServiceProxy`] is one such target. A [`ProxyRouter`] holds named targets
+//! and maps NSID namespaces (e.g. `chat.bsky`) to them, and
+//! [`ServiceProxyMiddleware`] applies the header to matching requests.
+//!
+//! Reference: <https://atproto.com/specs/xrpc#service-proxying>
+//!
+//! # Example
+//!
+//! ```rust
+//! use atproto_client::proxy::{ProxyRouter, ServiceProxy, ServiceProxyMiddleware};
+//! use atproto_client::xrpc::{XrpcClient, XrpcClientConfig};
+//!
+//! let router = ProxyRouter::new()
+//!     .with_target("chat", ServiceProxy::bsky_chat())
+//!     .with_route("chat.bsky.*", "chat");
+//!
+//! let proxy = router.proxy_for("chat.bsky.convo.listConvos").unwrap();
+//! assert_eq!(proxy.to_string(), "did:web:api.bsky.chat#bsky_chat");
+//!
+//! let client = XrpcClient::new(XrpcClientConfig::new("https://bsky.social"))
+//!     .with_middleware(ServiceProxyMiddleware::new(router));
+//! ```
+
+use crate::identity::{IdentityError, IdentityResolver};
+use crate::types::Did;
+use crate::xrpc::{HttpRequest, Middleware, XrpcError};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use thiserror::Error;
+
+/// Name of the service proxying header
+pub const PROXY_HEADER: &str = "atproto-proxy";
+
+/// Service id of Bluesky chat services
+pub const BSKY_CHAT_SERVICE_ID: &str = "bsky_chat";
+
+/// Service id of Bluesky AppView services
+pub const BSKY_APPVIEW_SERVICE_ID: &str = "bsky_appview";
+
+/// Service id of labeler services
+pub const LABELER_SERVICE_ID: &str = "atproto_labeler";
+
+/// Errors that can occur when configuring service proxies
+#[derive(Debug, Error)]
+pub enum ProxyError {
+    /// The service DID is not a valid DID
+    #[error("Invalid service DID: {0}")]
+    InvalidDid(String),
+
+    /// The service id is empty or malformed
+    #[error("Invalid service id: {0}")]
+    InvalidServiceId(String),
+
+    /// The proxy target is not in `did#service_id` form
+    #[error("Invalid proxy target: {0}")]
+    InvalidTarget(String),
+
+    /// The DID document does not declare the service
+    #[error("Service #{service_id} not found in DID document for {did}")]
+    ServiceNotFound {
+        /// Service DID
+        did: String,
+        /// Service id that was looked up
+        service_id: String,
+    },
+
+    /// The DID document could not be resolved
+    #[error("Identity error: {0}")]
+    Identity(#[from] IdentityError),
+}
+
+/// Result type for proxy operations
+pub type Result<T> = std::result::Result<T, ProxyError>;
+
+/// A service proxy target (`did#service_id`)
+///
+/// Serializes as the `did#service_id` string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ServiceProxy {
+    did: Did,
+    service_id: String,
+}
+
+impl ServiceProxy {
+    /// Create a proxy target, validating the DID and service id
+    ///
+    /// # Arguments
+    ///
+    /// * `did` - DID of the service (e.g., "did:web:api.bsky.chat")
+    /// * `service_id` - Service id without the leading '#' (e.g., "bsky_chat")
+    pub fn new(did: &str, service_id: &str) -> Result<Self> {
+        let did = Did::new(did).map_err(|e| ProxyError::InvalidDid(e.to_string()))?;
+
+        let valid_id = !service_id.is_empty()
+            && service_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'));
+        if !valid_id {
+            return Err(ProxyError::InvalidServiceId(service_id.to_string()));
+        }
+
+        Ok(Self { did, service_id: service_id.to_string() })
+    }
+
+    /// The Bluesky chat service
+    pub fn bsky_chat() -> Self {
+        Self::trusted("did:web:api.bsky.chat", BSKY_CHAT_SERVICE_ID)
+    }
+
+    /// The Bluesky AppView
+    pub fn bsky_appview() -> Self {
+        Self::trusted("did:web:api.bsky.app", BSKY_APPVIEW_SERVICE_ID)
+    }
+
+    /// A labeler, addressed by its DID
+    ///
+    /// # Arguments
+    ///
+    /// * `did` - DID of the labeler
+    pub fn labeler(did: &str) -> Result<Self> {
+        Self::new(did, LABELER_SERVICE_ID)
+    }
+
+    fn trusted(did: &str, service_id: &str) -> Self {
+        Self::new(did, service_id).expect("built-in proxy target is valid")
+    }
+
+    /// Get the service DID
+    pub fn did(&self) -> &Did {
+        &self.did
+    }
+
+    /// Get the service id (without the leading '#')
+    pub fn service_id(&self) -> &str {
+        &self.service_id
+    }
+
+    /// Resolve the service endpoint from the DID document
+    ///
+    /// Fails with [`ProxyError::ServiceNotFound`] if the document does not
+    /// declare the service, which means the PDS would reject proxied requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `resolver` - Identity resolver used to fetch the DID document
+    pub async fn resolve(&self, resolver: &IdentityResolver) -> Result<String> {
+        let doc = resolver.resolve_did(&self.did).await?;
+        doc.service_by_id(&self.service_id)
+            .map(|service| service.service_endpoint.trim_end_matches('/').to_string())
+            .ok_or_else(|| ProxyError::ServiceNotFound {
+                did: self.did.to_string(),
+                service_id: self.service_id.clone(),
+            })
+    }
+}
+
+impl fmt::Display for ServiceProxy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}#{}", self.did, self.service_id)
+    }
+}
+
+impl FromStr for ServiceProxy {
+    type Err = ProxyError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (did, service_id) = s
+            .split_once('#')
+            .ok_or_else(|| ProxyError::InvalidTarget(s.to_string()))?;
+        Self::new(did, service_id)
+    }
+}
+
+impl TryFrom<String> for ServiceProxy {
+    type Error = ProxyError;
+
+    fn try_from(s: String) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl From<ServiceProxy> for String {
+    fn from(proxy: ServiceProxy) -> Self {
+        proxy.to_string()
+    }
+}
+
+/// Named proxy targets and the NSID namespaces routed to them
+///
+/// Namespaces match whole NSID segments, so `chat.bsky` (or `chat.bsky.*`)
+/// matches `chat.bsky.convo.listConvos` but not `chat.bskyx.foo`. When several
+/// namespaces match, the longest wins.
+///
+/// Routers are serializable so they can be stored with an account.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProxyRouter {
+    targets: HashMap<String, ServiceProxy>,
+    routes: Vec<(String, String)>,
+}
+
+impl ProxyRouter {
+    /// Create an empty router
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a router sending `chat.bsky.*` to Bluesky chat
+    pub fn bluesky() -> Self {
+        Self::new()
+            .with_target("bsky_chat", ServiceProxy::bsky_chat())
+            .with_route("chat.bsky", "bsky_chat")
+    }
+
+    /// Add a named target
+    pub fn with_target(mut self, name: impl Into<String>, proxy: ServiceProxy) -> Self {
+        self.set_target(name, proxy);
+        self
+    }
+
+    /// Route an NSID namespace to a named target
+    pub fn with_route(mut self, namespace: &str, name: impl Into<String>) -> Self {
+        self.set_route(namespace, name);
+        self
+    }
+
+    /// Add or replace a named target
+    pub fn set_target(&mut self, name: impl Into<String>, proxy: ServiceProxy) {
+        self.targets.insert(name.into(), proxy);
+    }
+
+    /// Remove a named target and the routes pointing at it
+    pub fn remove_target(&mut self, name: &str) -> Option<ServiceProxy> {
+        self.routes.retain(|(_, target)| target != name);
+        self.targets.remove(name)
+    }
+
+    /// Get a named target
+    pub fn target(&self, name: &str) -> Option<&ServiceProxy> {
+        self.targets.get(name)
+    }
+
+    /// Route an NSID namespace to a named target, replacing any existing route
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace` - NSID prefix, optionally ending in ".*" (e.g., "chat.bsky.*")
+    /// * `name` - Name of the target
+    pub fn set_route(&mut self, namespace: &str, name: impl Into<String>) {
+        let namespace = normalize_namespace(namespace);
+        self.routes.retain(|(existing, _)| *existing != namespace);
+        self.routes.push((namespace, name.into()));
+    }
+
+    /// Remove the route for an NSID namespace
+    pub fn remove_route(&mut self, namespace: &str) {
+        let namespace = normalize_namespace(namespace);
+        self.routes.retain(|(existing, _)| *existing != namespace);
+    }
+
+    /// Get the target requests for an NSID should be proxied to
+    pub fn proxy_for(&self, nsid: &str) -> Option<&ServiceProxy> {
+        self.routes
+            .iter()
+            .filter(|(namespace, _)| {
+                nsid == namespace
+                    || (nsid.starts_with(namespace.as_str())
+                        && nsid.as_bytes().get(namespace.len()) == Some(&b'.'))
+            })
+            .max_by_key(|(namespace, _)| namespace.len())
+            .and_then(|(_, name)| self.targets.get(name))
+    }
+}
+
+fn normalize_namespace(namespace: &str) -> String {
+    namespace
+        .trim()
+        .trim_end_matches(".*")
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// Middleware setting the `atproto-proxy` header from a [`ProxyRouter`]
+///
+/// Requests that already carry the header are left alone, so callers can
+/// override the route for a single request.
+#[derive(Debug, Clone, Default)]
+pub struct ServiceProxyMiddleware {
+    router: Arc<RwLock<ProxyRouter>>,
+}
+
+impl ServiceProxyMiddleware {
+    /// Create a middleware with its own router
+    pub fn new(router: ProxyRouter) -> Self {
+        Self { router: Arc::new(RwLock::new(router)) }
+    }
+
+    /// Create a middleware sharing a router, so later changes apply to it
+    pub fn shared(router: Arc<RwLock<ProxyRouter>>) -> Self {
+        Self { router }
+    }
+
+    /// Get the router
+    pub fn router(&self) -> &Arc<RwLock<ProxyRouter>> {
+        &self.router
+    }
+}
+
+#[async_trait]
+impl Middleware for ServiceProxyMiddleware {
+    async fn before_request(
+        &self,
+        request: &mut HttpRequest,
+    ) -> std::result::Result<(), XrpcError> {
+        if request.header(PROXY_HEADER).is_some() {
+            return Ok(());
+        }
+
+        let proxy = self
+            .router
+            .read()
+            .unwrap()
+            .proxy_for(&request.nsid)
+            .cloned();
+        if let Some(proxy) = proxy {
+            request.set_header(PROXY_HEADER, proxy.to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identity::{DidDocument, DidResolver, IdentityResolverConfig};
+    use crate::transport::MockTransport;
+    use crate::xrpc::{XrpcClient, XrpcClientConfig, XrpcRequest};
+
+    struct StaticDidResolver(DidDocument);
+
+    #[async_trait]
+    impl DidResolver for StaticDidResolver {
+        async fn resolve_did(&self, _did: &Did) -> crate::identity::Result<DidDocument> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn chat_resolver() -> IdentityResolver {
+        let doc: DidDocument = serde_json::from_value(serde_json::json!({
+            "id": "did:web:api.bsky.chat",
+            "service": [{
+                "id": "#bsky_chat",
+                "type": "BskyChatService",
+                "serviceEndpoint": "https://api.bsky.chat/"
+            }]
+        }))
+        .unwrap();
+        IdentityResolver::new(IdentityResolverConfig::new())
+            .with_did_resolver(Arc::new(StaticDidResolver(doc)))
+    }
+
+    #[test]
+    fn test_service_proxy_parsing_and_validation() {
+        let proxy: ServiceProxy = "did:web:api.bsky.chat#bsky_chat".parse().unwrap();
+        assert_eq!(proxy, ServiceProxy::bsky_chat());
+        assert_eq!(proxy.did().as_str(), "did:web:api.bsky.chat");
+        assert_eq!(proxy.service_id(), "bsky_chat");
+
+        let labeler = ServiceProxy::labeler("did:plc:ar7c4by46qjdydhdevvrndac").unwrap();
+        assert_eq!(labeler.to_string(), "did:plc:ar7c4by46qjdydhdevvrndac#atproto_labeler");
+
+        assert!(matches!(
+            "did:web:api.bsky.chat".parse::<ServiceProxy>(),
+            Err(ProxyError::InvalidTarget(_))
+        ));
+        assert!(matches!(ServiceProxy::new("not-a-did", "svc"), Err(ProxyError::InvalidDid(_))));
+        assert!(matches!(
+            ServiceProxy::new("did:web:example.com", ""),
+            Err(ProxyError::InvalidServiceId(_))
+        ));
+        assert!(matches!(
+            ServiceProxy::new("did:web:example.com", "bad id"),
+            Err(ProxyError::InvalidServiceId(_))
+        ));
+    }
+
+    #[test]
+    fn test_router_matches_longest_namespace() {
+        let appview = ServiceProxy::new("did:web:appview.example.com", "bsky_appview").unwrap();
+        let mut router = ProxyRouter::new()
+            .with_target("chat", ServiceProxy::bsky_chat())
+            .with_target("appview", appview.clone())
+            .with_target("bsky", ServiceProxy::bsky_appview())
+            .with_route("chat.bsky.*", "chat")
+            .with_route("app.bsky", "bsky")
+            .with_route("app.bsky.feed.", "appview");
+
+        assert_eq!(
+            router.proxy_for("chat.bsky.convo.listConvos"),
+            Some(&ServiceProxy::bsky_chat())
+        );
+        assert_eq!(router.proxy_for("app.bsky.feed.getTimeline"), Some(&appview));
+        assert_eq!(
+            router.proxy_for("app.bsky.actor.getProfile"),
+            Some(&ServiceProxy::bsky_appview())
+        );
+        assert_eq!(router.proxy_for("chat.bskyx.convo.listConvos"), None);
+        assert_eq!(router.proxy_for("com.atproto.repo.createRecord"), None);
+
+        router.remove_target("appview");
+        assert_eq!(
+            router.proxy_for("app.bsky.feed.getTimeline"),
+            Some(&ServiceProxy::bsky_appview())
+        );
+
+        router.remove_route("chat.bsky");
+        assert_eq!(router.proxy_for("chat.bsky.convo.listConvos"), None);
+    }
+
+    #[test]
+    fn test_router_serialization() {
+        let router = ProxyRouter::bluesky()
+            .with_target("labeler", ServiceProxy::labeler("did:plc:labeler").unwrap())
+            .with_route("com.example.*", "labeler");
+
+        let json = serde_json::to_value(&router).unwrap();
+        assert_eq!(json["targets"]["bsky_chat"], "did:web:api.bsky.chat#bsky_chat");
+        let restored: ProxyRouter = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, router);
+
+        let invalid = serde_json::json!({"targets": {"chat": "not-a-target"}});
+        assert!(serde_json::from_value::<ProxyRouter>(invalid).is_err());
+        let empty: ProxyRouter = serde_json::from_str("{}").unwrap();
+        assert_eq!(empty, ProxyRouter::new());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_checks_did_document() {
+        let resolver = chat_resolver();
+
+        let endpoint = ServiceProxy::bsky_chat().resolve(&resolver).await.unwrap();
+        assert_eq!(endpoint, "https://api.bsky.chat");
+
+        let missing = ServiceProxy::new("did:web:api.bsky.chat", "other").unwrap();
+        assert!(matches!(
+            missing.resolve(&resolver).await,
+            Err(ProxyError::ServiceNotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_middleware_sets_header_unless_present() {
+        let transport = Arc::new(MockTransport::new().with_json(
+            "chat.bsky.convo.listConvos",
+            200,
+            serde_json::json!({"convos": []}),
+        ));
+        let router = ProxyRouter::new()
+            .with_target("chat", ServiceProxy::bsky_chat())
+            .with_route("chat.bsky", "chat");
+        let client = XrpcClient::new(
+            XrpcClientConfig::new("https://pds.example.com").with_transport(transport.clone()),
+        )
+        .with_middleware(ServiceProxyMiddleware::new(router));
+
+        let request = XrpcRequest::query("chat.bsky.convo.listConvos");
+        client.query::<serde_json::Value>(request).await.unwrap();
+
+        let request = XrpcRequest::query("chat.bsky.convo.listConvos")
+            .header("Atproto-Proxy", "did:web:chat.example.com#bsky_chat");
+        client.query::<serde_json::Value>(request).await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].header(PROXY_HEADER), Some("did:web:api.bsky.chat#bsky_chat"));
+        assert_eq!(requests[1].header(PROXY_HEADER), Some("did:web:chat.example.com#bsky_chat"));
+    }
+}
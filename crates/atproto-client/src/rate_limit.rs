@@ -0,0 +1,694 @@
+//! Rate limit tracking and request scheduling
+//!
+//! AT Protocol services report rate limits with `RateLimit-Limit`,
+//! `RateLimit-Remaining`, `RateLimit-Reset` and `RateLimit-Policy` headers, and
+//! rejected requests carry a 429 status with an optional `Retry-After` header.
+//!
+//! [`XrpcClient`](crate::xrpc::XrpcClient) feeds every response through a
+//! [`RateLimiter`], which tracks a budget per host and per NSID. Before a
+//! request is sent, the limiter delays it when its budget is nearly spent, and
+//! fails it with a `RateLimitExceeded` error if the wait would be longer than
+//! [`RateLimitConfig::max_wait`].
+//!
+//! Bulk operations can read the current budget to pace themselves or stop
+//! early with partial progress.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use atproto_client::xrpc::{XrpcClient, XrpcClientConfig};
+//!
+//! let client = XrpcClient::new(XrpcClientConfig::new("https://bsky.social"));
+//!
+//! if let Some(budget) = client.rate_limit_budget("com.atproto.repo.createRecord") {
+//!     println!("{} of {} requests left", budget.remaining, budget.limit);
+//! }
+//! let delay = client.rate_limit_delay("com.atproto.repo.createRecord");
+//! println!("Next write would wait {:?}", delay);
+//! ```
+
+use crate::xrpc::{HttpRequest, HttpResponse, XrpcError};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::time::Instant;
+
+/// `RateLimit-Reset` values at or above this are Unix timestamps, smaller
+/// values are seconds from now
+const RESET_EPOCH_THRESHOLD: u64 = 1_000_000_000;
+
+/// Error code of requests rejected locally because the budget is spent
+pub const RATE_LIMIT_EXCEEDED: &str = "RateLimitExceeded";
+
+/// Configuration for rate limit scheduling
+#[derive(Debug, Clone)]
+pub struct RateLimitConfig {
+    /// Whether requests are delayed according to tracked budgets
+    pub enabled: bool,
+    /// Fraction of a budget held in reserve; once fewer requests than this
+    /// remain, requests are spread evenly over the rest of the window
+    pub reserve: f64,
+    /// Longest a request may be delayed before it is rejected instead
+    pub max_wait: Duration,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            reserve: 0.05,
+            max_wait: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RateLimitConfig {
+    /// Create a configuration with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a configuration that only tracks budgets and never delays
+    pub fn disabled() -> Self {
+        Self { enabled: false, ..Default::default() }
+    }
+
+    /// Set the reserve fraction (clamped to 0.0..=1.0)
+    pub fn with_reserve(mut self, reserve: f64) -> Self {
+        self.reserve = reserve.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the maximum wait before a request is rejected
+    pub fn with_max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+}
+
+/// Rate limit headers parsed from a response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Requests allowed in the window
+    pub limit: u32,
+    /// Requests left in the window
+    pub remaining: u32,
+    /// Time until the window resets
+    pub reset_after: Option<Duration>,
+    /// Raw `RateLimit-Policy` value (e.g., "3000;w=300")
+    pub policy: Option<String>,
+}
+
+impl RateLimit {
+    /// Parse rate limit headers, or `None` if limit or remaining is missing
+    pub fn from_headers(headers: &HashMap<String, String>) -> Option<Self> {
+        Self::from_headers_at(headers, SystemTime::now())
+    }
+
+    fn from_headers_at(headers: &HashMap<String, String>, now: SystemTime) -> Option<Self> {
+        let limit = find_header(headers, "ratelimit-limit")?
+            .trim()
+            .parse()
+            .ok()?;
+        let remaining = find_header(headers, "ratelimit-remaining")?
+            .trim()
+            .parse()
+            .ok()?;
+        let reset_after = find_header(headers, "ratelimit-reset")
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .map(|reset| {
+                if reset >= RESET_EPOCH_THRESHOLD {
+                    let now_secs = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+                    Duration::from_secs(reset.saturating_sub(now_secs))
+                } else {
+                    Duration::from_secs(reset)
+                }
+            });
+        let policy = find_header(headers, "ratelimit-policy").map(str::to_string);
+
+        Some(Self { limit, remaining, reset_after, policy })
+    }
+}
+
+/// Parse a `Retry-After` value (delay in seconds or an HTTP date)
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    parse_retry_after_at(value, SystemTime::now())
+}
+
+fn parse_retry_after_at(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let at = UNIX_EPOCH + Duration::from_secs(date.timestamp().max(0) as u64);
+    Some(at.duration_since(now).unwrap_or_default())
+}
+
+/// Current rate limit budget for a host or NSID
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitBudget {
+    /// Requests allowed in the window
+    pub limit: u32,
+    /// Requests left in the window, including ones started since the last response
+    pub remaining: u32,
+    /// When the window resets
+    pub reset_at: Option<Instant>,
+    /// No requests should be sent before this time (from `Retry-After`)
+    pub blocked_until: Option<Instant>,
+}
+
+impl RateLimitBudget {
+    fn from_rate_limit(rate_limit: &RateLimit, now: Instant) -> Self {
+        Self {
+            limit: rate_limit.limit,
+            remaining: rate_limit.remaining,
+            reset_at: rate_limit.reset_after.map(|after| now + after),
+            blocked_until: None,
+        }
+    }
+
+    /// Time until the window resets
+    pub fn time_until_reset(&self) -> Option<Duration> {
+        self.reset_at
+            .map(|reset| reset.saturating_duration_since(Instant::now()))
+    }
+
+    /// Check if no requests are left in the window
+    pub fn is_exhausted(&self) -> bool {
+        !self.is_stale(Instant::now()) && self.remaining == 0
+    }
+
+    /// A budget whose window has reset no longer says anything about the future
+    fn is_stale(&self, now: Instant) -> bool {
+        let reset = self.reset_at.map(|reset| reset <= now).unwrap_or(false);
+        let unblocked = self.blocked_until.map(|until| until <= now).unwrap_or(true);
+        reset && unblocked
+    }
+
+    /// Delay to impose on the next request
+    fn delay(&self, reserve: f64, now: Instant) -> Duration {
+        if self.is_stale(now) {
+            return Duration::ZERO;
+        }
+
+        let blocked = self
+            .blocked_until
+            .map(|until| until.saturating_duration_since(now))
+            .unwrap_or_default();
+        let until_reset = self
+            .reset_at
+            .map(|reset| reset.saturating_duration_since(now))
+            .unwrap_or_default();
+
+        let reserved = ((self.limit as f64 * reserve).ceil() as u32).max(1);
+        let paced = if self.remaining == 0 {
+            until_reset
+        } else if self.remaining <= reserved {
+            // Spread the remaining requests evenly over the rest of the window
+            until_reset / (self.remaining + 1)
+        } else {
+            Duration::ZERO
+        };
+
+        blocked.max(paced)
+    }
+}
+
+/// Host budget, with the NSIDs whose responses reported it
+#[derive(Debug, Clone)]
+struct HostBudget {
+    budget: RateLimitBudget,
+    sources: HashSet<String>,
+}
+
+impl HostBudget {
+    /// Only a limit reported by more than one method is known to be host-wide
+    fn is_shared(&self) -> bool {
+        self.sources.len() > 1
+    }
+}
+
+#[derive(Debug, Default)]
+struct Budgets {
+    hosts: HashMap<String, HostBudget>,
+    methods: HashMap<(String, String), RateLimitBudget>,
+}
+
+impl Budgets {
+    fn host(&self, host: &str) -> Option<&RateLimitBudget> {
+        self.hosts
+            .get(host)
+            .filter(|host| host.is_shared())
+            .map(|host| &host.budget)
+    }
+
+    fn method(&self, key: &RequestKey) -> Option<&RateLimitBudget> {
+        self.methods.get(&(key.host.clone(), key.nsid.clone()))
+    }
+}
+
+/// Host and NSID a request is accounted against
+#[derive(Debug, Clone)]
+pub(crate) struct RequestKey {
+    host: String,
+    nsid: String,
+}
+
+/// Tracks rate limit budgets per host and per NSID
+///
+/// Response headers always update the NSID's budget. Services apply a broad
+/// host-wide limit alongside stricter per-method limits and report whichever
+/// applies, so a limit only counts as the host budget once responses from two
+/// different methods have reported it.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    budgets: Mutex<Budgets>,
+}
+
+impl RateLimiter {
+    /// Create a rate limiter
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self { config, budgets: Mutex::new(Budgets::default()) }
+    }
+
+    /// Get the configuration
+    pub fn config(&self) -> &RateLimitConfig {
+        &self.config
+    }
+
+    /// Get the budget shared by all requests to a host
+    ///
+    /// # Arguments
+    ///
+    /// * `service_url` - URL of the service (only the host and port are used)
+    pub fn host_budget(&self, service_url: &str) -> Option<RateLimitBudget> {
+        self.budgets
+            .lock()
+            .unwrap()
+            .host(&host_of(service_url))
+            .cloned()
+    }
+
+    /// Get the budget for one XRPC method on a host
+    ///
+    /// # Arguments
+    ///
+    /// * `service_url` - URL of the service (only the host and port are used)
+    /// * `nsid` - NSID of the XRPC method
+    pub fn method_budget(&self, service_url: &str, nsid: &str) -> Option<RateLimitBudget> {
+        let key = RequestKey { host: host_of(service_url), nsid: nsid.to_string() };
+        self.budgets.lock().unwrap().method(&key).cloned()
+    }
+
+    /// Get the delay the next request to an XRPC method would get
+    ///
+    /// # Arguments
+    ///
+    /// * `service_url` - URL of the service (only the host and port are used)
+    /// * `nsid` - NSID of the XRPC method
+    pub fn delay_for(&self, service_url: &str, nsid: &str) -> Duration {
+        let key = RequestKey { host: host_of(service_url), nsid: nsid.to_string() };
+        let budgets = self.budgets.lock().unwrap();
+        self.delay_locked(&budgets, &key, Instant::now())
+    }
+
+    /// Forget all tracked budgets
+    pub fn clear(&self) {
+        let mut budgets = self.budgets.lock().unwrap();
+        budgets.hosts.clear();
+        budgets.methods.clear();
+    }
+
+    fn delay_locked(&self, budgets: &Budgets, key: &RequestKey, now: Instant) -> Duration {
+        [budgets.host(&key.host), budgets.method(key)]
+            .into_iter()
+            .flatten()
+            .map(|budget| budget.delay(self.config.reserve, now))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Wait until a request may be sent, and count it against its budgets
+    pub(crate) async fn acquire(&self, request: &HttpRequest) -> Result<RequestKey, XrpcError> {
+        let key = RequestKey {
+            host: host_of(&request.url),
+            nsid: request.nsid.clone(),
+        };
+        if !self.config.enabled {
+            return Ok(key);
+        }
+
+        let delay = {
+            let now = Instant::now();
+            let mut budgets = self.budgets.lock().unwrap();
+            let delay = self.delay_locked(&budgets, &key, now);
+            if delay > self.config.max_wait {
+                return Err(XrpcError::new(
+                    429,
+                    RATE_LIMIT_EXCEEDED,
+                    format!(
+                        "Rate limit for {} on {} exhausted, retry in {}s",
+                        key.nsid,
+                        key.host,
+                        delay.as_secs()
+                    ),
+                ));
+            }
+
+            // Reserve a slot now so concurrent requests see it
+            let Budgets { hosts, methods } = &mut *budgets;
+            let host = hosts.get_mut(&key.host).map(|host| &mut host.budget);
+            let method = methods.get_mut(&(key.host.clone(), key.nsid.clone()));
+            for budget in [host, method].into_iter().flatten() {
+                if !budget.is_stale(now) {
+                    budget.remaining = budget.remaining.saturating_sub(1);
+                }
+            }
+            delay
+        };
+
+        if !delay.is_zero() {
+            tracing::debug!(
+                nsid = %key.nsid,
+                host = %key.host,
+                delay_ms = delay.as_millis() as u64,
+                "delaying request for rate limit"
+            );
+            tokio::time::sleep(delay).await;
+        }
+
+        Ok(key)
+    }
+
+    /// Update budgets from a response
+    pub(crate) fn record(&self, key: &RequestKey, response: &HttpResponse) {
+        let now = Instant::now();
+        let rate_limit = RateLimit::from_headers(&response.headers);
+        let mut budgets = self.budgets.lock().unwrap();
+        let method_key = (key.host.clone(), key.nsid.clone());
+
+        if let Some(rate_limit) = &rate_limit {
+            let budget = RateLimitBudget::from_rate_limit(rate_limit, now);
+
+            match budgets.hosts.get_mut(&key.host) {
+                Some(host) if host.budget.limit == rate_limit.limit => {
+                    host.budget = budget.clone();
+                    host.sources.insert(key.nsid.clone());
+                }
+                Some(host)
+                    if host.budget.limit > rate_limit.limit && !host.budget.is_stale(now) => {}
+                _ => {
+                    let sources = HashSet::from([key.nsid.clone()]);
+                    budgets
+                        .hosts
+                        .insert(key.host.clone(), HostBudget { budget: budget.clone(), sources });
+                }
+            }
+
+            budgets.methods.insert(method_key.clone(), budget);
+        }
+
+        if response.status == 429 {
+            let retry_after = response
+                .header("retry-after")
+                .and_then(parse_retry_after)
+                .or_else(|| rate_limit.as_ref().and_then(|limit| limit.reset_after));
+
+            if let Some(retry_after) = retry_after {
+                let until = now + retry_after;
+                let budget = budgets
+                    .methods
+                    .entry(method_key)
+                    .or_insert(RateLimitBudget {
+                        limit: 0,
+                        remaining: 0,
+                        reset_at: Some(until),
+                        blocked_until: None,
+                    });
+                budget.blocked_until = Some(until);
+            }
+        }
+    }
+}
+
+/// Host and port of a URL, used to key budgets
+fn host_of(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) => match (parsed.host_str(), parsed.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            _ => url.to_string(),
+        },
+        Err(_) => url.to_string(),
+    }
+}
+
+fn find_header<'a>(headers: &'a HashMap<String, String>, name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xrpc::HttpMethod;
+
+    fn headers(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn request(nsid: &str) -> HttpRequest {
+        HttpRequest {
+            method: HttpMethod::Post,
+            nsid: nsid.to_string(),
+            url: format!("https://pds.example.com/xrpc/{}", nsid),
+            params: Vec::new(),
+            headers: HashMap::new(),
+            body: None,
+            stream: None,
+        }
+    }
+
+    fn response(status: u16, pairs: &[(&str, &str)]) -> HttpResponse {
+        HttpResponse::new(status, headers(pairs), Vec::new())
+    }
+
+    #[test]
+    fn test_parse_rate_limit_headers() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let parsed = RateLimit::from_headers_at(
+            &headers(&[
+                ("RateLimit-Limit", "3000"),
+                ("ratelimit-remaining", "2999"),
+                ("RateLimit-Reset", "1700000300"),
+                ("RateLimit-Policy", "3000;w=300"),
+            ]),
+            now,
+        )
+        .unwrap();
+
+        assert_eq!(parsed.limit, 3000);
+        assert_eq!(parsed.remaining, 2999);
+        assert_eq!(parsed.reset_after, Some(Duration::from_secs(300)));
+        assert_eq!(parsed.policy.as_deref(), Some("3000;w=300"));
+
+        // Delta-seconds reset
+        let parsed = RateLimit::from_headers_at(
+            &headers(&[
+                ("RateLimit-Limit", "10"),
+                ("RateLimit-Remaining", "0"),
+                ("RateLimit-Reset", "42"),
+            ]),
+            now,
+        )
+        .unwrap();
+        assert_eq!(parsed.reset_after, Some(Duration::from_secs(42)));
+
+        assert!(RateLimit::from_headers(&headers(&[("RateLimit-Limit", "10")])).is_none());
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_445_412_480);
+        assert_eq!(parse_retry_after_at("120", now), Some(Duration::from_secs(120)));
+        assert_eq!(
+            parse_retry_after_at("Wed, 21 Oct 2015 07:30:00 GMT", now),
+            Some(Duration::from_secs(1_445_412_600 - 1_445_412_480))
+        );
+        assert_eq!(parse_retry_after_at("soon", now), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_budget_pacing() {
+        let limiter = RateLimiter::default();
+        let key = limiter
+            .acquire(&request("com.atproto.repo.createRecord"))
+            .await
+            .unwrap();
+
+        // Plenty left: no delay
+        limiter.record(
+            &key,
+            &response(
+                200,
+                &[
+                    ("RateLimit-Limit", "100"),
+                    ("RateLimit-Remaining", "50"),
+                    ("RateLimit-Reset", "60"),
+                ],
+            ),
+        );
+        let url = "https://pds.example.com";
+        assert_eq!(limiter.delay_for(url, "com.atproto.repo.createRecord"), Duration::ZERO);
+
+        // Within the reserve: spread over the window
+        limiter.record(
+            &key,
+            &response(
+                200,
+                &[
+                    ("RateLimit-Limit", "100"),
+                    ("RateLimit-Remaining", "3"),
+                    ("RateLimit-Reset", "60"),
+                ],
+            ),
+        );
+        assert_eq!(
+            limiter.delay_for(url, "com.atproto.repo.createRecord"),
+            Duration::from_secs(15)
+        );
+
+        let budget = limiter
+            .method_budget(url, "com.atproto.repo.createRecord")
+            .unwrap();
+        assert_eq!(budget.remaining, 3);
+        assert!(!budget.is_exhausted());
+
+        // Acquiring waits and reserves a slot
+        let started = Instant::now();
+        limiter
+            .acquire(&request("com.atproto.repo.createRecord"))
+            .await
+            .unwrap();
+        assert_eq!(started.elapsed(), Duration::from_secs(15));
+        let budget = limiter
+            .method_budget(url, "com.atproto.repo.createRecord")
+            .unwrap();
+        assert_eq!(budget.remaining, 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_exhausted_budget_rejects_beyond_max_wait() {
+        let limiter =
+            RateLimiter::new(RateLimitConfig::new().with_max_wait(Duration::from_secs(5)));
+        let key = limiter
+            .acquire(&request("com.atproto.server.createSession"))
+            .await
+            .unwrap();
+        limiter.record(
+            &key,
+            &response(
+                429,
+                &[
+                    ("RateLimit-Limit", "30"),
+                    ("RateLimit-Remaining", "0"),
+                    ("RateLimit-Reset", "300"),
+                    ("Retry-After", "300"),
+                ],
+            ),
+        );
+
+        let err = limiter
+            .acquire(&request("com.atproto.server.createSession"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), 429);
+        assert_eq!(err.error(), RATE_LIMIT_EXCEEDED);
+
+        // Once the window resets, requests flow again
+        tokio::time::advance(Duration::from_secs(301)).await;
+        assert!(limiter
+            .acquire(&request("com.atproto.server.createSession"))
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_host_budget_requires_shared_limit() {
+        let limiter = RateLimiter::default();
+        let url = "https://pds.example.com";
+        let global = [
+            ("RateLimit-Limit", "3000"),
+            ("RateLimit-Remaining", "2000"),
+            ("RateLimit-Reset", "300"),
+        ];
+
+        let key = limiter
+            .acquire(&request("app.bsky.feed.getTimeline"))
+            .await
+            .unwrap();
+        limiter.record(&key, &response(200, &global));
+        assert!(limiter.host_budget(url).is_none());
+
+        let key = limiter
+            .acquire(&request("app.bsky.actor.getProfile"))
+            .await
+            .unwrap();
+        limiter.record(&key, &response(200, &global));
+        assert_eq!(limiter.host_budget(url).unwrap().limit, 3000);
+
+        // A strict per-method limit does not throttle the whole host
+        let key = limiter
+            .acquire(&request("com.atproto.server.createSession"))
+            .await
+            .unwrap();
+        limiter.record(
+            &key,
+            &response(
+                200,
+                &[
+                    ("RateLimit-Limit", "30"),
+                    ("RateLimit-Remaining", "1"),
+                    ("RateLimit-Reset", "300"),
+                ],
+            ),
+        );
+
+        assert_eq!(limiter.host_budget(url).unwrap().limit, 3000);
+        assert_eq!(limiter.delay_for(url, "app.bsky.feed.getTimeline"), Duration::ZERO);
+        assert_eq!(
+            limiter.delay_for(url, "com.atproto.server.createSession"),
+            Duration::from_secs(150)
+        );
+
+        // Budgets on other hosts are independent
+        assert!(limiter.host_budget("https://api.bsky.app").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_disabled_limiter_never_delays() {
+        let limiter = RateLimiter::new(RateLimitConfig::disabled());
+        let key = limiter
+            .acquire(&request("com.atproto.repo.createRecord"))
+            .await
+            .unwrap();
+        limiter.record(&key, &response(429, &[("Retry-After", "3600")]));
+
+        assert!(limiter
+            .acquire(&request("com.atproto.repo.createRecord"))
+            .await
+            .is_ok());
+        let budget = limiter
+            .method_budget("https://pds.example.com", "com.atproto.repo.createRecord")
+            .unwrap();
+        assert!(budget.blocked_until.is_some());
+    }
+}
@@ -0,0 +1,287 @@
+//! CAR v1 (Content Addressable aRchive) reading and writing
+//!
+//! A CAR file is a varint-prefixed DAG-CBOR header listing root CIDs,
+//! followed by blocks, each stored as `varint(len) | CID bytes | block bytes`.
+//!
+//! Reference: <https://ipld.io/specs/transport/car/carv1/>
+
+use super::{RepoError, Result};
+use crate::cid::{generate_cid, validate_cid};
+use crate::lexicon::cbor::{decode_record, encode_record};
+use cid::Cid;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Cursor;
+
+/// CAR file header
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CarHeader {
+    /// CAR format version (always 1)
+    pub version: u64,
+    /// Root CIDs (a repository export has one: the commit)
+    pub roots: Vec<Cid>,
+}
+
+/// A parsed CAR file with every block verified against its CID
+///
+/// # Example
+///
+/// ```rust
+/// use atproto_client::repo::CarFile;
+///
+/// let mut car = CarFile::new(Vec::new());
+/// let cid = car.insert(b"block data".to_vec()).unwrap();
+/// car.set_roots(vec![cid]);
+///
+/// let parsed = CarFile::from_bytes(&car.to_bytes().unwrap()).unwrap();
+/// assert_eq!(parsed.root(), Some(&cid));
+/// assert_eq!(parsed.get(&cid), Some(&b"block data"[..]));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CarFile {
+    roots: Vec<Cid>,
+    blocks: HashMap<Cid, Vec<u8>>,
+    /// Block CIDs in file order
+    order: Vec<Cid>,
+}
+
+impl CarFile {
+    /// Create an empty CAR file with the given roots
+    pub fn new(roots: Vec<Cid>) -> Self {
+        Self { roots, blocks: HashMap::new(), order: Vec::new() }
+    }
+
+    /// Parse a CAR v1 file, verifying the CID of every block
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The CAR file contents
+    ///
+    /// # Errors
+    ///
+    /// - `RepoError::InvalidCar` - Malformed header or block framing
+    /// - `RepoError::Cid` - A block CID is not a CIDv1 with SHA-256
+    /// - `RepoError::CidMismatch` - A block's contents don't hash to its CID
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut offset = 0;
+
+        let header_len = read_varint(bytes, &mut offset)?;
+        let header_bytes = take(bytes, &mut offset, header_len)?;
+        let header: CarHeader = decode_record(header_bytes)
+            .map_err(|e| RepoError::InvalidCar(format!("Invalid header: {}", e)))?;
+        if header.version != 1 {
+            return Err(RepoError::InvalidCar(format!(
+                "Unsupported CAR version: {}",
+                header.version
+            )));
+        }
+
+        let mut car = Self::new(header.roots);
+        while offset < bytes.len() {
+            let section_len = read_varint(bytes, &mut offset)?;
+            let section = take(bytes, &mut offset, section_len)?;
+
+            let mut cursor = Cursor::new(section);
+            let cid = Cid::read_bytes(&mut cursor)
+                .map_err(|e| RepoError::InvalidCar(format!("Invalid block CID: {}", e)))?;
+            let data = &section[cursor.position() as usize..];
+
+            verify_block(&cid, data)?;
+            car.push(cid, data.to_vec());
+        }
+
+        Ok(car)
+    }
+
+    /// Serialize as a CAR v1 file, with blocks in insertion order
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let header = CarHeader { version: 1, roots: self.roots.clone() };
+        let header_bytes = encode_record(&header)?;
+
+        let mut out = Vec::new();
+        write_varint(&mut out, header_bytes.len());
+        out.extend_from_slice(&header_bytes);
+
+        for cid in &self.order {
+            let cid_bytes = cid.to_bytes();
+            let data = &self.blocks[cid];
+            write_varint(&mut out, cid_bytes.len() + data.len());
+            out.extend_from_slice(&cid_bytes);
+            out.extend_from_slice(data);
+        }
+
+        Ok(out)
+    }
+
+    /// Add a DAG-CBOR block, returning its CID
+    pub fn insert(&mut self, data: Vec<u8>) -> Result<Cid> {
+        let cid = generate_cid(&data)?;
+        self.push(cid, data);
+        Ok(cid)
+    }
+
+    fn push(&mut self, cid: Cid, data: Vec<u8>) {
+        if self.blocks.insert(cid, data).is_none() {
+            self.order.push(cid);
+        }
+    }
+
+    /// Set the root CIDs
+    pub fn set_roots(&mut self, roots: Vec<Cid>) {
+        self.roots = roots;
+    }
+
+    /// Get the root CIDs
+    pub fn roots(&self) -> &[Cid] {
+        &self.roots
+    }
+
+    /// Get the first root CID
+    pub fn root(&self) -> Option<&Cid> {
+        self.roots.first()
+    }
+
+    /// Get a block by CID
+    pub fn get(&self, cid: &Cid) -> Option<&[u8]> {
+        self.blocks.get(cid).map(Vec::as_slice)
+    }
+
+    /// Get a block by CID, failing if it is not in the file
+    pub fn require(&self, cid: &Cid) -> Result<&[u8]> {
+        self.get(cid).ok_or(RepoError::MissingBlock(*cid))
+    }
+
+    /// Check if a block is in the file
+    pub fn contains(&self, cid: &Cid) -> bool {
+        self.blocks.contains_key(cid)
+    }
+
+    /// Iterate over blocks in file order
+    pub fn blocks(&self) -> impl Iterator<Item = (&Cid, &[u8])> {
+        self.order
+            .iter()
+            .map(move |cid| (cid, self.blocks[cid].as_slice()))
+    }
+
+    /// Number of blocks
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Check if the file has no blocks
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+}
+
+/// Check that a block's contents hash to its CID
+///
+/// Only SHA-256 CIDs are accepted, as used throughout AT Protocol. The codec
+/// is not checked, so raw (blob) blocks verify as well as DAG-CBOR ones.
+pub fn verify_block(cid: &Cid, data: &[u8]) -> Result<()> {
+    validate_cid(cid)?;
+    let actual = generate_cid(data)?;
+    if actual.hash().digest() != cid.hash().digest() {
+        return Err(RepoError::CidMismatch {
+            expected: cid.to_string(),
+            actual: actual.to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn take<'a>(bytes: &'a [u8], offset: &mut usize, len: usize) -> Result<&'a [u8]> {
+    let end = offset
+        .checked_add(len)
+        .filter(|end| *end <= bytes.len())
+        .ok_or_else(|| RepoError::InvalidCar("Unexpected end of file".to_string()))?;
+    let slice = &bytes[*offset..end];
+    *offset = end;
+    Ok(slice)
+}
+
+fn read_varint(bytes: &[u8], offset: &mut usize) -> Result<usize> {
+    let mut value: u64 = 0;
+    for shift in (0..64).step_by(7) {
+        let byte = *bytes
+            .get(*offset)
+            .ok_or_else(|| RepoError::InvalidCar("Unexpected end of file".to_string()))?;
+        *offset += 1;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return usize::try_from(value)
+                .map_err(|_| RepoError::InvalidCar("Section too large".to_string()));
+        }
+    }
+    Err(RepoError::InvalidCar("Varint too long".to_string()))
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_varint_roundtrip() {
+        for value in [0, 1, 127, 128, 300, 16_384, u32::MAX as usize] {
+            let mut out = Vec::new();
+            write_varint(&mut out, value);
+            let mut offset = 0;
+            assert_eq!(read_varint(&out, &mut offset).unwrap(), value);
+            assert_eq!(offset, out.len());
+        }
+
+        let mut offset = 0;
+        assert!(read_varint(&[0x80], &mut offset).is_err());
+    }
+
+    #[test]
+    fn test_car_roundtrip() {
+        let mut car = CarFile::new(Vec::new());
+        let first = car.insert(encode_record(&"first").unwrap()).unwrap();
+        let second = car.insert(encode_record(&"second").unwrap()).unwrap();
+        car.insert(encode_record(&"first").unwrap()).unwrap();
+        car.set_roots(vec![second]);
+
+        let parsed = CarFile::from_bytes(&car.to_bytes().unwrap()).unwrap();
+        assert_eq!(parsed.roots(), &[second]);
+        assert_eq!(parsed.len(), 2);
+        let order: Vec<_> = parsed.blocks().map(|(cid, _)| *cid).collect();
+        assert_eq!(order, vec![first, second]);
+        assert_eq!(decode_record::<String>(parsed.require(&first).unwrap()).unwrap(), "first");
+    }
+
+    #[test]
+    fn test_tampered_block_is_rejected() {
+        let mut car = CarFile::new(Vec::new());
+        let cid = car.insert(encode_record(&"original").unwrap()).unwrap();
+        car.set_roots(vec![cid]);
+
+        let mut bytes = car.to_bytes().unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+
+        let err = CarFile::from_bytes(&bytes).unwrap_err();
+        assert!(
+            matches!(err, RepoError::CidMismatch { expected, .. } if expected == cid.to_string())
+        );
+    }
+
+    #[test]
+    fn test_truncated_file_is_rejected() {
+        let mut car = CarFile::new(Vec::new());
+        car.insert(encode_record(&"block").unwrap()).unwrap();
+        let bytes = car.to_bytes().unwrap();
+
+        let err = CarFile::from_bytes(&bytes[..bytes.len() - 3]).unwrap_err();
+        assert!(matches!(err, RepoError::InvalidCar(_)));
+        assert!(CarFile::from_bytes(&[]).is_err());
+    }
+}
@@ -0,0 +1,407 @@
+//! Repository exports: CAR files, MST traversal and record extraction
+//!
+//! A repository export (e.g. from `com.atproto.sync.getRepo`) is a CAR file
+//! whose root is the signed commit. The commit points at the root of a Merkle
+//! Search Tree mapping `collection/rkey` keys to record CIDs.
+//!
+//! Every block is checked against its CID when the CAR is parsed, and the MST
+//! is checked for key order and shape, so records read from a [`Repository`]
+//! are exactly the ones the commit covers. Verifying the commit signature
+//! against the account's DID document ([`Repository::verify`]) then proves
+//! the records were published by the account.
+//!
+//! Writes to a live repository that must not clobber concurrent edits go
+//! through [`RecordUpdate`] in [`write`].
+//!
+//! Reference: <https://atproto.com/specs/repository>
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use atproto_client::repo::Repository;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Post {
+//!     text: String,
+//! }
+//!
+//! let bytes = std::fs::read("backup.car").unwrap();
+//! let repo = Repository::from_car(&bytes).unwrap();
+//! println!("{} at rev {}", repo.did(), repo.rev());
+//!
+//! for record in repo.list_records("app.bsky.feed.post") {
+//!     let record = record.unwrap();
+//!     let post: Post = record.decode().unwrap();
+//!     println!("{}: {}", record.rkey, post.text);
+//! }
+//! ```
+
+pub mod car;
+pub mod mst;
+pub mod verify;
+pub mod write;
+
+pub use car::{verify_block, CarFile, CarHeader};
+pub use verify::{verify_record_cid, verify_record_proof};
+pub use write::{
+    create_records_paced, ApplyWritesBatch, BatchOutcome, PacedCreates, RecordUpdate, WriteError,
+    WriteOp,
+};
+
+use crate::cid::CidError;
+use crate::crypto::CryptoError;
+use crate::lexicon::cbor::{decode_record, CborError};
+use cid::Cid;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+/// Errors that can occur while reading a repository
+#[derive(Debug, Error)]
+pub enum RepoError {
+    /// Malformed CAR file
+    #[error("Invalid CAR file: {0}")]
+    InvalidCar(String),
+
+    /// A block's contents don't hash to its CID
+    #[error("Block CID mismatch: expected {expected}, got {actual}")]
+    CidMismatch {
+        /// CID the block was stored under
+        expected: String,
+        /// CID of the block's contents
+        actual: String,
+    },
+
+    /// A referenced block is not in the CAR file
+    #[error("Missing block: {0}")]
+    MissingBlock(Cid),
+
+    /// Malformed or unsupported commit
+    #[error("Invalid commit: {0}")]
+    InvalidCommit(String),
+
+    /// Malformed Merkle Search Tree
+    #[error("Invalid MST: {0}")]
+    InvalidMst(String),
+
+    /// The commit is for a different account than expected
+    #[error("Commit DID mismatch: expected {expected}, got {actual}")]
+    DidMismatch {
+        /// DID of the DID document
+        expected: String,
+        /// DID in the commit
+        actual: String,
+    },
+
+    /// The signed tree does not contain the record
+    #[error("Record not found: {0}")]
+    RecordNotFound(String),
+
+    /// The signed tree has a different CID for the record
+    #[error("Record {key} has CID {actual}, expected {expected}")]
+    RecordMismatch {
+        /// The `collection/rkey` key
+        key: String,
+        /// CID that was checked
+        expected: String,
+        /// CID under the signed tree
+        actual: String,
+    },
+
+    /// Invalid key or signature
+    #[error("Signature error: {0}")]
+    Crypto(#[from] CryptoError),
+
+    /// Invalid CID
+    #[error("CID error: {0}")]
+    Cid(#[from] CidError),
+
+    /// Block could not be decoded
+    #[error("CBOR error: {0}")]
+    Cbor(#[from] CborError),
+}
+
+/// Result type for repository operations
+pub type Result<T> = std::result::Result<T, RepoError>;
+
+/// A signed repository commit (version 3)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Commit {
+    /// DID of the repository
+    pub did: String,
+    /// Repository format version
+    pub version: u64,
+    /// CID of the MST root
+    pub data: Cid,
+    /// Revision TID
+    pub rev: String,
+    /// Previous commit (usually null in version 3)
+    pub prev: Option<Cid>,
+    /// Signature over the unsigned commit
+    #[serde(with = "serde_bytes")]
+    pub sig: Vec<u8>,
+}
+
+/// A record extracted from a repository
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoRecord {
+    /// Collection NSID
+    pub collection: String,
+    /// Record key
+    pub rkey: String,
+    /// CID of the record
+    pub cid: Cid,
+    /// DAG-CBOR encoded record
+    pub bytes: Vec<u8>,
+}
+
+impl RepoRecord {
+    /// Decode the record
+    pub fn decode<T>(&self) -> Result<T>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        Ok(decode_record(&self.bytes)?)
+    }
+}
+
+/// A repository read from a CAR export
+///
+/// The MST is walked when the repository is loaded, so every tree node must
+/// be present. Record blocks are only required when read, so exports missing
+/// some records can still be loaded. Record proofs (e.g. from
+/// `com.atproto.sync.getRecord`) only hold the nodes on one key's path; check
+/// them with [`verify_record_proof`] or [`mst::find`] instead.
+#[derive(Debug, Clone)]
+pub struct Repository {
+    car: CarFile,
+    commit_cid: Cid,
+    commit: Commit,
+    /// Record CIDs by `collection/rkey`
+    records: BTreeMap<String, Cid>,
+}
+
+impl Repository {
+    /// Parse and verify a repository CAR export
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The CAR file contents
+    pub fn from_car(bytes: &[u8]) -> Result<Self> {
+        Self::from_car_file(CarFile::from_bytes(bytes)?)
+    }
+
+    /// Load a repository from a parsed CAR file
+    pub fn from_car_file(car: CarFile) -> Result<Self> {
+        let (commit_cid, commit) = read_commit(&car)?;
+        let records = mst::walk(&car, &commit.data)?.into_iter().collect();
+        Ok(Self { car, commit_cid, commit, records })
+    }
+
+    /// Get the repository DID
+    pub fn did(&self) -> &str {
+        &self.commit.did
+    }
+
+    /// Get the revision of the commit
+    pub fn rev(&self) -> &str {
+        &self.commit.rev
+    }
+
+    /// Get the commit
+    pub fn commit(&self) -> &Commit {
+        &self.commit
+    }
+
+    /// Get the CID of the commit
+    pub fn commit_cid(&self) -> &Cid {
+        &self.commit_cid
+    }
+
+    /// Get the underlying CAR file
+    pub fn car(&self) -> &CarFile {
+        &self.car
+    }
+
+    /// Number of records in the repository
+    pub fn record_count(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Get the collections with at least one record, in order
+    pub fn collections(&self) -> Vec<&str> {
+        let mut collections: Vec<&str> = self
+            .records
+            .keys()
+            .filter_map(|key| key.split_once('/').map(|(collection, _)| collection))
+            .collect();
+        collections.dedup();
+        collections
+    }
+
+    /// Get the CID of a record without reading it
+    pub fn record_cid(&self, collection: &str, rkey: &str) -> Option<&Cid> {
+        self.records.get(&format!("{}/{}", collection, rkey))
+    }
+
+    /// Get a record by collection and record key
+    ///
+    /// # Returns
+    ///
+    /// `None` if the repository has no such record
+    ///
+    /// # Errors
+    ///
+    /// - `RepoError::MissingBlock` - The record is in the tree but not in the CAR file
+    pub fn get_record(&self, collection: &str, rkey: &str) -> Result<Option<RepoRecord>> {
+        match self.record_cid(collection, rkey) {
+            Some(cid) => self.load(collection, rkey, cid).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Iterate over the records of a collection in record key order
+    pub fn list_records<'a>(
+        &'a self,
+        collection: &str,
+    ) -> impl Iterator<Item = Result<RepoRecord>> + 'a {
+        let prefix = format!("{}/", collection);
+        self.records
+            .range(prefix.clone()..)
+            .take_while(move |(key, _)| key.starts_with(&prefix))
+            .map(move |(key, cid)| self.load_key(key, cid))
+    }
+
+    /// Iterate over all records in key order
+    pub fn records(&self) -> impl Iterator<Item = Result<RepoRecord>> + '_ {
+        self.records
+            .iter()
+            .map(|(key, cid)| self.load_key(key, cid))
+    }
+
+    fn load_key(&self, key: &str, cid: &Cid) -> Result<RepoRecord> {
+        // Keys were validated as `collection/rkey` by the MST walk
+        let (collection, rkey) = key.split_once('/').unwrap_or((key, ""));
+        self.load(collection, rkey, cid)
+    }
+
+    fn load(&self, collection: &str, rkey: &str, cid: &Cid) -> Result<RepoRecord> {
+        Ok(RepoRecord {
+            collection: collection.to_string(),
+            rkey: rkey.to_string(),
+            cid: *cid,
+            bytes: self.car.require(cid)?.to_vec(),
+        })
+    }
+}
+
+/// Read the commit at the root of a CAR file
+fn read_commit(car: &CarFile) -> Result<(Cid, Commit)> {
+    let commit_cid = *car
+        .root()
+        .ok_or_else(|| RepoError::InvalidCar("No root CID".to_string()))?;
+    let commit: Commit = decode_record(car.require(&commit_cid)?)
+        .map_err(|e| RepoError::InvalidCommit(e.to_string()))?;
+    if commit.version != 3 {
+        return Err(RepoError::InvalidCommit(format!(
+            "Unsupported repository version: {}",
+            commit.version
+        )));
+    }
+    Ok((commit_cid, commit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexicon::cbor::encode_record;
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Post {
+        text: String,
+    }
+
+    fn export(posts: usize, with_records: bool) -> Vec<u8> {
+        let mut car = CarFile::new(Vec::new());
+        let mut entries = Vec::new();
+        let mut record_blocks = CarFile::new(Vec::new());
+        for i in 0..posts {
+            let bytes = encode_record(&Post { text: format!("post {}", i) }).unwrap();
+            let cid = record_blocks.insert(bytes).unwrap();
+            entries.push((format!("app.bsky.feed.post/3k{:04}", i), cid));
+        }
+        let like = record_blocks
+            .insert(encode_record(&json!({"subject": "at://x"})).unwrap())
+            .unwrap();
+        entries.push(("app.bsky.feed.like/3kaaaa".to_string(), like));
+        entries.sort();
+
+        let data = mst::build(&mut car, &entries);
+        let commit = Commit {
+            did: "did:plc:alice".to_string(),
+            version: 3,
+            data,
+            rev: "3kaaaaaaaaaaa".to_string(),
+            prev: None,
+            sig: vec![0; 64],
+        };
+        let commit_cid = car.insert(encode_record(&commit).unwrap()).unwrap();
+        car.set_roots(vec![commit_cid]);
+        if with_records {
+            for (_, block) in record_blocks.blocks() {
+                car.insert(block.to_vec()).unwrap();
+            }
+        }
+        car.to_bytes().unwrap()
+    }
+
+    #[test]
+    fn test_read_repository_export() {
+        let repo = Repository::from_car(&export(40, true)).unwrap();
+        assert_eq!(repo.did(), "did:plc:alice");
+        assert_eq!(repo.rev(), "3kaaaaaaaaaaa");
+        assert_eq!(repo.record_count(), 41);
+        assert_eq!(repo.collections(), vec!["app.bsky.feed.like", "app.bsky.feed.post"]);
+
+        let record = repo
+            .get_record("app.bsky.feed.post", "3k0007")
+            .unwrap()
+            .unwrap();
+        assert_eq!(record.decode::<Post>().unwrap().text, "post 7");
+        assert!(repo
+            .get_record("app.bsky.feed.post", "missing")
+            .unwrap()
+            .is_none());
+
+        let rkeys: Vec<String> = repo
+            .list_records("app.bsky.feed.post")
+            .map(|record| record.unwrap().rkey)
+            .collect();
+        assert_eq!(rkeys.len(), 40);
+        assert_eq!(rkeys[0], "3k0000");
+        assert!(rkeys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(repo.list_records("app.bsky.feed").count(), 0);
+    }
+
+    #[test]
+    fn test_missing_record_blocks() {
+        let repo = Repository::from_car(&export(5, false)).unwrap();
+        assert!(repo.record_cid("app.bsky.feed.post", "3k0001").is_some());
+        assert!(matches!(
+            repo.get_record("app.bsky.feed.post", "3k0001"),
+            Err(RepoError::MissingBlock(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_commit() {
+        let mut car = CarFile::new(Vec::new());
+        let root = car
+            .insert(encode_record(&json!({"did": "did:plc:alice"})).unwrap())
+            .unwrap();
+        car.set_roots(vec![root]);
+        assert!(matches!(Repository::from_car_file(car), Err(RepoError::InvalidCommit(_))));
+    }
+}
@@ -0,0 +1,445 @@
+//! Merkle Search Tree (MST) traversal
+//!
+//! Repository records are indexed by an MST keyed on `collection/rkey`. Each
+//! node holds an optional left subtree (`l`) and sorted entries; each entry
+//! points at a record CID (`v`) and an optional subtree (`t`) of keys between
+//! it and the next entry. Keys are prefix-compressed against the previous
+//! entry in the node (`p` shared bytes, `k` suffix).
+//!
+//! A key's layer is the number of leading zero bit pairs in its SHA-256 hash,
+//! so the tree shape is fully determined by its keys. The walker checks this,
+//! along with key order, so a tree from an untrusted source cannot hide or
+//! reorder records.
+//!
+//! Reference: <https://atproto.com/specs/repository#mst-structure>
+
+use super::{CarFile, RepoError, Result};
+use crate::lexicon::cbor::decode_record;
+use cid::Cid;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// An MST node as stored in a block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeData {
+    /// Subtree of keys before the first entry
+    pub l: Option<Cid>,
+    /// Entries in key order
+    pub e: Vec<TreeEntry>,
+}
+
+/// An entry in an MST node
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TreeEntry {
+    /// Number of bytes shared with the previous entry's key
+    pub p: usize,
+    /// Key suffix after the shared prefix
+    #[serde(with = "serde_bytes")]
+    pub k: Vec<u8>,
+    /// CID of the record
+    pub v: Cid,
+    /// Subtree of keys between this entry and the next
+    pub t: Option<Cid>,
+}
+
+/// Get the MST layer of a key (leading zero bit pairs of its SHA-256 hash)
+///
+/// # Example
+///
+/// ```rust
+/// use atproto_client::repo::mst::key_layer;
+///
+/// // Most keys live on the bottom layer
+/// assert_eq!(key_layer(b"app.bsky.feed.post/3jqfcqzm3fo2j"), 0);
+/// ```
+pub fn key_layer(key: &[u8]) -> u32 {
+    let hash = Sha256::digest(key);
+    let mut layer = 0;
+    for byte in hash {
+        if byte == 0 {
+            layer += 4;
+            continue;
+        }
+        layer += byte.leading_zeros() / 2;
+        break;
+    }
+    layer
+}
+
+/// Walk an MST, returning every `(key, record CID)` pair in key order
+///
+/// Nodes are visited with an explicit stack. Every node below the root sits
+/// exactly one layer below its parent, and the root must hold entries unless
+/// the tree is empty, so the depth is bounded by the highest possible key
+/// layer (128).
+///
+/// # Arguments
+///
+/// * `blocks` - Blocks holding the tree's nodes
+/// * `root` - CID of the root node
+///
+/// # Errors
+///
+/// - `RepoError::MissingBlock` - A node is not in `blocks`
+/// - `RepoError::InvalidMst` - Keys are out of order, invalid, or on the wrong
+///   layer, or a node has no entries and no place in the tree
+pub fn walk(blocks: &CarFile, root: &Cid) -> Result<Vec<(String, Cid)>> {
+    let mut entries: Vec<(String, Cid)> = Vec::new();
+    let mut stack = vec![Visit::Node(*root, None)];
+
+    while let Some(visit) = stack.pop() {
+        match visit {
+            Visit::Node(cid, layer) => {
+                // Pushed in reverse so they pop in key order
+                for visit in expand_node(blocks, &cid, layer)?.into_iter().rev() {
+                    stack.push(visit);
+                }
+            }
+            Visit::Entry(key, cid) => push_entry(&mut entries, key, cid)?,
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Find the record CID for a key, visiting only the nodes on its path
+///
+/// Only the nodes from the root down to the key are needed, so this works on
+/// the partial trees in record proofs (e.g. from `com.atproto.sync.getRecord`).
+///
+/// # Arguments
+///
+/// * `blocks` - Blocks holding (at least) the nodes on the key's path
+/// * `root` - CID of the root node
+/// * `key` - The `collection/rkey` key
+///
+/// # Returns
+///
+/// The record CID, or `None` if the tree proves the key is absent
+pub fn find(blocks: &CarFile, root: &Cid, key: &str) -> Result<Option<Cid>> {
+    let key = key.as_bytes();
+    let mut cid = *root;
+
+    loop {
+        let node: NodeData = decode_record(blocks.require(&cid)?)?;
+
+        let mut subtree = node.l;
+        let mut previous: Vec<u8> = Vec::new();
+        for entry in &node.e {
+            let entry_key = expand_key(&previous, entry)?;
+            match key.cmp(entry_key.as_slice()) {
+                std::cmp::Ordering::Equal => return Ok(Some(entry.v)),
+                std::cmp::Ordering::Less => break,
+                std::cmp::Ordering::Greater => subtree = entry.t,
+            }
+            previous = entry_key;
+        }
+
+        match subtree {
+            Some(next) => cid = next,
+            None => return Ok(None),
+        }
+    }
+}
+
+/// Pending work while walking an MST
+enum Visit {
+    /// A node, which must be on the given layer unless it is the root
+    Node(Cid, Option<u32>),
+    /// A record entry
+    Entry(Vec<u8>, Cid),
+}
+
+/// Check a node and list its subtrees and entries in key order
+fn expand_node(blocks: &CarFile, cid: &Cid, layer: Option<u32>) -> Result<Vec<Visit>> {
+    let node: NodeData = decode_record(blocks.require(cid)?)?;
+
+    let actual = match (node.e.first(), layer) {
+        (Some(first), _) => {
+            let actual = key_layer(&expand_key(&[], first)?);
+            if layer.is_some_and(|expected| expected != actual) {
+                return Err(RepoError::InvalidMst(format!(
+                    "Node {} is on layer {} but should be on layer {:?}",
+                    cid, actual, layer
+                )));
+            }
+            actual
+        }
+        // An empty tree's root
+        (None, None) if node.l.is_none() => return Ok(Vec::new()),
+        // Intermediate nodes may skip a layer with only a left subtree
+        (None, Some(layer)) if node.l.is_some() => layer,
+        (None, _) => {
+            return Err(RepoError::InvalidMst(format!("Node {} has no entries", cid)));
+        }
+    };
+    let child = |subtree: Cid| match actual {
+        0 => Err(RepoError::InvalidMst(format!(
+            "Node {} on layer 0 has subtree {}",
+            cid, subtree
+        ))),
+        layer => Ok(Visit::Node(subtree, Some(layer - 1))),
+    };
+
+    let mut visits = Vec::with_capacity(node.e.len() * 2 + 1);
+    if let Some(left) = node.l {
+        visits.push(child(left)?);
+    }
+
+    let mut previous: Vec<u8> = Vec::new();
+    for entry in &node.e {
+        let key = expand_key(&previous, entry)?;
+        if key_layer(&key) != actual {
+            return Err(RepoError::InvalidMst(format!(
+                "Key {} is on the wrong layer",
+                String::from_utf8_lossy(&key)
+            )));
+        }
+        visits.push(Visit::Entry(key.clone(), entry.v));
+
+        if let Some(subtree) = entry.t {
+            visits.push(child(subtree)?);
+        }
+        previous = key;
+    }
+
+    Ok(visits)
+}
+
+/// Add a walked entry, checking the key is valid and after the previous one
+fn push_entry(entries: &mut Vec<(String, Cid)>, key: Vec<u8>, cid: Cid) -> Result<()> {
+    let key = String::from_utf8(key)
+        .map_err(|_| RepoError::InvalidMst("Key is not valid UTF-8".to_string()))?;
+    if !is_valid_key(&key) {
+        return Err(RepoError::InvalidMst(format!("Invalid key: {}", key)));
+    }
+    if let Some((last, _)) = entries.last() {
+        if *last >= key {
+            return Err(RepoError::InvalidMst(format!("Key {} is not after {}", key, last)));
+        }
+    }
+    entries.push((key, cid));
+    Ok(())
+}
+
+fn expand_key(previous: &[u8], entry: &TreeEntry) -> Result<Vec<u8>> {
+    let prefix = previous.get(..entry.p).ok_or_else(|| {
+        RepoError::InvalidMst("Key prefix is longer than previous key".to_string())
+    })?;
+    let mut key = prefix.to_vec();
+    key.extend_from_slice(&entry.k);
+    Ok(key)
+}
+
+/// Check a key has the `collection/rkey` shape
+fn is_valid_key(key: &str) -> bool {
+    let Some((collection, rkey)) = key.split_once('/') else {
+        return false;
+    };
+    !collection.is_empty()
+        && !rkey.is_empty()
+        && !rkey.contains('/')
+        && key.len() <= 1024
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "/_:~.-".contains(c))
+}
+
+/// Build a canonical MST from sorted keys, storing nodes in `blocks`
+#[cfg(test)]
+pub(crate) fn build(blocks: &mut CarFile, entries: &[(String, Cid)]) -> Cid {
+    use crate::lexicon::cbor::encode_record;
+
+    fn node(blocks: &mut CarFile, entries: &[(String, Cid)], layer: u32) -> Cid {
+        let mut data = NodeData { l: None, e: Vec::new() };
+        let mut group: Vec<(String, Cid)> = Vec::new();
+        let mut previous: &[u8] = &[];
+
+        let flush = |blocks: &mut CarFile, group: &mut Vec<(String, Cid)>| {
+            (!group.is_empty()).then(|| node(blocks, &std::mem::take(group), layer - 1))
+        };
+        for (key, cid) in entries {
+            if key_layer(key.as_bytes()) < layer {
+                group.push((key.clone(), *cid));
+                continue;
+            }
+            let subtree = flush(blocks, &mut group);
+            match data.e.last_mut() {
+                Some(last) => last.t = subtree,
+                None => data.l = subtree,
+            }
+            let shared = previous
+                .iter()
+                .zip(key.as_bytes())
+                .take_while(|(a, b)| a == b)
+                .count();
+            data.e.push(TreeEntry {
+                p: shared,
+                k: key.as_bytes()[shared..].to_vec(),
+                v: *cid,
+                t: None,
+            });
+            previous = key.as_bytes();
+        }
+        let subtree = flush(blocks, &mut group);
+        match data.e.last_mut() {
+            Some(last) => last.t = subtree,
+            None => data.l = subtree,
+        }
+
+        blocks.insert(encode_record(&data).unwrap()).unwrap()
+    }
+
+    let top = entries
+        .iter()
+        .map(|(key, _)| key_layer(key.as_bytes()))
+        .max()
+        .unwrap_or(0);
+    node(blocks, entries, top)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cid::generate_cid;
+    use crate::lexicon::cbor::encode_record;
+
+    fn entries(count: usize) -> Vec<(String, Cid)> {
+        let mut entries: Vec<_> = (0..count)
+            .map(|i| {
+                let key = format!("app.bsky.feed.post/3k{:06}", i);
+                let cid = generate_cid(key.as_bytes()).unwrap();
+                (key, cid)
+            })
+            .collect();
+        entries.sort();
+        entries
+    }
+
+    #[test]
+    fn test_key_layer() {
+        // Layers are spread roughly 3/4 on layer 0, 3/16 on layer 1, ...
+        let layers: Vec<u32> = entries(200)
+            .iter()
+            .map(|(key, _)| key_layer(key.as_bytes()))
+            .collect();
+        assert!(layers.iter().filter(|l| **l == 0).count() > 100);
+        assert!(layers.iter().any(|l| *l > 0));
+    }
+
+    #[test]
+    fn test_walk_returns_all_keys_in_order() {
+        let expected = entries(200);
+        let mut blocks = CarFile::new(Vec::new());
+        let root = build(&mut blocks, &expected);
+
+        assert_eq!(walk(&blocks, &root).unwrap(), expected);
+        assert!(blocks.len() > 1);
+    }
+
+    #[test]
+    fn test_walk_empty_tree() {
+        let mut blocks = CarFile::new(Vec::new());
+        let root = build(&mut blocks, &[]);
+        assert!(walk(&blocks, &root).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_walk_rejects_misordered_and_misplaced_keys() {
+        let cid = generate_cid(b"record").unwrap();
+        let bottom: Vec<String> = entries(50)
+            .into_iter()
+            .map(|(key, _)| key)
+            .filter(|key| key_layer(key.as_bytes()) == 0)
+            .take(2)
+            .collect();
+
+        // Out of order on one node
+        let mut blocks = CarFile::new(Vec::new());
+        let node = NodeData {
+            l: None,
+            e: vec![
+                TreeEntry {
+                    p: 0,
+                    k: bottom[1].clone().into_bytes(),
+                    v: cid,
+                    t: None,
+                },
+                TreeEntry {
+                    p: 0,
+                    k: bottom[0].clone().into_bytes(),
+                    v: cid,
+                    t: None,
+                },
+            ],
+        };
+        let root = blocks.insert(encode_record(&node).unwrap()).unwrap();
+        assert!(matches!(walk(&blocks, &root), Err(RepoError::InvalidMst(_))));
+
+        // A layer 0 key placed above a subtree
+        let child = blocks
+            .insert(
+                encode_record(&NodeData {
+                    l: None,
+                    e: vec![TreeEntry {
+                        p: 0,
+                        k: bottom[0].clone().into_bytes(),
+                        v: cid,
+                        t: None,
+                    }],
+                })
+                .unwrap(),
+            )
+            .unwrap();
+        let parent = NodeData {
+            l: Some(child),
+            e: vec![TreeEntry {
+                p: 0,
+                k: bottom[1].clone().into_bytes(),
+                v: cid,
+                t: None,
+            }],
+        };
+        let root = blocks.insert(encode_record(&parent).unwrap()).unwrap();
+        assert!(matches!(walk(&blocks, &root), Err(RepoError::InvalidMst(_))));
+    }
+
+    #[test]
+    fn test_find_on_path_only() {
+        let entries = entries(200);
+        let mut blocks = CarFile::new(Vec::new());
+        let root = build(&mut blocks, &entries);
+
+        for (key, cid) in entries.iter().step_by(17) {
+            assert_eq!(find(&blocks, &root, key).unwrap(), Some(*cid));
+        }
+        assert_eq!(find(&blocks, &root, "app.bsky.feed.post/zzz").unwrap(), None);
+        assert_eq!(find(&blocks, &root, "app.bsky.actor.profile/self").unwrap(), None);
+    }
+
+    #[test]
+    fn test_walk_missing_node() {
+        let mut blocks = CarFile::new(Vec::new());
+        let root = build(&mut blocks, &entries(200));
+
+        let mut partial = CarFile::new(Vec::new());
+        partial
+            .insert(blocks.require(&root).unwrap().to_vec())
+            .unwrap();
+        assert!(matches!(walk(&partial, &root), Err(RepoError::MissingBlock(_))));
+    }
+
+    #[test]
+    fn test_walk_rejects_chain_of_empty_nodes() {
+        let mut blocks = CarFile::new(Vec::new());
+        let mut cid = blocks
+            .insert(encode_record(&NodeData { l: None, e: Vec::new() }).unwrap())
+            .unwrap();
+        for _ in 0..50_000 {
+            let node = NodeData { l: Some(cid), e: Vec::new() };
+            cid = blocks.insert(encode_record(&node).unwrap()).unwrap();
+        }
+
+        assert!(matches!(walk(&blocks, &cid), Err(RepoError::InvalidMst(_))));
+    }
+}
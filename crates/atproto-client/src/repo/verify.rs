@@ -0,0 +1,303 @@
+//! Commit signature and record inclusion verification
+//!
+//! A commit is signed by the account's `#atproto` key over the DAG-CBOR
+//! encoding of the commit without its `sig` field. Once the commit is
+//! verified, any record whose key leads to its CID through the commit's MST is
+//! authentic, whichever server supplied it.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use atproto_client::repo::{verify_record_proof, CarFile};
+//! use atproto_client::{Did, IdentityResolver};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     // CAR from com.atproto.sync.getRecord
+//!     let proof = CarFile::from_bytes(&std::fs::read("record.car")?)?;
+//!
+//!     let resolver = IdentityResolver::default();
+//!     let did = Did::new("did:plc:ewvi7nxzyoun6zhxrhs64oiz")?;
+//!     let document = resolver.resolve_did(&did).await?;
+//!
+//!     let record = verify_record_proof(&proof, &document, "app.bsky.feed.post", "3k2a")?;
+//!     println!("Verified record {}", record.cid);
+//!     Ok(())
+//! }
+//! ```
+
+use super::{mst, CarFile, Commit, RepoError, RepoRecord, Repository, Result};
+use crate::crypto::PublicKey;
+use crate::identity::DidDocument;
+use crate::lexicon::cbor::encode_record;
+use cid::Cid;
+use serde::Serialize;
+
+/// The signed fields of a commit
+#[derive(Serialize)]
+struct UnsignedCommit<'a> {
+    did: &'a str,
+    version: u64,
+    data: &'a Cid,
+    rev: &'a str,
+    prev: &'a Option<Cid>,
+}
+
+impl Commit {
+    /// Get the bytes covered by the signature
+    pub fn unsigned_bytes(&self) -> Result<Vec<u8>> {
+        Ok(encode_record(&UnsignedCommit {
+            did: &self.did,
+            version: self.version,
+            data: &self.data,
+            rev: &self.rev,
+            prev: &self.prev,
+        })?)
+    }
+
+    /// Verify the commit signature with a public key
+    pub fn verify_signature(&self, key: &PublicKey) -> Result<()> {
+        key.verify(&self.unsigned_bytes()?, &self.sig)?;
+        Ok(())
+    }
+
+    /// Verify the commit against the account's DID document
+    ///
+    /// Checks that the commit belongs to the document's DID and is signed by
+    /// its `#atproto` verification method.
+    pub fn verify(&self, document: &DidDocument) -> Result<()> {
+        if self.did != document.id {
+            return Err(RepoError::DidMismatch {
+                expected: document.id.clone(),
+                actual: self.did.clone(),
+            });
+        }
+        self.verify_signature(&PublicKey::from_did_document(document)?)
+    }
+}
+
+impl Repository {
+    /// Verify the repository's commit against the account's DID document
+    ///
+    /// Together with the CID and MST checks made while loading, this proves
+    /// every record in the repository was published by the account.
+    pub fn verify(&self, document: &DidDocument) -> Result<()> {
+        self.commit().verify(document)
+    }
+}
+
+/// Verify that a record is included under a signed commit
+///
+/// The CAR's root must be a commit signed by the document's key, and must
+/// contain the MST nodes on the path to the record along with the record
+/// itself, as returned by `com.atproto.sync.getRecord`.
+///
+/// # Arguments
+///
+/// * `car` - The record proof
+/// * `document` - DID document of the repository's account
+/// * `collection` - Collection NSID
+/// * `rkey` - Record key
+///
+/// # Errors
+///
+/// - `RepoError::Crypto` - The signature does not verify
+/// - `RepoError::DidMismatch` - The commit is for another account
+/// - `RepoError::RecordNotFound` - The signed tree does not contain the record
+/// - `RepoError::MissingBlock` - The proof is missing a node or the record
+pub fn verify_record_proof(
+    car: &CarFile,
+    document: &DidDocument,
+    collection: &str,
+    rkey: &str,
+) -> Result<RepoRecord> {
+    let cid = find_verified(car, document, collection, rkey)?;
+    Ok(RepoRecord {
+        collection: collection.to_string(),
+        rkey: rkey.to_string(),
+        cid,
+        bytes: car.require(&cid)?.to_vec(),
+    })
+}
+
+/// Verify that a record CID (e.g. from an AppView response) is the one under
+/// a signed commit
+///
+/// Unlike [`verify_record_proof`], the record block itself is not needed.
+///
+/// # Arguments
+///
+/// * `car` - The record proof
+/// * `document` - DID document of the repository's account
+/// * `collection` - Collection NSID
+/// * `rkey` - Record key
+/// * `cid` - The CID to check
+pub fn verify_record_cid(
+    car: &CarFile,
+    document: &DidDocument,
+    collection: &str,
+    rkey: &str,
+    cid: &Cid,
+) -> Result<()> {
+    let actual = find_verified(car, document, collection, rkey)?;
+    if actual != *cid {
+        return Err(RepoError::RecordMismatch {
+            key: format!("{}/{}", collection, rkey),
+            expected: cid.to_string(),
+            actual: actual.to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn find_verified(
+    car: &CarFile,
+    document: &DidDocument,
+    collection: &str,
+    rkey: &str,
+) -> Result<Cid> {
+    let (_, commit) = super::read_commit(car)?;
+    commit.verify(document)?;
+
+    let key = format!("{}/{}", collection, rkey);
+    mst::find(car, &commit.data, &key)?.ok_or(RepoError::RecordNotFound(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::CryptoError;
+    use crate::lexicon::cbor::decode_record;
+    use k256::ecdsa::signature::Signer;
+    use rand::rngs::OsRng;
+    use serde_json::json;
+
+    struct Fixture {
+        car: CarFile,
+        document: DidDocument,
+        entries: Vec<(String, Cid)>,
+    }
+
+    fn document(did: &str, key: &PublicKey) -> DidDocument {
+        serde_json::from_value(json!({
+            "id": did,
+            "verificationMethod": [{
+                "id": format!("{}#atproto", did),
+                "type": "Multikey",
+                "controller": did,
+                "publicKeyMultibase": key.to_multikey()
+            }]
+        }))
+        .unwrap()
+    }
+
+    fn signed_repo(sign: impl Fn(&[u8]) -> (Vec<u8>, PublicKey)) -> Fixture {
+        let mut car = CarFile::new(Vec::new());
+        let mut entries: Vec<(String, Cid)> = (0..30)
+            .map(|i| {
+                let record = json!({"$type": "app.bsky.feed.post", "text": format!("post {}", i)});
+                let cid = car.insert(encode_record(&record).unwrap()).unwrap();
+                (format!("app.bsky.feed.post/3k{:04}", i), cid)
+            })
+            .collect();
+        entries.sort();
+
+        let mut commit = Commit {
+            did: "did:plc:alice".to_string(),
+            version: 3,
+            data: mst::build(&mut car, &entries),
+            rev: "3kaaaaaaaaaaa".to_string(),
+            prev: None,
+            sig: Vec::new(),
+        };
+        let (sig, key) = sign(&commit.unsigned_bytes().unwrap());
+        commit.sig = sig;
+        let root = car.insert(encode_record(&commit).unwrap()).unwrap();
+        car.set_roots(vec![root]);
+
+        Fixture {
+            car,
+            document: document("did:plc:alice", &key),
+            entries,
+        }
+    }
+
+    fn k256_repo() -> Fixture {
+        let signing_key = k256::ecdsa::SigningKey::random(&mut OsRng);
+        signed_repo(|bytes| {
+            let sig: k256::ecdsa::Signature = signing_key.sign(bytes);
+            (sig.to_bytes().to_vec(), PublicKey::K256(*signing_key.verifying_key()))
+        })
+    }
+
+    #[test]
+    fn test_verify_repository_k256_and_p256() {
+        let fixture = k256_repo();
+        let repo = Repository::from_car_file(fixture.car).unwrap();
+        repo.verify(&fixture.document).unwrap();
+
+        let signing_key = p256::ecdsa::SigningKey::random(&mut OsRng);
+        let fixture = signed_repo(|bytes| {
+            let sig: p256::ecdsa::Signature = signing_key.sign(bytes);
+            let sig = sig.normalize_s().unwrap_or(sig);
+            (sig.to_bytes().to_vec(), PublicKey::P256(*signing_key.verifying_key()))
+        });
+        let repo = Repository::from_car_file(fixture.car).unwrap();
+        repo.verify(&fixture.document).unwrap();
+    }
+
+    #[test]
+    fn test_wrong_key_and_wrong_did_are_rejected() {
+        let fixture = k256_repo();
+        let repo = Repository::from_car_file(fixture.car).unwrap();
+
+        let other = PublicKey::K256(*k256::ecdsa::SigningKey::random(&mut OsRng).verifying_key());
+        assert!(matches!(
+            repo.verify(&document("did:plc:alice", &other)),
+            Err(RepoError::Crypto(CryptoError::VerificationFailed))
+        ));
+
+        let mut document = fixture.document.clone();
+        document.id = "did:plc:mallory".to_string();
+        assert!(matches!(repo.verify(&document), Err(RepoError::DidMismatch { .. })));
+    }
+
+    #[test]
+    fn test_tampered_commit_is_rejected() {
+        let fixture = k256_repo();
+        let root = *fixture.car.root().unwrap();
+        let mut commit: Commit = decode_record(fixture.car.require(&root).unwrap()).unwrap();
+        commit.rev = "3kbbbbbbbbbbb".to_string();
+
+        assert!(matches!(
+            commit.verify(&fixture.document),
+            Err(RepoError::Crypto(CryptoError::VerificationFailed))
+        ));
+    }
+
+    #[test]
+    fn test_record_proofs() {
+        let fixture = k256_repo();
+        let (key, cid) = &fixture.entries[12];
+        let rkey = key.strip_prefix("app.bsky.feed.post/").unwrap();
+
+        let record =
+            verify_record_proof(&fixture.car, &fixture.document, "app.bsky.feed.post", rkey)
+                .unwrap();
+        assert_eq!(record.cid, *cid);
+        let value: serde_json::Value = record.decode().unwrap();
+        assert_eq!(value["$type"], "app.bsky.feed.post");
+
+        verify_record_cid(&fixture.car, &fixture.document, "app.bsky.feed.post", rkey, cid)
+            .unwrap();
+        let (_, other) = &fixture.entries[13];
+        assert!(matches!(
+            verify_record_cid(&fixture.car, &fixture.document, "app.bsky.feed.post", rkey, other),
+            Err(RepoError::RecordMismatch { .. })
+        ));
+        assert!(matches!(
+            verify_record_proof(&fixture.car, &fixture.document, "app.bsky.feed.post", "nope"),
+            Err(RepoError::RecordNotFound(_))
+        ));
+    }
+}
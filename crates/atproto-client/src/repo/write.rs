@@ -0,0 +1,1123 @@
+//! Record writes guarded by compare-and-swap
+//!
+//! `putRecord` replaces whatever is stored under a key, so when two clients
+//! edit the same record the later write silently discards the earlier one.
+//! [`RecordUpdate`] reads the record and its CID, applies an edit, and writes
+//! with `swapRecord` set to that CID. If the record changed in between, the
+//! PDS rejects the write with `InvalidSwap` and the edit is applied again to
+//! the fresh record.
+//!
+//! [`ApplyWritesBatch`] collects creates, updates and deletes and sends them
+//! through `com.atproto.repo.applyWrites`, split into chunks the PDS accepts.
+//! Each chunk is applied atomically; the [`BatchOutcome`] reports which
+//! chunks were applied, which failed and which were never sent.
+//!
+//! [`create_records_paced`] sends one `createRecord` per subject (e.g. bulk
+//! follows, blocks or list members), paced by the client's rate limiter, and
+//! reports which subjects were written when it has to stop.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use atproto_client::repo::write::RecordUpdate;
+//! use atproto_client::xrpc::{XrpcClient, XrpcClientConfig};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = XrpcClient::new(XrpcClientConfig::new("https://bsky.social"));
+//!
+//! let output = RecordUpdate::new("did:plc:abc123", "app.bsky.actor.profile", "self")
+//!     .apply(&client, |current: Option<serde_json::Value>| {
+//!         let mut profile = current
+//!             .unwrap_or_else(|| serde_json::json!({"$type": "app.bsky.actor.profile"}));
+//!         profile["displayName"] = "Alice".into();
+//!         Ok(profile)
+//!     })
+//!     .await?;
+//! println!("Profile is now {}", output.cid);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::api::com::atproto::repo::apply_writes::{self, InputWritesItem, OutputResultsItem};
+use crate::api::com::atproto::repo::{create_record, get_record, put_record};
+use crate::types::Tid;
+use crate::xrpc::{EndpointError, XrpcClient, XrpcError, XrpcRequest};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+use tokio::sync::RwLock;
+
+/// Number of attempts [`RecordUpdate`] makes before giving up
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// Most writes the reference PDS accepts in one `applyWrites` call
+pub const MAX_WRITES_PER_CALL: usize = 200;
+
+/// Errors from guarded record writes
+#[derive(Debug, Error)]
+pub enum WriteError {
+    /// XRPC error
+    #[error("XRPC error: {0}")]
+    Xrpc(#[from] XrpcError),
+
+    /// The record changed between every read and write
+    #[error("Record {uri} changed concurrently; gave up after {attempts} attempts")]
+    Conflict {
+        /// AT URI of the record
+        uri: String,
+        /// Number of writes attempted
+        attempts: u32,
+    },
+
+    /// The edit declined to produce a record
+    #[error("Update rejected: {0}")]
+    Rejected(String),
+
+    /// The server returned a record without a CID to swap against
+    #[error("No CID returned for {0}")]
+    MissingCid(String),
+
+    /// The record could not be converted to or from JSON
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// Some chunks of a batch were not applied
+    #[error("Applied {applied} of {total} writes: {source}")]
+    PartialBatch {
+        /// Writes applied before the failure
+        applied: usize,
+        /// Writes in the batch
+        total: usize,
+        /// Error from the first failed chunk
+        source: XrpcError,
+    },
+}
+
+/// Result type for guarded record writes
+pub type Result<T> = std::result::Result<T, WriteError>;
+
+/// Read-modify-write of a single record
+///
+/// The edit closure receives the current record, or `None` if there is no
+/// record under the key yet, and returns the record to write. It may run
+/// more than once, so it should derive its result from its argument only.
+/// Returning `Err` (e.g., [`WriteError::Rejected`]) stops the update without
+/// writing.
+#[derive(Debug, Clone)]
+pub struct RecordUpdate {
+    /// Handle or DID of the repo
+    repo: String,
+    /// Collection NSID
+    collection: String,
+    /// Record key
+    rkey: String,
+    /// Writes attempted before reporting a conflict
+    max_attempts: u32,
+    /// Lexicon validation requested from the PDS
+    validate: Option<bool>,
+}
+
+impl RecordUpdate {
+    /// Create an update for the record at `repo/collection/rkey`
+    pub fn new(
+        repo: impl Into<String>,
+        collection: impl Into<String>,
+        rkey: impl Into<String>,
+    ) -> Self {
+        Self {
+            repo: repo.into(),
+            collection: collection.into(),
+            rkey: rkey.into(),
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            validate: None,
+        }
+    }
+
+    /// Set how many writes to attempt before returning [`WriteError::Conflict`]
+    ///
+    /// Values below 1 are treated as 1.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Require (`true`) or skip (`false`) Lexicon validation on the PDS
+    pub fn with_validate(mut self, validate: bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+
+    /// AT URI of the record being updated
+    pub fn uri(&self) -> String {
+        format!("at://{}/{}/{}", self.repo, self.collection, self.rkey)
+    }
+
+    /// Read the record, apply `edit`, and write the result
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated client for the PDS hosting the repo
+    /// * `edit` - Produces the new record from the current one
+    ///
+    /// # Returns
+    ///
+    /// The URI and CID of the written record
+    ///
+    /// # Errors
+    ///
+    /// - `WriteError::Conflict` - Every attempt lost a race with another writer
+    /// - `WriteError::Xrpc` - Reading or writing failed
+    /// - Any error returned by `edit`
+    pub async fn apply<T, F>(&self, client: &XrpcClient, mut edit: F) -> Result<put_record::Output>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnMut(Option<T>) -> Result<T>,
+    {
+        for attempt in 1..=self.max_attempts {
+            let (swap_record, current) = match self.fetch(client).await? {
+                Some((cid, value)) => (Some(cid), Some(serde_json::from_value(value)?)),
+                None => (None, None),
+            };
+            let record = serde_json::to_value(edit(current)?)?;
+
+            match self.put(client, record, swap_record).await {
+                Ok(output) => return Ok(output),
+                Err(EndpointError::Declared { kind: put_record::Error::InvalidSwap, .. }) => {
+                    tracing::debug!(
+                        "Swap failed for {} (attempt {} of {})",
+                        self.uri(),
+                        attempt,
+                        self.max_attempts
+                    );
+                }
+                Err(e) => return Err(e.into_xrpc().into()),
+            }
+        }
+
+        Err(WriteError::Conflict { uri: self.uri(), attempts: self.max_attempts })
+    }
+
+    /// Fetch the current record and its CID, or `None` if it doesn't exist
+    async fn fetch(&self, client: &XrpcClient) -> Result<Option<(String, serde_json::Value)>> {
+        let params = get_record::Parameters {
+            cid: None,
+            collection: self.collection.clone(),
+            repo: self.repo.clone(),
+            rkey: self.rkey.clone(),
+        };
+
+        match get_record::get_record(client, &params).await {
+            Ok(output) => {
+                let cid = output.cid.ok_or(WriteError::MissingCid(output.uri))?;
+                Ok(Some((cid, output.value)))
+            }
+            Err(e) if is_record_not_found(&e) => Ok(None),
+            Err(e) => Err(e.into_xrpc().into()),
+        }
+    }
+
+    /// Write the record, swapping against `swap_record`
+    ///
+    /// A `None` swap is sent as an explicit `null`, which asks the PDS to
+    /// fail if a record was created since it was found missing.
+    async fn put(
+        &self,
+        client: &XrpcClient,
+        record: serde_json::Value,
+        swap_record: Option<String>,
+    ) -> std::result::Result<put_record::Output, EndpointError<put_record::Error>> {
+        let mut body = serde_json::json!({
+            "repo": self.repo,
+            "collection": self.collection,
+            "rkey": self.rkey,
+            "record": record,
+            "swapRecord": swap_record,
+        });
+        if let Some(validate) = self.validate {
+            body["validate"] = validate.into();
+        }
+
+        let request = XrpcRequest::procedure(put_record::NSID)
+            .json_body(&body)
+            .map_err(|e| XrpcError::new(0, "SerializeError", e.to_string()))?;
+        client
+            .procedure(request)
+            .await
+            .map(|response| response.data)
+            .map_err(|e| e.classify())
+    }
+}
+
+/// A single write in an [`ApplyWritesBatch`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum WriteOp {
+    /// Create a record
+    Create {
+        /// Collection NSID
+        collection: String,
+        /// Record key
+        rkey: String,
+        /// Record value
+        value: serde_json::Value,
+    },
+    /// Replace an existing record
+    Update {
+        /// Collection NSID
+        collection: String,
+        /// Record key
+        rkey: String,
+        /// Record value
+        value: serde_json::Value,
+    },
+    /// Delete a record
+    Delete {
+        /// Collection NSID
+        collection: String,
+        /// Record key
+        rkey: String,
+    },
+}
+
+impl WriteOp {
+    /// Collection the write targets
+    pub fn collection(&self) -> &str {
+        match self {
+            Self::Create { collection, .. }
+            | Self::Update { collection, .. }
+            | Self::Delete { collection, .. } => collection,
+        }
+    }
+
+    /// Record key the write targets
+    pub fn rkey(&self) -> &str {
+        match self {
+            Self::Create { rkey, .. } | Self::Update { rkey, .. } | Self::Delete { rkey, .. } => {
+                rkey
+            }
+        }
+    }
+
+    /// AT URI of the record in `repo`
+    pub fn uri(&self, repo: &str) -> String {
+        format!("at://{}/{}/{}", repo, self.collection(), self.rkey())
+    }
+
+    fn action(&self) -> WriteAction {
+        match self {
+            Self::Create { .. } => WriteAction::Create,
+            Self::Update { .. } => WriteAction::Update,
+            Self::Delete { .. } => WriteAction::Delete,
+        }
+    }
+
+    fn to_input(&self) -> InputWritesItem {
+        match self.clone() {
+            Self::Create { collection, rkey, value } => {
+                InputWritesItem::Create(Box::new(apply_writes::Create {
+                    collection,
+                    rkey: Some(rkey),
+                    value,
+                }))
+            }
+            Self::Update { collection, rkey, value } => {
+                InputWritesItem::Update(Box::new(apply_writes::Update { collection, rkey, value }))
+            }
+            Self::Delete { collection, rkey } => {
+                InputWritesItem::Delete(Box::new(apply_writes::Delete { collection, rkey }))
+            }
+        }
+    }
+}
+
+/// Kind of write
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteAction {
+    /// Record created
+    Create,
+    /// Record replaced
+    Update,
+    /// Record deleted
+    Delete,
+}
+
+/// Result of an applied write
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WriteResult {
+    /// Kind of write
+    pub action: WriteAction,
+    /// AT URI of the record
+    pub uri: String,
+    /// CID of the written record; `None` for deletes, or when the PDS
+    /// doesn't report per-write results
+    pub cid: Option<String>,
+}
+
+/// What happened to one chunk of a batch
+#[derive(Debug, Clone)]
+pub enum ChunkStatus {
+    /// The chunk was committed
+    Applied(Vec<WriteResult>),
+    /// The PDS rejected the chunk; none of its writes were applied
+    Failed(XrpcError),
+    /// The chunk was not sent because an earlier chunk failed
+    Skipped,
+}
+
+/// One `applyWrites` call of a batch
+#[derive(Debug, Clone)]
+pub struct BatchChunk {
+    /// Index of the chunk's first write in the batch
+    pub offset: usize,
+    /// The chunk's writes
+    pub writes: Vec<WriteOp>,
+    /// What happened to the chunk
+    pub status: ChunkStatus,
+}
+
+/// Outcome of [`ApplyWritesBatch::execute`]
+#[derive(Debug, Clone, Default)]
+pub struct BatchOutcome {
+    /// Chunks in the order they were sent
+    pub chunks: Vec<BatchChunk>,
+}
+
+impl BatchOutcome {
+    /// Whether every chunk was applied
+    pub fn is_complete(&self) -> bool {
+        self.chunks
+            .iter()
+            .all(|chunk| matches!(chunk.status, ChunkStatus::Applied(_)))
+    }
+
+    /// Results of the writes that were applied, in batch order
+    pub fn results(&self) -> impl Iterator<Item = &WriteResult> {
+        self.chunks.iter().flat_map(|chunk| match &chunk.status {
+            ChunkStatus::Applied(results) => results.as_slice(),
+            _ => &[],
+        })
+    }
+
+    /// Results of the creates that were applied
+    pub fn created(&self) -> impl Iterator<Item = &WriteResult> {
+        self.results()
+            .filter(|result| result.action == WriteAction::Create)
+    }
+
+    /// Writes that were not applied, with the error of the chunk that failed
+    ///
+    /// Writes of skipped chunks are paired with `None`.
+    pub fn unapplied(&self) -> impl Iterator<Item = (&WriteOp, Option<&XrpcError>)> {
+        self.chunks
+            .iter()
+            .filter_map(|chunk| match &chunk.status {
+                ChunkStatus::Applied(_) => None,
+                ChunkStatus::Failed(error) => Some((chunk, Some(error))),
+                ChunkStatus::Skipped => Some((chunk, None)),
+            })
+            .flat_map(|(chunk, error)| chunk.writes.iter().map(move |op| (op, error)))
+    }
+
+    /// Error of the first chunk that failed
+    pub fn first_error(&self) -> Option<&XrpcError> {
+        self.chunks.iter().find_map(|chunk| match &chunk.status {
+            ChunkStatus::Failed(error) => Some(error),
+            _ => None,
+        })
+    }
+
+    /// Get the applied results, or an error if any chunk wasn't applied
+    ///
+    /// # Errors
+    ///
+    /// - `WriteError::PartialBatch` - At least one chunk failed
+    pub fn into_result(self) -> Result<Vec<WriteResult>> {
+        let total = self.chunks.iter().map(|chunk| chunk.writes.len()).sum();
+        let Some(source) = self.first_error().cloned() else {
+            return Ok(self.results().cloned().collect());
+        };
+        let applied = self.results().count();
+        Err(WriteError::PartialBatch { applied, total, source })
+    }
+}
+
+/// Batched creates, updates and deletes in one repo
+///
+/// Writes are sent in chunks of at most [`MAX_WRITES_PER_CALL`]. Each chunk
+/// is atomic, but the batch as a whole is not: by default sending stops at
+/// the first failed chunk and the rest are reported as skipped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use atproto_client::repo::write::ApplyWritesBatch;
+/// use atproto_client::xrpc::{XrpcClient, XrpcClientConfig};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = XrpcClient::new(XrpcClientConfig::new("https://bsky.social"));
+///
+/// let mut batch = ApplyWritesBatch::new("did:plc:abc123");
+/// for subject in ["did:plc:alice", "did:plc:bob"] {
+///     batch.create(
+///         "app.bsky.graph.follow",
+///         &serde_json::json!({
+///             "$type": "app.bsky.graph.follow",
+///             "subject": subject,
+///             "createdAt": "2024-01-15T10:00:00Z",
+///         }),
+///     )?;
+/// }
+/// batch.delete("app.bsky.graph.follow", "3kabc");
+///
+/// for created in batch.execute(&client).await.into_result()? {
+///     println!("Followed: {}", created.uri);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ApplyWritesBatch {
+    /// Handle or DID of the repo
+    repo: String,
+    /// Writes in order
+    writes: Vec<WriteOp>,
+    /// Writes per `applyWrites` call
+    chunk_size: usize,
+    /// Lexicon validation requested from the PDS
+    validate: Option<bool>,
+    /// Commit the first chunk must apply on top of
+    swap_commit: Option<String>,
+    /// Keep sending chunks after one fails
+    continue_on_error: bool,
+    /// Timestamp of the last generated record key
+    last_tid: Option<Duration>,
+}
+
+impl ApplyWritesBatch {
+    /// Create an empty batch for `repo`
+    pub fn new(repo: impl Into<String>) -> Self {
+        Self {
+            repo: repo.into(),
+            writes: Vec::new(),
+            chunk_size: MAX_WRITES_PER_CALL,
+            validate: None,
+            swap_commit: None,
+            continue_on_error: false,
+            last_tid: None,
+        }
+    }
+
+    /// Set the number of writes per call, clamped to `1..=MAX_WRITES_PER_CALL`
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.clamp(1, MAX_WRITES_PER_CALL);
+        self
+    }
+
+    /// Require (`true`) or skip (`false`) Lexicon validation on the PDS
+    pub fn with_validate(mut self, validate: bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+
+    /// Fail unless the repo is still at commit `cid`
+    ///
+    /// Later chunks swap on the commit produced by the chunk before them, so
+    /// a write from another client anywhere in the repo stops the batch.
+    pub fn with_swap_commit(mut self, cid: impl Into<String>) -> Self {
+        self.swap_commit = Some(cid.into());
+        self
+    }
+
+    /// Keep sending the remaining chunks after one fails
+    pub fn with_continue_on_error(mut self, continue_on_error: bool) -> Self {
+        self.continue_on_error = continue_on_error;
+        self
+    }
+
+    /// Queue a create with a generated TID record key
+    ///
+    /// # Returns
+    ///
+    /// The generated record key
+    ///
+    /// # Errors
+    ///
+    /// - `WriteError::Json` - `value` could not be serialized
+    pub fn create<T: Serialize>(
+        &mut self,
+        collection: impl Into<String>,
+        value: &T,
+    ) -> Result<String> {
+        let rkey = self.next_tid().to_string();
+        self.create_with_rkey(collection, rkey.clone(), value)?;
+        Ok(rkey)
+    }
+
+    /// Queue a create with an explicit record key
+    ///
+    /// # Errors
+    ///
+    /// - `WriteError::Json` - `value` could not be serialized
+    pub fn create_with_rkey<T: Serialize>(
+        &mut self,
+        collection: impl Into<String>,
+        rkey: impl Into<String>,
+        value: &T,
+    ) -> Result<&mut Self> {
+        self.writes.push(WriteOp::Create {
+            collection: collection.into(),
+            rkey: rkey.into(),
+            value: serde_json::to_value(value)?,
+        });
+        Ok(self)
+    }
+
+    /// Queue a replacement of an existing record
+    ///
+    /// # Errors
+    ///
+    /// - `WriteError::Json` - `value` could not be serialized
+    pub fn update<T: Serialize>(
+        &mut self,
+        collection: impl Into<String>,
+        rkey: impl Into<String>,
+        value: &T,
+    ) -> Result<&mut Self> {
+        self.writes.push(WriteOp::Update {
+            collection: collection.into(),
+            rkey: rkey.into(),
+            value: serde_json::to_value(value)?,
+        });
+        Ok(self)
+    }
+
+    /// Queue a delete
+    pub fn delete(&mut self, collection: impl Into<String>, rkey: impl Into<String>) -> &mut Self {
+        self.writes
+            .push(WriteOp::Delete { collection: collection.into(), rkey: rkey.into() });
+        self
+    }
+
+    /// Queue a prepared write
+    pub fn push(&mut self, op: WriteOp) -> &mut Self {
+        self.writes.push(op);
+        self
+    }
+
+    /// Queued writes
+    pub fn writes(&self) -> &[WriteOp] {
+        &self.writes
+    }
+
+    /// Number of queued writes
+    pub fn len(&self) -> usize {
+        self.writes.len()
+    }
+
+    /// Whether no writes are queued
+    pub fn is_empty(&self) -> bool {
+        self.writes.is_empty()
+    }
+
+    /// Send the writes
+    ///
+    /// Errors are reported per chunk in the returned [`BatchOutcome`]; use
+    /// [`BatchOutcome::into_result`] to treat any failure as an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated client for the PDS hosting the repo
+    pub async fn execute(&self, client: &XrpcClient) -> BatchOutcome {
+        let mut outcome = BatchOutcome::default();
+        let mut swap_commit = self.swap_commit.clone();
+        let mut failed = false;
+
+        for (index, writes) in self.writes.chunks(self.chunk_size).enumerate() {
+            let offset = index * self.chunk_size;
+            let status = if failed && !self.continue_on_error {
+                ChunkStatus::Skipped
+            } else {
+                let input = apply_writes::Input {
+                    repo: self.repo.clone(),
+                    swap_commit: swap_commit.clone(),
+                    validate: self.validate,
+                    writes: writes.iter().map(WriteOp::to_input).collect(),
+                };
+                match apply_writes::apply_writes(client, &input).await {
+                    Ok(output) => {
+                        if swap_commit.is_some() {
+                            swap_commit = output.commit.as_ref().map(|commit| commit.cid.clone());
+                        }
+                        ChunkStatus::Applied(self.chunk_results(writes, output))
+                    }
+                    Err(e) => {
+                        failed = true;
+                        let error = e.into_xrpc();
+                        tracing::debug!(
+                            "applyWrites failed for writes {}..{}: {}",
+                            offset,
+                            offset + writes.len(),
+                            error
+                        );
+                        ChunkStatus::Failed(error)
+                    }
+                }
+            };
+            outcome
+                .chunks
+                .push(BatchChunk { offset, writes: writes.to_vec(), status });
+        }
+
+        outcome
+    }
+
+    /// Pair a chunk's writes with the results the PDS returned
+    fn chunk_results(&self, writes: &[WriteOp], output: apply_writes::Output) -> Vec<WriteResult> {
+        let mut returned = output.results.unwrap_or_default().into_iter();
+        writes
+            .iter()
+            .map(|op| {
+                let (uri, cid) = match returned.next() {
+                    Some(OutputResultsItem::CreateResult(result)) => (result.uri, Some(result.cid)),
+                    Some(OutputResultsItem::UpdateResult(result)) => (result.uri, Some(result.cid)),
+                    Some(OutputResultsItem::DeleteResult(_)) | None => (op.uri(&self.repo), None),
+                };
+                WriteResult { action: op.action(), uri, cid }
+            })
+            .collect()
+    }
+
+    /// Generate a TID record key later than any generated before
+    ///
+    /// TIDs made in a tight loop can share a microsecond, so each key is
+    /// bumped past the previous one.
+    fn next_tid(&mut self) -> Tid {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let now = Duration::from_micros(now.as_micros() as u64);
+        let next = match self.last_tid {
+            Some(last) if now <= last => last + Duration::from_micros(1),
+            _ => now,
+        };
+        self.last_tid = Some(next);
+        Tid::from_timestamp(UNIX_EPOCH + next)
+    }
+}
+
+/// Outcome of [`create_records_paced`]
+#[derive(Debug, Clone, Default)]
+pub struct PacedCreates {
+    /// Records created, in order
+    pub created: Vec<create_record::Output>,
+    /// Subjects not sent, because the rate limit budget ran out or an
+    /// earlier create failed
+    pub pending: Vec<String>,
+    /// How long to wait before sending the pending subjects, if known
+    pub retry_after: Option<Duration>,
+    /// Subject whose create failed with an error other than a rate limit
+    pub failed: Option<(String, XrpcError)>,
+}
+
+impl PacedCreates {
+    /// Whether every subject was written
+    pub fn is_complete(&self) -> bool {
+        self.pending.is_empty() && self.failed.is_none()
+    }
+}
+
+/// Send `createRecord` requests one at a time, paced to the rate limit
+///
+/// The client delays each request as its write budget runs low. Sending stops
+/// when a request is rate limited or fails; the records created so far are
+/// always returned, so the caller knows exactly what was written and can
+/// resume with the failed and pending subjects without creating duplicates.
+///
+/// The client lock is taken per request so session updates aren't blocked
+/// while a request waits for budget.
+///
+/// # Arguments
+///
+/// * `client` - Authenticated client for the PDS
+/// * `creates` - `createRecord` requests, each paired with the subject it
+///   writes (e.g. a DID)
+pub async fn create_records_paced(
+    client: &RwLock<XrpcClient>,
+    creates: Vec<(String, XrpcRequest)>,
+) -> PacedCreates {
+    let mut outcome = PacedCreates::default();
+    let mut creates = creates.into_iter();
+
+    while let Some((subject, request)) = creates.next() {
+        let client = client.read().await;
+        let error = match client.procedure::<create_record::Output>(request).await {
+            Ok(response) => {
+                outcome.created.push(response.data);
+                continue;
+            }
+            Err(error) => error,
+        };
+
+        if error.is_rate_limited() {
+            let delay = client.rate_limit_delay(create_record::NSID);
+            outcome.retry_after = (!delay.is_zero()).then_some(delay);
+            outcome.pending.push(subject);
+        } else {
+            tracing::debug!("createRecord failed for {}: {}", subject, error);
+            outcome.failed = Some((subject, error));
+        }
+        outcome
+            .pending
+            .extend(creates.by_ref().map(|(subject, _)| subject));
+    }
+
+    outcome
+}
+
+/// Whether a `getRecord` failure means the record doesn't exist
+///
+/// The reference PDS reports missing records as `InvalidRequest` rather than
+/// the declared `RecordNotFound`.
+fn is_record_not_found(error: &EndpointError<get_record::Error>) -> bool {
+    match error {
+        EndpointError::Declared { kind: get_record::Error::RecordNotFound, .. } => true,
+        EndpointError::Other(e) => {
+            e.error() == "InvalidRequest" && e.message().starts_with("Could not locate record")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use crate::xrpc::XrpcClientConfig;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    const GET: &str = "com.atproto.repo.getRecord";
+    const PUT: &str = "com.atproto.repo.putRecord";
+
+    fn client(transport: &Arc<MockTransport>) -> XrpcClient {
+        XrpcClient::new(
+            XrpcClientConfig::new("https://pds.example.com").with_transport(transport.clone()),
+        )
+    }
+
+    fn stored(cid: &str, name: &str) -> serde_json::Value {
+        json!({
+            "uri": "at://did:plc:alice/app.bsky.actor.profile/self",
+            "cid": cid,
+            "value": {"$type": "app.bsky.actor.profile", "displayName": name}
+        })
+    }
+
+    fn written() -> serde_json::Value {
+        json!({"uri": "at://did:plc:alice/app.bsky.actor.profile/self", "cid": "bafynew"})
+    }
+
+    fn put_bodies(transport: &MockTransport) -> Vec<serde_json::Value> {
+        transport
+            .requests()
+            .iter()
+            .filter(|request| request.nsid == PUT)
+            .map(|request| serde_json::from_slice(request.body.as_ref().unwrap()).unwrap())
+            .collect()
+    }
+
+    fn rename(current: Option<serde_json::Value>) -> Result<serde_json::Value> {
+        let mut profile = current.unwrap_or_else(|| json!({"$type": "app.bsky.actor.profile"}));
+        profile["description"] = "edited".into();
+        Ok(profile)
+    }
+
+    #[tokio::test]
+    async fn test_update_swaps_against_current_cid() {
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(GET, 200, stored("bafyold", "Alice"))
+                .with_json(PUT, 200, written()),
+        );
+
+        let update = RecordUpdate::new("did:plc:alice", "app.bsky.actor.profile", "self");
+        let output = update.apply(&client(&transport), rename).await.unwrap();
+
+        assert_eq!(output.cid, "bafynew");
+        let bodies = put_bodies(&transport);
+        assert_eq!(bodies.len(), 1);
+        assert_eq!(bodies[0]["swapRecord"], "bafyold");
+        assert_eq!(bodies[0]["record"]["displayName"], "Alice");
+        assert_eq!(bodies[0]["record"]["description"], "edited");
+        assert!(bodies[0].get("validate").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_update_retries_after_invalid_swap() {
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(GET, 200, stored("bafyold", "Alice"))
+                .with_json(GET, 200, stored("bafyother", "Alice B."))
+                .with_json(
+                    PUT,
+                    400,
+                    json!({"error": "InvalidSwap", "message": "Record was at bafyother"}),
+                )
+                .with_json(PUT, 200, written()),
+        );
+
+        let update = RecordUpdate::new("did:plc:alice", "app.bsky.actor.profile", "self");
+        update.apply(&client(&transport), rename).await.unwrap();
+
+        let bodies = put_bodies(&transport);
+        assert_eq!(bodies.len(), 2);
+        assert_eq!(bodies[1]["swapRecord"], "bafyother");
+        assert_eq!(bodies[1]["record"]["displayName"], "Alice B.");
+    }
+
+    #[tokio::test]
+    async fn test_update_gives_up_after_max_attempts() {
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(GET, 200, stored("bafyold", "Alice"))
+                .with_json(PUT, 400, json!({"error": "InvalidSwap", "message": "Conflict"})),
+        );
+
+        let update = RecordUpdate::new("did:plc:alice", "app.bsky.actor.profile", "self")
+            .with_max_attempts(2);
+        let result = update.apply(&client(&transport), rename).await;
+
+        assert!(matches!(result, Err(WriteError::Conflict { attempts: 2, .. })));
+        assert_eq!(transport.request_count(PUT), 2);
+    }
+
+    #[tokio::test]
+    async fn test_missing_record_is_created_with_null_swap() {
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(
+                    GET,
+                    400,
+                    json!({"error": "InvalidRequest", "message": "Could not locate record: at://did:plc:alice/app.bsky.actor.profile/self"}),
+                )
+                .with_json(PUT, 200, written()),
+        );
+
+        let update = RecordUpdate::new("did:plc:alice", "app.bsky.actor.profile", "self")
+            .with_validate(true);
+        update.apply(&client(&transport), rename).await.unwrap();
+
+        let bodies = put_bodies(&transport);
+        assert!(bodies[0]["swapRecord"].is_null());
+        assert!(bodies[0].as_object().unwrap().contains_key("swapRecord"));
+        assert_eq!(bodies[0]["validate"], true);
+        assert_eq!(bodies[0]["record"]["$type"], "app.bsky.actor.profile");
+    }
+
+    #[tokio::test]
+    async fn test_rejected_edit_does_not_write() {
+        let transport = Arc::new(MockTransport::new().with_json(
+            GET,
+            400,
+            json!({"error": "RecordNotFound", "message": "Not found"}),
+        ));
+
+        let update = RecordUpdate::new("did:plc:alice", "app.bsky.graph.list", "3k");
+        let result = update
+            .apply(&client(&transport), |current: Option<serde_json::Value>| {
+                current.ok_or_else(|| WriteError::Rejected("List not found".to_string()))
+            })
+            .await;
+
+        assert!(matches!(result, Err(WriteError::Rejected(_))));
+        assert_eq!(transport.request_count(PUT), 0);
+    }
+
+    const APPLY: &str = "com.atproto.repo.applyWrites";
+
+    fn apply_bodies(transport: &MockTransport) -> Vec<serde_json::Value> {
+        transport
+            .requests()
+            .iter()
+            .filter(|request| request.nsid == APPLY)
+            .map(|request| serde_json::from_slice(request.body.as_ref().unwrap()).unwrap())
+            .collect()
+    }
+
+    fn follow_batch(count: usize) -> ApplyWritesBatch {
+        let mut batch = ApplyWritesBatch::new("did:plc:alice").with_chunk_size(2);
+        for i in 0..count {
+            batch
+                .create("app.bsky.graph.follow", &json!({"subject": format!("did:plc:u{}", i)}))
+                .unwrap();
+        }
+        batch
+    }
+
+    #[test]
+    fn test_generated_rkeys_are_unique_and_ordered() {
+        let batch = follow_batch(50);
+        let rkeys: Vec<&str> = batch.writes().iter().map(WriteOp::rkey).collect();
+
+        assert!(rkeys.iter().all(|rkey| Tid::new(*rkey).is_ok()));
+        assert!(rkeys.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[tokio::test]
+    async fn test_batch_is_chunked_and_results_are_paired() {
+        let created = |rkey: &str| {
+            json!({
+                "$type": "com.atproto.repo.applyWrites#createResult",
+                "uri": format!("at://did:plc:alice/app.bsky.graph.follow/{}", rkey),
+                "cid": format!("bafy{}", rkey)
+            })
+        };
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(APPLY, 200, json!({"results": [created("a"), created("b")]}))
+                .with_json(
+                    APPLY,
+                    200,
+                    json!({"results": [{"$type": "com.atproto.repo.applyWrites#deleteResult"}]}),
+                ),
+        );
+
+        let mut batch = follow_batch(2);
+        batch.delete("app.bsky.graph.follow", "old");
+        let outcome = batch.execute(&client(&transport)).await;
+
+        assert!(outcome.is_complete());
+        assert_eq!(outcome.chunks.len(), 2);
+        assert_eq!(outcome.chunks[1].offset, 2);
+        let created: Vec<_> = outcome
+            .created()
+            .map(|result| result.uri.as_str())
+            .collect();
+        assert_eq!(
+            created,
+            [
+                "at://did:plc:alice/app.bsky.graph.follow/a",
+                "at://did:plc:alice/app.bsky.graph.follow/b"
+            ]
+        );
+        let deleted = outcome.results().last().unwrap();
+        assert_eq!(deleted.action, WriteAction::Delete);
+        assert_eq!(deleted.uri, "at://did:plc:alice/app.bsky.graph.follow/old");
+        assert_eq!(deleted.cid, None);
+
+        let bodies = apply_bodies(&transport);
+        assert_eq!(bodies[0]["writes"].as_array().unwrap().len(), 2);
+        assert_eq!(bodies[0]["writes"][0]["$type"], "com.atproto.repo.applyWrites#create");
+        assert_eq!(bodies[0]["writes"][0]["rkey"], batch.writes()[0].rkey());
+        assert_eq!(bodies[1]["writes"][0]["$type"], "com.atproto.repo.applyWrites#delete");
+    }
+
+    #[tokio::test]
+    async fn test_batch_stops_at_failed_chunk() {
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(APPLY, 200, json!({}))
+                .with_json(APPLY, 400, json!({"error": "InvalidRequest", "message": "Bad record"})),
+        );
+
+        let outcome = follow_batch(5).execute(&client(&transport)).await;
+
+        assert!(!outcome.is_complete());
+        assert!(matches!(outcome.chunks[0].status, ChunkStatus::Applied(_)));
+        assert!(matches!(outcome.chunks[1].status, ChunkStatus::Failed(_)));
+        assert!(matches!(outcome.chunks[2].status, ChunkStatus::Skipped));
+        assert_eq!(transport.request_count(APPLY), 2);
+
+        let unapplied: Vec<_> = outcome.unapplied().collect();
+        assert_eq!(unapplied.len(), 3);
+        assert_eq!(unapplied[0].1.unwrap().error(), "InvalidRequest");
+        assert!(unapplied[2].1.is_none());
+
+        match outcome.into_result() {
+            Err(WriteError::PartialBatch { applied, total, source }) => {
+                assert_eq!((applied, total), (2, 5));
+                assert_eq!(source.message(), "Bad record");
+            }
+            other => panic!("Expected partial batch, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_can_continue_after_failure() {
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(APPLY, 400, json!({"error": "InvalidRequest", "message": "Bad record"}))
+                .with_json(APPLY, 200, json!({})),
+        );
+
+        let outcome = follow_batch(4)
+            .with_continue_on_error(true)
+            .execute(&client(&transport))
+            .await;
+
+        assert!(matches!(outcome.chunks[0].status, ChunkStatus::Failed(_)));
+        assert!(matches!(outcome.chunks[1].status, ChunkStatus::Applied(_)));
+        assert_eq!(outcome.results().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_swap_commit_chains_through_chunks() {
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(APPLY, 200, json!({"commit": {"cid": "bafycommit2", "rev": "2"}}))
+                .with_json(APPLY, 200, json!({"commit": {"cid": "bafycommit3", "rev": "3"}})),
+        );
+
+        let outcome = follow_batch(3)
+            .with_swap_commit("bafycommit1")
+            .with_validate(false)
+            .execute(&client(&transport))
+            .await;
+
+        assert!(outcome.is_complete());
+        let bodies = apply_bodies(&transport);
+        assert_eq!(bodies[0]["swapCommit"], "bafycommit1");
+        assert_eq!(bodies[1]["swapCommit"], "bafycommit2");
+        assert_eq!(bodies[1]["validate"], false);
+    }
+
+    #[tokio::test]
+    async fn test_paced_creates_keep_results_on_failure() {
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(
+                    create_record::NSID,
+                    200,
+                    json!({"uri": "at://did:plc:alice/app.bsky.graph.block/1", "cid": "bafy1"}),
+                )
+                .with_json(
+                    create_record::NSID,
+                    400,
+                    json!({"error": "InvalidRequest", "message": "Invalid subject"}),
+                ),
+        );
+        let client = RwLock::new(client(&transport));
+
+        let creates = (1..=4)
+            .map(|i| {
+                let subject = format!("did:plc:user{}", i);
+                let request = XrpcRequest::procedure(create_record::NSID)
+                    .json_body(&json!({"repo": "did:plc:alice", "record": {"subject": subject}}))
+                    .unwrap();
+                (subject, request)
+            })
+            .collect();
+        let outcome = create_records_paced(&client, creates).await;
+
+        assert!(!outcome.is_complete());
+        assert_eq!(outcome.created.len(), 1);
+        assert_eq!(outcome.created[0].uri, "at://did:plc:alice/app.bsky.graph.block/1");
+        let (subject, error) = outcome.failed.unwrap();
+        assert_eq!(subject, "did:plc:user2");
+        assert_eq!(error.error(), "InvalidRequest");
+        assert_eq!(outcome.pending, vec!["did:plc:user3", "did:plc:user4"]);
+        assert_eq!(outcome.retry_after, None);
+        assert_eq!(transport.request_count(create_record::NSID), 2);
+    }
+}
@@ -28,6 +28,8 @@
 //!     pds_url: None,
 //!     is_self_hosted: Some(false),
 //!     app_view_url: Some("https://api.bsky.app".to_string()),
+//!     oauth: None,
+//!     proxy_router: None,
 //! };
 //!
 //! // Check if session is expired
@@ -38,6 +40,8 @@ mod manager;
 
 pub use manager::{AccountExport, SessionManager, SessionManagerError, SessionStorage};
 
+use crate::oauth::OAuthSessionData;
+use crate::proxy::ProxyRouter;
 use chrono::{DateTime, Duration, Utc};
 use jsonwebtoken::{decode, decode_header, DecodingKey, Validation};
 use serde::{Deserialize, Serialize};
@@ -131,6 +135,15 @@ pub struct SessionAccount {
     /// This is a key differentiator allowing users to choose their AppView provider
     #[serde(skip_serializing_if = "Option::is_none")]
     pub app_view_url: Option<String>,
+
+    /// OAuth state if this account signed in with OAuth (DPoP-bound tokens)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oauth: Option<OAuthSessionData>,
+
+    /// Service proxy targets and routes for this account
+    /// (`None` uses [`ProxyRouter::bluesky`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy_router: Option<ProxyRouter>,
 }
 
 impl SessionAccount {
@@ -151,6 +164,8 @@ impl SessionAccount {
             pds_url: None,
             is_self_hosted: None,
             app_view_url: None,
+            oauth: None,
+            proxy_router: None,
         }
     }
 
@@ -166,6 +181,7 @@ impl SessionAccount {
             refresh_jwt: self.refresh_jwt.clone().unwrap_or_default(),
             active: self.active.unwrap_or(true),
             status: self.status.clone(),
+            oauth: self.oauth.clone(),
         })
     }
 
@@ -213,6 +229,10 @@ pub struct AtpSessionData {
     /// Account status (e.g., "takendown", "suspended", "deactivated")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
+
+    /// OAuth state if the tokens are DPoP-bound OAuth tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth: Option<OAuthSessionData>,
 }
 
 fn default_active() -> bool {
@@ -237,6 +257,8 @@ impl AtpSessionData {
             pds_url: None,
             is_self_hosted: None,
             app_view_url: None,
+            oauth: self.oauth.clone(),
+            proxy_router: None,
         }
     }
 }
@@ -454,6 +476,54 @@ pub fn is_signup_queued(access_jwt: &str) -> bool {
         .unwrap_or(false)
 }
 
+/// Credential a password session was created with
+///
+/// The PDS records this in the access token's `scope` claim.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SessionCredential {
+    /// The account's main password
+    Password,
+    /// An app password
+    AppPassword,
+    /// An app password with privileged access (e.g. direct messages)
+    PrivilegedAppPassword,
+}
+
+impl SessionCredential {
+    /// Whether the session was created with an app password
+    pub fn is_app_password(&self) -> bool {
+        matches!(self, Self::AppPassword | Self::PrivilegedAppPassword)
+    }
+}
+
+/// Get the credential a session was created with
+///
+/// Returns None if the token can't be parsed, e.g. an opaque OAuth token.
+///
+/// # Arguments
+///
+/// * `access_jwt` - The access JWT token
+///
+/// # Example
+///
+/// ```rust
+/// use atproto_client::session::session_credential;
+///
+/// let token = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9...";
+/// if session_credential(token).is_some_and(|c| c.is_app_password()) {
+///     println!("Signed in with an app password");
+/// }
+/// ```
+pub fn session_credential(access_jwt: &str) -> Option<SessionCredential> {
+    let claims = parse_jwt_claims(access_jwt).ok()?;
+    Some(match claims.scope.as_deref() {
+        Some("com.atproto.appPass") => SessionCredential::AppPassword,
+        Some("com.atproto.appPassPrivileged") => SessionCredential::PrivilegedAppPassword,
+        _ => SessionCredential::Password,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -524,6 +594,7 @@ mod tests {
             refresh_jwt: "refresh_token".to_string(),
             active: true,
             status: None,
+            oauth: None,
         };
 
         let account = session_data.to_session_account("https://bsky.social".to_string());
@@ -553,6 +624,8 @@ mod tests {
             pds_url: None,
             is_self_hosted: Some(false),
             app_view_url: None,
+            oauth: None,
+            proxy_router: None,
         };
 
         let json = serde_json::to_string(&account).unwrap();
@@ -747,6 +820,40 @@ mod tests {
         assert!(!is_signup_queued(&token));
     }
 
+    #[test]
+    fn test_session_credential_from_scope() {
+        use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+
+        let token = |scope: &str| {
+            let claims = JwtClaims {
+                sub: Some("did:plc:test123".to_string()),
+                iat: Some(Utc::now().timestamp()),
+                exp: Some((Utc::now() + Duration::hours(1)).timestamp()),
+                scope: Some(scope.to_string()),
+                extra: serde_json::json!({}),
+            };
+            encode(
+                &Header::new(Algorithm::HS256),
+                &claims,
+                &EncodingKey::from_secret(b"test_secret"),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(
+            session_credential(&token("com.atproto.access")),
+            Some(SessionCredential::Password)
+        );
+        assert_eq!(
+            session_credential(&token("com.atproto.appPass")),
+            Some(SessionCredential::AppPassword)
+        );
+        let privileged = session_credential(&token("com.atproto.appPassPrivileged")).unwrap();
+        assert_eq!(privileged, SessionCredential::PrivilegedAppPassword);
+        assert!(privileged.is_app_password());
+        assert_eq!(session_credential("opaque-oauth-token"), None);
+    }
+
     #[test]
     fn test_is_session_expired_with_valid_tokens() {
         use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
@@ -0,0 +1,678 @@
+//! Repository event stream (`com.atproto.sync.subscribeRepos`)
+//!
+//! [`Firehose`] reads frames on a background task and hands decoded events to
+//! the consumer through a bounded channel. When the consumer falls behind, the
+//! channel fills, the task stops reading and the socket's receive window
+//! closes, so a slow consumer never buffers more than
+//! [`FirehoseConfig::buffer_size`] events in memory.
+//!
+//! The sequence number of every event read is remembered, and dropped
+//! connections are re-established from it with exponential backoff. The
+//! sequence number of the last event handed to the consumer is available
+//! from [`Firehose::cursor`] and should be persisted to resume after a
+//! restart.
+
+use super::{websocket_base, Frame, Result, StreamError};
+use crate::repo::{CarFile, RepoRecord};
+use cid::Cid;
+use futures_util::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+
+/// NSID of the repository event stream
+pub const SUBSCRIBE_REPOS: &str = "com.atproto.sync.subscribeRepos";
+
+/// Error sent by relays that drop consumers which fall too far behind
+const CONSUMER_TOO_SLOW: &str = "ConsumerTooSlow";
+
+/// Action of a repository operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RepoAction {
+    /// Record created
+    Create,
+    /// Record updated
+    Update,
+    /// Record deleted
+    Delete,
+}
+
+/// A single record operation in a commit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepoOp {
+    /// Operation type
+    pub action: RepoAction,
+    /// Record path (`collection/rkey`)
+    pub path: String,
+    /// New record CID (null for deletes)
+    pub cid: Option<Cid>,
+    /// Previous record CID (absent for creates)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev: Option<Cid>,
+}
+
+impl RepoOp {
+    /// Get the collection NSID
+    pub fn collection(&self) -> &str {
+        self.path
+            .split_once('/')
+            .map_or(&self.path, |(collection, _)| collection)
+    }
+
+    /// Get the record key
+    pub fn rkey(&self) -> &str {
+        self.path.split_once('/').map_or("", |(_, rkey)| rkey)
+    }
+}
+
+/// `#commit` - a repository commit with the blocks it changed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitEvent {
+    /// Stream sequence number
+    pub seq: i64,
+    /// Deprecated, always false
+    #[serde(default)]
+    pub rebase: bool,
+    /// Deprecated; the commit was too large to include blocks
+    #[serde(default)]
+    pub too_big: bool,
+    /// DID of the repository
+    pub repo: String,
+    /// CID of the commit
+    pub commit: Cid,
+    /// Revision of the commit
+    pub rev: String,
+    /// Revision of the previous commit, if any
+    pub since: Option<String>,
+    /// CAR file with the commit and changed blocks
+    #[serde(with = "serde_bytes")]
+    pub blocks: Vec<u8>,
+    /// Record operations
+    pub ops: Vec<RepoOp>,
+    /// Deprecated, always empty
+    #[serde(default)]
+    pub blobs: Vec<Cid>,
+    /// MST root CID of the previous commit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev_data: Option<Cid>,
+    /// Timestamp of the event
+    pub time: String,
+}
+
+impl CommitEvent {
+    /// Parse the attached blocks
+    pub fn car(&self) -> Result<CarFile> {
+        Ok(CarFile::from_bytes(&self.blocks)?)
+    }
+
+    /// Get the records created or updated by the commit
+    ///
+    /// # Errors
+    ///
+    /// - `StreamError::Repo` - The blocks are malformed or a record block is missing
+    pub fn records(&self) -> Result<Vec<RepoRecord>> {
+        let car = self.car()?;
+        self.ops
+            .iter()
+            .filter_map(|op| op.cid.map(|cid| (op, cid)))
+            .map(|(op, cid)| {
+                Ok(RepoRecord {
+                    collection: op.collection().to_string(),
+                    rkey: op.rkey().to_string(),
+                    cid,
+                    bytes: car.require(&cid)?.to_vec(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// `#sync` - the current state of a repository, e.g. after a reset
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncEvent {
+    /// Stream sequence number
+    pub seq: i64,
+    /// DID of the repository
+    pub did: String,
+    /// CAR file with the commit block
+    #[serde(with = "serde_bytes")]
+    pub blocks: Vec<u8>,
+    /// Revision of the commit
+    pub rev: String,
+    /// Timestamp of the event
+    pub time: String,
+}
+
+impl SyncEvent {
+    /// Parse the attached blocks
+    pub fn car(&self) -> Result<CarFile> {
+        Ok(CarFile::from_bytes(&self.blocks)?)
+    }
+}
+
+/// `#identity` - the account's handle or DID document may have changed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdentityEvent {
+    /// Stream sequence number
+    pub seq: i64,
+    /// DID of the account
+    pub did: String,
+    /// Timestamp of the event
+    pub time: String,
+    /// Current handle, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+}
+
+/// `#account` - the account's hosting status changed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountEvent {
+    /// Stream sequence number
+    pub seq: i64,
+    /// DID of the account
+    pub did: String,
+    /// Timestamp of the event
+    pub time: String,
+    /// Whether the account's repository is available
+    pub active: bool,
+    /// Reason the account is inactive (e.g., "takendown", "deactivated")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+/// `#info` - an informational message from the server
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InfoEvent {
+    /// Message name (e.g., "OutdatedCursor")
+    pub name: String,
+    /// Human-readable message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// An event from the repository event stream
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FirehoseEvent {
+    /// `#commit`
+    Commit(Box<CommitEvent>),
+    /// `#sync`
+    Sync(SyncEvent),
+    /// `#identity`
+    Identity(IdentityEvent),
+    /// `#account`
+    Account(AccountEvent),
+    /// `#info`
+    Info(InfoEvent),
+    /// A message type this client doesn't know
+    Unknown {
+        /// Message type from the frame header
+        message_type: String,
+        /// DAG-CBOR encoded body
+        body: Vec<u8>,
+    },
+}
+
+impl FirehoseEvent {
+    /// Decode an event from a frame
+    ///
+    /// # Errors
+    ///
+    /// - `StreamError::Server` - The frame is an error frame
+    /// - `StreamError::Cbor` - The body doesn't match its message type
+    pub fn from_frame(frame: Frame) -> Result<Self> {
+        let frame = frame.into_result()?;
+        Ok(match frame.message_type().unwrap_or_default() {
+            "#commit" => Self::Commit(Box::new(frame.decode_body()?)),
+            "#sync" => Self::Sync(frame.decode_body()?),
+            "#identity" => Self::Identity(frame.decode_body()?),
+            "#account" => Self::Account(frame.decode_body()?),
+            "#info" => Self::Info(frame.decode_body()?),
+            message_type => Self::Unknown {
+                message_type: message_type.to_string(),
+                body: frame.body,
+            },
+        })
+    }
+
+    /// Get the stream sequence number, if the event has one
+    pub fn seq(&self) -> Option<i64> {
+        match self {
+            Self::Commit(event) => Some(event.seq),
+            Self::Sync(event) => Some(event.seq),
+            Self::Identity(event) => Some(event.seq),
+            Self::Account(event) => Some(event.seq),
+            Self::Info(_) | Self::Unknown { .. } => None,
+        }
+    }
+}
+
+/// Configuration for a firehose subscription
+#[derive(Debug, Clone)]
+pub struct FirehoseConfig {
+    /// Relay or PDS host (e.g., "wss://bsky.network")
+    pub service: String,
+    /// Sequence number to resume after
+    pub cursor: Option<i64>,
+    /// Number of decoded events buffered ahead of the consumer
+    pub buffer_size: usize,
+    /// Reconnection attempts before giving up (0 to disable)
+    pub max_reconnects: u32,
+    /// Delay before the first reconnection attempt
+    pub reconnect_delay: Duration,
+    /// Upper bound for the exponential reconnection backoff
+    pub max_reconnect_delay: Duration,
+}
+
+impl FirehoseConfig {
+    /// Create a configuration for a relay or PDS host
+    ///
+    /// `https://` and `http://` hosts are converted to `wss://` and `ws://`,
+    /// and hosts without a scheme use `wss://`.
+    pub fn new(service: impl Into<String>) -> Self {
+        Self {
+            service: service.into(),
+            cursor: None,
+            buffer_size: 1024,
+            max_reconnects: 10,
+            reconnect_delay: Duration::from_secs(1),
+            max_reconnect_delay: Duration::from_secs(60),
+        }
+    }
+
+    /// Resume after a sequence number
+    pub fn with_cursor(mut self, cursor: i64) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Set the number of events buffered ahead of the consumer (at least 1)
+    pub fn with_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = buffer_size.max(1);
+        self
+    }
+
+    /// Set the number of reconnection attempts and the initial backoff
+    pub fn with_reconnect(mut self, max_reconnects: u32, delay: Duration) -> Self {
+        self.max_reconnects = max_reconnects;
+        self.reconnect_delay = delay;
+        self
+    }
+
+    /// Build the subscription URL for a cursor
+    fn url(&self, cursor: Option<i64>) -> String {
+        let service = websocket_base(&self.service);
+        match cursor {
+            Some(cursor) => format!("{}/xrpc/{}?cursor={}", service, SUBSCRIBE_REPOS, cursor),
+            None => format!("{}/xrpc/{}", service, SUBSCRIBE_REPOS),
+        }
+    }
+}
+
+/// A subscription to `com.atproto.sync.subscribeRepos`
+///
+/// Implements [`Stream`], yielding decoded events in order. Frames that fail
+/// to decode are yielded as errors without ending the stream. The stream ends
+/// after a server error frame, or after a connection error once reconnection
+/// attempts are exhausted.
+///
+/// Dropping the subscription closes the connection.
+#[derive(Debug)]
+pub struct Firehose {
+    events: mpsc::Receiver<Result<FirehoseEvent>>,
+    cursor: Option<i64>,
+    task: JoinHandle<()>,
+}
+
+impl Firehose {
+    /// Connect to the event stream
+    ///
+    /// The connection is made on a background task, so connection errors are
+    /// reported through the stream. Must be called within a Tokio runtime.
+    pub fn connect(config: FirehoseConfig) -> Self {
+        let cursor = config.cursor;
+        let (sender, events) = mpsc::channel(config.buffer_size.max(1));
+        let task = tokio::spawn(run(config, sender));
+        Self { events, cursor, task }
+    }
+
+    /// Get the sequence number of the last event received by the consumer
+    ///
+    /// Before any event arrives, this is the configured starting cursor.
+    pub fn cursor(&self) -> Option<i64> {
+        self.cursor
+    }
+
+    /// Wait for the next event
+    ///
+    /// # Returns
+    ///
+    /// `None` once the stream has ended
+    pub async fn next_event(&mut self) -> Option<Result<FirehoseEvent>> {
+        self.next().await
+    }
+}
+
+impl Stream for Firehose {
+    type Item = Result<FirehoseEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll = self.events.poll_recv(cx);
+        if let Poll::Ready(Some(Ok(event))) = &poll {
+            if let Some(seq) = event.seq() {
+                self.cursor = Some(seq);
+            }
+        }
+        poll
+    }
+}
+
+impl Drop for Firehose {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Read frames into the channel, reconnecting from the last sequence number
+async fn run(config: FirehoseConfig, sender: mpsc::Sender<Result<FirehoseEvent>>) {
+    let mut cursor = config.cursor;
+    let mut attempts = 0;
+    let mut delay = config.reconnect_delay;
+
+    loop {
+        let error = match connect_async(config.url(cursor)).await {
+            Ok((mut socket, _)) => loop {
+                let data = match socket.next().await {
+                    Some(Ok(Message::Binary(data))) => data,
+                    Some(Ok(Message::Close(_))) | None => {
+                        break StreamError::Connection("Connection closed".to_string());
+                    }
+                    Some(Ok(_)) => continue,
+                    Some(Err(e)) => break StreamError::Connection(e.to_string()),
+                };
+                attempts = 0;
+                delay = config.reconnect_delay;
+
+                let event = Frame::from_bytes(&data).and_then(FirehoseEvent::from_frame);
+                let server_error = match &event {
+                    Ok(event) => {
+                        cursor = event.seq().or(cursor);
+                        None
+                    }
+                    Err(StreamError::Server { error, .. }) => Some(error.clone()),
+                    Err(_) => None,
+                };
+                if sender.send(event).await.is_err() {
+                    // The consumer is gone
+                    return;
+                }
+                match server_error.as_deref() {
+                    Some(CONSUMER_TOO_SLOW) => {
+                        break StreamError::Connection("Consumer too slow".to_string());
+                    }
+                    Some(_) => return,
+                    None => {}
+                }
+            },
+            Err(e) => StreamError::Connection(e.to_string()),
+        };
+
+        if attempts >= config.max_reconnects {
+            let _ = sender.send(Err(error)).await;
+            return;
+        }
+        attempts += 1;
+        tracing::warn!(
+            "Firehose disconnected ({}), reconnecting in {:?} (attempt {})",
+            error,
+            delay,
+            attempts
+        );
+        tokio::time::sleep(delay).await;
+        delay = (delay * 2).min(config.max_reconnect_delay);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexicon::cbor::{decode_record, encode_record};
+    use futures_util::SinkExt;
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+    fn identity(seq: i64) -> Frame {
+        Frame::message(
+            "#identity",
+            &IdentityEvent {
+                seq,
+                did: format!("did:plc:user{}", seq),
+                time: "2024-01-01T00:00:00Z".to_string(),
+                handle: None,
+            },
+        )
+        .unwrap()
+    }
+
+    /// Serve one recorded session per connection, recording request paths
+    async fn replay(sessions: Vec<Vec<Frame>>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let paths = Arc::new(Mutex::new(Vec::new()));
+        let recorded = paths.clone();
+
+        tokio::spawn(async move {
+            for frames in sessions {
+                let (stream, _) = listener.accept().await.unwrap();
+                let paths = paths.clone();
+                #[allow(clippy::result_large_err)]
+                let callback = move |request: &Request, response: Response| {
+                    paths.lock().unwrap().push(request.uri().to_string());
+                    Ok(response)
+                };
+                let mut socket = tokio_tungstenite::accept_hdr_async(stream, callback)
+                    .await
+                    .unwrap();
+                for frame in frames {
+                    socket
+                        .send(Message::Binary(frame.to_bytes().unwrap()))
+                        .await
+                        .unwrap();
+                }
+                let _ = socket.close(None).await;
+            }
+        });
+
+        (format!("ws://{}", address), recorded)
+    }
+
+    fn config(service: &str) -> FirehoseConfig {
+        FirehoseConfig::new(service).with_reconnect(0, Duration::from_millis(10))
+    }
+
+    #[test]
+    fn test_subscription_url() {
+        let config = FirehoseConfig::new("https://bsky.network/");
+        assert_eq!(config.url(None), "wss://bsky.network/xrpc/com.atproto.sync.subscribeRepos");
+        assert_eq!(
+            config.url(Some(42)),
+            "wss://bsky.network/xrpc/com.atproto.sync.subscribeRepos?cursor=42"
+        );
+        assert!(FirehoseConfig::new("localhost:2583")
+            .url(None)
+            .starts_with("wss://localhost:2583/"));
+        assert!(FirehoseConfig::new("http://localhost:2583")
+            .url(None)
+            .starts_with("ws://localhost:2583/"));
+    }
+
+    #[test]
+    fn test_decode_commit_records() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Post {
+            text: String,
+        }
+
+        let mut car = CarFile::new(Vec::new());
+        let record = car
+            .insert(encode_record(&Post { text: "hello".to_string() }).unwrap())
+            .unwrap();
+        let commit = car
+            .insert(encode_record(&json!({"did": "did:plc:alice"})).unwrap())
+            .unwrap();
+        car.set_roots(vec![commit]);
+
+        let event = CommitEvent {
+            seq: 10,
+            rebase: false,
+            too_big: false,
+            repo: "did:plc:alice".to_string(),
+            commit,
+            rev: "3kaaaaaaaaaab".to_string(),
+            since: Some("3kaaaaaaaaaaa".to_string()),
+            blocks: car.to_bytes().unwrap(),
+            ops: vec![
+                RepoOp {
+                    action: RepoAction::Create,
+                    path: "app.bsky.feed.post/3kpost".to_string(),
+                    cid: Some(record),
+                    prev: None,
+                },
+                RepoOp {
+                    action: RepoAction::Delete,
+                    path: "app.bsky.feed.like/3klike".to_string(),
+                    cid: None,
+                    prev: Some(record),
+                },
+            ],
+            blobs: Vec::new(),
+            prev_data: None,
+            time: "2024-01-01T00:00:00Z".to_string(),
+        };
+
+        let frame = Frame::message("#commit", &event).unwrap();
+        let decoded = FirehoseEvent::from_frame(frame).unwrap();
+        assert_eq!(decoded.seq(), Some(10));
+        let FirehoseEvent::Commit(decoded) = decoded else {
+            panic!("Expected commit event");
+        };
+        assert_eq!(*decoded, event);
+        assert_eq!(decoded.ops[1].collection(), "app.bsky.feed.like");
+        assert_eq!(decoded.ops[1].rkey(), "3klike");
+
+        let records = decoded.records().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].rkey, "3kpost");
+        assert_eq!(decode_record::<Post>(&records[0].bytes).unwrap().text, "hello");
+    }
+
+    #[test]
+    fn test_decode_unknown_message() {
+        let frame = Frame::message("#tombstone", &json!({"seq": 1})).unwrap();
+        match FirehoseEvent::from_frame(frame).unwrap() {
+            FirehoseEvent::Unknown { message_type, .. } => assert_eq!(message_type, "#tombstone"),
+            other => panic!("Expected unknown event, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_session() {
+        let frames = vec![
+            identity(1),
+            Frame::message(
+                "#account",
+                &json!({"seq": 2, "did": "did:plc:bob", "time": "2024-01-01T00:00:00Z",
+                        "active": false, "status": "deactivated"}),
+            )
+            .unwrap(),
+            Frame::message("#info", &json!({"name": "OutdatedCursor"})).unwrap(),
+            Frame::message("#account", &json!({"seq": "bad"})).unwrap(),
+            identity(3),
+        ];
+        let (service, paths) = replay(vec![frames]).await;
+        let mut firehose = Firehose::connect(config(&service).with_cursor(0));
+        assert_eq!(firehose.cursor(), Some(0));
+
+        let event = firehose.next_event().await.unwrap().unwrap();
+        assert!(matches!(event, FirehoseEvent::Identity(ref e) if e.did == "did:plc:user1"));
+        assert_eq!(firehose.cursor(), Some(1));
+
+        match firehose.next_event().await.unwrap().unwrap() {
+            FirehoseEvent::Account(account) => {
+                assert!(!account.active);
+                assert_eq!(account.status.as_deref(), Some("deactivated"));
+            }
+            other => panic!("Expected account event, got {:?}", other),
+        }
+        let event = firehose.next_event().await.unwrap().unwrap();
+        assert!(matches!(event, FirehoseEvent::Info(ref info) if info.name == "OutdatedCursor"));
+        assert_eq!(firehose.cursor(), Some(2));
+
+        // A malformed body is reported without ending the stream
+        assert!(matches!(firehose.next_event().await, Some(Err(StreamError::Cbor(_)))));
+        let event = firehose.next_event().await.unwrap().unwrap();
+        assert_eq!(event.seq(), Some(3));
+
+        // Reconnection is disabled, so the close ends the stream
+        assert!(matches!(firehose.next_event().await, Some(Err(StreamError::Connection(_)))));
+        assert!(firehose.next_event().await.is_none());
+        assert_eq!(firehose.cursor(), Some(3));
+        assert_eq!(paths.lock().unwrap()[0], "/xrpc/com.atproto.sync.subscribeRepos?cursor=0");
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_resumes_from_cursor() {
+        let (service, paths) =
+            replay(vec![vec![identity(1), identity(2)], vec![identity(3)]]).await;
+        let config = FirehoseConfig::new(&service).with_reconnect(1, Duration::from_millis(10));
+        let firehose = Firehose::connect(config);
+
+        let events: Vec<_> = firehose.take(3).collect().await;
+        let seqs: Vec<_> = events.iter().map(|e| e.as_ref().unwrap().seq()).collect();
+        assert_eq!(seqs, vec![Some(1), Some(2), Some(3)]);
+
+        let paths = paths.lock().unwrap();
+        assert_eq!(paths[0], "/xrpc/com.atproto.sync.subscribeRepos");
+        assert_eq!(paths[1], "/xrpc/com.atproto.sync.subscribeRepos?cursor=2");
+    }
+
+    #[tokio::test]
+    async fn test_error_frame_ends_stream() {
+        let frames = vec![
+            identity(1),
+            Frame::error("FutureCursor", Some("Cursor in the future")).unwrap(),
+            identity(2),
+        ];
+        let (service, _) = replay(vec![frames]).await;
+        let firehose = Firehose::connect(config(&service).with_reconnect(3, Duration::ZERO));
+
+        let events: Vec<_> = firehose.collect().await;
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            &events[1],
+            Err(StreamError::Server { error, .. }) if error == "FutureCursor"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_slow_consumer_receives_every_event() {
+        let frames = (1..=50).map(identity).collect();
+        let (service, _) = replay(vec![frames]).await;
+        let mut firehose = Firehose::connect(config(&service).with_buffer_size(1));
+
+        for seq in 1..=50 {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            let event = firehose.next_event().await.unwrap().unwrap();
+            assert_eq!(event.seq(), Some(seq));
+        }
+        assert_eq!(firehose.cursor(), Some(50));
+    }
+}
@@ -0,0 +1,200 @@
+//! Event stream frame encoding
+//!
+//! A frame is two concatenated DAG-CBOR values: a header and a body. Message
+//! frames have `op = 1` and a short message type such as `#commit`; error
+//! frames have `op = -1`, no type, and an `{error, message}` body.
+
+use super::{Result, StreamError};
+use crate::lexicon::cbor::{decode_prefix, decode_record, encode_record};
+use serde::{Deserialize, Serialize};
+
+/// Frame header
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FrameHeader {
+    /// Operation: 1 for messages, -1 for errors
+    pub op: i64,
+    /// Message type (e.g., "#commit"), absent on error frames
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub t: Option<String>,
+}
+
+/// Body of an error frame
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct ErrorBody {
+    error: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+}
+
+/// A single event stream frame
+///
+/// # Example
+///
+/// ```rust
+/// use atproto_client::stream::Frame;
+/// use serde_json::json;
+///
+/// let frame = Frame::message("#info", &json!({"name": "OutdatedCursor"})).unwrap();
+/// let parsed = Frame::from_bytes(&frame.to_bytes().unwrap()).unwrap();
+///
+/// assert_eq!(parsed.message_type(), Some("#info"));
+/// let body: serde_json::Value = parsed.decode_body().unwrap();
+/// assert_eq!(body["name"], "OutdatedCursor");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// Frame header
+    pub header: FrameHeader,
+    /// DAG-CBOR encoded body
+    pub body: Vec<u8>,
+}
+
+impl Frame {
+    /// Header `op` of message frames
+    pub const OP_MESSAGE: i64 = 1;
+
+    /// Header `op` of error frames
+    pub const OP_ERROR: i64 = -1;
+
+    /// Create a message frame
+    ///
+    /// # Arguments
+    ///
+    /// * `message_type` - Short message type (e.g., "#commit")
+    /// * `body` - Message body
+    pub fn message<T: Serialize>(message_type: &str, body: &T) -> Result<Self> {
+        Ok(Self {
+            header: FrameHeader {
+                op: Self::OP_MESSAGE,
+                t: Some(message_type.to_string()),
+            },
+            body: encode_record(body)?,
+        })
+    }
+
+    /// Create an error frame
+    pub fn error(error: &str, message: Option<&str>) -> Result<Self> {
+        let body = ErrorBody {
+            error: error.to_string(),
+            message: message.map(str::to_string),
+        };
+        Ok(Self {
+            header: FrameHeader { op: Self::OP_ERROR, t: None },
+            body: encode_record(&body)?,
+        })
+    }
+
+    /// Parse a frame from a binary WebSocket message
+    ///
+    /// # Errors
+    ///
+    /// - `StreamError::InvalidFrame` - Unknown `op`, missing type or missing body
+    /// - `StreamError::Cbor` - The header is not valid DAG-CBOR
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (header, body): (FrameHeader, _) = decode_prefix(bytes)?;
+        match header.op {
+            Self::OP_MESSAGE if header.t.is_none() => {
+                return Err(StreamError::InvalidFrame("Message frame without type".to_string()));
+            }
+            Self::OP_MESSAGE | Self::OP_ERROR => {}
+            op => return Err(StreamError::InvalidFrame(format!("Unknown op: {}", op))),
+        }
+        if body.is_empty() {
+            return Err(StreamError::InvalidFrame("Missing body".to_string()));
+        }
+        Ok(Self { header, body: body.to_vec() })
+    }
+
+    /// Encode the frame as a binary WebSocket message
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = encode_record(&self.header)?;
+        bytes.extend_from_slice(&self.body);
+        Ok(bytes)
+    }
+
+    /// Get the message type, or `None` for error frames
+    pub fn message_type(&self) -> Option<&str> {
+        self.header.t.as_deref()
+    }
+
+    /// Check if this is an error frame
+    pub fn is_error(&self) -> bool {
+        self.header.op == Self::OP_ERROR
+    }
+
+    /// Decode the body
+    pub fn decode_body<T>(&self) -> Result<T>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        Ok(decode_record(&self.body)?)
+    }
+
+    /// Convert an error frame into `StreamError::Server`
+    ///
+    /// Message frames are returned unchanged.
+    pub fn into_result(self) -> Result<Self> {
+        if !self.is_error() {
+            return Ok(self);
+        }
+        let body: ErrorBody = self.decode_body()?;
+        Err(StreamError::Server { error: body.error, message: body.message })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_message_frame_roundtrip() {
+        let frame =
+            Frame::message("#identity", &json!({"seq": 7, "did": "did:plc:alice"})).unwrap();
+        let parsed = Frame::from_bytes(&frame.to_bytes().unwrap()).unwrap();
+
+        assert_eq!(parsed, frame);
+        assert!(!parsed.is_error());
+        assert_eq!(parsed.message_type(), Some("#identity"));
+        let body: serde_json::Value = parsed.into_result().unwrap().decode_body().unwrap();
+        assert_eq!(body["seq"], 7);
+    }
+
+    #[test]
+    fn test_error_frame() {
+        let frame = Frame::error("FutureCursor", Some("Cursor in the future")).unwrap();
+        let parsed = Frame::from_bytes(&frame.to_bytes().unwrap()).unwrap();
+
+        assert!(parsed.is_error());
+        assert_eq!(parsed.message_type(), None);
+        match parsed.into_result() {
+            Err(StreamError::Server { error, message }) => {
+                assert_eq!(error, "FutureCursor");
+                assert_eq!(message.as_deref(), Some("Cursor in the future"));
+            }
+            other => panic!("Expected server error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_invalid_frames() {
+        let header = |op: i64, t: Option<&str>| {
+            encode_record(&FrameHeader { op, t: t.map(str::to_string) }).unwrap()
+        };
+        let body = encode_record(&json!({})).unwrap();
+
+        let mut unknown_op = header(2, Some("#commit"));
+        unknown_op.extend(&body);
+        assert!(matches!(Frame::from_bytes(&unknown_op), Err(StreamError::InvalidFrame(_))));
+
+        let mut untyped = header(1, None);
+        untyped.extend(&body);
+        assert!(matches!(Frame::from_bytes(&untyped), Err(StreamError::InvalidFrame(_))));
+
+        assert!(matches!(
+            Frame::from_bytes(&header(1, Some("#commit"))),
+            Err(StreamError::InvalidFrame(_))
+        ));
+        assert!(matches!(Frame::from_bytes(&[0xff]), Err(StreamError::Cbor(_))));
+    }
+}
@@ -0,0 +1,677 @@
+//! Jetstream JSON event stream
+//!
+//! Jetstream serves the repository event stream as JSON, with records
+//! already extracted from their blocks and optional filtering by collection
+//! and account. It suits consumers that don't need to verify commits.
+//!
+//! Events are identified by `time_us`, a microsecond timestamp that doubles as
+//! the cursor. Jetstream only guarantees ordering per account, so
+//! [`Jetstream`] reconnects from slightly before the last event it read
+//! ([`JetstreamConfig::rewind`]). Consumers should tolerate seeing an event
+//! more than once.
+//!
+//! In compressed mode each message is a zstd frame compressed with the
+//! dictionary Jetstream publishes, which must be supplied with
+//! [`JetstreamConfig::with_compression`].
+//!
+//! Reference: <https://github.com/bluesky-social/jetstream>
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use atproto_client::stream::{Jetstream, JetstreamConfig, JetstreamEvent};
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Post {
+//!     text: String,
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let config = JetstreamConfig::new("wss://jetstream2.us-east.bsky.network")
+//!         .with_wanted_collections(["app.bsky.feed.post"]);
+//!     let mut jetstream = Jetstream::connect(config);
+//!
+//!     while let Some(Ok(event)) = jetstream.next_event().await {
+//!         if let JetstreamEvent::Commit { did, commit, .. } = &event {
+//!             if let Ok(Some(post)) = commit.record_as::<Post>() {
+//!                 println!("{}: {}", did, post.text);
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use super::{websocket_base, AccountEvent, IdentityEvent, RepoAction, Result, StreamError};
+use futures_util::{Stream, StreamExt};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Public Jetstream instance operated by Bluesky
+pub const DEFAULT_JETSTREAM: &str = "wss://jetstream2.us-east.bsky.network";
+
+/// Most collections a subscription may filter on
+pub const MAX_WANTED_COLLECTIONS: usize = 100;
+
+/// Most accounts a subscription may filter on
+pub const MAX_WANTED_DIDS: usize = 10_000;
+
+/// Largest decompressed event accepted in compressed mode
+pub const MAX_DECOMPRESSED_SIZE: usize = 4 * 1024 * 1024;
+
+/// A record operation from a Jetstream commit event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JetstreamCommit {
+    /// Revision of the commit
+    pub rev: String,
+    /// Operation type
+    pub operation: RepoAction,
+    /// Collection NSID
+    pub collection: String,
+    /// Record key
+    pub rkey: String,
+    /// The record (absent for deletes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record: Option<serde_json::Value>,
+    /// CID of the record (absent for deletes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+}
+
+impl JetstreamCommit {
+    /// Deserialize the record into a typed record struct
+    ///
+    /// # Returns
+    ///
+    /// `None` for deletes, which carry no record
+    ///
+    /// # Errors
+    ///
+    /// - `StreamError::Json` - The record doesn't match `T`
+    pub fn record_as<T>(&self) -> Result<Option<T>>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        match &self.record {
+            Some(record) => Ok(Some(T::deserialize(record)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// An event from Jetstream
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum JetstreamEvent {
+    /// A record was created, updated or deleted
+    Commit {
+        /// DID of the repository
+        did: String,
+        /// Event time in microseconds since the Unix epoch (the cursor)
+        time_us: i64,
+        /// The record operation
+        commit: JetstreamCommit,
+    },
+    /// The account's handle or DID document may have changed
+    Identity {
+        /// DID of the account
+        did: String,
+        /// Event time in microseconds since the Unix epoch (the cursor)
+        time_us: i64,
+        /// Identity details
+        identity: IdentityEvent,
+    },
+    /// The account's hosting status changed
+    Account {
+        /// DID of the account
+        did: String,
+        /// Event time in microseconds since the Unix epoch (the cursor)
+        time_us: i64,
+        /// Account details
+        account: AccountEvent,
+    },
+}
+
+impl JetstreamEvent {
+    /// Get the DID of the account the event is about
+    pub fn did(&self) -> &str {
+        match self {
+            Self::Commit { did, .. } | Self::Identity { did, .. } | Self::Account { did, .. } => {
+                did
+            }
+        }
+    }
+
+    /// Get the event time in microseconds, used as the cursor
+    pub fn time_us(&self) -> i64 {
+        match self {
+            Self::Commit { time_us, .. }
+            | Self::Identity { time_us, .. }
+            | Self::Account { time_us, .. } => *time_us,
+        }
+    }
+}
+
+/// Configuration for a Jetstream subscription
+#[derive(Debug, Clone)]
+pub struct JetstreamConfig {
+    /// Jetstream host (e.g., "wss://jetstream2.us-east.bsky.network")
+    pub service: String,
+    /// Collections to receive commits for (NSIDs or prefixes like `app.bsky.graph.*`)
+    pub wanted_collections: Vec<String>,
+    /// Accounts to receive events for
+    pub wanted_dids: Vec<String>,
+    /// Microsecond timestamp to start from
+    pub cursor: Option<i64>,
+    /// zstd dictionary; set to request compressed messages
+    pub dictionary: Option<Vec<u8>>,
+    /// How far before the last event read to resume after a reconnect
+    pub rewind: Duration,
+    /// Number of decoded events buffered ahead of the consumer
+    pub buffer_size: usize,
+    /// Reconnection attempts before giving up (0 to disable)
+    pub max_reconnects: u32,
+    /// Delay before the first reconnection attempt
+    pub reconnect_delay: Duration,
+    /// Upper bound for the exponential reconnection backoff
+    pub max_reconnect_delay: Duration,
+}
+
+impl Default for JetstreamConfig {
+    fn default() -> Self {
+        Self::new(DEFAULT_JETSTREAM)
+    }
+}
+
+impl JetstreamConfig {
+    /// Create a configuration for a Jetstream host
+    pub fn new(service: impl Into<String>) -> Self {
+        Self {
+            service: service.into(),
+            wanted_collections: Vec::new(),
+            wanted_dids: Vec::new(),
+            cursor: None,
+            dictionary: None,
+            rewind: Duration::from_secs(3),
+            buffer_size: 1024,
+            max_reconnects: 10,
+            reconnect_delay: Duration::from_secs(1),
+            max_reconnect_delay: Duration::from_secs(60),
+        }
+    }
+
+    /// Only receive commits to these collections
+    ///
+    /// At most [`MAX_WANTED_COLLECTIONS`] are kept.
+    pub fn with_wanted_collections<I, S>(mut self, collections: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.wanted_collections = collections
+            .into_iter()
+            .map(Into::into)
+            .take(MAX_WANTED_COLLECTIONS)
+            .collect();
+        self
+    }
+
+    /// Only receive events for these accounts
+    ///
+    /// At most [`MAX_WANTED_DIDS`] are kept.
+    pub fn with_wanted_dids<I, S>(mut self, dids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.wanted_dids = dids
+            .into_iter()
+            .map(Into::into)
+            .take(MAX_WANTED_DIDS)
+            .collect();
+        self
+    }
+
+    /// Start from a microsecond timestamp
+    pub fn with_cursor(mut self, time_us: i64) -> Self {
+        self.cursor = Some(time_us);
+        self
+    }
+
+    /// Request zstd-compressed messages, decoded with the given dictionary
+    pub fn with_compression(mut self, dictionary: Vec<u8>) -> Self {
+        self.dictionary = Some(dictionary);
+        self
+    }
+
+    /// Set how far to rewind the cursor when reconnecting
+    pub fn with_rewind(mut self, rewind: Duration) -> Self {
+        self.rewind = rewind;
+        self
+    }
+
+    /// Set the number of events buffered ahead of the consumer (at least 1)
+    pub fn with_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = buffer_size.max(1);
+        self
+    }
+
+    /// Set the number of reconnection attempts and the initial backoff
+    pub fn with_reconnect(mut self, max_reconnects: u32, delay: Duration) -> Self {
+        self.max_reconnects = max_reconnects;
+        self.reconnect_delay = delay;
+        self
+    }
+
+    /// Build the subscription URL for a cursor
+    fn url(&self, cursor: Option<i64>) -> Result<Url> {
+        let base = format!("{}/subscribe", websocket_base(&self.service));
+        let mut url = Url::parse(&base)
+            .map_err(|e| StreamError::Connection(format!("Invalid URL {}: {}", base, e)))?;
+        {
+            let mut query = url.query_pairs_mut();
+            for collection in &self.wanted_collections {
+                query.append_pair("wantedCollections", collection);
+            }
+            for did in &self.wanted_dids {
+                query.append_pair("wantedDids", did);
+            }
+            if let Some(cursor) = cursor {
+                query.append_pair("cursor", &cursor.to_string());
+            }
+            if self.dictionary.is_some() {
+                query.append_pair("compress", "true");
+            }
+        }
+        if url.query() == Some("") {
+            url.set_query(None);
+        }
+        Ok(url)
+    }
+
+    /// Decode a message into an event
+    fn decode(&self, message: Message) -> Option<Result<JetstreamEvent>> {
+        let json = match message {
+            Message::Text(text) => text.into_bytes(),
+            Message::Binary(data) => match &self.dictionary {
+                Some(dictionary) => match decompress(&data, dictionary) {
+                    Ok(json) => json,
+                    Err(e) => return Some(Err(e)),
+                },
+                None => data,
+            },
+            _ => return None,
+        };
+        Some(serde_json::from_slice(&json).map_err(StreamError::from))
+    }
+}
+
+/// Decompress a zstd frame using a dictionary
+fn decompress(data: &[u8], dictionary: &[u8]) -> Result<Vec<u8>> {
+    let mut json = Vec::new();
+    zstd::stream::read::Decoder::with_dictionary(data, dictionary)
+        .and_then(|decoder| {
+            decoder
+                .take(MAX_DECOMPRESSED_SIZE as u64 + 1)
+                .read_to_end(&mut json)
+        })
+        .map_err(|e| StreamError::Decompression(e.to_string()))?;
+    if json.len() > MAX_DECOMPRESSED_SIZE {
+        return Err(StreamError::Decompression(format!(
+            "event exceeds {} bytes",
+            MAX_DECOMPRESSED_SIZE
+        )));
+    }
+    Ok(json)
+}
+
+/// A Jetstream subscription
+///
+/// Implements [`Stream`], yielding decoded events in order. Messages that fail
+/// to decode are yielded as errors without ending the stream. The stream ends
+/// after a connection error once reconnection attempts are exhausted.
+///
+/// Dropping the subscription closes the connection.
+#[derive(Debug)]
+pub struct Jetstream {
+    events: mpsc::Receiver<Result<JetstreamEvent>>,
+    cursor: Option<i64>,
+    task: JoinHandle<()>,
+}
+
+impl Jetstream {
+    /// Connect to Jetstream
+    ///
+    /// The connection is made on a background task, so connection errors are
+    /// reported through the stream. Must be called within a Tokio runtime.
+    pub fn connect(config: JetstreamConfig) -> Self {
+        let cursor = config.cursor;
+        let (sender, events) = mpsc::channel(config.buffer_size.max(1));
+        let task = tokio::spawn(run(config, sender));
+        Self { events, cursor, task }
+    }
+
+    /// Get the `time_us` of the last event received by the consumer
+    ///
+    /// Before any event arrives, this is the configured starting cursor.
+    pub fn cursor(&self) -> Option<i64> {
+        self.cursor
+    }
+
+    /// Wait for the next event
+    ///
+    /// # Returns
+    ///
+    /// `None` once the stream has ended
+    pub async fn next_event(&mut self) -> Option<Result<JetstreamEvent>> {
+        self.next().await
+    }
+}
+
+impl Stream for Jetstream {
+    type Item = Result<JetstreamEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll = self.events.poll_recv(cx);
+        if let Poll::Ready(Some(Ok(event))) = &poll {
+            self.cursor = Some(event.time_us());
+        }
+        poll
+    }
+}
+
+impl Drop for Jetstream {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Read messages into the channel, reconnecting from the rewound cursor
+async fn run(config: JetstreamConfig, sender: mpsc::Sender<Result<JetstreamEvent>>) {
+    let rewind = i64::try_from(config.rewind.as_micros()).unwrap_or(i64::MAX);
+    let mut cursor = config.cursor;
+    let mut last_read = None;
+    let mut attempts = 0;
+    let mut delay = config.reconnect_delay;
+
+    loop {
+        let url = match config.url(cursor) {
+            Ok(url) => url,
+            Err(e) => {
+                let _ = sender.send(Err(e)).await;
+                return;
+            }
+        };
+
+        let error = match connect_async(url.as_str()).await {
+            Ok((mut socket, _)) => loop {
+                let message = match socket.next().await {
+                    Some(Ok(Message::Close(_))) | None => {
+                        break StreamError::Connection("Connection closed".to_string());
+                    }
+                    Some(Ok(message)) => message,
+                    Some(Err(e)) => break StreamError::Connection(e.to_string()),
+                };
+                let Some(event) = config.decode(message) else {
+                    continue;
+                };
+                attempts = 0;
+                delay = config.reconnect_delay;
+
+                if let Ok(event) = &event {
+                    last_read = Some(event.time_us());
+                }
+                if sender.send(event).await.is_err() {
+                    // The consumer is gone
+                    return;
+                }
+            },
+            Err(e) => StreamError::Connection(e.to_string()),
+        };
+
+        if attempts >= config.max_reconnects {
+            let _ = sender.send(Err(error)).await;
+            return;
+        }
+        if let Some(time_us) = last_read {
+            cursor = Some(time_us.saturating_sub(rewind));
+        }
+        attempts += 1;
+        tracing::warn!(
+            "Jetstream disconnected ({}), reconnecting in {:?} (attempt {})",
+            error,
+            delay,
+            attempts
+        );
+        tokio::time::sleep(delay).await;
+        delay = (delay * 2).min(config.max_reconnect_delay);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::SinkExt;
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+    const DICTIONARY: &[u8] = br#"{"did":"did:plc:","time_us":,"kind":"commit","commit":{"rev":"","operation":"create","collection":"app.bsky.feed.post","rkey":"","record":{"$type":"app.bsky.feed.post","text":"","createdAt":""},"cid":"bafyrei"}}"#;
+
+    #[derive(Debug, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Like {
+        subject: serde_json::Value,
+        created_at: String,
+    }
+
+    fn like(time_us: i64) -> serde_json::Value {
+        json!({
+            "did": "did:plc:alice",
+            "time_us": time_us,
+            "kind": "commit",
+            "commit": {
+                "rev": "3l3qo2vutsw2b",
+                "operation": "create",
+                "collection": "app.bsky.feed.like",
+                "rkey": "3l3qo2vuowo2b",
+                "record": {
+                    "$type": "app.bsky.feed.like",
+                    "createdAt": "2024-09-09T19:46:02.102Z",
+                    "subject": {
+                        "cid": "bafyreidc6sydkkbchcyg62v77wbhzvb2mvytlmsychqgwf2xojjtirmzj4",
+                        "uri": "at://did:plc:bob/app.bsky.feed.post/3l3pte3p2e325"
+                    }
+                },
+                "cid": "bafyreidwaivazkwu67xztlmuobx35hs2lnfh3kolmgfmucldvhd3sgzcqi"
+            }
+        })
+    }
+
+    /// Serve one recorded session per connection, recording request URIs
+    async fn replay(sessions: Vec<Vec<Message>>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let uris = Arc::new(Mutex::new(Vec::new()));
+        let recorded = uris.clone();
+
+        tokio::spawn(async move {
+            for messages in sessions {
+                let (stream, _) = listener.accept().await.unwrap();
+                let uris = uris.clone();
+                #[allow(clippy::result_large_err)]
+                let callback = move |request: &Request, response: Response| {
+                    uris.lock().unwrap().push(request.uri().to_string());
+                    Ok(response)
+                };
+                let mut socket = tokio_tungstenite::accept_hdr_async(stream, callback)
+                    .await
+                    .unwrap();
+                for message in messages {
+                    socket.send(message).await.unwrap();
+                }
+                let _ = socket.close(None).await;
+            }
+        });
+
+        (format!("ws://{}", address), recorded)
+    }
+
+    fn text(value: serde_json::Value) -> Message {
+        Message::Text(value.to_string())
+    }
+
+    #[test]
+    fn test_subscription_url() {
+        let config = JetstreamConfig::new("https://jetstream.example.com")
+            .with_wanted_collections(["app.bsky.feed.post", "app.bsky.graph.*"])
+            .with_wanted_dids(["did:plc:alice"])
+            .with_compression(DICTIONARY.to_vec());
+        assert_eq!(
+            config.url(Some(1725911162329308)).unwrap().as_str(),
+            "wss://jetstream.example.com/subscribe?wantedCollections=app.bsky.feed.post\
+             &wantedCollections=app.bsky.graph.*&wantedDids=did%3Aplc%3Aalice\
+             &cursor=1725911162329308&compress=true"
+        );
+        assert_eq!(
+            JetstreamConfig::default().url(None).unwrap().as_str(),
+            "wss://jetstream2.us-east.bsky.network/subscribe"
+        );
+
+        let dids = (0..MAX_WANTED_DIDS + 1).map(|i| format!("did:plc:{}", i));
+        assert_eq!(
+            JetstreamConfig::default()
+                .with_wanted_dids(dids)
+                .wanted_dids
+                .len(),
+            MAX_WANTED_DIDS
+        );
+    }
+
+    #[test]
+    fn test_decode_events() {
+        let event: JetstreamEvent = serde_json::from_value(like(1725911162329308)).unwrap();
+        assert_eq!(event.did(), "did:plc:alice");
+        assert_eq!(event.time_us(), 1725911162329308);
+        let JetstreamEvent::Commit { commit, .. } = &event else {
+            panic!("Expected commit event");
+        };
+        assert_eq!(commit.operation, RepoAction::Create);
+        let like: Like = commit.record_as().unwrap().unwrap();
+        assert_eq!(like.created_at, "2024-09-09T19:46:02.102Z");
+        assert_eq!(like.subject["uri"], "at://did:plc:bob/app.bsky.feed.post/3l3pte3p2e325");
+
+        let delete: JetstreamEvent = serde_json::from_value(json!({
+            "did": "did:plc:alice",
+            "time_us": 2,
+            "kind": "commit",
+            "commit": {"rev": "3l3", "operation": "delete",
+                       "collection": "app.bsky.feed.like", "rkey": "3l3qo2vuowo2b"}
+        }))
+        .unwrap();
+        let JetstreamEvent::Commit { commit, .. } = &delete else {
+            panic!("Expected commit event");
+        };
+        assert!(commit.record_as::<Like>().unwrap().is_none());
+
+        let identity: JetstreamEvent = serde_json::from_value(json!({
+            "did": "did:plc:alice",
+            "time_us": 3,
+            "kind": "identity",
+            "identity": {"did": "did:plc:alice", "handle": "alice.test",
+                         "seq": 1409752997, "time": "2024-09-05T06:11:04.870Z"}
+        }))
+        .unwrap();
+        assert!(matches!(
+            identity,
+            JetstreamEvent::Identity { ref identity, .. } if identity.handle.as_deref() == Some("alice.test")
+        ));
+
+        let account: JetstreamEvent = serde_json::from_value(json!({
+            "did": "did:plc:alice",
+            "time_us": 4,
+            "kind": "account",
+            "account": {"active": false, "did": "did:plc:alice", "seq": 1409753013,
+                        "time": "2024-09-05T06:11:04.870Z", "status": "takendown"}
+        }))
+        .unwrap();
+        assert!(matches!(account, JetstreamEvent::Account { ref account, .. } if !account.active));
+    }
+
+    #[tokio::test]
+    async fn test_compressed_session() {
+        let compress = |value: serde_json::Value| {
+            let mut compressor = zstd::block::Compressor::with_dict(DICTIONARY.to_vec());
+            Message::Binary(
+                compressor
+                    .compress(value.to_string().as_bytes(), 3)
+                    .unwrap(),
+            )
+        };
+        let (service, uris) = replay(vec![vec![compress(like(10)), compress(like(11))]]).await;
+        let config = JetstreamConfig::new(service)
+            .with_wanted_collections(["app.bsky.feed.like"])
+            .with_compression(DICTIONARY.to_vec())
+            .with_reconnect(0, Duration::ZERO);
+        let mut jetstream = Jetstream::connect(config);
+
+        assert_eq!(jetstream.next_event().await.unwrap().unwrap().time_us(), 10);
+        assert_eq!(jetstream.next_event().await.unwrap().unwrap().time_us(), 11);
+        assert_eq!(jetstream.cursor(), Some(11));
+        assert!(matches!(jetstream.next_event().await, Some(Err(StreamError::Connection(_)))));
+        assert_eq!(
+            uris.lock().unwrap()[0],
+            "/subscribe?wantedCollections=app.bsky.feed.like&compress=true"
+        );
+    }
+
+    #[test]
+    fn test_decompression_is_capped() {
+        let mut compressor = zstd::block::Compressor::with_dict(DICTIONARY.to_vec());
+        let event = like(10).to_string();
+        let frame = compressor.compress(event.as_bytes(), 3).unwrap();
+        assert_eq!(decompress(&frame, DICTIONARY).unwrap(), event.as_bytes());
+
+        let oversized = vec![b' '; MAX_DECOMPRESSED_SIZE + 1];
+        let frame = compressor.compress(&oversized, 3).unwrap();
+        assert!(frame.len() < 1024);
+        assert!(matches!(decompress(&frame, DICTIONARY), Err(StreamError::Decompression(_))));
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_rewinds_cursor() {
+        let (service, uris) = replay(vec![
+            vec![text(like(5_000_000)), text(json!({"kind": "unknown"})), text(like(6_000_000))],
+            vec![text(like(6_000_000)), text(like(7_000_000))],
+        ])
+        .await;
+        let config = JetstreamConfig::new(service)
+            .with_cursor(1_000_000)
+            .with_rewind(Duration::from_secs(2))
+            .with_reconnect(1, Duration::from_millis(10));
+        let jetstream = Jetstream::connect(config);
+
+        let events: Vec<_> = jetstream.take(5).collect().await;
+        assert!(matches!(events[1], Err(StreamError::Json(_))));
+        let times: Vec<_> = events
+            .iter()
+            .filter_map(|event| event.as_ref().ok())
+            .map(JetstreamEvent::time_us)
+            .collect();
+        assert_eq!(times, vec![5_000_000, 6_000_000, 6_000_000, 7_000_000]);
+
+        let uris = uris.lock().unwrap();
+        assert_eq!(uris[0], "/subscribe?cursor=1000000");
+        assert_eq!(uris[1], "/subscribe?cursor=4000000");
+    }
+}
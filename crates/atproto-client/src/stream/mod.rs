@@ -0,0 +1,112 @@
+//! Event stream subscriptions
+//!
+//! Lexicon `subscription` endpoints are served over WebSockets. Each binary
+//! message is a frame: a DAG-CBOR header (`op` and message type `t`) followed
+//! by a DAG-CBOR body. Error frames have `op = -1` and an `{error, message}`
+//! body, after which the server closes the connection.
+//!
+//! This module provides:
+//! - [`Frame`] - frame decoding and encoding
+//! - [`Firehose`] - a consumer for `com.atproto.sync.subscribeRepos`
+//! - [`Jetstream`] - a consumer for Jetstream, a filtered JSON rendition of
+//!   the firehose
+//!
+//! Reference: <https://atproto.com/specs/event-stream>
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use atproto_client::stream::{Firehose, FirehoseConfig, FirehoseEvent};
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let config = FirehoseConfig::new("wss://bsky.network").with_cursor(123_456);
+//!     let mut firehose = Firehose::connect(config);
+//!
+//!     while let Some(event) = firehose.next_event().await {
+//!         match event {
+//!             Ok(FirehoseEvent::Commit(commit)) => {
+//!                 for op in &commit.ops {
+//!                     println!("{} {:?} {}", commit.repo, op.action, op.path);
+//!                 }
+//!             }
+//!             Ok(_) => {}
+//!             Err(e) => eprintln!("Stream error: {}", e),
+//!         }
+//!         // Persist this to resume after a restart
+//!         let _cursor = firehose.cursor();
+//!     }
+//! }
+//! ```
+
+pub mod firehose;
+pub mod frame;
+pub mod jetstream;
+
+pub use firehose::{
+    AccountEvent, CommitEvent, Firehose, FirehoseConfig, FirehoseEvent, IdentityEvent, InfoEvent,
+    RepoAction, RepoOp, SyncEvent,
+};
+pub use frame::{Frame, FrameHeader};
+pub use jetstream::{Jetstream, JetstreamCommit, JetstreamConfig, JetstreamEvent};
+
+use crate::lexicon::cbor::CborError;
+use crate::repo::RepoError;
+use thiserror::Error;
+
+/// Errors that can occur while consuming an event stream
+#[derive(Debug, Error)]
+pub enum StreamError {
+    /// WebSocket connection failed or was lost
+    #[error("Connection error: {0}")]
+    Connection(String),
+
+    /// Malformed frame
+    #[error("Invalid frame: {0}")]
+    InvalidFrame(String),
+
+    /// The server sent an error frame
+    #[error("Stream error {error}: {}", message.as_deref().unwrap_or(""))]
+    Server {
+        /// Error name (e.g., "FutureCursor", "ConsumerTooSlow")
+        error: String,
+        /// Human-readable error message
+        message: Option<String>,
+    },
+
+    /// Frame body could not be decoded
+    #[error("CBOR error: {0}")]
+    Cbor(#[from] CborError),
+
+    /// Blocks attached to an event could not be read
+    #[error("Repository error: {0}")]
+    Repo(#[from] RepoError),
+
+    /// JSON message or record could not be decoded
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// Compressed message could not be decompressed
+    #[error("Decompression error: {0}")]
+    Decompression(String),
+}
+
+/// Result type for event stream operations
+pub type Result<T> = std::result::Result<T, StreamError>;
+
+/// Normalize a service host to a WebSocket base URL
+///
+/// `https://` and `http://` become `wss://` and `ws://`, and hosts without a
+/// scheme use `wss://`.
+fn websocket_base(service: &str) -> String {
+    let service = service.trim_end_matches('/');
+    if let Some(host) = service.strip_prefix("https://") {
+        format!("wss://{}", host)
+    } else if let Some(host) = service.strip_prefix("http://") {
+        format!("ws://{}", host)
+    } else if service.starts_with("wss://") || service.starts_with("ws://") {
+        service.to_string()
+    } else {
+        format!("wss://{}", service)
+    }
+}
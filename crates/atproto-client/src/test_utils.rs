@@ -237,6 +237,117 @@ pub mod xrpc {
     }
 }
 
+/// Local stand-ins for identity resolution backends
+pub mod identity {
+    use crate::identity::{
+        DidDocument, DidResolver, HandleResolver, IdentityError, IdentityResolver,
+        IdentityResolverConfig, Result,
+    };
+    use crate::types::{Did, Handle};
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Handle backend backed by a fixed handle -> DID map
+    pub struct StaticHandleResolver {
+        records: HashMap<String, String>,
+        calls: AtomicUsize,
+    }
+
+    impl StaticHandleResolver {
+        pub fn new(records: &[(&str, &str)]) -> Self {
+            Self {
+                records: records
+                    .iter()
+                    .map(|(h, d)| (h.to_string(), d.to_string()))
+                    .collect(),
+                calls: AtomicUsize::new(0),
+            }
+        }
+
+        /// Number of lookups performed
+        pub fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl HandleResolver for StaticHandleResolver {
+        async fn resolve_handle(&self, handle: &Handle) -> Result<Option<Did>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(self
+                .records
+                .get(handle.as_str())
+                .map(|d| Did::new(d.clone()).unwrap()))
+        }
+    }
+
+    /// Handle backend whose lookups always fail, like an unreachable server
+    pub struct FailingHandleResolver;
+
+    #[async_trait]
+    impl HandleResolver for FailingHandleResolver {
+        async fn resolve_handle(&self, _handle: &Handle) -> Result<Option<Did>> {
+            Err(IdentityError::Network("connection timed out".to_string()))
+        }
+    }
+
+    /// DID backend backed by a fixed set of documents
+    pub struct StaticDidResolver {
+        docs: HashMap<String, DidDocument>,
+        calls: AtomicUsize,
+    }
+
+    impl StaticDidResolver {
+        pub fn new(docs: Vec<DidDocument>) -> Self {
+            Self {
+                docs: docs.into_iter().map(|d| (d.id.clone(), d)).collect(),
+                calls: AtomicUsize::new(0),
+            }
+        }
+
+        /// Number of lookups performed
+        pub fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl DidResolver for StaticDidResolver {
+        async fn resolve_did(&self, did: &Did) -> Result<DidDocument> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.docs
+                .get(did.as_str())
+                .cloned()
+                .ok_or_else(|| IdentityError::DidNotFound(did.to_string()))
+        }
+    }
+
+    /// Build a DID document claiming a handle and hosted on a PDS
+    pub fn did_document(did: &str, handle: &str, pds: &str) -> DidDocument {
+        serde_json::from_value(serde_json::json!({
+            "id": did,
+            "alsoKnownAs": [format!("at://{}", handle)],
+            "service": [{
+                "id": "#atproto_pds",
+                "type": "AtprotoPersonalDataServer",
+                "serviceEndpoint": pds
+            }]
+        }))
+        .unwrap()
+    }
+
+    /// Build a resolver for a single account using local stand-ins
+    pub fn resolver_for(did: &str, handle: &str, pds: &str) -> IdentityResolver {
+        IdentityResolver::new(IdentityResolverConfig::default())
+            .with_handle_resolvers(vec![Arc::new(StaticHandleResolver::new(&[(handle, did)]))])
+            .with_did_resolver(Arc::new(StaticDidResolver::new(vec![did_document(
+                did, handle, pds,
+            )])))
+    }
+}
+
 /// Assertion helpers for AT Protocol types
 pub mod assertions {
     use super::*;
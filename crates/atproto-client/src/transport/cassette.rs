@@ -0,0 +1,454 @@
+//! Record/replay transport backed by JSON cassettes
+
+use super::Transport;
+use crate::xrpc::{HttpMethod, HttpRequest, HttpResponse, XrpcError};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Whether a [`RecordReplayTransport`] is recording or replaying
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Send requests through the inner transport and save each interaction
+    Record,
+    /// Answer requests from the cassette without touching the network
+    Replay,
+}
+
+/// A request or response body stored in a cassette
+///
+/// JSON bodies are stored as JSON so cassettes stay readable and diffable;
+/// other bodies are stored as text or base64.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "encoding", content = "data", rename_all = "lowercase")]
+pub enum CassetteBody {
+    /// JSON body
+    Json(serde_json::Value),
+    /// UTF-8 text body
+    Text(String),
+    /// Binary body, base64 encoded
+    Base64(String),
+}
+
+impl CassetteBody {
+    /// Store raw body bytes, or `None` for an empty body
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        use base64::Engine;
+
+        if bytes.is_empty() {
+            return None;
+        }
+        if let Ok(value) = serde_json::from_slice(bytes) {
+            return Some(Self::Json(value));
+        }
+        Some(match std::str::from_utf8(bytes) {
+            Ok(text) => Self::Text(text.to_string()),
+            Err(_) => Self::Base64(base64::engine::general_purpose::STANDARD.encode(bytes)),
+        })
+    }
+
+    /// Get the raw body bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        use base64::Engine;
+
+        match self {
+            Self::Json(value) => serde_json::to_vec(value).unwrap_or_default(),
+            Self::Text(text) => text.as_bytes().to_vec(),
+            Self::Base64(data) => base64::engine::general_purpose::STANDARD
+                .decode(data)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// A recorded request
+///
+/// Request headers are not stored, so credentials (Authorization, DPoP) never
+/// end up in a cassette.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CassetteRequest {
+    /// HTTP method
+    pub method: HttpMethod,
+    /// NSID of the XRPC method
+    pub nsid: String,
+    /// Full URL without query parameters
+    pub url: String,
+    /// Query parameters
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<(String, String)>,
+    /// Request body
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<CassetteBody>,
+}
+
+impl CassetteRequest {
+    fn from_request(request: &HttpRequest) -> Self {
+        Self {
+            method: request.method,
+            nsid: request.nsid.clone(),
+            url: request.url.clone(),
+            params: request.params.clone(),
+            body: request.body.as_deref().and_then(CassetteBody::from_bytes),
+        }
+    }
+
+    /// Check if this recorded request matches another
+    ///
+    /// Requests match on method, NSID, query parameters (in any order) and
+    /// body. The service URL is ignored so cassettes can be replayed against
+    /// any host.
+    fn matches(&self, other: &CassetteRequest) -> bool {
+        let sorted = |params: &[(String, String)]| {
+            let mut params = params.to_vec();
+            params.sort();
+            params
+        };
+
+        self.method == other.method
+            && self.nsid == other.nsid
+            && sorted(&self.params) == sorted(&other.params)
+            && self.body == other.body
+    }
+}
+
+/// A recorded response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CassetteResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// Response body
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<CassetteBody>,
+}
+
+impl CassetteResponse {
+    fn from_response(response: &HttpResponse) -> Self {
+        Self {
+            status: response.status,
+            headers: response.headers.clone(),
+            body: CassetteBody::from_bytes(&response.body),
+        }
+    }
+
+    fn to_response(&self) -> HttpResponse {
+        let body = self
+            .body
+            .as_ref()
+            .map(CassetteBody::to_bytes)
+            .unwrap_or_default();
+        HttpResponse::new(self.status, self.headers.clone(), body)
+    }
+}
+
+/// A recorded request/response pair
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The request that was sent
+    pub request: CassetteRequest,
+    /// The response that was received
+    pub response: CassetteResponse,
+}
+
+/// A list of recorded interactions, stored as a JSON file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Cassette {
+    /// Interactions in the order they were recorded
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Load a cassette from a JSON file
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let data = std::fs::read(path)?;
+        serde_json::from_slice(&data).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Save the cassette as pretty-printed JSON, creating parent directories
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let data = serde_json::to_vec_pretty(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, data)
+    }
+}
+
+#[derive(Debug)]
+struct CassetteState {
+    cassette: Cassette,
+    /// Which interactions have already been replayed
+    used: Vec<bool>,
+}
+
+/// Transport that records real traffic to a cassette and replays it offline
+///
+/// In record mode, requests are sent through an inner transport and every
+/// response is appended to the cassette, which is saved after each interaction.
+/// In replay mode, each request is answered with the first unused recorded
+/// interaction that matches it; requests with no match fail with a
+/// `CassetteMiss` error (status 0).
+///
+/// Response bodies are stored verbatim, so cassettes recorded while logging in
+/// contain session tokens and should not be committed.
+///
+/// # Examples
+/// ```rust,no_run
+/// use atproto_client::transport::{RecordReplayTransport, ReqwestTransport};
+/// use atproto_client::xrpc::{XrpcClient, XrpcClientConfig};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// // Record on the first run, replay on later runs
+/// let inner = Arc::new(ReqwestTransport::new(Duration::from_secs(30), "tests"));
+/// let transport = RecordReplayTransport::auto("tests/cassettes/timeline.json", inner).unwrap();
+///
+/// let config = XrpcClientConfig::new("https://bsky.social").with_transport(Arc::new(transport));
+/// let client = XrpcClient::new(config);
+/// ```
+#[derive(Debug)]
+pub struct RecordReplayTransport {
+    path: PathBuf,
+    mode: CassetteMode,
+    inner: Option<Arc<dyn Transport>>,
+    state: Mutex<CassetteState>,
+}
+
+impl RecordReplayTransport {
+    /// Record interactions through `inner` into a new cassette at `path`
+    ///
+    /// Any existing cassette at `path` is overwritten on the first interaction.
+    pub fn record(path: impl Into<PathBuf>, inner: Arc<dyn Transport>) -> Self {
+        Self {
+            path: path.into(),
+            mode: CassetteMode::Record,
+            inner: Some(inner),
+            state: Mutex::new(CassetteState { cassette: Cassette::default(), used: Vec::new() }),
+        }
+    }
+
+    /// Replay interactions from the cassette at `path`
+    pub fn replay(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let cassette = Cassette::load(&path)?;
+        let used = vec![false; cassette.interactions.len()];
+
+        Ok(Self {
+            path,
+            mode: CassetteMode::Replay,
+            inner: None,
+            state: Mutex::new(CassetteState { cassette, used }),
+        })
+    }
+
+    /// Replay the cassette at `path` if it exists, otherwise record it
+    pub fn auto(path: impl Into<PathBuf>, inner: Arc<dyn Transport>) -> io::Result<Self> {
+        let path = path.into();
+        if path.exists() {
+            Self::replay(path)
+        } else {
+            Ok(Self::record(path, inner))
+        }
+    }
+
+    /// Get the current mode
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// Get the cassette path
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Get a copy of the cassette
+    pub fn cassette(&self) -> Cassette {
+        self.state.lock().unwrap().cassette.clone()
+    }
+
+    /// Count recorded interactions that have not been replayed
+    pub fn remaining(&self) -> usize {
+        self.state
+            .lock()
+            .unwrap()
+            .used
+            .iter()
+            .filter(|used| !**used)
+            .count()
+    }
+
+    /// Save the cassette to its path
+    pub fn save(&self) -> io::Result<()> {
+        self.state.lock().unwrap().cassette.save(&self.path)
+    }
+
+    fn replay_request(&self, request: &HttpRequest) -> Result<HttpResponse, XrpcError> {
+        let recorded = CassetteRequest::from_request(request);
+        let mut state = self.state.lock().unwrap();
+        let CassetteState { cassette, used } = &mut *state;
+
+        let index = cassette
+            .interactions
+            .iter()
+            .enumerate()
+            .position(|(i, interaction)| !used[i] && interaction.request.matches(&recorded))
+            .ok_or_else(|| {
+                XrpcError::new(
+                    0,
+                    "CassetteMiss",
+                    format!(
+                        "No recorded interaction for {} {} in {}",
+                        request.method.as_str(),
+                        request.nsid,
+                        self.path.display()
+                    ),
+                )
+            })?;
+
+        used[index] = true;
+        Ok(cassette.interactions[index].response.to_response())
+    }
+}
+
+#[async_trait]
+impl Transport for RecordReplayTransport {
+    async fn send(&self, mut request: HttpRequest) -> Result<HttpResponse, XrpcError> {
+        // Cassettes store whole bodies
+        request.buffer_stream().await?;
+        let inner = match (self.mode, &self.inner) {
+            (CassetteMode::Record, Some(inner)) => inner,
+            _ => return self.replay_request(&request),
+        };
+
+        let recorded = CassetteRequest::from_request(&request);
+        let response = inner.send(request).await?;
+
+        let mut state = self.state.lock().unwrap();
+        state.cassette.interactions.push(Interaction {
+            request: recorded,
+            response: CassetteResponse::from_response(&response),
+        });
+        state.used.push(true);
+        if let Err(e) = state.cassette.save(&self.path) {
+            tracing::warn!("Failed to save cassette {}: {}", self.path.display(), e);
+        }
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use crate::xrpc::{XrpcClient, XrpcClientConfig, XrpcRequest};
+    use serde_json::json;
+
+    fn client(transport: Arc<dyn Transport>, service_url: &str) -> XrpcClient {
+        XrpcClient::new(XrpcClientConfig::new(service_url).with_transport(transport))
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cassettes/profile.json");
+
+        let mock = Arc::new(MockTransport::new().with_json(
+            "app.bsky.actor.getProfile",
+            200,
+            json!({"did": "did:plc:alice", "handle": "alice.test"}),
+        ));
+        let recorder = Arc::new(RecordReplayTransport::record(&path, mock.clone()));
+        let mut recording = client(recorder.clone(), "https://bsky.social");
+        recording.set_auth_header(Some("secret-token".to_string()));
+
+        let request = XrpcRequest::query("app.bsky.actor.getProfile").param("actor", "alice.test");
+        recording.query::<serde_json::Value>(request).await.unwrap();
+        assert_eq!(recorder.cassette().interactions.len(), 1);
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        assert!(saved.contains("alice.test"));
+        assert!(!saved.contains("secret-token"));
+
+        // Replay against a different host without the inner transport
+        let player = Arc::new(RecordReplayTransport::replay(&path).unwrap());
+        assert_eq!(player.mode(), CassetteMode::Replay);
+        let replaying = client(player.clone(), "https://pds.example.com");
+
+        let request = XrpcRequest::query("app.bsky.actor.getProfile").param("actor", "alice.test");
+        let response = replaying.query::<serde_json::Value>(request).await.unwrap();
+        assert_eq!(response.data["did"], "did:plc:alice");
+        assert_eq!(player.remaining(), 0);
+        assert_eq!(mock.request_count("app.bsky.actor.getProfile"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_replay_miss_and_consumption_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("unread.json");
+
+        let request = |count: i64| Interaction {
+            request: CassetteRequest {
+                method: HttpMethod::Get,
+                nsid: "app.bsky.notification.getUnreadCount".to_string(),
+                url: "https://bsky.social/xrpc/app.bsky.notification.getUnreadCount".to_string(),
+                params: Vec::new(),
+                body: None,
+            },
+            response: CassetteResponse {
+                status: 200,
+                headers: HashMap::new(),
+                body: Some(CassetteBody::Json(json!({"count": count}))),
+            },
+        };
+        Cassette { interactions: vec![request(4), request(0)] }
+            .save(&path)
+            .unwrap();
+
+        let client =
+            client(Arc::new(RecordReplayTransport::replay(&path).unwrap()), "https://bsky.social");
+        for expected in [4, 0] {
+            let response = client
+                .query::<serde_json::Value>(XrpcRequest::query(
+                    "app.bsky.notification.getUnreadCount",
+                ))
+                .await
+                .unwrap();
+            assert_eq!(response.data["count"], expected);
+        }
+
+        let err = client
+            .query::<serde_json::Value>(XrpcRequest::query("app.bsky.notification.getUnreadCount"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.error(), "CassetteMiss");
+    }
+
+    #[test]
+    fn test_cassette_body_encoding() {
+        assert_eq!(CassetteBody::from_bytes(b""), None);
+        assert_eq!(
+            CassetteBody::from_bytes(br#"{"a":1}"#),
+            Some(CassetteBody::Json(json!({"a": 1})))
+        );
+        assert_eq!(
+            CassetteBody::from_bytes(b"hello"),
+            Some(CassetteBody::Text("hello".to_string()))
+        );
+
+        let binary = vec![0xff, 0x00, 0xfe];
+        let body = CassetteBody::from_bytes(&binary).unwrap();
+        assert!(matches!(body, CassetteBody::Base64(_)));
+        assert_eq!(body.to_bytes(), binary);
+
+        let json = serde_json::to_value(CassetteBody::Text("hi".to_string())).unwrap();
+        assert_eq!(json, json!({"encoding": "text", "data": "hi"}));
+    }
+}
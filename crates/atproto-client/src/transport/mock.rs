@@ -0,0 +1,195 @@
+//! In-memory mock transport
+
+use super::Transport;
+use crate::xrpc::{HttpRequest, HttpResponse, XrpcError};
+use async_trait::async_trait;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+/// Transport returning canned responses, keyed by NSID
+///
+/// Responses queued for an NSID are returned in order; the last one is
+/// repeated for any further requests. Requests for an NSID with no queued
+/// responses get a 501 `MethodNotImplemented` error response. Every request is
+/// recorded and can be inspected with [`requests`](MockTransport::requests);
+/// streamed bodies are read into `body` first.
+///
+/// # Examples
+/// ```
+/// use atproto_client::transport::MockTransport;
+///
+/// let transport = MockTransport::new()
+///     .with_json("com.atproto.server.describeServer", 200, serde_json::json!({
+///         "did": "did:web:bsky.social",
+///         "availableUserDomains": [".bsky.social"]
+///     }));
+/// assert_eq!(transport.request_count("com.atproto.server.describeServer"), 0);
+/// ```
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    responses: Mutex<HashMap<String, VecDeque<Result<HttpResponse, XrpcError>>>>,
+    requests: Mutex<Vec<HttpRequest>>,
+}
+
+impl MockTransport {
+    /// Create a mock transport with no responses
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a JSON response for an NSID
+    pub fn with_json(self, nsid: impl Into<String>, status: u16, body: serde_json::Value) -> Self {
+        self.push_json(nsid, status, body);
+        self
+    }
+
+    /// Queue a JSON response for an NSID
+    pub fn push_json(&self, nsid: impl Into<String>, status: u16, body: serde_json::Value) {
+        self.push_response(nsid, HttpResponse::json(status, &body));
+    }
+
+    /// Queue a raw response for an NSID
+    pub fn push_response(&self, nsid: impl Into<String>, response: HttpResponse) {
+        self.push(nsid.into(), Ok(response));
+    }
+
+    /// Queue a transport error (e.g., a network failure) for an NSID
+    pub fn push_error(&self, nsid: impl Into<String>, error: XrpcError) {
+        self.push(nsid.into(), Err(error));
+    }
+
+    fn push(&self, nsid: String, result: Result<HttpResponse, XrpcError>) {
+        self.responses
+            .lock()
+            .unwrap()
+            .entry(nsid)
+            .or_default()
+            .push_back(result);
+    }
+
+    /// Get all requests sent so far, oldest first
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Count requests sent for an NSID
+    pub fn request_count(&self, nsid: &str) -> usize {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|request| request.nsid == nsid)
+            .count()
+    }
+
+    /// Forget recorded requests and queued responses
+    pub fn reset(&self) {
+        self.responses.lock().unwrap().clear();
+        self.requests.lock().unwrap().clear();
+    }
+}
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn send(&self, mut request: HttpRequest) -> Result<HttpResponse, XrpcError> {
+        request.buffer_stream().await?;
+        let nsid = request.nsid.clone();
+        self.requests.lock().unwrap().push(request);
+
+        let mut responses = self.responses.lock().unwrap();
+        match responses.get_mut(&nsid) {
+            Some(queue) if queue.len() > 1 => queue.pop_front().unwrap(),
+            Some(queue) if !queue.is_empty() => queue[0].clone(),
+            _ => Ok(HttpResponse::json(
+                501,
+                &serde_json::json!({
+                    "error": "MethodNotImplemented",
+                    "message": format!("No mock response for {}", nsid)
+                }),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xrpc::{XrpcClient, XrpcClientConfig, XrpcRequest};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn client(transport: &Arc<MockTransport>) -> XrpcClient {
+        XrpcClient::new(
+            XrpcClientConfig::new("https://pds.example.com").with_transport(transport.clone()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_responses_are_returned_in_order_and_last_repeats() {
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json("app.bsky.notification.getUnreadCount", 200, json!({"count": 1}))
+                .with_json("app.bsky.notification.getUnreadCount", 200, json!({"count": 2})),
+        );
+        let client = client(&transport);
+
+        let mut counts = Vec::new();
+        for _ in 0..3 {
+            let request = XrpcRequest::query("app.bsky.notification.getUnreadCount");
+            let response = client.query::<serde_json::Value>(request).await.unwrap();
+            counts.push(response.data["count"].as_i64().unwrap());
+        }
+
+        assert_eq!(counts, vec![1, 2, 2]);
+        assert_eq!(transport.request_count("app.bsky.notification.getUnreadCount"), 3);
+    }
+
+    #[tokio::test]
+    async fn test_requests_are_recorded() {
+        let transport = Arc::new(MockTransport::new().with_json(
+            "com.atproto.repo.createRecord",
+            200,
+            json!({"uri": "at://did:plc:test/app.bsky.feed.post/1", "cid": "bafy"}),
+        ));
+        let client = client(&transport);
+
+        let request = XrpcRequest::procedure("com.atproto.repo.createRecord")
+            .json_body(&json!({"repo": "did:plc:test"}))
+            .unwrap();
+        client
+            .procedure::<serde_json::Value>(request)
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url, "https://pds.example.com/xrpc/com.atproto.repo.createRecord");
+        assert_eq!(requests[0].header("content-type"), Some("application/json"));
+        let body: serde_json::Value =
+            serde_json::from_slice(requests[0].body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["repo"], "did:plc:test");
+    }
+
+    #[tokio::test]
+    async fn test_unmatched_request_and_errors() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_error(
+            "app.bsky.feed.getTimeline",
+            XrpcError::new(0, "NetworkError", "connection reset"),
+        );
+        let client = client(&transport);
+
+        let err = client
+            .query::<serde_json::Value>(XrpcRequest::query("app.bsky.actor.getProfile"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), 501);
+        assert_eq!(err.error(), "MethodNotImplemented");
+
+        let err = client
+            .query::<serde_json::Value>(XrpcRequest::query("app.bsky.feed.getTimeline"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.error(), "NetworkError");
+    }
+}
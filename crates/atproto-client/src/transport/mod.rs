@@ -0,0 +1,134 @@
+//! HTTP transports for the XRPC client
+//!
+//! [`XrpcClient`](crate::xrpc::XrpcClient) sends requests through a [`Transport`]
+//! at the end of its middleware chain. This module provides:
+//! - [`ReqwestTransport`] - the default transport, backed by `reqwest`
+//! - [`MockTransport`] - canned in-memory responses for tests
+//! - [`RecordReplayTransport`] - records real traffic to JSON cassettes and
+//!   replays it offline
+//!
+//! # Example
+//!
+//! ```rust
+//! use atproto_client::transport::MockTransport;
+//! use atproto_client::xrpc::{XrpcClient, XrpcClientConfig, XrpcRequest};
+//! use std::sync::Arc;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let transport = Arc::new(MockTransport::new());
+//!     transport.push_json(
+//!         "app.bsky.notification.getUnreadCount",
+//!         200,
+//!         serde_json::json!({ "count": 3 }),
+//!     );
+//!
+//!     let config = XrpcClientConfig::new("https://bsky.social").with_transport(transport.clone());
+//!     let client = XrpcClient::new(config);
+//!
+//!     let request = XrpcRequest::query("app.bsky.notification.getUnreadCount");
+//!     let response = client.query::<serde_json::Value>(request).await?;
+//!     assert_eq!(response.data["count"], 3);
+//!     assert_eq!(transport.requests().len(), 1);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod cassette;
+mod mock;
+
+pub use cassette::{
+    Cassette, CassetteBody, CassetteMode, CassetteRequest, CassetteResponse, Interaction,
+    RecordReplayTransport,
+};
+pub use mock::MockTransport;
+
+use crate::xrpc::{HttpMethod, HttpRequest, HttpResponse, XrpcError};
+use async_trait::async_trait;
+use reqwest::Client as ReqwestClient;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Sends HTTP requests on behalf of an XRPC client
+///
+/// Transport errors (connection failures, timeouts) are returned as an
+/// [`XrpcError`] with status 0. HTTP error statuses are returned as responses.
+#[async_trait]
+pub trait Transport: Send + Sync + std::fmt::Debug {
+    /// Send a request and return the raw response
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, XrpcError>;
+}
+
+/// Transport backed by `reqwest`
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: ReqwestClient,
+}
+
+impl ReqwestTransport {
+    /// Create a transport with a timeout and user agent
+    pub fn new(timeout: Duration, user_agent: &str) -> Self {
+        let client = ReqwestClient::builder()
+            .timeout(timeout)
+            .user_agent(user_agent)
+            .build()
+            .expect("Failed to build HTTP client");
+
+        Self { client }
+    }
+
+    /// Create a transport from an existing `reqwest` client
+    pub fn from_client(client: ReqwestClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, XrpcError> {
+        let mut req = match request.method {
+            HttpMethod::Get => self.client.get(&request.url),
+            HttpMethod::Post => self.client.post(&request.url),
+            HttpMethod::Put => self.client.put(&request.url),
+            HttpMethod::Delete => self.client.delete(&request.url),
+        };
+
+        if !request.params.is_empty() {
+            req = req.query(&request.params);
+        }
+        for (key, value) in &request.headers {
+            req = req.header(key, value);
+        }
+        if let Some(stream) = request.stream {
+            let body = stream
+                .open()
+                .map_err(|e| XrpcError::new(0, "StreamError", e.to_string()))?;
+            req = req.body(reqwest::Body::wrap_stream(body));
+        } else if let Some(body) = request.body {
+            req = req.body(body);
+        }
+
+        // Execute request
+        let response = req
+            .send()
+            .await
+            .map_err(|e| XrpcError::new(0, "NetworkError", format!("Request failed: {}", e)))?;
+
+        let status = response.status().as_u16();
+
+        // Extract headers
+        let mut headers = HashMap::new();
+        for (key, value) in response.headers() {
+            if let Ok(value_str) = value.to_str() {
+                headers.insert(key.to_string(), value_str.to_string());
+            }
+        }
+
+        let body = response.bytes().await.map_err(|e| {
+            XrpcError::new(0, "ParseError", format!("Failed to read response: {}", e))
+        })?;
+
+        Ok(HttpResponse::new(status, headers, body.to_vec()))
+    }
+}
@@ -0,0 +1,649 @@
+//! Streaming blob uploads
+//!
+//! [`BlobUpload`] sends a blob to `com.atproto.repo.uploadBlob` from memory,
+//! a file or an async reader. File and reader bodies are streamed, so a large
+//! video is never held in memory. Uploads can report progress through a
+//! `watch` channel and be cancelled with a [`CancellationToken`].
+//!
+//! Uploads are checked against [`BlobLimits`] before anything is sent. The PDS
+//! doesn't advertise blob limits in `describeServer`; the limits that matter
+//! are the ones the record field referencing the blob declares, which
+//! [`BlobLimits::from_schema`] reads from the lexicon.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use atproto_client::upload::{BlobLimits, BlobUpload, UploadProgress};
+//! use atproto_client::xrpc::{XrpcClient, XrpcClientConfig};
+//! use tokio::sync::watch;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = XrpcClient::new(XrpcClientConfig::new("https://bsky.social"));
+//! let (progress, mut updates) = watch::channel(UploadProgress::default());
+//!
+//! let upload = BlobUpload::from_file("clip.mp4", "video/mp4")
+//!     .await?
+//!     .with_limits(BlobLimits::new().with_accept(["video/mp4"]).with_max_size(100_000_000))
+//!     .with_progress(progress);
+//!
+//! tokio::spawn(async move {
+//!     while updates.changed().await.is_ok() {
+//!         let progress = *updates.borrow();
+//!         println!("{}/{} bytes", progress.sent, progress.total);
+//!     }
+//! });
+//!
+//! let blob = upload.send(&client).await?;
+//! println!("Uploaded {}", blob.ref_link.cid);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::api::com::atproto::repo::upload_blob;
+use crate::lexicon::validation::mime_type_matches;
+use crate::lexicon::{BlobConstraints, BlobRef, LexType, LexiconDef, SchemaRegistry};
+use crate::xrpc::{ByteStream, StreamBody, XrpcClient, XrpcError, XrpcRequest};
+use futures_util::stream::{self, StreamExt};
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use thiserror::Error;
+use tokio::io::AsyncRead;
+use tokio::sync::watch;
+use tokio_util::io::ReaderStream;
+
+pub use tokio_util::sync::CancellationToken;
+
+/// Size of the chunks in-memory bodies are sent in
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Uploads [`upload_all`] runs at once by default
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Errors that can occur while uploading a blob
+#[derive(Debug, Error)]
+pub enum UploadError {
+    /// XRPC error
+    #[error("XRPC error: {0}")]
+    Xrpc(#[from] XrpcError),
+
+    /// Reading the source failed
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+
+    /// The blob is larger than the limit
+    #[error("Blob is {size} bytes; the limit is {max}")]
+    TooLarge {
+        /// Blob size in bytes
+        size: u64,
+        /// Maximum size in bytes
+        max: u64,
+    },
+
+    /// The MIME type is not accepted
+    #[error("MIME type '{mime_type}' is not accepted (expected one of {accept:?})")]
+    UnsupportedMimeType {
+        /// MIME type of the blob
+        mime_type: String,
+        /// Accepted MIME types
+        accept: Vec<String>,
+    },
+
+    /// The upload was cancelled
+    #[error("Upload cancelled")]
+    Cancelled,
+}
+
+/// Result type for blob uploads
+pub type Result<T> = std::result::Result<T, UploadError>;
+
+/// Bytes sent so far
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UploadProgress {
+    /// Bytes handed to the transport
+    pub sent: u64,
+    /// Blob size in bytes
+    pub total: u64,
+}
+
+impl UploadProgress {
+    /// Fraction sent, from 0.0 to 1.0
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.sent as f64 / self.total as f64
+        }
+    }
+}
+
+/// MIME type and size limits for a blob
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlobLimits {
+    /// Accepted MIME types (supports patterns like `image/*`); `None` accepts any
+    pub accept: Option<Vec<String>>,
+    /// Maximum size in bytes
+    pub max_size: Option<u64>,
+}
+
+impl BlobLimits {
+    /// Create limits that accept any blob
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the accepted MIME types
+    pub fn with_accept<I, S>(mut self, accept: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.accept = Some(accept.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the maximum size in bytes
+    pub fn with_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Read the limits of a blob property from a lexicon
+    ///
+    /// # Arguments
+    ///
+    /// * `registry` - Registry containing the lexicon
+    /// * `def_ref` - Object or record definition (e.g., `app.bsky.embed.images#image`)
+    /// * `property` - Blob property of the definition (e.g., `image`)
+    ///
+    /// # Returns
+    ///
+    /// `None` if the definition or property doesn't exist or isn't a blob
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use atproto_client::lexicon::SchemaRegistry;
+    /// use atproto_client::upload::BlobLimits;
+    ///
+    /// let registry = SchemaRegistry::with_builtin();
+    /// let limits = BlobLimits::from_schema(&registry, "app.bsky.embed.images#image", "image").unwrap();
+    /// assert!(limits.check("image/png", 1_000).is_ok());
+    /// assert!(limits.check("video/mp4", 1_000).is_err());
+    /// ```
+    pub fn from_schema(registry: &SchemaRegistry, def_ref: &str, property: &str) -> Option<Self> {
+        let nsid = def_ref.split('#').next().unwrap_or(def_ref);
+        let object = match registry.resolve_ref(nsid, def_ref).ok()? {
+            LexiconDef::Object(object) => object,
+            LexiconDef::Record(record) => &record.record,
+            _ => return None,
+        };
+        match object.properties.get(property)? {
+            LexType::Blob(blob) => Some(Self::from(&blob.constraints)),
+            _ => None,
+        }
+    }
+
+    /// Check a blob against the limits
+    ///
+    /// # Errors
+    ///
+    /// - `UploadError::UnsupportedMimeType` - `mime_type` is not accepted
+    /// - `UploadError::TooLarge` - `size` exceeds the maximum
+    pub fn check(&self, mime_type: &str, size: u64) -> Result<()> {
+        if let Some(accept) = &self.accept {
+            if !accept
+                .iter()
+                .any(|pattern| mime_type_matches(pattern, mime_type))
+            {
+                return Err(UploadError::UnsupportedMimeType {
+                    mime_type: mime_type.to_string(),
+                    accept: accept.clone(),
+                });
+            }
+        }
+        match self.max_size {
+            Some(max) if size > max => Err(UploadError::TooLarge { size, max }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl From<&BlobConstraints> for BlobLimits {
+    fn from(constraints: &BlobConstraints) -> Self {
+        Self {
+            accept: constraints.accept.clone(),
+            max_size: constraints.max_size.map(|max| max as u64),
+        }
+    }
+}
+
+/// A blob to upload to the PDS
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use atproto_client::upload::{BlobUpload, CancellationToken};
+/// use atproto_client::xrpc::{XrpcClient, XrpcClientConfig};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = XrpcClient::new(XrpcClientConfig::new("https://bsky.social"));
+/// let cancel = CancellationToken::new();
+///
+/// let upload = BlobUpload::from_file("photo.jpg", "image/jpeg")
+///     .await?
+///     .with_cancellation(cancel.clone());
+///
+/// // Elsewhere, e.g. when the user discards the draft:
+/// // cancel.cancel();
+///
+/// let blob = upload.send(&client).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BlobUpload {
+    /// Blob contents
+    body: StreamBody,
+    /// MIME type sent as the content type
+    mime_type: String,
+    /// Limits checked before sending
+    limits: Option<BlobLimits>,
+    /// Progress updates
+    progress: Option<Arc<watch::Sender<UploadProgress>>>,
+    /// Stops the upload when cancelled
+    cancel: Option<CancellationToken>,
+}
+
+impl BlobUpload {
+    /// Upload a blob held in memory
+    pub fn from_bytes(data: impl Into<Vec<u8>>, mime_type: impl Into<String>) -> Self {
+        let data = Arc::new(data.into());
+        let len = data.len() as u64;
+        let body = StreamBody::new(len, move || {
+            let data = data.clone();
+            let chunks = (0..data.len())
+                .step_by(CHUNK_SIZE)
+                .map(move |start| Ok(data[start..(start + CHUNK_SIZE).min(data.len())].to_vec()));
+            Ok(Box::pin(stream::iter(chunks)) as ByteStream)
+        });
+        Self::new(body, mime_type)
+    }
+
+    /// Upload a file, streaming it from disk
+    ///
+    /// The file is opened again if the request is replayed.
+    ///
+    /// # Errors
+    ///
+    /// - `UploadError::Io` - The file can't be read
+    pub async fn from_file(path: impl AsRef<Path>, mime_type: impl Into<String>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let len = tokio::fs::metadata(&path).await?.len();
+        let body = StreamBody::new(len, move || {
+            let chunks =
+                stream::once(tokio::fs::File::open(path.clone())).flat_map(|file| match file {
+                    Ok(file) => ReaderStream::new(file)
+                        .map(|chunk| chunk.map(Vec::from))
+                        .left_stream(),
+                    Err(e) => stream::once(async { Err(e) }).right_stream(),
+                });
+            Ok(Box::pin(chunks) as ByteStream)
+        });
+        Ok(Self::new(body, mime_type))
+    }
+
+    /// Upload `len` bytes streamed from an async reader
+    ///
+    /// A reader can only be read once, so a replayed request fails with an
+    /// IO error instead of sending a partial body.
+    pub fn from_reader<R>(reader: R, len: u64, mime_type: impl Into<String>) -> Self
+    where
+        R: AsyncRead + Send + Unpin + 'static,
+    {
+        let reader = Mutex::new(Some(reader));
+        let body = StreamBody::new(len, move || {
+            let reader = reader
+                .lock()
+                .unwrap()
+                .take()
+                .ok_or_else(|| io::Error::other("Upload reader was already consumed"))?;
+            Ok(Box::pin(ReaderStream::new(reader).map(|chunk| chunk.map(Vec::from))) as ByteStream)
+        });
+        Self::new(body, mime_type)
+    }
+
+    fn new(body: StreamBody, mime_type: impl Into<String>) -> Self {
+        Self {
+            body,
+            mime_type: mime_type.into(),
+            limits: None,
+            progress: None,
+            cancel: None,
+        }
+    }
+
+    /// Check the blob against `limits` before sending
+    pub fn with_limits(mut self, limits: BlobLimits) -> Self {
+        self.limits = Some(limits);
+        self
+    }
+
+    /// Publish progress to a watch channel as the body is sent
+    pub fn with_progress(mut self, progress: watch::Sender<UploadProgress>) -> Self {
+        self.progress = Some(Arc::new(progress));
+        self
+    }
+
+    /// Stop the upload when `token` is cancelled
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+
+    /// Blob size in bytes
+    pub fn size(&self) -> u64 {
+        self.body.len()
+    }
+
+    /// MIME type of the blob
+    pub fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+
+    /// Limits the blob is checked against before sending, if any
+    pub fn limits(&self) -> Option<&BlobLimits> {
+        self.limits.as_ref()
+    }
+
+    /// Check the blob against its limits without sending it
+    ///
+    /// # Errors
+    ///
+    /// - `UploadError::UnsupportedMimeType` - The MIME type is not accepted
+    /// - `UploadError::TooLarge` - The blob exceeds the maximum size
+    pub fn validate(&self) -> Result<()> {
+        match &self.limits {
+            Some(limits) => limits.check(&self.mime_type, self.size()),
+            None => Ok(()),
+        }
+    }
+
+    /// Upload the blob
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated client for the PDS
+    ///
+    /// # Returns
+    ///
+    /// The blob reference to embed in a record
+    ///
+    /// # Errors
+    ///
+    /// - `UploadError::UnsupportedMimeType` / `UploadError::TooLarge` - The
+    ///   blob fails its limits; nothing is sent
+    /// - `UploadError::Cancelled` - The cancellation token fired
+    /// - `UploadError::Xrpc` - The upload failed
+    pub async fn send(self, client: &XrpcClient) -> Result<BlobRef> {
+        self.validate()?;
+
+        let cancel = self.cancel.unwrap_or_default();
+        if cancel.is_cancelled() {
+            return Err(UploadError::Cancelled);
+        }
+
+        let total = self.body.len();
+        let source = self.body;
+        let progress = self.progress;
+        let stream_cancel = cancel.clone();
+        let body = StreamBody::new(total, move || {
+            let progress = progress.clone();
+            let cancel = stream_cancel.clone();
+            let mut sent = 0;
+            if let Some(progress) = &progress {
+                progress.send_replace(UploadProgress { sent, total });
+            }
+            let chunks = source.open()?.map(move |chunk| {
+                if cancel.is_cancelled() {
+                    return Err(io::Error::new(io::ErrorKind::Interrupted, "Upload cancelled"));
+                }
+                let chunk = chunk?;
+                sent += chunk.len() as u64;
+                if let Some(progress) = &progress {
+                    progress.send_replace(UploadProgress { sent, total });
+                }
+                Ok(chunk)
+            });
+            Ok(Box::pin(chunks) as ByteStream)
+        });
+
+        let request = XrpcRequest::procedure(upload_blob::NSID)
+            .stream_body(body)
+            .encoding(self.mime_type);
+
+        tokio::select! {
+            _ = cancel.cancelled() => Err(UploadError::Cancelled),
+            response = client.procedure::<upload_blob::Output>(request) => {
+                Ok(response?.data.blob)
+            }
+        }
+    }
+}
+
+/// Upload several blobs, up to `concurrency` at a time
+///
+/// # Arguments
+///
+/// * `client` - Authenticated client for the PDS
+/// * `uploads` - Blobs to upload
+/// * `concurrency` - Uploads in flight at once (at least 1)
+///
+/// # Returns
+///
+/// One result per upload, in the order given
+pub async fn upload_all(
+    client: &XrpcClient,
+    uploads: Vec<BlobUpload>,
+    concurrency: usize,
+) -> Vec<Result<BlobRef>> {
+    stream::iter(uploads)
+        .map(|upload| upload.send(client))
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use crate::xrpc::XrpcClientConfig;
+    use serde_json::json;
+    use std::io::Write;
+
+    fn client(transport: &Arc<MockTransport>) -> XrpcClient {
+        XrpcClient::new(
+            XrpcClientConfig::new("https://pds.example.com").with_transport(transport.clone()),
+        )
+    }
+
+    fn uploaded(size: usize) -> serde_json::Value {
+        json!({
+            "blob": {
+                "$type": "blob",
+                "ref": {"$link": "bafkreibme22gw2h7y2h7tg2fhqotaqjucnbc24deqo72b6mkl2egezxhvy"},
+                "mimeType": "image/png",
+                "size": size
+            }
+        })
+    }
+
+    #[test]
+    fn test_limits_check_mime_and_size() {
+        let limits = BlobLimits::new()
+            .with_accept(["image/*"])
+            .with_max_size(1_000_000);
+
+        assert!(limits.check("image/jpeg", 1_000_000).is_ok());
+        assert!(matches!(
+            limits.check("image/jpeg", 1_000_001),
+            Err(UploadError::TooLarge { size: 1_000_001, max: 1_000_000 })
+        ));
+        assert!(matches!(
+            limits.check("video/mp4", 10),
+            Err(UploadError::UnsupportedMimeType { .. })
+        ));
+        assert!(BlobLimits::new()
+            .check("application/octet-stream", u64::MAX)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_limits_from_builtin_lexicons() {
+        let registry = SchemaRegistry::with_builtin();
+
+        let image =
+            BlobLimits::from_schema(&registry, "app.bsky.embed.images#image", "image").unwrap();
+        assert_eq!(image.max_size, Some(1_000_000));
+        assert_eq!(image.accept, Some(vec!["image/*".to_string()]));
+
+        let avatar =
+            BlobLimits::from_schema(&registry, "app.bsky.actor.profile", "avatar").unwrap();
+        assert!(avatar.check("image/png", 1_000).is_ok());
+
+        assert!(BlobLimits::from_schema(&registry, "app.bsky.embed.images#image", "alt").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_upload_streams_file_and_reports_progress() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        let data: Vec<u8> = (0..200_000u32).map(|i| i as u8).collect();
+        file.write_all(&data).unwrap();
+
+        let transport =
+            Arc::new(MockTransport::new().with_json(upload_blob::NSID, 200, uploaded(data.len())));
+        let (progress, updates) = watch::channel(UploadProgress::default());
+
+        let blob = BlobUpload::from_file(file.path(), "image/png")
+            .await
+            .unwrap()
+            .with_progress(progress)
+            .send(&client(&transport))
+            .await
+            .unwrap();
+
+        assert_eq!(blob.size, data.len());
+        let progress = *updates.borrow();
+        assert_eq!(progress, UploadProgress { sent: 200_000, total: 200_000 });
+        assert_eq!(progress.fraction(), 1.0);
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.body.as_deref(), Some(data.as_slice()));
+        assert_eq!(request.header("Content-Type"), Some("image/png"));
+        assert_eq!(request.header("Content-Length"), Some("200000"));
+    }
+
+    #[tokio::test]
+    async fn test_limits_are_checked_before_sending() {
+        let transport = Arc::new(MockTransport::new());
+        let upload = BlobUpload::from_bytes(vec![0u8; 2_000], "image/png")
+            .with_limits(BlobLimits::new().with_max_size(1_000));
+
+        let result = upload.send(&client(&transport)).await;
+
+        assert!(matches!(result, Err(UploadError::TooLarge { size: 2_000, .. })));
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_upload_is_not_sent() {
+        let transport = Arc::new(MockTransport::new());
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+
+        let result = BlobUpload::from_bytes(vec![1, 2, 3], "image/png")
+            .with_cancellation(cancel)
+            .send(&client(&transport))
+            .await;
+
+        assert!(matches!(result, Err(UploadError::Cancelled)));
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reader_can_only_be_sent_once() {
+        let reader = std::io::Cursor::new(vec![7u8; 10]);
+        let upload = BlobUpload::from_reader(reader, 10, "image/png");
+
+        assert_eq!(upload.body.read_all().await.unwrap(), vec![7u8; 10]);
+        assert!(upload.body.read_all().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_file_is_opened_when_read() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let upload = BlobUpload::from_file(file.path(), "image/png")
+            .await
+            .unwrap();
+        file.close().unwrap();
+
+        assert!(upload.body.read_all().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_upload_all_keeps_order() {
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(upload_blob::NSID, 200, uploaded(1))
+                .with_json(upload_blob::NSID, 200, uploaded(2))
+                .with_json(upload_blob::NSID, 200, uploaded(3)),
+        );
+        let uploads = vec![
+            BlobUpload::from_bytes(vec![1], "image/png"),
+            BlobUpload::from_bytes(vec![2, 2], "image/png"),
+            BlobUpload::from_bytes(vec![3, 3, 3], "video/mp4")
+                .with_limits(BlobLimits::new().with_accept(["image/*"])),
+        ];
+
+        let results = upload_all(&client(&transport), uploads, 2).await;
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_ok());
+        assert!(matches!(results[2], Err(UploadError::UnsupportedMimeType { .. })));
+        assert_eq!(transport.request_count(upload_blob::NSID), 2);
+    }
+
+    #[tokio::test]
+    async fn test_streamed_body_reaches_server() {
+        use wiremock::matchers::{body_bytes, header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let data: Vec<u8> = (0..150_000u32).map(|i| (i % 251) as u8).collect();
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(&data).unwrap();
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/xrpc/com.atproto.repo.uploadBlob"))
+            .and(header("Content-Type", "image/png"))
+            .and(body_bytes(data.clone()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(uploaded(data.len())))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = XrpcClient::new(XrpcClientConfig::new(server.uri()));
+        let blob = BlobUpload::from_file(file.path(), "image/png")
+            .await
+            .unwrap()
+            .send(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(blob.size, data.len());
+    }
+}
@@ -2,10 +2,13 @@
 //!
 //! This module implements the XRPC (Cross-Platform Remote Procedure Call) protocol
 //! used by AT Protocol services. It provides request/response types, error handling,
-//! and the core HTTP client with retry logic.
+//! the core HTTP client, and a middleware chain used for retries, auth and logging.
 //!
 //! Reference: original-bluesky/src/state/session/agent.ts
 
+use crate::labelers::{parse_labelers, AcceptedLabeler, CONTENT_LABELERS_HEADER};
+use crate::proxy::{ServiceProxy, PROXY_HEADER};
+use crate::rate_limit::{parse_retry_after, RateLimitBudget, RateLimiter, RATE_LIMIT_EXCEEDED};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::Duration;
@@ -67,15 +70,54 @@ impl XrpcError {
     /// Based on original-bluesky/src/state/messages/convo/const.ts
     /// Network failure statuses: 1, 408, 425, 429, 500, 502, 503, 504, 522, 524
     pub fn is_network_error(&self) -> bool {
-        matches!(self.status, 1 | 408 | 425 | 429 | 500 | 502 | 503 | 504 | 522 | 524)
+        is_network_failure_status(self.status)
     }
 
     /// Check if this error is recoverable (can be retried)
+    ///
+    /// Requests rejected locally because the rate limit budget is spent are
+    /// not recoverable by retrying immediately.
     pub fn is_recoverable(&self) -> bool {
-        self.is_network_error()
+        self.is_network_error() && self.error != RATE_LIMIT_EXCEEDED
+    }
+
+    /// Check if this error is a rate limit rejection (HTTP 429)
+    pub fn is_rate_limited(&self) -> bool {
+        self.status == 429
+    }
+
+    /// Match the error code against an endpoint's declared errors
+    ///
+    /// Returns `None` for network failures and errors the endpoint doesn't
+    /// declare.
+    pub fn declared<E: XrpcErrorKind>(&self) -> Option<E> {
+        E::from_error_name(&self.error)
+    }
+
+    /// Classify this error against an endpoint's declared errors
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atproto_client::api::app::bsky::feed::get_post_thread;
+    /// use atproto_client::xrpc::{EndpointError, XrpcError};
+    ///
+    /// let error = XrpcError::new(400, "NotFound", "Post not found");
+    /// match error.classify::<get_post_thread::Error>() {
+    ///     EndpointError::Declared { kind: get_post_thread::Error::NotFound, .. } => {}
+    ///     other => panic!("unexpected error: {}", other),
+    /// }
+    /// ```
+    pub fn classify<E: XrpcErrorKind>(self) -> EndpointError<E> {
+        EndpointError::from(self)
     }
 }
 
+/// Check if an HTTP status indicates a network failure that should be retried
+fn is_network_failure_status(status: u16) -> bool {
+    matches!(status, 1 | 408 | 425 | 429 | 500 | 502 | 503 | 504 | 522 | 524)
+}
+
 impl std::fmt::Display for XrpcError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "XRPC error {}: {} - {}", self.status, self.error, self.message)
@@ -84,12 +126,114 @@ impl std::fmt::Display for XrpcError {
 
 impl std::error::Error for XrpcError {}
 
+/// Errors declared by an XRPC endpoint
+///
+/// Lexicon queries and procedures list the error names they can return.
+/// Implementations map those names to enum variants; the `lexgen` code
+/// generator derives one `Error` enum per endpoint in [`crate::api`], and
+/// endpoints without a schema can implement this trait by hand.
+///
+/// # Example
+///
+/// ```
+/// use atproto_client::xrpc::{XrpcError, XrpcErrorKind};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum GetThreadError {
+///     NotFound,
+///     BlockedActor,
+/// }
+///
+/// impl XrpcErrorKind for GetThreadError {
+///     fn from_error_name(name: &str) -> Option<Self> {
+///         match name {
+///             "NotFound" => Some(Self::NotFound),
+///             "BlockedActor" => Some(Self::BlockedActor),
+///             _ => None,
+///         }
+///     }
+///
+///     fn error_name(&self) -> &'static str {
+///         match self {
+///             Self::NotFound => "NotFound",
+///             Self::BlockedActor => "BlockedActor",
+///         }
+///     }
+/// }
+///
+/// let error = XrpcError::new(400, "BlockedActor", "Requester has blocked actor");
+/// assert_eq!(error.declared::<GetThreadError>(), Some(GetThreadError::BlockedActor));
+/// ```
+pub trait XrpcErrorKind: Sized + std::fmt::Debug {
+    /// Map a declared error name to a variant
+    fn from_error_name(name: &str) -> Option<Self>;
+
+    /// Get the error name as declared in the lexicon
+    fn error_name(&self) -> &'static str;
+}
+
+/// An XRPC error classified against an endpoint's declared errors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EndpointError<E> {
+    /// An error the endpoint declares
+    Declared {
+        /// The declared error
+        kind: E,
+        /// The original error, for its status and message
+        error: XrpcError,
+    },
+    /// Any other error (network, auth, rate limit or undeclared)
+    Other(XrpcError),
+}
+
+impl<E> EndpointError<E> {
+    /// Get the declared error, if this is one
+    pub fn kind(&self) -> Option<&E> {
+        match self {
+            Self::Declared { kind, .. } => Some(kind),
+            Self::Other(_) => None,
+        }
+    }
+
+    /// Get the underlying XRPC error
+    pub fn xrpc(&self) -> &XrpcError {
+        match self {
+            Self::Declared { error, .. } | Self::Other(error) => error,
+        }
+    }
+
+    /// Convert into the underlying XRPC error
+    pub fn into_xrpc(self) -> XrpcError {
+        match self {
+            Self::Declared { error, .. } | Self::Other(error) => error,
+        }
+    }
+}
+
+impl<E: XrpcErrorKind> From<XrpcError> for EndpointError<E> {
+    fn from(error: XrpcError) -> Self {
+        match error.declared() {
+            Some(kind) => Self::Declared { kind, error },
+            None => Self::Other(error),
+        }
+    }
+}
+
+impl<E> std::fmt::Display for EndpointError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.xrpc().fmt(f)
+    }
+}
+
+impl<E: std::fmt::Debug> std::error::Error for EndpointError<E> {}
+
 // =============================================================================
 // Request Types
 // =============================================================================
 
 /// HTTP method for XRPC requests
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
 pub enum HttpMethod {
     /// GET request (used for queries)
     Get,
@@ -129,6 +273,8 @@ pub struct XrpcRequest {
     pub headers: HashMap<String, String>,
     /// Request body (for POST/PUT)
     pub body: Option<Vec<u8>>,
+    /// Request body read from a stream while sending; replaces `body`
+    pub stream: Option<StreamBody>,
     /// Encoding type (e.g., "application/json")
     pub encoding: Option<String>,
 }
@@ -142,6 +288,7 @@ impl XrpcRequest {
             params: HashMap::new(),
             headers: HashMap::new(),
             body: None,
+            stream: None,
             encoding: None,
         }
     }
@@ -154,6 +301,7 @@ impl XrpcRequest {
             params: HashMap::new(),
             headers: HashMap::new(),
             body: None,
+            stream: None,
             encoding: Some("application/json".to_string()),
         }
     }
@@ -170,12 +318,26 @@ impl XrpcRequest {
         self
     }
 
+    /// Proxy the request through the PDS to a service (`atproto-proxy` header)
+    pub fn proxy(self, proxy: &ServiceProxy) -> Self {
+        self.header(PROXY_HEADER, proxy.to_string())
+    }
+
     /// Set the request body (for POST/PUT)
     pub fn body(mut self, body: Vec<u8>) -> Self {
         self.body = Some(body);
         self
     }
 
+    /// Stream the request body instead of holding it in memory
+    ///
+    /// Transports that can't stream read the whole body before sending.
+    pub fn stream_body(mut self, stream: StreamBody) -> Self {
+        self.body = None;
+        self.stream = Some(stream);
+        self
+    }
+
     /// Set the request body from JSON
     pub fn json_body<T: Serialize>(mut self, value: &T) -> Result<Self, serde_json::Error> {
         let body = serde_json::to_vec(value)?;
@@ -219,6 +381,13 @@ impl<T> XrpcResponse<T> {
         self.headers.get(key)
     }
 
+    /// Get the labelers the AppView applied (`atproto-content-labelers` header)
+    ///
+    /// Returns `None` if the response did not say which labelers were applied.
+    pub fn content_labelers(&self) -> Option<Vec<AcceptedLabeler>> {
+        find_header(&self.headers, CONTENT_LABELERS_HEADER).map(parse_labelers)
+    }
+
     /// Check if the response is successful (2xx status)
     pub fn is_success(&self) -> bool {
         (200..300).contains(&self.status)
@@ -240,6 +409,10 @@ pub struct XrpcClientConfig {
     pub user_agent: String,
     /// Custom headers to include in all requests
     pub default_headers: HashMap<String, String>,
+    /// Transport used to send requests (defaults to [`ReqwestTransport`])
+    pub transport: Option<Arc<dyn Transport>>,
+    /// Rate limiter tracking budgets (defaults to a new limiter per client)
+    pub rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 impl Default for XrpcClientConfig {
@@ -249,6 +422,8 @@ impl Default for XrpcClientConfig {
             timeout: Duration::from_secs(30),
             user_agent: format!("Aurora-Compass/{}", env!("CARGO_PKG_VERSION")),
             default_headers: HashMap::new(),
+            transport: None,
+            rate_limiter: None,
         }
     }
 }
@@ -279,6 +454,20 @@ impl XrpcClientConfig {
         self.default_headers.insert(key.into(), value.into());
         self
     }
+
+    /// Set the transport used to send requests
+    ///
+    /// Timeout and user agent settings only apply to the default transport.
+    pub fn with_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Set the rate limiter, e.g. to share budgets between clients
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
 }
 
 // =============================================================================
@@ -320,6 +509,35 @@ mod tests {
         assert!(!error.is_recoverable());
     }
 
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum TestError {
+        NotFound,
+    }
+
+    impl XrpcErrorKind for TestError {
+        fn from_error_name(name: &str) -> Option<Self> {
+            (name == "NotFound").then_some(Self::NotFound)
+        }
+
+        fn error_name(&self) -> &'static str {
+            "NotFound"
+        }
+    }
+
+    #[test]
+    fn test_xrpc_error_classify() {
+        let error = XrpcError::new(400, "NotFound", "Post not found");
+        let classified = error.clone().classify::<TestError>();
+        assert_eq!(classified.kind(), Some(&TestError::NotFound));
+        assert_eq!(classified.xrpc(), &error);
+        assert_eq!(classified.to_string(), error.to_string());
+
+        let other = XrpcError::new(503, "ServiceUnavailable", "Service is down");
+        let classified = other.clone().classify::<TestError>();
+        assert_eq!(classified, EndpointError::Other(other.clone()));
+        assert_eq!(classified.into_xrpc(), other);
+    }
+
     #[test]
     fn test_xrpc_request_query() {
         let req = XrpcRequest::query("com.atproto.repo.getRecord")
@@ -742,14 +960,542 @@ mod retry_tests {
     }
 }
 
+// =============================================================================
+// XRPC Middleware
+// =============================================================================
+
+use async_trait::async_trait;
+use futures_util::{Stream, StreamExt};
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// HTTP-level request passed through the middleware chain
+///
+/// Built from an [`XrpcRequest`] and the client configuration. Default headers,
+/// request headers and the content type are already merged into `headers`.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    /// HTTP method
+    pub method: HttpMethod,
+    /// NSID of the XRPC method
+    pub nsid: String,
+    /// Full URL without query parameters
+    pub url: String,
+    /// Query parameters
+    pub params: Vec<(String, String)>,
+    /// Request headers
+    pub headers: HashMap<String, String>,
+    /// Request body
+    pub body: Option<Vec<u8>>,
+    /// Request body to stream instead of `body`
+    pub stream: Option<StreamBody>,
+}
+
+impl HttpRequest {
+    /// Get a header value (case-insensitive)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// Set a header, replacing any existing value regardless of case
+    pub fn set_header(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        let name = name.into();
+        self.remove_header(&name);
+        self.headers.insert(name, value.into());
+    }
+
+    /// Remove a header (case-insensitive)
+    pub fn remove_header(&mut self, name: &str) {
+        self.headers
+            .retain(|key, _| !key.eq_ignore_ascii_case(name));
+    }
+
+    /// Read a streamed body into `body`
+    ///
+    /// For transports that need the whole body up front. Does nothing if the
+    /// body isn't streamed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a status 0 `StreamError` if the stream fails.
+    pub async fn buffer_stream(&mut self) -> Result<(), XrpcError> {
+        if let Some(stream) = self.stream.take() {
+            let body = stream
+                .read_all()
+                .await
+                .map_err(|e| XrpcError::new(0, "StreamError", e.to_string()))?;
+            self.body = Some(body);
+        }
+        Ok(())
+    }
+}
+
+/// Chunks of a streamed request body
+pub type ByteStream = Pin<Box<dyn Stream<Item = std::io::Result<Vec<u8>>> + Send>>;
+
+/// Opens a [`ByteStream`]
+type OpenStream = dyn Fn() -> std::io::Result<ByteStream> + Send + Sync;
+
+/// Request body read from a stream while it is sent
+///
+/// The body is produced by a function that opens the stream, so a request
+/// can be replayed (e.g., after a token refresh) when the source can be read
+/// again, such as a file. Sources that can only be read once return an error
+/// from the second open.
+#[derive(Clone)]
+pub struct StreamBody {
+    /// Body length in bytes, sent as `Content-Length`
+    len: u64,
+    /// Opens the stream
+    open: Arc<OpenStream>,
+}
+
+impl StreamBody {
+    /// Create a body of `len` bytes read from the streams `open` returns
+    pub fn new<F>(len: u64, open: F) -> Self
+    where
+        F: Fn() -> std::io::Result<ByteStream> + Send + Sync + 'static,
+    {
+        Self { len, open: Arc::new(open) }
+    }
+
+    /// Body length in bytes
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Whether the body is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Open the stream
+    ///
+    /// # Errors
+    ///
+    /// Returns the source's error if it can't be (re)opened.
+    pub fn open(&self) -> std::io::Result<ByteStream> {
+        (self.open)()
+    }
+
+    /// Read the whole body into memory
+    ///
+    /// # Errors
+    ///
+    /// Returns the source's error if it can't be opened or read.
+    pub async fn read_all(&self) -> std::io::Result<Vec<u8>> {
+        let mut stream = self.open()?;
+        let mut body = Vec::with_capacity(usize::try_from(self.len).unwrap_or(0));
+        while let Some(chunk) = stream.next().await {
+            body.extend_from_slice(&chunk?);
+        }
+        Ok(body)
+    }
+}
+
+impl std::fmt::Debug for StreamBody {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamBody")
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+/// HTTP-level response passed back through the middleware chain
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers
+    pub headers: HashMap<String, String>,
+    /// Raw response body
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Create a new response
+    pub fn new(status: u16, headers: HashMap<String, String>, body: Vec<u8>) -> Self {
+        Self { status, headers, body }
+    }
+
+    /// Create a response with a JSON body
+    pub fn json(status: u16, body: &serde_json::Value) -> Self {
+        let headers = HashMap::from([("content-type".to_string(), "application/json".to_string())]);
+        Self::new(status, headers, serde_json::to_vec(body).unwrap_or_default())
+    }
+
+    /// Get a header value (case-insensitive)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// Check if the response is successful (2xx status)
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Parse the XRPC error carried by an unsuccessful response
+    pub fn to_error(&self) -> XrpcError {
+        match serde_json::from_slice::<XrpcErrorResponse>(&self.body) {
+            Ok(error_response) => {
+                XrpcError::new(self.status, error_response.error, error_response.message)
+            }
+            Err(_) => XrpcError::new(
+                self.status,
+                "Unknown",
+                format!("HTTP {}: {}", self.status, String::from_utf8_lossy(&self.body)),
+            ),
+        }
+    }
+}
+
+fn find_header<'a>(headers: &'a HashMap<String, String>, name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Middleware wrapping XRPC requests
+///
+/// Middlewares run in the order they were added; each one sees the request
+/// before the middlewares added after it, and the response after them. Simple
+/// middlewares implement [`before_request`](Middleware::before_request) and/or
+/// [`after_response`](Middleware::after_response); middlewares that need to
+/// short-circuit or re-send requests override [`handle`](Middleware::handle).
+///
+/// # Examples
+/// ```
+/// use atproto_client::xrpc::{HttpRequest, Middleware, XrpcClient, XrpcClientConfig, XrpcError};
+/// use async_trait::async_trait;
+///
+/// struct RequestId;
+///
+/// #[async_trait]
+/// impl Middleware for RequestId {
+///     async fn before_request(&self, request: &mut HttpRequest) -> Result<(), XrpcError> {
+///         request.set_header("X-Request-Id", "abc123");
+///         Ok(())
+///     }
+/// }
+///
+/// let client = XrpcClient::new(XrpcClientConfig::new("https://bsky.social"))
+///     .with_middleware(RequestId);
+/// ```
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    /// Inspect or modify a request before it is sent
+    async fn before_request(&self, _request: &mut HttpRequest) -> Result<(), XrpcError> {
+        Ok(())
+    }
+
+    /// Inspect or modify a response before it is returned
+    async fn after_response(&self, _response: &mut HttpResponse) -> Result<(), XrpcError> {
+        Ok(())
+    }
+
+    /// Process a request, calling `next` to continue the chain
+    async fn handle(
+        &self,
+        mut request: HttpRequest,
+        next: Next<'_>,
+    ) -> Result<HttpResponse, XrpcError> {
+        self.before_request(&mut request).await?;
+        let mut response = next.run(request).await?;
+        self.after_response(&mut response).await?;
+        Ok(response)
+    }
+}
+
+/// The remainder of a middleware chain
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    client: &'a XrpcClient,
+    middlewares: &'a [Arc<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+    /// Get the client executing the request
+    pub fn client(&self) -> &'a XrpcClient {
+        self.client
+    }
+
+    /// Run the rest of the chain and send the request
+    pub async fn run(self, request: HttpRequest) -> Result<HttpResponse, XrpcError> {
+        match self.middlewares.split_first() {
+            Some((middleware, rest)) => {
+                let next = Next { client: self.client, middlewares: rest };
+                middleware.handle(request, next).await
+            }
+            None => self.client.dispatch(request).await,
+        }
+    }
+}
+
+/// Middleware retrying network failures with exponential backoff
+///
+/// Retries responses and errors whose status is a network failure status (see
+/// [`XrpcError::is_network_error`]). This is what
+/// [`XrpcClient::query_with_retry`] and [`XrpcClient::procedure_with_retry`] use.
+#[derive(Debug, Clone, Default)]
+pub struct RetryMiddleware {
+    config: RetryConfig,
+}
+
+impl RetryMiddleware {
+    /// Create a retry middleware
+    pub fn new(config: RetryConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl Middleware for RetryMiddleware {
+    async fn handle(
+        &self,
+        request: HttpRequest,
+        next: Next<'_>,
+    ) -> Result<HttpResponse, XrpcError> {
+        let mut attempt = 0;
+
+        loop {
+            let result = next.run(request.clone()).await;
+
+            let retryable = match &result {
+                Ok(response) => is_network_failure_status(response.status),
+                Err(err) => err.is_recoverable(),
+            };
+            if !retryable || attempt >= self.config.max_retries {
+                return result;
+            }
+
+            // Honour Retry-After, but don't wait longer than the configured maximum
+            let mut delay = self.config.calculate_delay(attempt);
+            if let Some(retry_after) = result
+                .as_ref()
+                .ok()
+                .and_then(|response| response.header("retry-after"))
+                .and_then(parse_retry_after)
+            {
+                if retry_after > self.config.max_delay {
+                    return result;
+                }
+                delay = delay.max(retry_after);
+            }
+
+            sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Middleware logging requests and responses with `tracing`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LoggingMiddleware;
+
+#[async_trait]
+impl Middleware for LoggingMiddleware {
+    async fn handle(
+        &self,
+        request: HttpRequest,
+        next: Next<'_>,
+    ) -> Result<HttpResponse, XrpcError> {
+        let method = request.method;
+        let nsid = request.nsid.clone();
+        let started = std::time::Instant::now();
+
+        let result = next.run(request).await;
+        let elapsed_ms = started.elapsed().as_millis() as u64;
+
+        match &result {
+            Ok(response) => tracing::debug!(
+                method = method.as_str(),
+                nsid = %nsid,
+                status = response.status,
+                elapsed_ms,
+                "XRPC request completed"
+            ),
+            Err(err) => tracing::warn!(
+                method = method.as_str(),
+                nsid = %nsid,
+                error = %err,
+                elapsed_ms,
+                "XRPC request failed"
+            ),
+        }
+
+        result
+    }
+}
+
+// =============================================================================
+// Middleware Tests
+// =============================================================================
+
+#[cfg(test)]
+mod middleware_tests {
+    use super::*;
+    use std::sync::Mutex;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Records the order in which middlewares see requests and responses
+    struct Recorder {
+        name: &'static str,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl Middleware for Recorder {
+        async fn before_request(&self, request: &mut HttpRequest) -> Result<(), XrpcError> {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("before {}", self.name));
+            request.set_header(format!("X-{}", self.name), "1");
+            Ok(())
+        }
+
+        async fn after_response(&self, _response: &mut HttpResponse) -> Result<(), XrpcError> {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("after {}", self.name));
+            Ok(())
+        }
+    }
+
+    /// Answers every request without reaching the network
+    struct ShortCircuit;
+
+    #[async_trait]
+    impl Middleware for ShortCircuit {
+        async fn handle(
+            &self,
+            _request: HttpRequest,
+            _next: Next<'_>,
+        ) -> Result<HttpResponse, XrpcError> {
+            Ok(HttpResponse::new(200, HashMap::new(), br#"{"cached":true}"#.to_vec()))
+        }
+    }
+
+    #[test]
+    fn test_http_request_headers_case_insensitive() {
+        let mut request = HttpRequest {
+            method: HttpMethod::Get,
+            nsid: "com.example.test".to_string(),
+            url: "https://example.com/xrpc/com.example.test".to_string(),
+            params: Vec::new(),
+            headers: HashMap::new(),
+            body: None,
+            stream: None,
+        };
+
+        request.set_header("authorization", "Bearer a");
+        request.set_header("Authorization", "Bearer b");
+        assert_eq!(request.headers.len(), 1);
+        assert_eq!(request.header("AUTHORIZATION"), Some("Bearer b"));
+
+        request.remove_header("authorization");
+        assert!(request.header("Authorization").is_none());
+    }
+
+    #[test]
+    fn test_http_response_to_error() {
+        let response = HttpResponse::new(
+            400,
+            HashMap::new(),
+            br#"{"error":"InvalidRequest","message":"Bad input"}"#.to_vec(),
+        );
+        let error = response.to_error();
+        assert_eq!(error.status(), 400);
+        assert_eq!(error.error(), "InvalidRequest");
+
+        let response = HttpResponse::new(502, HashMap::new(), b"Bad Gateway".to_vec());
+        assert_eq!(response.to_error().error(), "Unknown");
+    }
+
+    #[tokio::test]
+    async fn test_middleware_order() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/xrpc/com.example.test"))
+            .and(header("X-outer", "1"))
+            .and(header("X-inner", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let client = XrpcClient::new(XrpcClientConfig::new(mock_server.uri()))
+            .with_middleware(Recorder { name: "outer", log: log.clone() })
+            .with_middleware(Recorder { name: "inner", log: log.clone() });
+
+        let _: XrpcResponse<serde_json::Value> = client
+            .query(XrpcRequest::query("com.example.test"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["before outer", "before inner", "after inner", "after outer"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_middleware_short_circuit() {
+        let client = XrpcClient::new(XrpcClientConfig::new("http://127.0.0.1:1"))
+            .with_middleware(ShortCircuit);
+
+        let response: XrpcResponse<serde_json::Value> = client
+            .query(XrpcRequest::query("com.example.test"))
+            .await
+            .unwrap();
+        assert_eq!(response.data["cached"], true);
+    }
+
+    #[tokio::test]
+    async fn test_retry_middleware() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/xrpc/com.example.flaky"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/xrpc/com.example.flaky"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&mock_server)
+            .await;
+
+        let retry =
+            RetryMiddleware::new(RetryConfig::new(2).with_initial_delay(Duration::from_millis(1)));
+        let client = XrpcClient::new(XrpcClientConfig::new(mock_server.uri()))
+            .with_middleware(LoggingMiddleware)
+            .with_middleware(retry);
+
+        let response: XrpcResponse<serde_json::Value> = client
+            .query(XrpcRequest::query("com.example.flaky"))
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+    }
+}
+
 // =============================================================================
 // XRPC Client Implementation
 // =============================================================================
 
-use reqwest::{Client as ReqwestClient, Response as ReqwestResponse};
+use crate::oauth::DpopAuth;
+use crate::transport::{ReqwestTransport, Transport};
 
 /// XRPC client for making requests to AT Protocol services
 ///
+/// Requests pass through the client's [`Middleware`] chain before being sent
+/// by its [`Transport`].
+///
 /// Reference: original-bluesky/src/state/session/agent.ts
 ///
 /// # Examples
@@ -767,24 +1513,68 @@ use reqwest::{Client as ReqwestClient, Response as ReqwestResponse};
 ///     Ok(())
 /// }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct XrpcClient {
-    /// HTTP client
-    client: ReqwestClient,
+    /// Transport sending requests at the end of the chain
+    transport: Arc<dyn Transport>,
+    /// Rate limit budgets, checked before each request is sent
+    rate_limiter: Arc<RateLimiter>,
     /// Configuration
     config: XrpcClientConfig,
+    /// Middleware chain, outermost first
+    middlewares: Vec<Arc<dyn Middleware>>,
+    /// DPoP credentials for OAuth sessions (replaces the Authorization header)
+    dpop: Option<Arc<DpopAuth>>,
+}
+
+impl std::fmt::Debug for XrpcClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("XrpcClient")
+            .field("config", &self.config)
+            .field("middlewares", &self.middlewares.len())
+            .field("dpop", &self.dpop)
+            .finish()
+    }
 }
 
 impl XrpcClient {
     /// Create a new XRPC client
     pub fn new(config: XrpcClientConfig) -> Self {
-        let client = ReqwestClient::builder()
-            .timeout(config.timeout)
-            .user_agent(&config.user_agent)
-            .build()
-            .expect("Failed to build HTTP client");
+        let transport = config
+            .transport
+            .clone()
+            .unwrap_or_else(|| Arc::new(ReqwestTransport::new(config.timeout, &config.user_agent)));
+
+        let rate_limiter = config.rate_limiter.clone().unwrap_or_default();
 
-        Self { client, config }
+        Self {
+            transport,
+            rate_limiter,
+            config,
+            middlewares: Vec::new(),
+            dpop: None,
+        }
+    }
+
+    /// Add a middleware to the end of the chain
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// Add a shared middleware to the end of the chain
+    pub fn add_middleware(&mut self, middleware: Arc<dyn Middleware>) {
+        self.middlewares.push(middleware);
+    }
+
+    /// Remove all middlewares
+    pub fn clear_middlewares(&mut self) {
+        self.middlewares.clear();
+    }
+
+    /// Get the number of middlewares in the chain
+    pub fn middleware_count(&self) -> usize {
+        self.middlewares.len()
     }
 
     /// Make a query request (GET)
@@ -862,7 +1652,41 @@ impl XrpcClient {
         self.execute_request(request).await
     }
 
+    /// Make a query request (GET) returning the raw response body
+    ///
+    /// For methods with binary output, such as `com.atproto.sync.getRepo`
+    /// (a CAR file) or `com.atproto.sync.getBlob`. Error responses are still
+    /// parsed as XRPC errors.
+    ///
+    /// # Examples
+    /// ```
+    /// use atproto_client::xrpc::{XrpcClient, XrpcClientConfig, XrpcRequest};
+    ///
+    /// async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = XrpcClient::new(XrpcClientConfig::new("https://bsky.social"));
+    ///
+    ///     let request = XrpcRequest::query("com.atproto.sync.getRepo")
+    ///         .param("did", "did:plc:123");
+    ///     let response = client.query_bytes(request).await?;
+    ///
+    ///     println!("Repo CAR: {} bytes", response.data.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn query_bytes(
+        &self,
+        request: XrpcRequest,
+    ) -> Result<XrpcResponse<Vec<u8>>, XrpcError> {
+        let response = self.send_with(request, None).await?;
+        if !response.is_success() {
+            return Err(response.to_error());
+        }
+        Ok(XrpcResponse::new(response.status, response.headers, response.body))
+    }
+
     /// Make a query request with retry logic
+    ///
+    /// Runs the request with a [`RetryMiddleware`] outside the client's chain.
     pub async fn query_with_retry<T>(
         &self,
         request: XrpcRequest,
@@ -871,10 +1695,13 @@ impl XrpcClient {
     where
         T: for<'de> Deserialize<'de> + Clone,
     {
-        network_retry(max_retries, || self.query(request.clone())).await
+        let retry = RetryMiddleware::new(RetryConfig::new(max_retries));
+        self.execute_with(request, Some(Arc::new(retry))).await
     }
 
     /// Make a procedure request with retry logic
+    ///
+    /// Runs the request with a [`RetryMiddleware`] outside the client's chain.
     pub async fn procedure_with_retry<T>(
         &self,
         request: XrpcRequest,
@@ -883,7 +1710,8 @@ impl XrpcClient {
     where
         T: for<'de> Deserialize<'de> + Clone,
     {
-        network_retry(max_retries, || self.procedure(request.clone())).await
+        let retry = RetryMiddleware::new(RetryConfig::new(max_retries));
+        self.execute_with(request, Some(Arc::new(retry))).await
     }
 
     /// Set or update the authorization header
@@ -906,6 +1734,7 @@ impl XrpcClient {
     /// client.set_auth_header(None);
     /// ```
     pub fn set_auth_header(&mut self, auth: Option<String>) {
+        self.dpop = None;
         if let Some(token) = auth {
             self.config
                 .default_headers
@@ -915,98 +1744,115 @@ impl XrpcClient {
         }
     }
 
+    /// Set or clear DPoP credentials
+    ///
+    /// When set, requests carry `Authorization: DPoP <token>` and a fresh `DPoP`
+    /// proof instead of the default Authorization header. The credentials run as
+    /// the innermost middleware so proofs are signed for the final request.
+    pub fn set_dpop_auth(&mut self, auth: Option<DpopAuth>) {
+        self.config.default_headers.remove("Authorization");
+        self.dpop = auth.map(Arc::new);
+    }
+
+    /// Get the current DPoP credentials
+    pub fn dpop_auth(&self) -> Option<&DpopAuth> {
+        self.dpop.as_deref()
+    }
+
     /// Execute an XRPC request
     async fn execute_request<T>(&self, request: XrpcRequest) -> Result<XrpcResponse<T>, XrpcError>
     where
         T: for<'de> Deserialize<'de>,
     {
-        // Build URL
-        let url = format!("{}/xrpc/{}", self.config.service_url, request.nsid);
-
-        // Build reqwest request
-        let mut req = match request.method {
-            HttpMethod::Get => self.client.get(&url),
-            HttpMethod::Post => self.client.post(&url),
-            HttpMethod::Put => self.client.put(&url),
-            HttpMethod::Delete => self.client.delete(&url),
-        };
+        self.execute_with(request, None).await
+    }
 
-        // Add query parameters
-        for (key, value) in &request.params {
-            req = req.query(&[(key, value)]);
-        }
+    /// Execute an XRPC request with an optional extra outermost middleware
+    async fn execute_with<T>(
+        &self,
+        request: XrpcRequest,
+        outer: Option<Arc<dyn Middleware>>,
+    ) -> Result<XrpcResponse<T>, XrpcError>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let response = self.send_with(request, outer).await?;
 
-        // Add default headers
-        for (key, value) in &self.config.default_headers {
-            req = req.header(key, value);
+        // Convert to XrpcResponse
+        self.parse_response(response)
+    }
+
+    /// Send an XRPC request through the middleware chain without parsing the body
+    async fn send_with(
+        &self,
+        request: XrpcRequest,
+        outer: Option<Arc<dyn Middleware>>,
+    ) -> Result<HttpResponse, XrpcError> {
+        let mut chain: Vec<Arc<dyn Middleware>> = Vec::with_capacity(self.middlewares.len() + 2);
+        chain.extend(outer);
+        chain.extend(self.middlewares.iter().cloned());
+        if let Some(dpop) = &self.dpop {
+            chain.push(dpop.clone());
         }
 
-        // Add request headers
-        for (key, value) in &request.headers {
-            req = req.header(key, value);
+        let next = Next { client: self, middlewares: &chain };
+        next.run(self.build_http_request(request)).await
+    }
+
+    /// Build the HTTP request for an XRPC request
+    fn build_http_request(&self, request: XrpcRequest) -> HttpRequest {
+        let mut http_request = HttpRequest {
+            method: request.method,
+            url: format!("{}/xrpc/{}", self.config.service_url, request.nsid),
+            nsid: request.nsid,
+            params: request.params.into_iter().collect(),
+            headers: HashMap::new(),
+            body: None,
+            stream: None,
+        };
+
+        // Add default headers, then request headers
+        for (key, value) in self.config.default_headers.iter().chain(&request.headers) {
+            http_request.set_header(key.clone(), value.clone());
         }
 
         // Add body if present
-        if let Some(body) = &request.body {
-            if let Some(encoding) = &request.encoding {
-                req = req.header("Content-Type", encoding);
+        if let Some(stream) = request.stream {
+            if let Some(encoding) = request.encoding {
+                http_request.set_header("Content-Type", encoding);
+            }
+            http_request.set_header("Content-Length", stream.len().to_string());
+            http_request.stream = Some(stream);
+        } else if let Some(body) = request.body {
+            if let Some(encoding) = request.encoding {
+                http_request.set_header("Content-Type", encoding);
             }
-            req = req.body(body.clone());
+            http_request.body = Some(body);
         }
 
-        // Execute request
-        let response = req
-            .send()
-            .await
-            .map_err(|e| XrpcError::new(0, "NetworkError", format!("Request failed: {}", e)))?;
-
-        // Convert to XrpcResponse
-        self.parse_response(response).await
+        http_request
     }
 
-    /// Parse reqwest response into XrpcResponse
-    async fn parse_response<T>(
-        &self,
-        response: ReqwestResponse,
-    ) -> Result<XrpcResponse<T>, XrpcError>
+    /// Parse an HTTP response into an XrpcResponse
+    fn parse_response<T>(&self, response: HttpResponse) -> Result<XrpcResponse<T>, XrpcError>
     where
         T: for<'de> Deserialize<'de>,
     {
-        let status = response.status().as_u16();
-
-        // Extract headers
-        let mut headers = HashMap::new();
-        for (key, value) in response.headers() {
-            if let Ok(value_str) = value.to_str() {
-                headers.insert(key.to_string(), value_str.to_string());
-            }
-        }
-
         // Check if response is an error
-        if !response.status().is_success() {
-            let error_body = response.text().await.unwrap_or_default();
-
-            // Try to parse as XrpcErrorResponse
-            if let Ok(error_response) = serde_json::from_str::<XrpcErrorResponse>(&error_body) {
-                return Err(XrpcError::new(status, error_response.error, error_response.message));
-            } else {
-                return Err(XrpcError::new(
-                    status,
-                    "Unknown",
-                    format!("HTTP {}: {}", status, error_body),
-                ));
-            }
+        if !response.is_success() {
+            return Err(response.to_error());
         }
 
-        // Parse successful response
-        let body = response.text().await.map_err(|e| {
-            XrpcError::new(0, "ParseError", format!("Failed to read response: {}", e))
-        })?;
-
-        let data: T = serde_json::from_str(&body)
+        // Parse successful response; procedures without output may send no body
+        let body: &[u8] = if response.body.is_empty() {
+            b"null"
+        } else {
+            &response.body
+        };
+        let data: T = serde_json::from_slice(body)
             .map_err(|e| XrpcError::new(0, "ParseError", format!("Failed to parse JSON: {}", e)))?;
 
-        Ok(XrpcResponse::new(status, headers, data))
+        Ok(XrpcResponse::new(response.status, response.headers, data))
     }
 
     /// Get the client configuration
@@ -1018,6 +1864,53 @@ impl XrpcClient {
     pub fn service_url(&self) -> &str {
         &self.config.service_url
     }
+
+    /// Get the transport sending requests
+    pub fn transport(&self) -> &Arc<dyn Transport> {
+        &self.transport
+    }
+
+    /// Get the rate limiter tracking this client's budgets
+    pub fn rate_limiter(&self) -> &Arc<RateLimiter> {
+        &self.rate_limiter
+    }
+
+    /// Get the current rate limit budget for an XRPC method on this service
+    ///
+    /// Returns the tighter of the method's own budget and the host-wide
+    /// budget, or `None` if no rate limit headers have been seen yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `nsid` - NSID of the XRPC method
+    pub fn rate_limit_budget(&self, nsid: &str) -> Option<RateLimitBudget> {
+        let url = &self.config.service_url;
+        let method = self.rate_limiter.method_budget(url, nsid);
+        let host = self.rate_limiter.host_budget(url);
+
+        match (method, host) {
+            (Some(method), Some(host)) if host.remaining < method.remaining => Some(host),
+            (Some(method), _) => Some(method),
+            (None, host) => host,
+        }
+    }
+
+    /// Get the delay the next request to an XRPC method would get
+    ///
+    /// # Arguments
+    ///
+    /// * `nsid` - NSID of the XRPC method
+    pub fn rate_limit_delay(&self, nsid: &str) -> Duration {
+        self.rate_limiter.delay_for(&self.config.service_url, nsid)
+    }
+
+    /// Send a request through the rate limiter and transport (the end of the chain)
+    async fn dispatch(&self, request: HttpRequest) -> Result<HttpResponse, XrpcError> {
+        let key = self.rate_limiter.acquire(&request).await?;
+        let response = self.transport.send(request).await?;
+        self.rate_limiter.record(&key, &response);
+        Ok(response)
+    }
 }
 
 // =============================================================================
@@ -1047,6 +1940,56 @@ mod client_tests {
         assert_eq!(client.service_url(), "https://bsky.social");
     }
 
+    #[test]
+    fn test_parse_empty_response() {
+        let client = XrpcClient::new(XrpcClientConfig::default());
+        let response = HttpResponse::new(200, HashMap::new(), Vec::new());
+
+        let parsed = client
+            .parse_response::<Option<serde_json::Value>>(response)
+            .unwrap();
+        assert_eq!(parsed.data, None);
+    }
+
+    #[tokio::test]
+    async fn test_query_bytes_returns_raw_body() {
+        use crate::transport::MockTransport;
+
+        let car = vec![0x3a, 0xa2, 0x65, 0x72, 0x6f, 0x6f, 0x74, 0x73];
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(
+            "com.atproto.sync.getRepo",
+            HttpResponse::new(
+                200,
+                HashMap::from([(
+                    "content-type".to_string(),
+                    "application/vnd.ipld.car".to_string(),
+                )]),
+                car.clone(),
+            ),
+        );
+        transport.push_json(
+            "com.atproto.sync.getBlob",
+            400,
+            serde_json::json!({"error": "BlobNotFound", "message": "Blob not found"}),
+        );
+        let client = XrpcClient::new(XrpcClientConfig::default().with_transport(transport.clone()));
+
+        let request = XrpcRequest::query("com.atproto.sync.getRepo").param("did", "did:plc:test");
+        let response = client.query_bytes(request).await.unwrap();
+        assert_eq!(response.data, car);
+        assert_eq!(
+            response.header("content-type").map(String::as_str),
+            Some("application/vnd.ipld.car")
+        );
+
+        let err = client
+            .query_bytes(XrpcRequest::query("com.atproto.sync.getBlob"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.error(), "BlobNotFound");
+    }
+
     // Note: Integration tests with real network requests would go in tests/ directory
     // These are just basic construction tests
 }
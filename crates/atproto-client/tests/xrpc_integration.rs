@@ -3,10 +3,16 @@
 //! These tests use wiremock to create a mock XRPC server and test
 //! the full request/response cycle, error handling, and retry behavior.
 
-use atproto_client::xrpc::{XrpcClient, XrpcClientConfig, XrpcError, XrpcRequest, XrpcResponse};
+use atproto_client::oauth::{DpopAuth, DpopKey};
+use atproto_client::transport::MockTransport;
+use atproto_client::xrpc::{
+    HttpResponse, RetryConfig, RetryMiddleware, XrpcClient, XrpcClientConfig, XrpcError,
+    XrpcRequest, XrpcResponse,
+};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use std::time::Duration;
-use wiremock::matchers::{method, path};
+use wiremock::matchers::{header, header_exists, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // Test data structures
@@ -449,3 +455,130 @@ async fn test_malformed_json_error() {
     assert_eq!(error.error(), "ParseError");
     assert!(error.message().contains("Failed to parse JSON"));
 }
+
+// =============================================================================
+// DPoP Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_dpop_auth_retries_with_nonce() {
+    let mock_server = MockServer::start().await;
+
+    // First request is rejected and a nonce is issued
+    Mock::given(method("GET"))
+        .and(path("/xrpc/com.example.dpop"))
+        .respond_with(
+            ResponseTemplate::new(401)
+                .insert_header("DPoP-Nonce", "server-nonce")
+                .insert_header("WWW-Authenticate", r#"DPoP error="use_dpop_nonce""#)
+                .set_body_json(serde_json::json!({
+                    "error": "use_dpop_nonce",
+                    "message": "Authorization server requires nonce in DPoP proof"
+                })),
+        )
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/xrpc/com.example.dpop"))
+        .and(header("Authorization", "DPoP access-token"))
+        .and(header_exists("DPoP"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .mount(&mock_server)
+        .await;
+
+    let config = XrpcClientConfig::new(mock_server.uri());
+    let mut client = XrpcClient::new(config);
+    client.set_dpop_auth(Some(DpopAuth::new("access-token", DpopKey::generate())));
+
+    let request = XrpcRequest::query("com.example.dpop");
+    let response: XrpcResponse<serde_json::Value> = client.query(request).await.unwrap();
+
+    assert_eq!(response.status, 200);
+    assert_eq!(client.dpop_auth().and_then(|d| d.nonce()).as_deref(), Some("server-nonce"));
+}
+
+#[tokio::test]
+async fn test_set_auth_header_clears_dpop() {
+    let config = XrpcClientConfig::new("https://bsky.social");
+    let mut client = XrpcClient::new(config);
+
+    client.set_dpop_auth(Some(DpopAuth::new("token", DpopKey::generate())));
+    assert!(client.dpop_auth().is_some());
+
+    client.set_auth_header(Some("Bearer token".to_string()));
+    assert!(client.dpop_auth().is_none());
+}
+
+// =============================================================================
+// Transport Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_retry_with_mock_transport() {
+    let transport = Arc::new(MockTransport::new());
+    transport.push_json(
+        "com.example.flaky",
+        503,
+        serde_json::json!({"error": "ServiceUnavailable", "message": "Try again"}),
+    );
+    transport.push_json("com.example.flaky", 200, serde_json::json!({"name": "ok", "value": 1}));
+
+    let config = XrpcClientConfig::new("https://pds.example.com").with_transport(transport.clone());
+    let retry_config = RetryConfig::new(2).with_initial_delay(Duration::from_millis(1));
+    let client = XrpcClient::new(config).with_middleware(RetryMiddleware::new(retry_config));
+
+    let request = XrpcRequest::query("com.example.flaky");
+    let response: XrpcResponse<TestQuery> = client.query(request).await.unwrap();
+
+    assert_eq!(response.data, TestQuery { name: "ok".to_string(), value: 1 });
+    assert_eq!(transport.request_count("com.example.flaky"), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limit_headers_and_retry_after() {
+    let transport = Arc::new(MockTransport::new());
+    let limited = std::collections::HashMap::from([
+        ("RateLimit-Limit".to_string(), "100".to_string()),
+        ("RateLimit-Remaining".to_string(), "0".to_string()),
+        ("RateLimit-Reset".to_string(), "2".to_string()),
+        ("Retry-After".to_string(), "2".to_string()),
+    ]);
+    let body = serde_json::json!({"error": "RateLimitExceeded", "message": "Slow down"});
+    transport.push_response(
+        "com.atproto.repo.createRecord",
+        HttpResponse::new(429, limited, serde_json::to_vec(&body).unwrap()),
+    );
+    let ok = std::collections::HashMap::from([
+        ("RateLimit-Limit".to_string(), "100".to_string()),
+        ("RateLimit-Remaining".to_string(), "99".to_string()),
+        ("RateLimit-Reset".to_string(), "300".to_string()),
+    ]);
+    let body = serde_json::json!({"uri": "at://did:plc:test/app.bsky.feed.post/1", "cid": "bafy"});
+    transport.push_response(
+        "com.atproto.repo.createRecord",
+        HttpResponse::new(200, ok, serde_json::to_vec(&body).unwrap()),
+    );
+
+    let config = XrpcClientConfig::new("https://pds.example.com").with_transport(transport.clone());
+    let retry_config = RetryConfig::new(2).with_initial_delay(Duration::from_millis(1));
+    let client = XrpcClient::new(config).with_middleware(RetryMiddleware::new(retry_config));
+
+    let started = tokio::time::Instant::now();
+    let request = XrpcRequest::procedure("com.atproto.repo.createRecord")
+        .json_body(&serde_json::json!({}))
+        .unwrap();
+    let response: XrpcResponse<TestOutput> = client.procedure(request).await.unwrap();
+
+    assert_eq!(response.data.cid, "bafy");
+    assert!(started.elapsed() >= Duration::from_secs(2));
+    assert_eq!(transport.request_count("com.atproto.repo.createRecord"), 2);
+
+    let budget = client
+        .rate_limit_budget("com.atproto.repo.createRecord")
+        .unwrap();
+    assert_eq!(budget.limit, 100);
+    assert_eq!(budget.remaining, 99);
+    assert_eq!(client.rate_limit_delay("com.atproto.repo.createRecord"), Duration::ZERO);
+}
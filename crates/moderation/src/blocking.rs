@@ -37,10 +37,13 @@
 //! }
 //! ```
 
-use atproto_client::xrpc::{XrpcClient, XrpcRequest};
+use atproto_client::api::com::atproto::repo::create_record;
+use atproto_client::repo::write::create_records_paced;
+use atproto_client::xrpc::{XrpcClient, XrpcError, XrpcRequest};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 use tokio::sync::RwLock;
 
@@ -181,6 +184,19 @@ pub struct CreateRecordResponse {
     pub cid: String,
 }
 
+/// Outcome of blocking many accounts
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportBlocksResult {
+    /// URIs of the block records created, in order
+    pub blocked: Vec<String>,
+    /// Accounts not sent because the rate limit budget ran out or a block failed
+    pub pending: Vec<String>,
+    /// How long to wait before importing the pending accounts, if known
+    pub retry_after: Option<Duration>,
+    /// Account whose block failed with an error other than a rate limit
+    pub failed: Option<(String, XrpcError)>,
+}
+
 /// Block service for managing account blocks and mutes
 ///
 /// Provides methods for blocking, unblocking, muting, and unmuting accounts,
@@ -278,21 +294,7 @@ impl BlockService {
     pub async fn block(&self, did: &str) -> Result<String> {
         self.validate_did(did)?;
 
-        let now = Utc::now().to_rfc3339();
-
-        let body = serde_json::json!({
-            "repo": "self",
-            "collection": "app.bsky.graph.block",
-            "record": {
-                "$type": "app.bsky.graph.block",
-                "subject": did,
-                "createdAt": now
-            }
-        });
-
-        let request = XrpcRequest::procedure("com.atproto.repo.createRecord")
-            .json_body(&body)
-            .map_err(|e| BlockError::ApiError(e.to_string()))?;
+        let request = block_request(did)?;
 
         let client = self.client.read().await;
         let response = client
@@ -306,6 +308,64 @@ impl BlockService {
         Ok(create_response.uri)
     }
 
+    /// Block many accounts, pacing requests to the rate limit
+    ///
+    /// Used to import block lists. Requests are delayed by the client as the
+    /// write budget runs low. If the budget runs out, the blocks created so far
+    /// are returned along with the accounts still pending and how long to wait
+    /// before importing them. If a block fails, the blocks created so far are
+    /// returned with the failed account and its error, so a retry doesn't
+    /// block anyone twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `dids` - DIDs of the accounts to block
+    ///
+    /// # Errors
+    ///
+    /// - `BlockError::InvalidDid` - Any DID is empty or invalid (nothing is blocked)
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use moderation::blocking::BlockService;
+    /// # use atproto_client::xrpc::{XrpcClient, XrpcClientConfig};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let config = XrpcClientConfig::new("https://bsky.social");
+    /// # let client = XrpcClient::new(config);
+    /// # let service = BlockService::new(client);
+    /// let dids = vec!["did:plc:spam1".to_string(), "did:plc:spam2".to_string()];
+    /// let result = service.import_blocks(&dids).await?;
+    /// if let Some(wait) = result.retry_after {
+    ///     println!("{} left, resume in {:?}", result.pending.len(), wait);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn import_blocks(&self, dids: &[String]) -> Result<ImportBlocksResult> {
+        for did in dids {
+            self.validate_did(did)?;
+        }
+
+        let creates = dids
+            .iter()
+            .map(|did| Ok((did.clone(), block_request(did)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let outcome = create_records_paced(&self.client, creates).await;
+        Ok(ImportBlocksResult {
+            blocked: outcome
+                .created
+                .into_iter()
+                .map(|created| created.uri)
+                .collect(),
+            pending: outcome.pending,
+            retry_after: outcome.retry_after,
+            failed: outcome.failed,
+        })
+    }
+
     /// Unblock an account
     ///
     /// Deletes the block record, allowing the previously blocked account to
@@ -824,6 +884,23 @@ impl BlockService {
     }
 }
 
+/// Build the createRecord request for a block
+fn block_request(did: &str) -> Result<XrpcRequest> {
+    let body = serde_json::json!({
+        "repo": "self",
+        "collection": "app.bsky.graph.block",
+        "record": {
+            "$type": "app.bsky.graph.block",
+            "subject": did,
+            "createdAt": Utc::now().to_rfc3339()
+        }
+    });
+
+    XrpcRequest::procedure(create_record::NSID)
+        .json_body(&body)
+        .map_err(|e| BlockError::ApiError(e.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1319,4 +1396,108 @@ mod tests {
     fn test_max_pages_constant() {
         assert_eq!(MAX_PAGES, 10);
     }
+
+    // =========================================================================
+    // Bulk Operation Tests
+    // =========================================================================
+
+    #[tokio::test(start_paused = true)]
+    async fn test_import_blocks_paces_and_stops_on_rate_limit() {
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::{HttpResponse, XrpcClientConfig};
+        use std::collections::HashMap;
+
+        let headers = |remaining: &str| {
+            HashMap::from([
+                ("RateLimit-Limit".to_string(), "100".to_string()),
+                ("RateLimit-Remaining".to_string(), remaining.to_string()),
+                ("RateLimit-Reset".to_string(), "20".to_string()),
+            ])
+        };
+        let created = serde_json::json!({
+            "uri": "at://did:plc:me/app.bsky.graph.block/1",
+            "cid": "bafy"
+        });
+
+        // Nearly spent budgets slow the import down, a 429 stops it
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(
+            create_record::NSID,
+            HttpResponse::new(200, headers("1"), serde_json::to_vec(&created).unwrap()),
+        );
+        let mut limited = headers("0");
+        limited.insert("Retry-After".to_string(), "600".to_string());
+        transport.push_response(
+            create_record::NSID,
+            HttpResponse::new(
+                429,
+                limited,
+                serde_json::to_vec(&serde_json::json!({
+                    "error": "RateLimitExceeded",
+                    "message": "Rate Limit Exceeded"
+                }))
+                .unwrap(),
+            ),
+        );
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let service = BlockService::new(XrpcClient::new(config));
+
+        let dids: Vec<String> = (1..=3).map(|i| format!("did:plc:spam{}", i)).collect();
+        let started = tokio::time::Instant::now();
+        let result = service.import_blocks(&dids).await.unwrap();
+
+        assert_eq!(result.blocked, vec!["at://did:plc:me/app.bsky.graph.block/1".to_string()]);
+        assert_eq!(result.pending, dids[1..].to_vec());
+        assert!(result.retry_after.unwrap() > Duration::from_secs(500));
+        // The second block waited for the last slot in the window
+        assert!(started.elapsed() >= Duration::from_secs(10));
+        assert_eq!(transport.request_count(create_record::NSID), 2);
+    }
+
+    #[tokio::test]
+    async fn test_import_blocks_returns_partial_result_on_error() {
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::XrpcClientConfig;
+
+        let transport = Arc::new(
+            MockTransport::new()
+                .with_json(
+                    create_record::NSID,
+                    200,
+                    serde_json::json!({"uri": "at://did:plc:me/app.bsky.graph.block/1", "cid": "bafy"}),
+                )
+                .with_json(
+                    create_record::NSID,
+                    500,
+                    serde_json::json!({"error": "InternalServerError", "message": "Oops"}),
+                ),
+        );
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let service = BlockService::new(XrpcClient::new(config));
+
+        let dids: Vec<String> = (1..=3).map(|i| format!("did:plc:spam{}", i)).collect();
+        let result = service.import_blocks(&dids).await.unwrap();
+
+        assert_eq!(result.blocked, vec!["at://did:plc:me/app.bsky.graph.block/1".to_string()]);
+        let (did, error) = result.failed.unwrap();
+        assert_eq!(did, "did:plc:spam2");
+        assert_eq!(error.error(), "InternalServerError");
+        assert_eq!(result.pending, dids[2..].to_vec());
+    }
+
+    #[tokio::test]
+    async fn test_import_blocks_validates_all_dids_first() {
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::XrpcClientConfig;
+
+        let transport = Arc::new(MockTransport::new());
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let service = BlockService::new(XrpcClient::new(config));
+
+        let dids = vec!["did:plc:ok".to_string(), "not-a-did".to_string()];
+        let result = service.import_blocks(&dids).await;
+
+        assert!(matches!(result, Err(BlockError::InvalidDid(_))));
+        assert!(transport.requests().is_empty());
+    }
 }
@@ -4,6 +4,7 @@
 //! IMPORTANT: Unlike the official Bluesky client, subscription to Bluesky's moderation
 //! service is OPTIONAL. Users can choose which labeling services to subscribe to.
 
+use atproto_client::labelers::{AcceptedLabeler, LabelerProvider};
 use atproto_client::xrpc::{XrpcClient, XrpcRequest};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -268,6 +269,9 @@ pub struct LabelerPreferences {
     /// Whether to show adult content from this labeler
     #[serde(default)]
     pub show_adult_content: bool,
+    /// Whether content taken down by this labeler is removed rather than labeled
+    #[serde(default)]
+    pub redact: bool,
 }
 
 impl LabelerPreferences {
@@ -356,6 +360,36 @@ impl LabelerSubscriptions {
     }
 }
 
+/// Subscribed labelers are accepted on AppView reads, sorted by DID, with the
+/// `redact` flag taken from each labeler's preferences.
+///
+/// Wrap the subscriptions in a lock to share them with
+/// [`BskyAgent::set_labeler_provider`](atproto_client::BskyAgent::set_labeler_provider):
+///
+/// ```rust
+/// use atproto_client::BskyAgent;
+/// use moderation::labels::LabelerSubscriptions;
+/// use std::sync::{Arc, RwLock};
+///
+/// let subscriptions = Arc::new(RwLock::new(LabelerSubscriptions::new()));
+/// let agent = BskyAgent::new("https://bsky.social").unwrap();
+/// agent.set_labeler_provider(Some(subscriptions.clone()));
+///
+/// subscriptions.write().unwrap().subscribe("did:plc:ar7c4by46qjdydhdevvrndac");
+/// ```
+impl LabelerProvider for LabelerSubscriptions {
+    fn accepted_labelers(&self) -> Vec<AcceptedLabeler> {
+        let mut dids: Vec<&String> = self.subscribed.iter().collect();
+        dids.sort();
+        dids.into_iter()
+            .map(|did| {
+                let redact = self.preferences.get(did).is_some_and(|prefs| prefs.redact);
+                AcceptedLabeler::new(did.as_str()).with_redact(redact)
+            })
+            .collect()
+    }
+}
+
 /// Result of applying labels to content
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LabelResult {
@@ -855,6 +889,22 @@ mod tests {
         assert!(list.contains(&"did:plc:labeler2"));
     }
 
+    #[test]
+    fn test_subscriptions_accepted_labelers() {
+        let mut subs = LabelerSubscriptions::new();
+        subs.subscribe("did:plc:labeler2");
+        subs.subscribe("did:plc:labeler1");
+        subs.get_preferences_mut("did:plc:labeler2").unwrap().redact = true;
+
+        assert_eq!(
+            subs.accepted_labelers(),
+            vec![
+                AcceptedLabeler::new("did:plc:labeler1"),
+                AcceptedLabeler::new("did:plc:labeler2").with_redact(true),
+            ]
+        );
+    }
+
     // Label result tests
 
     #[test]
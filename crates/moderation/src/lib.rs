@@ -21,7 +21,7 @@ pub mod reporting;
 // Re-export commonly used types
 pub use blocking::{
     BlockError, BlockService, BlockedProfileView, GetBlocksResponse, GetMutesResponse,
-    MutedProfileView,
+    ImportBlocksResult, MutedProfileView,
 };
 pub use filtering::{ContentFilter, FilterPreferences, FilterReason, FilterResult};
 pub use reporting::{ReportError, ReportReason, ReportService, ReportSubject};
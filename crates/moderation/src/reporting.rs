@@ -11,6 +11,10 @@
 //! - **Lists/Feeds**: Report curated content for violations
 //! - **Chat Messages**: Report direct messages for harassment, spam, etc.
 //!
+//! Reports go to the PDS's default moderation service unless sent to a specific
+//! labeler with [`ReportService::report_to`], which proxies the request to the
+//! labeler's `#atproto_labeler` service.
+//!
 //! # Example
 //!
 //! ```rust,no_run
@@ -44,6 +48,7 @@
 //! }
 //! ```
 
+use atproto_client::proxy::ServiceProxy;
 use atproto_client::xrpc::{XrpcClient, XrpcRequest};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -423,6 +428,42 @@ impl ReportService {
         reason: ReportReason,
         subject: ReportSubject,
         details: Option<String>,
+    ) -> Result<ReportResult> {
+        self.send_report(None, reason, subject, details).await
+    }
+
+    /// Submit a report to a specific labeler
+    ///
+    /// The request is proxied through the PDS to the labeler's moderation
+    /// service, e.g. `ServiceProxy::labeler("did:plc:...")`.
+    ///
+    /// # Arguments
+    ///
+    /// * `labeler` - The labeler service to report to
+    /// * `reason` - The reason for the report
+    /// * `subject` - What is being reported
+    /// * `details` - Optional additional details about the report
+    ///
+    /// # Returns
+    ///
+    /// Report result with ID and timestamp
+    pub async fn report_to(
+        &self,
+        labeler: &ServiceProxy,
+        reason: ReportReason,
+        subject: ReportSubject,
+        details: Option<String>,
+    ) -> Result<ReportResult> {
+        self.send_report(Some(labeler), reason, subject, details)
+            .await
+    }
+
+    async fn send_report(
+        &self,
+        proxy: Option<&ServiceProxy>,
+        reason: ReportReason,
+        subject: ReportSubject,
+        details: Option<String>,
     ) -> Result<ReportResult> {
         // Validate details length
         if let Some(ref d) = details {
@@ -437,9 +478,12 @@ impl ReportService {
             reason: details,
         };
 
-        let request = XrpcRequest::procedure("com.atproto.moderation.createReport")
+        let mut request = XrpcRequest::procedure("com.atproto.moderation.createReport")
             .json_body(&request_body)
             .map_err(|e| ReportError::ApiError(e.to_string()))?;
+        if let Some(proxy) = proxy {
+            request = request.proxy(proxy);
+        }
 
         let client = self.client.read().await;
         let response = client
@@ -1173,4 +1217,45 @@ mod tests {
 
         assert_eq!(set.len(), 2);
     }
+
+    #[tokio::test]
+    async fn test_report_to_labeler_sets_proxy_header() {
+        use atproto_client::proxy::PROXY_HEADER;
+        use atproto_client::transport::MockTransport;
+        use atproto_client::xrpc::XrpcClientConfig;
+
+        let transport = Arc::new(MockTransport::new().with_json(
+            "com.atproto.moderation.createReport",
+            200,
+            serde_json::json!({
+                "id": 42,
+                "reasonType": "com.atproto.moderation.defs#reasonSpam",
+                "subject": {"$type": "com.atproto.admin.defs#repoRef", "did": "did:plc:spammer"},
+                "reportedBy": "did:plc:alice",
+                "createdAt": "2024-01-01T00:00:00Z"
+            }),
+        ));
+        let config = XrpcClientConfig::default().with_transport(transport.clone());
+        let service = ReportService::new(XrpcClient::new(config));
+
+        let labeler = ServiceProxy::labeler("did:plc:ar7c4by46qjdydhdevvrndac").unwrap();
+        let subject = ReportSubject::Account(AccountSubject::new("did:plc:spammer"));
+        let result = service
+            .report_to(&labeler, ReportReason::Spam, subject, None)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 42);
+
+        service
+            .report_account("did:plc:spammer", ReportReason::Spam, None)
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(
+            requests[0].header(PROXY_HEADER),
+            Some("did:plc:ar7c4by46qjdydhdevvrndac#atproto_labeler")
+        );
+        assert_eq!(requests[1].header(PROXY_HEADER), None);
+    }
 }
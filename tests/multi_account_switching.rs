@@ -31,6 +31,8 @@ fn create_test_account(did: &str, handle: &str) -> SessionAccount {
         signup_queued: Some(false),
         is_self_hosted: Some(false),
         app_view_url: None,
+        oauth: None,
+        proxy_router: None,
     }
 }
 
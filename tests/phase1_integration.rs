@@ -34,6 +34,8 @@ async fn test_session_lifecycle_with_persistence() {
             signup_queued: Some(false),
             is_self_hosted: Some(false),
             app_view_url: None,
+            oauth: None,
+            proxy_router: None,
         };
 
         manager.add_account(account).await.unwrap();
@@ -75,6 +77,8 @@ async fn test_multi_account_isolation() {
         signup_queued: Some(false),
         is_self_hosted: Some(false),
         app_view_url: None,
+        oauth: None,
+        proxy_router: None,
     };
 
     let account2 = SessionAccount {
@@ -92,6 +96,8 @@ async fn test_multi_account_isolation() {
         signup_queued: Some(false),
         is_self_hosted: Some(false),
         app_view_url: None,
+        oauth: None,
+        proxy_router: None,
     };
 
     manager.add_account(account1).await.unwrap();
@@ -231,6 +237,8 @@ async fn test_complete_user_scenario() {
         signup_queued: Some(false),
         is_self_hosted: Some(false),
         app_view_url: None,
+        oauth: None,
+        proxy_router: None,
     };
 
     session_manager.add_account(user_account).await.unwrap();
@@ -319,6 +327,8 @@ async fn test_error_handling() {
         signup_queued: Some(false),
         is_self_hosted: Some(false),
         app_view_url: None,
+        oauth: None,
+        proxy_router: None,
     };
 
     manager.add_account(account.clone()).await.unwrap();